@@ -0,0 +1,119 @@
+//! Stable denial reason codes shared by the corridor crates, so logs and
+//! APIs can count denials by reason without matching on message text.
+
+#![forbid(unsafe_code)]
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Why an action was denied or held. Serializes as its `code()`; codes
+/// never change once published, new reasons get new variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DenyCode {
+    /// The action names a different corridor than the one checked.
+    CorridorMismatch,
+    /// No corridor is registered under the action's id.
+    CorridorNotRegistered,
+    /// No consent credential or decision exists.
+    FpicAbsent,
+    /// Consent is still pending.
+    FpicPending,
+    /// Consent is not granted or was revoked.
+    FpicRevoked,
+    /// Consent was withheld by the communities.
+    FpicWithheld,
+    /// Consent is outside its validity window.
+    FpicExpired,
+    /// The consent signature did not verify.
+    FpicUnverified,
+    /// The corridor's aggregate eco score is below the action's minimum.
+    EcoBelowMin,
+    /// One eco metric is below the action's minimum for it.
+    EcoMetricBelowMin,
+    /// FEAR/PAIN channels are forbidden.
+    NrFearPain,
+    /// Covert mental-state inference is forbidden.
+    NrCovertInference,
+    /// Belief-shaping or mental manipulation is forbidden.
+    NrBeliefShaping,
+    /// An overlay-defined policy gate did not hold.
+    PolicyGate,
+}
+
+impl DenyCode {
+    pub const ALL: [DenyCode; 14] = [
+        DenyCode::CorridorMismatch,
+        DenyCode::CorridorNotRegistered,
+        DenyCode::FpicAbsent,
+        DenyCode::FpicPending,
+        DenyCode::FpicRevoked,
+        DenyCode::FpicWithheld,
+        DenyCode::FpicExpired,
+        DenyCode::FpicUnverified,
+        DenyCode::EcoBelowMin,
+        DenyCode::EcoMetricBelowMin,
+        DenyCode::NrFearPain,
+        DenyCode::NrCovertInference,
+        DenyCode::NrBeliefShaping,
+        DenyCode::PolicyGate,
+    ];
+
+    /// Stable machine code, e.g. "FPIC_ABSENT".
+    pub fn code(self) -> &'static str {
+        match self {
+            DenyCode::CorridorMismatch => "CORRIDOR_MISMATCH",
+            DenyCode::CorridorNotRegistered => "CORRIDOR_NOT_REGISTERED",
+            DenyCode::FpicAbsent => "FPIC_ABSENT",
+            DenyCode::FpicPending => "FPIC_PENDING",
+            DenyCode::FpicRevoked => "FPIC_REVOKED",
+            DenyCode::FpicWithheld => "FPIC_WITHHELD",
+            DenyCode::FpicExpired => "FPIC_EXPIRED",
+            DenyCode::FpicUnverified => "FPIC_UNVERIFIED",
+            DenyCode::EcoBelowMin => "ECO_BELOW_MIN",
+            DenyCode::EcoMetricBelowMin => "ECO_METRIC_BELOW_MIN",
+            DenyCode::NrFearPain => "NR_FEAR_PAIN",
+            DenyCode::NrCovertInference => "NR_COVERT_INFERENCE",
+            DenyCode::NrBeliefShaping => "NR_BELIEF_SHAPING",
+            DenyCode::PolicyGate => "POLICY_GATE",
+        }
+    }
+
+    /// Inverse of `code()`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        DenyCode::ALL.into_iter().find(|c| c.code() == code)
+    }
+}
+
+impl fmt::Display for DenyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for DenyCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        DenyCode::from_code(s).ok_or_else(|| format!("unknown deny code '{}'", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_stable_and_serialize_as_themselves() {
+        for code in DenyCode::ALL {
+            assert_eq!(DenyCode::from_code(code.code()), Some(code));
+            assert_eq!(serde_json::to_value(code).unwrap(), code.code());
+        }
+        assert_eq!("FPIC_ABSENT".parse::<DenyCode>(), Ok(DenyCode::FpicAbsent));
+    }
+}
@@ -0,0 +1,118 @@
+//! Strict DID syntax, per the DID Core ABNF:
+//!
+//! ```text
+//! did                = "did:" method-name ":" method-specific-id
+//! method-name        = 1*method-char
+//! method-char        = %x61-7A / DIGIT
+//! method-specific-id = *( *idchar ":" ) 1*idchar
+//! idchar             = ALPHA / DIGIT / "." / "-" / "_" / pct-encoded
+//! pct-encoded        = "%" HEXDIG HEXDIG
+//! ```
+//!
+//! `CorridorId::new` accepts more (legacy names, any-case scheme and
+//! method, DID URL parts); `CorridorId::new_strict` accepts only this.
+
+#![forbid(unsafe_code)]
+
+use core::fmt;
+
+/// Why an ID is not a bare DID. Indexes are byte offsets into the ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DidSyntaxError {
+    /// Does not start with the lowercase `did:` scheme.
+    MissingScheme,
+    /// No method name between `did:` and the next `:`.
+    EmptyMethod,
+    /// Method names are lowercase ASCII letters and digits only.
+    InvalidMethodChar { index: usize, ch: char },
+    /// No `:` and method-specific id after the method name.
+    MissingMethodSpecificId,
+    /// The method-specific id is empty or ends with `:`.
+    EmptyLastSegment,
+    /// A character outside `idchar`.
+    InvalidIdChar { index: usize, ch: char },
+    /// A `%` not followed by two hex digits.
+    InvalidPercentEncoding { index: usize },
+    /// A DID URL path, query or fragment (`/`, `?`, `#`); corridor IDs
+    /// name the DID itself.
+    DidUrlComponent { index: usize, ch: char },
+}
+
+impl fmt::Display for DidSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DidSyntaxError::MissingScheme => f.write_str("must start with 'did:'"),
+            DidSyntaxError::EmptyMethod => f.write_str("method name is empty"),
+            DidSyntaxError::InvalidMethodChar { index, ch } => write!(
+                f,
+                "method name has {:?} at byte {}; only a-z and 0-9 are allowed",
+                ch, index
+            ),
+            DidSyntaxError::MissingMethodSpecificId => {
+                f.write_str("missing ':' and method-specific id after the method name")
+            }
+            DidSyntaxError::EmptyLastSegment => {
+                f.write_str("method-specific id is empty or ends with ':'")
+            }
+            DidSyntaxError::InvalidIdChar { index, ch } => write!(
+                f,
+                "method-specific id has {:?} at byte {}; allowed are letters, digits, \
+                 '.', '-', '_', ':' and %-escapes",
+                ch, index
+            ),
+            DidSyntaxError::InvalidPercentEncoding { index } => {
+                write!(f, "'%' at byte {} is not followed by two hex digits", index)
+            }
+            DidSyntaxError::DidUrlComponent { index, ch } => write!(
+                f,
+                "{:?} at byte {} starts a DID URL component; only the bare DID is allowed",
+                ch, index
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DidSyntaxError {}
+
+/// Check `did` against the DID Core grammar, reporting the first problem.
+pub fn check_strict_did(did: &str) -> Result<(), DidSyntaxError> {
+    let rest = did
+        .strip_prefix("did:")
+        .ok_or(DidSyntaxError::MissingScheme)?;
+    let method_len = rest.find(':').unwrap_or(rest.len());
+    if method_len == 0 {
+        return Err(DidSyntaxError::EmptyMethod);
+    }
+    if let Some((i, ch)) = rest[..method_len]
+        .char_indices()
+        .find(|&(_, c)| !matches!(c, 'a'..='z' | '0'..='9'))
+    {
+        return Err(DidSyntaxError::InvalidMethodChar { index: 4 + i, ch });
+    }
+    if method_len == rest.len() {
+        return Err(DidSyntaxError::MissingMethodSpecificId);
+    }
+
+    let start = 4 + method_len + 1;
+    let id = &did[start..];
+    let bytes = id.as_bytes();
+    for (i, ch) in id.char_indices() {
+        let index = start + i;
+        match ch {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '.' | '-' | '_' | ':' => {}
+            '%' => {
+                if !(bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+                    && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit))
+                {
+                    return Err(DidSyntaxError::InvalidPercentEncoding { index });
+                }
+            }
+            '/' | '?' | '#' => return Err(DidSyntaxError::DidUrlComponent { index, ch }),
+            _ => return Err(DidSyntaxError::InvalidIdChar { index, ch }),
+        }
+    }
+    if id.is_empty() || id.ends_with(':') {
+        return Err(DidSyntaxError::EmptyLastSegment);
+    }
+    Ok(())
+}
@@ -0,0 +1,234 @@
+//! Canonical FPIC (free, prior and informed consent) status: whether a
+//! corridor's data may be used, and for which purposes, in the form the
+//! corridor crates exchange it.
+
+#![forbid(unsafe_code)]
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::time::Timestamp;
+use crate::validate::{into_result, Finding, Validate};
+
+/// A use of corridor data or land that consent can be scoped to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FpicPurpose {
+    Research,
+    Monitoring,
+    Restoration,
+    Infrastructure,
+    /// Passing data on to parties outside the grant.
+    DataSharing,
+}
+
+impl FpicPurpose {
+    pub const ALL: [FpicPurpose; 5] = [
+        FpicPurpose::Research,
+        FpicPurpose::Monitoring,
+        FpicPurpose::Restoration,
+        FpicPurpose::Infrastructure,
+        FpicPurpose::DataSharing,
+    ];
+
+    /// Stable snake_case key used in interchange formats and tokens.
+    pub fn key(self) -> &'static str {
+        match self {
+            FpicPurpose::Research => "research",
+            FpicPurpose::Monitoring => "monitoring",
+            FpicPurpose::Restoration => "restoration",
+            FpicPurpose::Infrastructure => "infrastructure",
+            FpicPurpose::DataSharing => "data_sharing",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.key() == key.trim())
+    }
+}
+
+impl fmt::Display for FpicPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// FPIC / Indigenous Data Sovereignty status, treated as a mandatory,
+/// schema-level precondition for any use of corridor-linked data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FpicStatus {
+    /// No FPIC decision or scope established for this corridor/context.
+    Pending,
+    /// Explicit FPIC granted, under the conditions of the grant.
+    Granted(FpicGrant),
+    /// FPIC withheld or revoked, with human-readable reason / link.
+    Withheld { reason: String },
+}
+
+impl FpicStatus {
+    /// True if this is a grant still in force at `at`.
+    pub fn is_active_grant(&self, at: Timestamp) -> bool {
+        matches!(self, FpicStatus::Granted(grant) if grant.is_active_at(at))
+    }
+
+    /// True if this is a grant in force at `at` that covers `purpose`.
+    pub fn permits(&self, at: Timestamp, purpose: FpicPurpose) -> bool {
+        matches!(
+            self,
+            FpicStatus::Granted(grant) if grant.is_active_at(at) && grant.covers(purpose)
+        )
+    }
+}
+
+/// Area a grant is limited to, as a bounding box in the coordinates of
+/// the corridor's geometry (`[x, y]`, e.g. lon/lat degrees).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GeographicScope {
+    pub min: [f64; 2],
+    pub max: [f64; 2],
+}
+
+impl GeographicScope {
+    /// True if `point` lies inside the box, edges included.
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        (0..2).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+}
+
+/// Conditions of an FPIC grant. The full terms live on the ledger under
+/// `consent_ref`; the conditions here are the parts that can be checked
+/// locally, without reaching the ledger.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FpicGrant {
+    /// External reference (e.g., ALN shard / ledger entry) describing
+    /// scope, conditions, and revocation rules.
+    pub consent_ref: String,
+    /// End of the grant (exclusive); `None` for grants without a term.
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+    /// Uses the communities consented to; the grant covers nothing else.
+    #[serde(default)]
+    pub purposes: BTreeSet<FpicPurpose>,
+    /// Area the grant is limited to; `None` covers the whole corridor.
+    #[serde(default)]
+    pub geographic_scope: Option<GeographicScope>,
+}
+
+impl FpicGrant {
+    /// A corridor-wide grant without a term.
+    pub fn new(
+        consent_ref: impl Into<String>,
+        purposes: impl IntoIterator<Item = FpicPurpose>,
+    ) -> Self {
+        Self {
+            consent_ref: consent_ref.into(),
+            expires_at: None,
+            purposes: purposes.into_iter().collect(),
+            geographic_scope: None,
+        }
+    }
+
+    pub fn expires_at(mut self, at: Timestamp) -> Self {
+        self.expires_at = Some(at);
+        self
+    }
+
+    pub fn geographic_scope(mut self, scope: GeographicScope) -> Self {
+        self.geographic_scope = Some(scope);
+        self
+    }
+
+    /// True until `expires_at`, if any.
+    pub fn is_active_at(&self, at: Timestamp) -> bool {
+        self.expires_at.is_none_or(|end| at < end)
+    }
+
+    pub fn covers(&self, purpose: FpicPurpose) -> bool {
+        self.purposes.contains(&purpose)
+    }
+
+    /// True if `point` lies in the geographic scope, or the grant has none.
+    pub fn covers_point(&self, point: [f64; 2]) -> bool {
+        self.geographic_scope
+            .is_none_or(|scope| scope.contains(point))
+    }
+}
+
+impl Validate for FpicStatus {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let finding = match self {
+            FpicStatus::Granted(grant) => return grant.validate(),
+            FpicStatus::Withheld { reason } if reason.trim().is_empty() => {
+                Some(Finding::new("reason", "withholding must give a reason"))
+            }
+            _ => None,
+        };
+        into_result(finding.into_iter().collect())
+    }
+}
+
+impl Validate for FpicGrant {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Vec::new();
+        if self.consent_ref.trim().is_empty() {
+            findings.push(Finding::new(
+                "consent_ref",
+                "grant must reference its consent",
+            ));
+        }
+        if self.purposes.is_empty() {
+            findings.push(Finding::new(
+                "purposes",
+                "grant must name the uses consented to",
+            ));
+        }
+        if let Some(scope) = &self.geographic_scope {
+            let finite = scope.min.iter().chain(&scope.max).all(|c| c.is_finite());
+            if !finite || scope.min[0] > scope.max[0] || scope.min[1] > scope.max[1] {
+                findings.push(Finding::new(
+                    "geographic_scope",
+                    "scope must be a finite box with min <= max",
+                ));
+            }
+        }
+        into_result(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+    use std::time::SystemTime;
+
+    #[test]
+    fn grant_expiry_reads_documents_written_with_system_time() {
+        let end = SystemTime::UNIX_EPOCH + Duration::new(3_600, 250);
+        let written = serde_json::json!({
+            "consent_ref": "aln:consent:1",
+            "expires_at": end,
+            "purposes": ["monitoring"],
+        });
+        let status = FpicStatus::Granted(serde_json::from_value(written.clone()).unwrap());
+        assert_eq!(status.validate(), Ok(()));
+        assert!(status.permits(Timestamp::UNIX_EPOCH, FpicPurpose::Monitoring));
+        assert!(!status.permits(Timestamp::UNIX_EPOCH, FpicPurpose::Research));
+        assert!(!status.is_active_grant(end.into()));
+
+        let FpicStatus::Granted(grant) = &status else {
+            unreachable!()
+        };
+        assert_eq!(
+            serde_json::to_value(grant).unwrap()["expires_at"],
+            written["expires_at"]
+        );
+        let bad_nanos =
+            serde_json::json!({"secs_since_epoch": 0, "nanos_since_epoch": 1_000_000_000u32});
+        assert!(serde_json::from_value::<Timestamp>(bad_nanos).is_err());
+    }
+}
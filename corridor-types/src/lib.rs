@@ -0,0 +1,334 @@
+//! Canonical corridor types.
+//! `CorridorId` is defined once here and re-exported by every crate that
+//! names corridors, so IDs parsed anywhere compare equal everywhere.
+//!
+//! `DenyCode` is likewise shared, so denials from every crate are counted
+//! under the same codes, and so are `CommunityId`, `Timestamp` and the
+//! `Validate` trait.
+//!
+//! `EcoImpactMetrics`, `FpicStatus` and `NeurorightsConstraints` are the
+//! canonical metric, FPIC and neurorights types. The morpheus
+//! eco-corridor-core re-exports them as its own; the map crate and
+//! `crates/eco-corridor-core` keep richer models and convert to and from
+//! these, with the field mapping documented at each conversion, so
+//! crates exchange corridors through one form instead of pairwise
+//! adapters.
+//!
+//! The crate is `no_std` with `alloc` when the default `std` feature is
+//! off, so the precondition kernels built on it run on field gateways.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![forbid(unsafe_code)]
+
+extern crate alloc;
+
+pub mod deny;
+pub mod did;
+pub mod fpic;
+pub mod metrics;
+pub mod neurorights;
+pub mod time;
+pub mod validate;
+
+pub use deny::DenyCode;
+pub use did::DidSyntaxError;
+pub use fpic::{FpicGrant, FpicPurpose, FpicStatus, GeographicScope};
+pub use metrics::EcoImpactMetrics;
+pub use neurorights::NeurorightsConstraints;
+pub use time::Timestamp;
+pub use validate::{Finding, Validate};
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// Opaque, non-empty corridor identifier.
+/// Examples: "territory:nation-x:river-y", "eco:desert:phoenix",
+/// "did:example:corridor:Phoenix_Desert#north".
+///
+/// Two forms are accepted and normalized at construction (including
+/// deserialization and `FromStr`) so that IDs from config files, CLIs,
+/// and HTTP paths resolve to the same map key. Input is first trimmed
+/// and converted to Unicode NFC, so precomposed and combining spellings
+/// of the same name match.
+///
+/// DID form (starts with `did:`, any case): parsed per DID syntax into a
+/// method, a method-specific id, and optional path, query, and fragment
+/// (see `did()`). Only the `did` scheme and the method are lowercased;
+/// the rest is case-sensitive and kept verbatim.
+///
+/// Legacy names (anything else), e.g. "Eco: Desert : Phoenix_North" and
+/// "eco:desert:phoenix-north" are the same ID:
+/// - case is folded to lowercase;
+/// - `:` separates segments; whitespace around segments is trimmed;
+/// - within a segment, runs of whitespace, `_` and `-` become one `-`,
+///   and leading/trailing `-` are dropped;
+/// - empty segments are rejected.
+///
+/// Ordering and `Display` use the normalized form, so sorted listings
+/// agree with map iteration order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "String")]
+pub struct CorridorId(String);
+
+/// Components of a DID-form `CorridorId`, borrowed from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DidParts<'a> {
+    /// Lowercase method name, e.g. "example" or "web".
+    pub method: &'a str,
+    /// Method-specific id; may itself contain `:`.
+    pub method_specific_id: &'a str,
+    /// Path including its leading `/`.
+    pub path: Option<&'a str>,
+    /// Query without the leading `?`.
+    pub query: Option<&'a str>,
+    /// Fragment without the leading `#`.
+    pub fragment: Option<&'a str>,
+}
+
+impl CorridorId {
+    /// Create a new CorridorId in normalized form, rejecting empty IDs,
+    /// legacy IDs with empty segments, and malformed DIDs.
+    pub fn new(id: impl AsRef<str>) -> Result<Self, String> {
+        let nfc: String = id.as_ref().trim().nfc().collect();
+        let s = nfc.as_str();
+        if s.is_empty() {
+            return Err("CorridorId must be non-empty".into());
+        }
+        if s.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("did:")) {
+            let parts = split_did(&s[4..])
+                .map_err(|e| format!("CorridorId '{}' is not a valid DID: {}", s, e))?;
+            return Ok(Self(format!(
+                "did:{}:{}",
+                parts.method.to_ascii_lowercase(),
+                &s[4 + parts.method.len() + 1..]
+            )));
+        }
+        let mut segments = Vec::new();
+        for raw in s.split(':') {
+            let segment = normalize_segment(raw);
+            if segment.is_empty() {
+                return Err(format!("CorridorId '{}' has an empty segment", s));
+            }
+            segments.push(segment);
+        }
+        Ok(Self(segments.join(":")))
+    }
+
+    /// Strict counterpart of `new` and `FromStr`, for registries that
+    /// should refuse anything but a bare DID: after trimming, `id` must
+    /// match the DID Core grammar exactly (see `did`), so legacy names,
+    /// uppercase schemes or methods and DID URL parts are rejected.
+    pub fn new_strict(id: impl AsRef<str>) -> Result<Self, DidSyntaxError> {
+        let id = id.as_ref().trim();
+        did::check_strict_did(id)?;
+        Ok(Self(id.into()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// DID components, or `None` for legacy names.
+    pub fn did(&self) -> Option<DidParts<'_>> {
+        let rest = self.0.strip_prefix("did:")?;
+        Some(split_did(rest).expect("validated at construction"))
+    }
+
+    pub fn is_did(&self) -> bool {
+        self.0.starts_with("did:")
+    }
+}
+
+/// Split and validate everything after `did:`.
+pub fn split_did(rest: &str) -> Result<DidParts<'_>, String> {
+    let (method, tail) = rest.split_once(':').ok_or("missing method-specific id")?;
+    if method.is_empty() || !method.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(format!(
+            "method '{}' must be non-empty and alphanumeric",
+            method
+        ));
+    }
+    let (tail, fragment) = match tail.split_once('#') {
+        Some((t, f)) => (t, Some(f)),
+        None => (tail, None),
+    };
+    let (tail, query) = match tail.split_once('?') {
+        Some((t, q)) => (t, Some(q)),
+        None => (tail, None),
+    };
+    let (method_specific_id, path) = match tail.find('/') {
+        Some(i) => (&tail[..i], Some(&tail[i..])),
+        None => (tail, None),
+    };
+    let id_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '%' | ':');
+    if method_specific_id.is_empty()
+        || method_specific_id.ends_with(':')
+        || !method_specific_id.chars().all(id_char)
+    {
+        return Err(format!(
+            "method-specific id '{}' is malformed",
+            method_specific_id
+        ));
+    }
+    if [path, query, fragment]
+        .iter()
+        .flatten()
+        .any(|part| part.chars().any(char::is_whitespace))
+    {
+        return Err("path, query, and fragment must not contain whitespace".into());
+    }
+    Ok(DidParts {
+        method,
+        method_specific_id,
+        path,
+        query,
+        fragment,
+    })
+}
+
+impl FromStr for CorridorId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for CorridorId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, String> {
+        Self::new(value)
+    }
+}
+
+/// Normalize one `:`-separated segment of a legacy name; see `CorridorId`.
+pub fn normalize_segment(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut pending_dash = false;
+    for ch in raw.chars() {
+        if ch.is_whitespace() || ch == '_' || ch == '-' {
+            pending_dash = !out.is_empty();
+        } else {
+            if pending_dash {
+                out.push('-');
+                pending_dash = false;
+            }
+            out.extend(ch.to_lowercase());
+        }
+    }
+    out
+}
+
+impl fmt::Display for CorridorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Minimal community identifier for ALN / DID / IDS ledgers, shared so
+/// stewardship and consent name communities the same way in every crate.
+///
+/// The field is unchecked so legacy ledger names keep loading; `new`
+/// accepts only DIDs, and `did` tells the two apart.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CommunityId(pub String);
+
+impl CommunityId {
+    /// A community identified by a DID, e.g. "did:web:nation-x.example".
+    pub fn new(did: impl Into<String>) -> Result<Self, String> {
+        let id = Self(did.into());
+        id.did()?;
+        Ok(id)
+    }
+
+    /// DID components, or why the id is not a DID.
+    pub fn did(&self) -> Result<DidParts<'_>, String> {
+        let rest = self
+            .0
+            .strip_prefix("did:")
+            .ok_or_else(|| format!("CommunityId '{}' is not a DID", self.0))?;
+        split_did(rest).map_err(|e| format!("CommunityId '{}' is not a valid DID: {}", self.0, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_normalized() {
+        let id = CorridorId::new("  Eco: Desert : Phoenix__North- ").unwrap();
+        assert_eq!(id.as_str(), "eco:desert:phoenix-north");
+        assert_eq!(id, CorridorId::new("eco:desert:phoenix-north").unwrap());
+        assert!(CorridorId::new("eco::desert").is_err());
+        assert!(CorridorId::new("eco:_:desert").is_err());
+
+        let parsed: CorridorId = serde_json::from_str("\"ECO:River Y\"").unwrap();
+        assert_eq!(parsed.as_str(), "eco:river-y");
+        assert!(parsed.did().is_none());
+
+        let composed: CorridorId = "eco:Caf\u{e9}".parse().unwrap();
+        let combining: CorridorId = "ECO:cafe\u{301}".parse().unwrap();
+        assert_eq!(composed, combining);
+        assert_eq!(composed.to_string(), "eco:caf\u{e9}");
+    }
+
+    #[test]
+    fn did_ids_parse_into_components() {
+        let id = CorridorId::new(" DID:Example:corridor:Phoenix_Desert/zones?v=2#north ").unwrap();
+        assert_eq!(
+            id.as_str(),
+            "did:example:corridor:Phoenix_Desert/zones?v=2#north"
+        );
+        let did = id.did().unwrap();
+        assert_eq!(did.method, "example");
+        assert_eq!(did.method_specific_id, "corridor:Phoenix_Desert");
+        assert_eq!(did.path, Some("/zones"));
+        assert_eq!(did.query, Some("v=2"));
+        assert_eq!(did.fragment, Some("north"));
+
+        assert!(CorridorId::new("did:example").is_err());
+        assert!(CorridorId::new("did:ex-ample:x").is_err());
+        assert!(CorridorId::new("did:example:has space").is_err());
+        assert!(CorridorId::new("did:example:trailing:").is_err());
+    }
+
+    #[test]
+    fn strict_mode_accepts_only_bare_dids() {
+        let id = CorridorId::new_strict(" did:example:corridor:Phoenix_Desert%2Dn ").unwrap();
+        assert_eq!(id, CorridorId::new(id.as_str()).unwrap());
+        assert!(CorridorId::new_strict("did:web::a").is_ok());
+
+        let err = |s: &str| CorridorId::new_strict(s).unwrap_err();
+        assert_eq!(err("eco:desert:phoenix"), DidSyntaxError::MissingScheme);
+        assert_eq!(err("DID:example:x"), DidSyntaxError::MissingScheme);
+        assert_eq!(err("did::x"), DidSyntaxError::EmptyMethod);
+        assert_eq!(
+            err("did:Example:x"),
+            DidSyntaxError::InvalidMethodChar { index: 4, ch: 'E' }
+        );
+        assert_eq!(err("did:example"), DidSyntaxError::MissingMethodSpecificId);
+        assert_eq!(err("did:example:a:"), DidSyntaxError::EmptyLastSegment);
+        assert_eq!(
+            err("did:example:caf\u{e9}"),
+            DidSyntaxError::InvalidIdChar { index: 15, ch: '\u{e9}' }
+        );
+        assert_eq!(
+            err("did:example:a%2"),
+            DidSyntaxError::InvalidPercentEncoding { index: 13 }
+        );
+        assert_eq!(
+            err("did:example:a#north"),
+            DidSyntaxError::DidUrlComponent { index: 13, ch: '#' }
+        );
+    }
+}
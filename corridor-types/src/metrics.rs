@@ -0,0 +1,66 @@
+//! Canonical eco-impact scores: one score per living system, the common
+//! denominator the corridor crates convert their richer metrics through.
+
+#![forbid(unsafe_code)]
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validate::{check_unit_interval, into_result, Finding, Validate};
+
+/// Eco-impact metrics over soil, water, microbiomes, and biodiversity.
+/// All values are normalized to 0.0–1.0 where 1.0 is least harm / best
+/// observed state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EcoImpactMetrics {
+    pub soil_score: f32,
+    pub water_score: f32,
+    pub microbiome_score: f32,
+    pub biodiversity_score: f32,
+}
+
+impl EcoImpactMetrics {
+    /// Scores clamped into [0.0, 1.0].
+    pub fn new(soil: f32, water: f32, micro: f32, bio: f32) -> Self {
+        Self {
+            soil_score: soil.clamp(0.0, 1.0),
+            water_score: water.clamp(0.0, 1.0),
+            microbiome_score: micro.clamp(0.0, 1.0),
+            biodiversity_score: bio.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Simple aggregate, used only for advisory scoring / classification.
+    /// This must NEVER be used to drive actuators or automatic land-use
+    /// changes.
+    pub fn aggregate(&self) -> f32 {
+        self.scores().iter().sum::<f32>() / 4.0
+    }
+
+    /// Soil, water, microbiome and biodiversity scores, in that order.
+    pub fn scores(&self) -> [f32; 4] {
+        [
+            self.soil_score,
+            self.water_score,
+            self.microbiome_score,
+            self.biodiversity_score,
+        ]
+    }
+}
+
+impl Validate for EcoImpactMetrics {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        into_result(
+            [
+                ("soil_score", self.soil_score),
+                ("water_score", self.water_score),
+                ("microbiome_score", self.microbiome_score),
+                ("biodiversity_score", self.biodiversity_score),
+            ]
+            .into_iter()
+            .filter_map(|(path, value)| check_unit_interval(path, value))
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+//! Canonical neurorights capsule: the four guarantees every corridor
+//! crate can state, whatever finer-grained constraints it keeps.
+
+#![forbid(unsafe_code)]
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validate::{Finding, Validate};
+
+/// Minimal neurorights constraint capsule for corridor-linked knowledge
+/// objects. Importantly, this is **purely declarative** metadata: it has
+/// no methods that change state or actuate anything. It only labels
+/// obligations.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeurorightsConstraints {
+    /// Mental privacy: no covert inference from corridor data into inner
+    /// mental state.
+    pub mental_privacy_protection: bool,
+    /// Explicit prohibition on coercive neuromorphic channels linked to
+    /// this corridor.
+    pub forbid_coercive_channels: bool,
+    /// Explicit prohibition on downgrades/rollbacks of rights or
+    /// capabilities as a result of any analysis of this corridor.
+    pub forbid_downgrade_or_rollback: bool,
+    /// True iff any discipline (FEAR/PAIN) signals tied to this corridor
+    /// are explicitly voluntary, labeled, and non-propagating to
+    /// non-participants.
+    pub discipline_personalized_and_noncoercive: bool,
+}
+
+impl NeurorightsConstraints {
+    /// Every guarantee set: the default for Indigenous eco-corridor
+    /// objects.
+    pub fn strict_floor() -> Self {
+        Self {
+            mental_privacy_protection: true,
+            forbid_coercive_channels: true,
+            forbid_downgrade_or_rollback: true,
+            discipline_personalized_and_noncoercive: true,
+        }
+    }
+}
+
+impl Validate for NeurorightsConstraints {
+    /// Every combination of flags is a valid (if weak) capsule.
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+//! Clock-free timestamps for the corridor kernels.
+//! `SystemTime` needs an OS clock, which field gateways may lack, so the
+//! shared types take `Timestamp`s; with the `std` feature they convert to
+//! and from `SystemTime`.
+
+#![forbid(unsafe_code)]
+
+use core::fmt;
+use core::ops::Add;
+use core::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An instant as its offset from the Unix epoch. Earlier instants are not
+/// representable; converting one from `SystemTime` yields the epoch.
+///
+/// Serializes in the same shape as serde's `SystemTime`, so documents
+/// written with either type read back with the other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(Duration);
+
+impl Timestamp {
+    pub const UNIX_EPOCH: Timestamp = Timestamp(Duration::ZERO);
+
+    pub const fn from_unix(since_epoch: Duration) -> Self {
+        Timestamp(since_epoch)
+    }
+
+    pub const fn since_unix_epoch(self) -> Duration {
+        self.0
+    }
+
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Timestamp)
+    }
+
+    /// The current time from the system clock.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// Panics on overflow, as `SystemTime + Duration` does.
+    fn add(self, duration: Duration) -> Timestamp {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+/// Unix seconds and nanoseconds, e.g. "1700000000.000000250".
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:09}", self.0.as_secs(), self.0.subsec_nanos())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SystemTime")]
+struct Repr {
+    secs_since_epoch: u64,
+    nanos_since_epoch: u32,
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            secs_since_epoch: self.0.as_secs(),
+            nanos_since_epoch: self.0.subsec_nanos(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        if repr.nanos_since_epoch >= 1_000_000_000 {
+            return Err(serde::de::Error::custom(
+                "nanos_since_epoch must be below one second",
+            ));
+        }
+        Ok(Timestamp(Duration::new(
+            repr.secs_since_epoch,
+            repr.nanos_since_epoch,
+        )))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::time::SystemTime> for Timestamp {
+    fn from(time: std::time::SystemTime) -> Self {
+        Timestamp(
+            time.duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Timestamp> for std::time::SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        std::time::UNIX_EPOCH + timestamp.0
+    }
+}
@@ -0,0 +1,99 @@
+//! Post-construction validation shared by the corridor crates.
+//! Constructors validate their input, but values arriving through serde
+//! or FFI bypass them; `Validate` re-checks a value as it stands and
+//! reports every problem at once.
+
+#![forbid(unsafe_code)]
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::Serialize;
+
+/// One problem found by `Validate::validate`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Finding {
+    /// Dotted path of the offending field from the validated value, e.g.
+    /// "eco_impact.soil_score"; empty for the value itself.
+    pub path: String,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The findings of a nested value's `validate`, with paths made
+    /// relative to its parent under `field`.
+    pub fn within(field: &str, result: Result<(), Vec<Finding>>) -> Vec<Finding> {
+        result
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|f| Finding {
+                path: if f.path.is_empty() {
+                    field.to_string()
+                } else {
+                    format!("{}.{}", field, f.path)
+                },
+                message: f.message,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Re-check a value's invariants after construction.
+pub trait Validate {
+    /// `Ok(())` if the value is valid, otherwise every finding.
+    fn validate(&self) -> Result<(), Vec<Finding>>;
+}
+
+/// `Ok(())` if `findings` is empty, otherwise `Err(findings)`.
+pub fn into_result(findings: Vec<Finding>) -> Result<(), Vec<Finding>> {
+    if findings.is_empty() {
+        Ok(())
+    } else {
+        Err(findings)
+    }
+}
+
+/// A finding at `path` unless `value` is a score in [0.0, 1.0].
+pub fn check_unit_interval(path: &str, value: f32) -> Option<Finding> {
+    (!(0.0..=1.0).contains(&value))
+        .then(|| Finding::new(path, format!("{} is not within [0.0, 1.0]", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_findings_are_prefixed() {
+        let inner = into_result(vec![
+            Finding::new("", "empty"),
+            check_unit_interval("score", f32::NAN).unwrap(),
+        ]);
+        let outer = Finding::within("metrics", inner);
+        assert_eq!(outer[0].to_string(), "metrics: empty");
+        assert_eq!(outer[1].path, "metrics.score");
+        assert!(check_unit_interval("score", 0.5).is_none());
+        assert_eq!(into_result(Finding::within("metrics", Ok(()))), Ok(()));
+    }
+}
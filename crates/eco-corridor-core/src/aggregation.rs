@@ -0,0 +1,77 @@
+//! Strategies for folding `EcoImpactMetrics` into the aggregate the eco
+//! guard compares against. Weighting is corridor-specific by design, so
+//! each `IndigenousEcoCorridorMap` carries its own aggregator.[file:3][file:4]
+
+#![forbid(unsafe_code)]
+
+use alloc::string::String;
+use core::fmt;
+
+use crate::indigenous_corridor::{EcoImpactMetrics, EcoMetric, EcoScalar};
+
+/// Folds the four metrics into one score in [0.0, 1.0].
+pub trait EcoAggregator: fmt::Debug {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> EcoScalar;
+}
+
+/// Every metric counts equally; `EcoImpactMetrics::aggregate_score`, and
+/// the aggregator maps use unless given another.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnweightedMean;
+
+impl EcoAggregator for UnweightedMean {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> EcoScalar {
+        metrics.aggregate_score()
+    }
+}
+
+/// Mean with a non-negative weight per metric.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedMean {
+    weights: [f32; 4],
+}
+
+impl WeightedMean {
+    /// Weights in `EcoMetric::ALL` order; they must be finite and
+    /// non-negative, and not all zero.
+    pub fn new(weights: [f32; 4]) -> Result<Self, String> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("aggregation weights must be finite and non-negative".into());
+        }
+        if weights.iter().all(|w| *w == 0.0) {
+            return Err("aggregation weights must not all be zero".into());
+        }
+        Ok(Self { weights })
+    }
+
+    pub fn weight(&self, metric: EcoMetric) -> f32 {
+        self.weights[metric as usize]
+    }
+}
+
+impl EcoAggregator for WeightedMean {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> EcoScalar {
+        let total: f32 = self.weights.iter().sum();
+        let weighted: f32 = EcoMetric::ALL
+            .into_iter()
+            .map(|m| metrics.get(m).value() * self.weight(m))
+            .sum();
+        EcoScalar::saturating(weighted / total)
+    }
+}
+
+/// The worst metric decides: a corridor is only as healthy as its
+/// weakest system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinimumComponent;
+
+impl EcoAggregator for MinimumComponent {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> EcoScalar {
+        EcoScalar::saturating(
+            EcoMetric::ALL
+                .into_iter()
+                .map(|m| metrics.get(m).value())
+                .fold(1.0, f32::min),
+        )
+    }
+}
@@ -0,0 +1,116 @@
+//! Conversions between the kernel's metrics, consent and neurorights
+//! types and the canonical `corridor-types` ones, so corridors evaluated
+//! here can be exchanged with the corridor map and morpheus records.
+//!
+//! The mapping is lossy where the models differ:
+//! - Metrics: soil, water and microbiome scores carry over. The canonical
+//!   biodiversity score takes microbiome diversity, the kernel's only
+//!   diversity measure; corridor resilience has no canonical counterpart,
+//!   so converting back takes it from the caller.
+//! - Consent: a `VerifiableConsent` is a signed credential, so only the
+//!   kernel-to-canonical direction exists, judged at a given time and
+//!   scoped to the purposes the caller names; signatures are not checked.
+//! - Neurorights: `NoCovertInference` is mental privacy, and coercive
+//!   channels are forbidden when both `ForbidFearPainCoercion` and
+//!   `NoMentalManipulation` are set. `ForbidFearPainCoercion` alone makes
+//!   discipline signals non-coercive. The kernel states no rollback
+//!   guarantee, so converted capsules leave it unset.
+
+#![forbid(unsafe_code)]
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use corridor_types::{FpicGrant, FpicPurpose, FpicStatus};
+
+use crate::indigenous_corridor::{
+    ConsentStatus, EcoImpactMetrics, EcoScalar, NeurorightsFlag, NeurorightsFlags, Timestamp,
+    VerifiableConsent,
+};
+
+impl From<&EcoImpactMetrics> for corridor_types::EcoImpactMetrics {
+    fn from(metrics: &EcoImpactMetrics) -> Self {
+        Self {
+            soil_score: metrics.soil_health.value(),
+            water_score: metrics.water_quality.value(),
+            microbiome_score: metrics.microbiome_diversity.value(),
+            biodiversity_score: metrics.microbiome_diversity.value(),
+        }
+    }
+}
+
+impl EcoImpactMetrics {
+    /// The kernel's metrics for canonical ones, with the resilience score
+    /// they lack. Fails if a canonical score is outside [0.0, 1.0].
+    pub fn from_canonical(
+        metrics: &corridor_types::EcoImpactMetrics,
+        corridor_resilience: EcoScalar,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            soil_health: EcoScalar::new(metrics.soil_score)?,
+            water_quality: EcoScalar::new(metrics.water_score)?,
+            microbiome_diversity: EcoScalar::new(metrics.microbiome_score)?,
+            corridor_resilience,
+        })
+    }
+}
+
+impl VerifiableConsent {
+    /// Canonical FPIC status of this consent at `at`, granting
+    /// `purposes`. A grant becomes `Granted` until `valid_until`, under
+    /// the reference "issuer@issued_at"; a grant not yet valid is
+    /// `Pending`, and a lapsed or revoked one is `Withheld`.
+    pub fn fpic_status(
+        &self,
+        at: Timestamp,
+        purposes: impl IntoIterator<Item = FpicPurpose>,
+    ) -> FpicStatus {
+        match self.status {
+            ConsentStatus::Pending => FpicStatus::Pending,
+            ConsentStatus::Revoked => FpicStatus::Withheld {
+                reason: format!("revoked by {}", self.issuer_did),
+            },
+            ConsentStatus::Granted if self.revoked_at.is_some() => FpicStatus::Withheld {
+                reason: format!("revoked by {}", self.issuer_did),
+            },
+            ConsentStatus::Granted if at < self.valid_from => FpicStatus::Pending,
+            ConsentStatus::Granted if at >= self.valid_until => FpicStatus::Withheld {
+                reason: "expired".into(),
+            },
+            ConsentStatus::Granted => FpicStatus::Granted(
+                FpicGrant::new(format!("{}@{}", self.issuer_did, self.issued_at), purposes)
+                    .expires_at(self.valid_until),
+            ),
+        }
+    }
+}
+
+impl From<&NeurorightsFlags> for corridor_types::NeurorightsConstraints {
+    fn from(flags: &NeurorightsFlags) -> Self {
+        let fear_pain = flags.contains(&NeurorightsFlag::ForbidFearPainCoercion);
+        Self {
+            mental_privacy_protection: flags.contains(&NeurorightsFlag::NoCovertInference),
+            forbid_coercive_channels: fear_pain
+                && flags.contains(&NeurorightsFlag::NoMentalManipulation),
+            forbid_downgrade_or_rollback: false,
+            discipline_personalized_and_noncoercive: fear_pain,
+        }
+    }
+}
+
+impl From<&corridor_types::NeurorightsConstraints> for NeurorightsFlags {
+    fn from(rights: &corridor_types::NeurorightsConstraints) -> Self {
+        let mut flags = Vec::new();
+        if rights.forbid_coercive_channels || rights.discipline_personalized_and_noncoercive {
+            flags.push(NeurorightsFlag::ForbidFearPainCoercion);
+        }
+        if rights.forbid_coercive_channels {
+            flags.push(NeurorightsFlag::NoMentalManipulation);
+        }
+        if rights.mental_privacy_protection {
+            flags.push(NeurorightsFlag::NoCovertInference);
+        }
+        NeurorightsFlags::new(flags)
+    }
+}
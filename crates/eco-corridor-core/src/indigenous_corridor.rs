@@ -1,32 +1,29 @@
 #![forbid(unsafe_code)]
 
-use std::time::SystemTime;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::aggregation::{EcoAggregator, UnweightedMean};
+use crate::policy::{PolicyGate, PredicateInput};
 
 /// CorridorId: DID-like, non-empty, validated at construction.
 /// This is the anchor for an Indigenous eco-corridor identity.[file:3][file:4]
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct CorridorId(String);
-
-impl CorridorId {
-    /// Create a CorridorId from a string-like value, enforcing simple
-    /// non-empty and prefix rules. You can later strengthen this to full DID
-    /// validation without changing call sites.[file:3]
-    pub fn from_str(value: &str) -> Result<Self, String> {
-        let trimmed = value.trim();
-        if trimmed.is_empty() {
-            return Err("CorridorId must not be empty".into());
-        }
-        // Minimal DID-style guard: ensure there is at least one ':' separator.
-        if !trimmed.contains(':') {
-            return Err("CorridorId must contain a DID-style prefix (e.g. did:...)".into());
-        }
-        Ok(CorridorId(trimmed.to_owned()))
-    }
+///
+/// The canonical type from `corridor-types`, shared with
+/// indigenous-eco-corridor-map, so both crates agree on IDs by
+/// construction rather than by keeping two parsers in step.
+pub use corridor_types::{CorridorId, DidParts, DidSyntaxError};
 
-    pub fn as_str(&self) -> &str {
-        &self.0
-    }
-}
+/// Stable denial codes shared with the other corridor crates.
+pub use corridor_types::DenyCode;
+
+pub use crate::time::Timestamp;
 
 /// Normalized scalar in [0.0, 1.0]. 1.0 = best (least harm / highest integrity).[file:3][file:4]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -43,6 +40,11 @@ impl EcoScalar {
     pub fn value(self) -> f32 {
         self.0
     }
+
+    /// `value` clamped into range, for aggregates that may round outside.
+    pub(crate) fn saturating(value: f32) -> Self {
+        EcoScalar(value.clamp(0.0, 1.0))
+    }
 }
 
 /// EcoImpactMetrics: biophysical heartbeat of the corridor.[file:3][file:4]
@@ -57,14 +59,54 @@ pub struct EcoImpactMetrics {
 }
 
 impl EcoImpactMetrics {
-    /// Simple aggregate; you can replace with weighted or corridor-specific logic later.
+    /// Unweighted mean of the four metrics. Corridors that weigh them
+    /// differently set an `EcoAggregator` on their map.
     pub fn aggregate_score(&self) -> EcoScalar {
         let s = self.soil_health.value()
             + self.water_quality.value()
             + self.microbiome_diversity.value()
             + self.corridor_resilience.value();
         // Average of four metrics.
-        EcoScalar((s / 4.0).clamp(0.0, 1.0))
+        EcoScalar::saturating(s / 4.0)
+    }
+
+    pub fn get(&self, metric: EcoMetric) -> EcoScalar {
+        match metric {
+            EcoMetric::SoilHealth => self.soil_health,
+            EcoMetric::WaterQuality => self.water_quality,
+            EcoMetric::MicrobiomeDiversity => self.microbiome_diversity,
+            EcoMetric::CorridorResilience => self.corridor_resilience,
+        }
+    }
+}
+
+/// One field of `EcoImpactMetrics`, for per-metric thresholds.[file:4]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EcoMetric {
+    SoilHealth,
+    WaterQuality,
+    MicrobiomeDiversity,
+    CorridorResilience,
+}
+
+impl EcoMetric {
+    pub const ALL: [EcoMetric; 4] = [
+        EcoMetric::SoilHealth,
+        EcoMetric::WaterQuality,
+        EcoMetric::MicrobiomeDiversity,
+        EcoMetric::CorridorResilience,
+    ];
+}
+
+impl fmt::Display for EcoMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            EcoMetric::SoilHealth => "soil_health",
+            EcoMetric::WaterQuality => "water_quality",
+            EcoMetric::MicrobiomeDiversity => "microbiome_diversity",
+            EcoMetric::CorridorResilience => "corridor_resilience",
+        })
     }
 }
 
@@ -86,22 +128,88 @@ pub struct VerifiableConsent {
     /// Current status of consent.
     pub status: ConsentStatus,
     /// Issuance timestamp.
-    pub issued_at: SystemTime,
+    pub issued_at: Timestamp,
     /// Optional revocation timestamp.
-    pub revoked_at: Option<SystemTime>,
+    pub revoked_at: Option<Timestamp>,
+    /// Start of the window in which the consent may be relied on.
+    pub valid_from: Timestamp,
+    /// End of the window (exclusive); the consent lapses after this and
+    /// must be renewed by the issuer.
+    pub valid_until: Timestamp,
     /// Detached signature or reference; to be checked by a ledger / VC layer.
     pub signature_hex: String,
 }
 
 impl VerifiableConsent {
-    /// Quick helper: true only if status is Granted and not revoked yet.
-    pub fn is_effectively_granted(&self) -> bool {
-        self.status == ConsentStatus::Granted && self.revoked_at.is_none()
+    /// True only if status is Granted, not revoked yet, and `at` falls in
+    /// the validity window, and, when a `verifier` is supplied, the
+    /// issuer's signature checks out.
+    pub fn is_effectively_granted(
+        &self,
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> bool {
+        self.status == ConsentStatus::Granted
+            && self.revoked_at.is_none()
+            && self.is_valid_at(at)
+            && verifier.is_none_or(|v| self.verify_signature(v))
+    }
+
+    /// True if `valid_from <= at < valid_until`.
+    pub fn is_valid_at(&self, at: Timestamp) -> bool {
+        self.valid_from <= at && at < self.valid_until
+    }
+
+    /// Bytes the issuer signs: issuer, subject, status, and the issuance,
+    /// revocation and validity timestamps (Unix seconds.nanoseconds, `-`
+    /// when absent), one per line.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let status = match self.status {
+            ConsentStatus::Granted => "granted",
+            ConsentStatus::Revoked => "revoked",
+            ConsentStatus::Pending => "pending",
+        };
+        format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            self.issuer_did,
+            self.subject_corridor_id,
+            status,
+            self.issued_at,
+            self.revoked_at.map_or("-".to_string(), |t| t.to_string()),
+            self.valid_from,
+            self.valid_until
+        )
+        .into_bytes()
+    }
+
+    /// Check `signature_hex` over `signing_payload` with `verifier`.
+    /// Malformed hex never verifies.
+    pub fn verify_signature(&self, verifier: &dyn ConsentVerifier) -> bool {
+        decode_hex(&self.signature_hex).is_some_and(|signature| {
+            verifier.verify(&self.issuer_did, &self.signing_payload(), &signature)
+        })
+    }
+}
+
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
     }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Checks consent signatures against issuer keys. Key resolution is
+/// external: higher layers implement this with their DID / VC tooling.[file:3]
+pub trait ConsentVerifier {
+    fn verify(&self, issuer_did: &str, payload: &[u8], signature: &[u8]) -> bool;
 }
 
 /// Neurorights-sensitive capabilities within the corridor.[file:3][file:1]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NeurorightsFlag {
     /// Disallow any use of FEAR / PAIN as coercive channels.
     ForbidFearPainCoercion,
@@ -135,6 +243,167 @@ impl NeurorightsFlags {
     }
 }
 
+/// Why `check_preconditions` denied an action at this layer.[file:3][file:4]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PreconditionError {
+    /// The action names a corridor no registry entry exists for.
+    NotRegistered(CorridorId),
+    /// The action names a different corridor than this map.
+    CorridorMismatch {
+        map: CorridorId,
+        request: CorridorId,
+    },
+    /// The corridor's aggregate eco score is below what the action requires.
+    EcoThresholdNotMet { required: f32, actual: f32 },
+    /// One metric is below the minimum the action sets for it.
+    MetricThresholdNotMet {
+        metric: EcoMetric,
+        required: f32,
+        actual: f32,
+    },
+    /// A high-impact action found no consent credential.
+    FpicMissing,
+    /// A high-impact action found consent that is not granted or was revoked.
+    FpicRevoked,
+    /// A high-impact action found granted consent outside its validity window.
+    FpicExpired,
+    /// A high-impact action found consent whose signature did not verify.
+    FpicUnverified,
+    /// The action would use a capability this flag forbids.
+    NeurorightsViolation(NeurorightsFlag),
+    /// An overlay-defined `PolicyGate` did not hold; carries its id.
+    PolicyGateFailed(String),
+}
+
+impl fmt::Display for PreconditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreconditionError::NotRegistered(id) => {
+                write!(f, "Corridor guard: no corridor registered as '{}'", id)
+            }
+            PreconditionError::CorridorMismatch { map, request } => write!(
+                f,
+                "Corridor mismatch: action corridor_id '{}' does not match map corridor_id '{}'",
+                request, map
+            ),
+            PreconditionError::EcoThresholdNotMet { required, actual } => write!(
+                f,
+                "EcoImpact guard: required_min_eco_score {:.3} > corridor aggregate {:.3}",
+                required, actual
+            ),
+            PreconditionError::MetricThresholdNotMet {
+                metric,
+                required,
+                actual,
+            } => write!(
+                f,
+                "EcoImpact guard: required minimum {} {:.3} > corridor {:.3}",
+                metric, required, actual
+            ),
+            PreconditionError::FpicMissing => f.write_str(
+                "FPIC/IDS guard: no consent credential present for high-impact action",
+            ),
+            PreconditionError::FpicRevoked => {
+                f.write_str("FPIC/IDS guard: consent not granted or already revoked")
+            }
+            PreconditionError::FpicExpired => {
+                f.write_str("FPIC/IDS guard: consent is outside its validity window")
+            }
+            PreconditionError::FpicUnverified => {
+                f.write_str("FPIC/IDS guard: consent signature did not verify")
+            }
+            PreconditionError::NeurorightsViolation(flag) => match flag {
+                NeurorightsFlag::ForbidFearPainCoercion => f.write_str(
+                    "Neurorights guard: FEAR/PAIN channels are forbidden in this corridor",
+                ),
+                NeurorightsFlag::NoCovertInference => f.write_str(
+                    "Neurorights guard: covert mental-state inference is forbidden in this corridor",
+                ),
+                NeurorightsFlag::NoMentalManipulation => f.write_str(
+                    "Neurorights guard: mental manipulation / belief-shaping is forbidden",
+                ),
+            },
+            PreconditionError::PolicyGateFailed(id) => {
+                write!(f, "Policy guard: gate '{}' does not hold", id)
+            }
+        }
+    }
+}
+
+impl core::error::Error for PreconditionError {}
+
+impl PreconditionError {
+    /// Stable code for aggregating denials without matching messages.
+    pub fn deny_code(&self) -> DenyCode {
+        match self {
+            PreconditionError::NotRegistered(_) => DenyCode::CorridorNotRegistered,
+            PreconditionError::CorridorMismatch { .. } => DenyCode::CorridorMismatch,
+            PreconditionError::EcoThresholdNotMet { .. } => DenyCode::EcoBelowMin,
+            PreconditionError::MetricThresholdNotMet { .. } => DenyCode::EcoMetricBelowMin,
+            PreconditionError::FpicMissing => DenyCode::FpicAbsent,
+            PreconditionError::FpicRevoked => DenyCode::FpicRevoked,
+            PreconditionError::FpicExpired => DenyCode::FpicExpired,
+            PreconditionError::FpicUnverified => DenyCode::FpicUnverified,
+            PreconditionError::NeurorightsViolation(flag) => match flag {
+                NeurorightsFlag::ForbidFearPainCoercion => DenyCode::NrFearPain,
+                NeurorightsFlag::NoCovertInference => DenyCode::NrCovertInference,
+                NeurorightsFlag::NoMentalManipulation => DenyCode::NrBeliefShaping,
+            },
+            PreconditionError::PolicyGateFailed(_) => DenyCode::PolicyGate,
+        }
+    }
+}
+
+/// One guard applied by `check_preconditions`.[file:3][file:4]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreconditionGuard {
+    CorridorBinding,
+    EcoImpact,
+    Metric(EcoMetric),
+    FpicIds,
+    Neurorights(NeurorightsFlag),
+    /// An overlay-defined gate, by id.
+    Policy(String),
+}
+
+/// A guard the request passed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PassedGuard {
+    pub guard: PreconditionGuard,
+    /// For `EcoImpact` and `Metric`, how far the corridor score exceeds
+    /// the requirement; `None` for pass/fail guards.
+    pub margin: Option<f32>,
+}
+
+/// Outcome of every guard for one request, in evaluation order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreconditionReport {
+    pub violations: Vec<PreconditionError>,
+    pub passed: Vec<PassedGuard>,
+}
+
+/// Result of one request in `check_batch`.
+pub type PreconditionOutcome = PreconditionReport;
+
+impl PreconditionReport {
+    /// True if no guard failed.
+    pub fn is_permitted(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Codes of every violation, in evaluation order.
+    pub fn deny_codes(&self) -> Vec<DenyCode> {
+        self.violations
+            .iter()
+            .map(PreconditionError::deny_code)
+            .collect()
+    }
+
+    fn pass(&mut self, guard: PreconditionGuard, margin: Option<f32>) {
+        self.passed.push(PassedGuard { guard, margin });
+    }
+}
+
 /// FPIC / IDS gate for corridor operations. Optional: some corridors may
 /// be in a pre-consultation state, but high-impact actions must check for
 /// a `Some` value with `ConsentStatus::Granted` and cryptographic validity.[file:3][file:4]
@@ -147,6 +416,11 @@ pub struct IndigenousEcoCorridorMap {
     pub eco_metrics: EcoImpactMetrics,
     pub fpic_ids_state: FpicIdsState,
     pub neurorights_flags: NeurorightsFlags,
+    /// Overlay-defined gates, applied after the built-in guards.
+    pub policy_gates: Vec<PolicyGate>,
+    /// How the eco guard and `aggregate` gates fold `eco_metrics`;
+    /// `UnweightedMean` unless set with `with_aggregator`.
+    pub aggregator: Arc<dyn EcoAggregator + Send + Sync>,
 }
 
 impl IndigenousEcoCorridorMap {
@@ -164,82 +438,247 @@ impl IndigenousEcoCorridorMap {
             eco_metrics,
             fpic_ids_state,
             neurorights_flags,
+            policy_gates: Vec::new(),
+            aggregator: Arc::new(UnweightedMean),
         }
     }
 
+    /// Aggregate `eco_metrics` with `aggregator` instead of the unweighted
+    /// mean.
+    pub fn with_aggregator(
+        mut self,
+        aggregator: impl EcoAggregator + Send + Sync + 'static,
+    ) -> Self {
+        self.aggregator = Arc::new(aggregator);
+        self
+    }
+
+    /// The corridor's eco aggregate under its aggregator.
+    pub fn aggregate_score(&self) -> EcoScalar {
+        self.aggregator.aggregate(&self.eco_metrics)
+    }
+
+    /// Add an overlay-defined gate; may be called repeatedly.
+    pub fn with_policy_gate(mut self, gate: PolicyGate) -> Self {
+        self.policy_gates.push(gate);
+        self
+    }
+
     /// Non-actuating governance precondition check for high-impact actions.[file:3][file:4]
     /// Returns Ok(()) if the request MAY proceed subject to downstream checks;
-    /// returns Err(reason) if the action must be denied at this layer.
+    /// returns the first failed guard if the action must be denied at this layer.
+    /// See `evaluate_all` for every failure at once.
     pub fn check_preconditions(
         &self,
         request: &CorridorActionRequest,
-    ) -> Result<(), String> {
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> Result<(), PreconditionError> {
+        match self.evaluate_all(request, at, verifier).violations.into_iter().next() {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+
+    /// Apply every guard of `check_preconditions` without stopping at the
+    /// first failure, so a proposal author sees everything to fix in one pass.
+    /// Consent must be valid at `at`, normally the time of the request;
+    /// signatures are checked only when a `verifier` is supplied.
+    pub fn evaluate_all(
+        &self,
+        request: &CorridorActionRequest,
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> PreconditionReport {
+        let consent = if request.high_impact || !self.policy_gates.is_empty() {
+            self.consent_violation(at, verifier)
+        } else {
+            None
+        };
+        self.evaluate(request, self.aggregate_score().value(), &consent)
+    }
+
+    /// `evaluate_all` for many alternative requests, e.g. proposals a
+    /// planning tool is scoring. The eco aggregate and the consent check
+    /// (including any signature verification) run once for the batch.
+    /// Outcomes are in request order.
+    pub fn check_batch(
+        &self,
+        requests: &[CorridorActionRequest],
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> Vec<PreconditionOutcome> {
+        let consent = if !self.policy_gates.is_empty() || requests.iter().any(|r| r.high_impact) {
+            self.consent_violation(at, verifier)
+        } else {
+            None
+        };
+        let agg = self.aggregate_score().value();
+        requests
+            .iter()
+            .map(|request| self.evaluate(request, agg, &consent))
+            .collect()
+    }
+
+    /// Why the consent credential does not cover a high-impact action at
+    /// `at`, if it does not.
+    /// This kernel enforces presence, logical status and the validity
+    /// window; signatures only when the caller supplies a verifier.
+    fn consent_violation(
+        &self,
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> Option<PreconditionError> {
+        match &self.fpic_ids_state {
+            None => Some(PreconditionError::FpicMissing),
+            Some(vc) if vc.status != ConsentStatus::Granted || vc.revoked_at.is_some() => {
+                Some(PreconditionError::FpicRevoked)
+            }
+            Some(vc) if !vc.is_valid_at(at) => Some(PreconditionError::FpicExpired),
+            Some(vc) if !vc.is_effectively_granted(at, verifier) => {
+                Some(PreconditionError::FpicUnverified)
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// The guards proper, given the corridor aggregate and the outcome of
+    /// `consent_violation` (computed for high-impact requests, and for all
+    /// requests when the map has policy gates).
+    fn evaluate(
+        &self,
+        request: &CorridorActionRequest,
+        agg: f32,
+        consent: &Option<PreconditionError>,
+    ) -> PreconditionReport {
+        let mut report = PreconditionReport::default();
+
         // 1. Corridor binding: action corridor must match map corridor.
-        if request.corridor_id.as_str() != self.corridor_id.as_str() {
-            return Err("Corridor mismatch: action corridor_id does not match map corridor_id"
-                .into());
+        if request.corridor_id != self.corridor_id {
+            report.violations.push(PreconditionError::CorridorMismatch {
+                map: self.corridor_id.clone(),
+                request: request.corridor_id.clone(),
+            });
+        } else {
+            report.pass(PreconditionGuard::CorridorBinding, None);
         }
 
         // 2. Eco-impact guard: deny if requested eco load exceeds thresholds.[file:3][file:4]
         // Here we use a simple threshold on aggregate score; you can refine later.
-        let agg = self.eco_metrics.aggregate_score().value();
         if request.required_min_eco_score > agg {
-            return Err(format!(
-                "EcoImpact guard: required_min_eco_score {:.3} > corridor aggregate {:.3}",
-                request.required_min_eco_score, agg
-            ));
+            report.violations.push(PreconditionError::EcoThresholdNotMet {
+                required: request.required_min_eco_score,
+                actual: agg,
+            });
+        } else {
+            report.pass(
+                PreconditionGuard::EcoImpact,
+                Some(agg - request.required_min_eco_score),
+            );
+        }
+        // A healthy aggregate must not mask one collapsed metric.
+        for (&metric, &required) in &request.metric_minimums {
+            let actual = self.eco_metrics.get(metric).value();
+            if required > actual {
+                report.violations.push(PreconditionError::MetricThresholdNotMet {
+                    metric,
+                    required,
+                    actual,
+                });
+            } else {
+                report.pass(PreconditionGuard::Metric(metric), Some(actual - required));
+            }
         }
 
         // 3. FPIC / IDS: for high-impact actions, consent must be granted and valid.[file:3][file:4]
-        if request.high_impact {
-            match &self.fpic_ids_state {
-                None => {
-                    return Err("FPIC/IDS guard: no consent credential present for high-impact action"
-                        .into());
-                }
-                Some(vc) if !vc.is_effectively_granted() => {
-                    return Err("FPIC/IDS guard: consent not granted or already revoked".into());
-                }
-                Some(_vc) => {
-                    // Cryptographic / ledger checks are performed by higher layers;
-                    // this kernel only enforces presence + logical status.
-                }
-            }
+        match consent {
+            Some(violation) if request.high_impact => report.violations.push(violation.clone()),
+            _ => report.pass(PreconditionGuard::FpicIds, None),
         }
 
         // 4. Neurorights guardrails: forbid coercive neuromorphic modes at kernel level.[file:3][file:1]
-        if request.may_use_fear_pain_channels
-            && self
-                .neurorights_flags
-                .contains(&NeurorightsFlag::ForbidFearPainCoercion)
-        {
-            return Err(
-                "Neurorights guard: FEAR/PAIN channels are forbidden in this corridor".into(),
-            );
+        let requested = [
+            (
+                request.may_use_fear_pain_channels,
+                NeurorightsFlag::ForbidFearPainCoercion,
+            ),
+            (
+                request.may_infer_mental_state,
+                NeurorightsFlag::NoCovertInference,
+            ),
+            (
+                request.may_attempt_belief_shaping,
+                NeurorightsFlag::NoMentalManipulation,
+            ),
+        ];
+        for (requested, flag) in requested {
+            if requested && self.neurorights_flags.contains(&flag) {
+                report
+                    .violations
+                    .push(PreconditionError::NeurorightsViolation(flag));
+            } else {
+                report.pass(PreconditionGuard::Neurorights(flag), None);
+            }
         }
 
-        if request.may_infer_mental_state
-            && self
-                .neurorights_flags
-                .contains(&NeurorightsFlag::NoCovertInference)
-        {
-            return Err(
-                "Neurorights guard: covert mental-state inference is forbidden in this corridor"
-                    .into(),
-            );
+        // 5. Overlay-defined policy gates.
+        let input = PredicateInput {
+            eco_metrics: &self.eco_metrics,
+            aggregate: agg,
+            neurorights_flags: &self.neurorights_flags,
+            fpic_granted: consent.is_none(),
+            request,
+        };
+        for gate in &self.policy_gates {
+            if gate.require.holds(&input) {
+                report.pass(PreconditionGuard::Policy(gate.id.clone()), None);
+            } else {
+                report
+                    .violations
+                    .push(PreconditionError::PolicyGateFailed(gate.id.clone()));
+            }
         }
 
-        if request.may_attempt_belief_shaping
-            && self
-                .neurorights_flags
-                .contains(&NeurorightsFlag::NoMentalManipulation)
-        {
-            return Err(
-                "Neurorights guard: mental manipulation / belief-shaping is forbidden".into(),
-            );
+        report
+    }
+}
+
+/// What a proposed action is for, so requests are self-describing in
+/// audit logs.[file:3][file:4]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionPurpose {
+    /// Simulated reforestation or restoration planning.
+    ReforestationSim,
+    /// Passive monitoring of corridor condition.
+    Monitoring,
+    /// Research studies within the corridor.
+    Research,
+    /// Resource extraction (mining, logging, water withdrawal).
+    Extraction,
+    /// Surveys for roads, pipelines, power lines and similar infrastructure.
+    InfrastructureSurvey,
+    /// Anything else; the request's `description` should say what.
+    Other,
+}
+
+impl ActionPurpose {
+    /// Stable key for audit logs.
+    pub fn key(self) -> &'static str {
+        match self {
+            ActionPurpose::ReforestationSim => "reforestation_sim",
+            ActionPurpose::Monitoring => "monitoring",
+            ActionPurpose::Research => "research",
+            ActionPurpose::Extraction => "extraction",
+            ActionPurpose::InfrastructureSurvey => "infrastructure_survey",
+            ActionPurpose::Other => "other",
         }
+    }
+}
 
-        Ok(())
+impl fmt::Display for ActionPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
     }
 }
 
@@ -248,10 +687,17 @@ impl IndigenousEcoCorridorMap {
 #[derive(Clone, Debug)]
 pub struct CorridorActionRequest {
     pub corridor_id: CorridorId,
+    pub purpose: ActionPurpose,
+    /// DID of whoever proposes the action.
+    pub proposer_did: Option<String>,
+    /// Free-text description for reviewers and audit logs.
+    pub description: Option<String>,
     /// Minimal ecological integrity acceptable for this action, 0–1.
     /// For example, reforestation simulation might require >= 0.5,
     /// invasive extraction might require >= 0.9 (and likely be denied). [file:4]
     pub required_min_eco_score: f32,
+    /// Minimums for individual metrics, each enforced on its own.
+    pub metric_minimums: BTreeMap<EcoMetric, f32>,
     /// Whether this action is considered high-impact (actuation, large-scale change, etc.).
     pub high_impact: bool,
     /// Whether the downstream system plans to route FEAR/PAIN as FEEDBACK channels.
@@ -263,24 +709,91 @@ pub struct CorridorActionRequest {
 }
 
 impl CorridorActionRequest {
-    pub fn new(
+    /// Start building a request. Requests default to high-impact, so FPIC
+    /// is required unless the caller opts out, and to no neurorights-
+    /// sensitive capabilities.
+    pub fn builder(
         corridor_id: CorridorId,
-        required_min_eco_score: f32,
-        high_impact: bool,
-        may_use_fear_pain_channels: bool,
-        may_infer_mental_state: bool,
-        may_attempt_belief_shaping: bool,
-    ) -> Result<Self, String> {
-        if !(0.0..=1.0).contains(&required_min_eco_score) {
+        purpose: ActionPurpose,
+    ) -> CorridorActionRequestBuilder {
+        CorridorActionRequestBuilder {
+            request: CorridorActionRequest {
+                corridor_id,
+                purpose,
+                proposer_did: None,
+                description: None,
+                required_min_eco_score: 0.0,
+                metric_minimums: BTreeMap::new(),
+                high_impact: true,
+                may_use_fear_pain_channels: false,
+                may_infer_mental_state: false,
+                may_attempt_belief_shaping: false,
+            },
+        }
+    }
+}
+
+/// Builder for `CorridorActionRequest`; see `CorridorActionRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CorridorActionRequestBuilder {
+    request: CorridorActionRequest,
+}
+
+impl CorridorActionRequestBuilder {
+    pub fn proposer_did(mut self, did: impl Into<String>) -> Self {
+        self.request.proposer_did = Some(did.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.request.description = Some(description.into());
+        self
+    }
+
+    pub fn required_min_eco_score(mut self, score: f32) -> Self {
+        self.request.required_min_eco_score = score;
+        self
+    }
+
+    /// Require `metric` to be at least `score`, replacing any earlier minimum.
+    pub fn min_metric(mut self, metric: EcoMetric, score: f32) -> Self {
+        self.request.metric_minimums.insert(metric, score);
+        self
+    }
+
+    pub fn high_impact(mut self, high_impact: bool) -> Self {
+        self.request.high_impact = high_impact;
+        self
+    }
+
+    pub fn may_use_fear_pain_channels(mut self, may: bool) -> Self {
+        self.request.may_use_fear_pain_channels = may;
+        self
+    }
+
+    pub fn may_infer_mental_state(mut self, may: bool) -> Self {
+        self.request.may_infer_mental_state = may;
+        self
+    }
+
+    pub fn may_attempt_belief_shaping(mut self, may: bool) -> Self {
+        self.request.may_attempt_belief_shaping = may;
+        self
+    }
+
+    pub fn build(self) -> Result<CorridorActionRequest, String> {
+        if !(0.0..=1.0).contains(&self.request.required_min_eco_score) {
             return Err("required_min_eco_score must be within [0.0, 1.0]".into());
         }
-        Ok(CorridorActionRequest {
-            corridor_id,
-            required_min_eco_score,
-            high_impact,
-            may_use_fear_pain_channels,
-            may_infer_mental_state,
-            may_attempt_belief_shaping,
-        })
+        if let Some(metric) = self
+            .request
+            .metric_minimums
+            .iter()
+            .find(|(_, score)| !(0.0..=1.0).contains(*score))
+            .map(|(metric, _)| metric)
+        {
+            return Err(format!("minimum {} must be within [0.0, 1.0]", metric));
+        }
+        Ok(self.request)
     }
 }
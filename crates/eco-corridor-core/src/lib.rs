@@ -1,9 +1,30 @@
+//! Precondition kernel for Indigenous eco-corridors.
+//! Without the default `std` feature the crate is `no_std` + `alloc`, so
+//! the same guards run on embedded field gateways; times are `Timestamp`s
+//! supplied by the caller rather than read from a system clock.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod aggregation;
+pub mod canonical;
 pub mod indigenous_corridor;
+pub mod policy;
+pub mod registry;
+pub mod time;
+pub mod validation;
 
 #[cfg(test)]
 mod tests {
+    use super::aggregation::{MinimumComponent, WeightedMean};
     use super::indigenous_corridor::*;
-    use std::time::SystemTime;
+    use super::policy::PolicyGate;
+    use super::registry::CorridorRegistry;
+    use super::validation::Validate;
+    use corridor_types::{FpicPurpose, FpicStatus};
+    use std::str::FromStr;
+    use std::time::Duration;
 
     #[test]
     fn high_impact_action_requires_granted_fpic() {
@@ -16,12 +37,16 @@ mod tests {
             corridor_resilience: EcoScalar::new(0.85).unwrap(),
         };
 
+        let now = Timestamp::from_unix(Duration::from_secs(1_700_000_000));
+        let year = Duration::from_secs(365 * 24 * 60 * 60);
         let vc = VerifiableConsent {
             issuer_did: "did:example:tribal-council:xyz".to_string(),
             subject_corridor_id: cid.as_str().to_string(),
             status: ConsentStatus::Granted,
-            issued_at: SystemTime::now(),
+            issued_at: now,
             revoked_at: None,
+            valid_from: now,
+            valid_until: now + year,
             signature_hex: "deadbeef".to_string(),
         };
 
@@ -33,16 +58,261 @@ mod tests {
 
         let map = IndigenousEcoCorridorMap::new(cid.clone(), eco, Some(vc), neurorights);
 
-        let req = CorridorActionRequest::new(
-            cid,
-            0.6,   // requires eco score >= 0.6
-            true,  // high-impact
-            false, // no FEAR/PAIN
-            false, // no mental-state inference
-            false, // no belief-shaping
+        let req = CorridorActionRequest::builder(cid, ActionPurpose::ReforestationSim)
+            .proposer_did("did:example:restoration-team")
+            .description("native seed planting along the wash")
+            .required_min_eco_score(0.6)
+            .build()
+            .unwrap();
+        assert!(req.high_impact);
+
+        assert!(map.check_preconditions(&req, now, None).is_ok());
+        assert_eq!(
+            map.check_preconditions(&req, now + 2 * year, None),
+            Err(PreconditionError::FpicExpired)
+        );
+
+        let stressed =
+            CorridorActionRequest::builder(req.corridor_id.clone(), ActionPurpose::Research)
+                .required_min_eco_score(0.95)
+                .high_impact(false)
+                .may_use_fear_pain_channels(true)
+                .build()
+                .unwrap();
+        assert!(matches!(
+            map.check_preconditions(&stressed, now, None),
+            Err(PreconditionError::EcoThresholdNotMet { required, .. }) if required == 0.95
+        ));
+        let coercive = CorridorActionRequest {
+            required_min_eco_score: 0.6,
+            ..stressed.clone()
+        };
+        assert_eq!(
+            map.check_preconditions(&coercive, now, None),
+            Err(PreconditionError::NeurorightsViolation(
+                NeurorightsFlag::ForbidFearPainCoercion
+            ))
+        );
+
+        let report = map.evaluate_all(&stressed, now, None);
+        assert_eq!(
+            report.deny_codes(),
+            [DenyCode::EcoBelowMin, DenyCode::NrFearPain]
+        );
+        assert!(!report.is_permitted());
+        let fpic = &report.passed[1];
+        assert_eq!(fpic.guard, PreconditionGuard::FpicIds);
+        let eco = &map.evaluate_all(&coercive, now, None).passed[1];
+        assert_eq!(eco.guard, PreconditionGuard::EcoImpact);
+        assert!((eco.margin.unwrap() - 0.2125).abs() < 1e-6);
+
+        let outcomes = map.check_batch(&[req.clone(), stressed.clone(), coercive], now, None);
+        assert_eq!(outcomes[1], map.evaluate_all(&stressed, now, None));
+        let permitted: Vec<_> = outcomes.iter().map(|o| o.is_permitted()).collect();
+        assert_eq!(permitted, [true, false, false]);
+
+        assert!(
+            CorridorActionRequest::builder(map.corridor_id.clone(), ActionPurpose::Extraction)
+                .required_min_eco_score(1.5)
+                .build()
+                .is_err()
+        );
+
+        let thirsty =
+            CorridorActionRequest::builder(map.corridor_id.clone(), ActionPurpose::Extraction)
+                .required_min_eco_score(0.6)
+                .min_metric(EcoMetric::SoilHealth, 0.5)
+                .min_metric(EcoMetric::WaterQuality, 0.75)
+                .build()
+                .unwrap();
+        let report = map.evaluate_all(&thirsty, now, None);
+        assert_eq!(
+            report.violations,
+            [PreconditionError::MetricThresholdNotMet {
+                metric: EcoMetric::WaterQuality,
+                required: 0.75,
+                actual: 0.7,
+            }]
+        );
+        assert_eq!(report.passed[2].guard, PreconditionGuard::Metric(EcoMetric::SoilHealth));
+
+        let gate: PolicyGate = serde_json::from_str(
+            r#"{
+                "id": "snc:extraction-water",
+                "description": "extraction only where water is healthy",
+                "require": {"op": "any", "any": [
+                    {"op": "not", "predicate": {"op": "purpose", "purpose": "extraction"}},
+                    {"op": "metric", "metric": "water_quality", "cmp": "ge", "value": 0.8}
+                ]}
+            }"#,
         )
         .unwrap();
+        let gated = map.clone().with_policy_gate(gate);
+        assert!(gated.check_preconditions(&req, now, None).is_ok());
+        assert_eq!(
+            gated.evaluate_all(&thirsty, now, None).violations.last(),
+            Some(&PreconditionError::PolicyGateFailed(
+                "snc:extraction-water".into()
+            ))
+        );
+
+        let elsewhere = CorridorId::from_str("did:example:corridor:gila-river").unwrap();
+        let mut registry = CorridorRegistry::new();
+        registry.register(map);
+        assert!(registry.check(&req, now, None).is_ok());
+        let unregistered = CorridorActionRequest {
+            corridor_id: elsewhere.clone(),
+            ..req
+        };
+        assert_eq!(
+            registry.check(&unregistered, now, None),
+            Err(PreconditionError::NotRegistered(elsewhere))
+        );
+    }
+
+    #[test]
+    fn maps_aggregate_with_their_own_strategy() {
+        let cid = CorridorId::from_str("did:example:corridor:phoenix-desert").unwrap();
+        let score = |v| EcoScalar::new(v).unwrap();
+        let eco = EcoImpactMetrics {
+            soil_health: score(1.0),
+            water_quality: score(0.5),
+            microbiome_diversity: score(1.0),
+            corridor_resilience: score(1.0),
+        };
+        let map =
+            IndigenousEcoCorridorMap::new(cid.clone(), eco, None, NeurorightsFlags::new(vec![]));
+        let req = CorridorActionRequest::builder(cid, ActionPurpose::Monitoring)
+            .high_impact(false)
+            .required_min_eco_score(0.75)
+            .build()
+            .unwrap();
+        let at = Timestamp::UNIX_EPOCH;
+        assert_eq!(map.aggregate_score().value(), 0.875);
+        assert!(map.check_preconditions(&req, at, None).is_ok());
+
+        let weakest = map.clone().with_aggregator(MinimumComponent);
+        assert_eq!(
+            weakest.check_preconditions(&req, at, None),
+            Err(PreconditionError::EcoThresholdNotMet {
+                required: 0.75,
+                actual: 0.5,
+            })
+        );
+
+        let water_first = map.with_aggregator(WeightedMean::new([1.0, 3.0, 0.0, 0.0]).unwrap());
+        assert_eq!(water_first.aggregate_score().value(), 0.625);
+        assert!(WeightedMean::new([0.0; 4]).is_err());
+    }
+
+    struct XorKey;
+
+    impl ConsentVerifier for XorKey {
+        fn verify(&self, issuer_did: &str, payload: &[u8], signature: &[u8]) -> bool {
+            issuer_did == "did:example:tribal-council:xyz"
+                && payload
+                    .iter()
+                    .map(|b| b ^ 0x5a)
+                    .eq(signature.iter().copied())
+        }
+    }
 
-        assert!(map.check_preconditions(&req).is_ok());
+    #[test]
+    fn consent_signatures_are_checked_when_a_verifier_is_supplied() {
+        let mut vc = VerifiableConsent {
+            issuer_did: "did:example:tribal-council:xyz".to_string(),
+            subject_corridor_id: "did:example:corridor:phoenix-desert".to_string(),
+            status: ConsentStatus::Granted,
+            issued_at: Timestamp::UNIX_EPOCH,
+            revoked_at: None,
+            valid_from: Timestamp::UNIX_EPOCH,
+            valid_until: Timestamp::UNIX_EPOCH + Duration::from_secs(86_400),
+            signature_hex: "deadbeef".to_string(),
+        };
+        let at = Timestamp::UNIX_EPOCH;
+        assert!(vc.is_effectively_granted(at, None));
+        assert!(!vc.is_effectively_granted(at, Some(&XorKey)));
+
+        vc.signature_hex = vc
+            .signing_payload()
+            .iter()
+            .map(|b| format!("{:02x}", b ^ 0x5a))
+            .collect();
+        assert!(vc.is_effectively_granted(at, Some(&XorKey)));
+        assert_eq!(vc.validate(), Ok(()));
+        vc.subject_corridor_id = "eco:elsewhere".to_string();
+        assert!(!vc.verify_signature(&XorKey));
+
+        vc.status = ConsentStatus::Revoked;
+        vc.valid_until = vc.valid_from;
+        let findings = vc.validate().unwrap_err();
+        let paths: Vec<_> = findings.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["revoked_at", "valid_until"]);
+    }
+
+    #[test]
+    fn kernel_types_convert_to_the_canonical_ones() {
+        let score = |v| EcoScalar::new(v).unwrap();
+        let eco = EcoImpactMetrics {
+            soil_health: score(0.8),
+            water_quality: score(0.7),
+            microbiome_diversity: score(0.9),
+            corridor_resilience: score(0.4),
+        };
+        let canonical = corridor_types::EcoImpactMetrics::from(&eco);
+        assert_eq!(canonical.scores(), [0.8, 0.7, 0.9, 0.9]);
+        let back = EcoImpactMetrics::from_canonical(&canonical, score(0.4)).unwrap();
+        assert_eq!(back.get(EcoMetric::WaterQuality), score(0.7));
+        let out_of_range = corridor_types::EcoImpactMetrics {
+            soil_score: 1.5,
+            ..canonical
+        };
+        assert!(EcoImpactMetrics::from_canonical(&out_of_range, score(0.4)).is_err());
+
+        let start = Timestamp::from_unix(Duration::from_secs(1_700_000_000));
+        let end = start + Duration::from_secs(86_400);
+        let mut vc = VerifiableConsent {
+            issuer_did: "did:example:tribal-council:xyz".to_string(),
+            subject_corridor_id: "did:example:corridor:phoenix-desert".to_string(),
+            status: ConsentStatus::Granted,
+            issued_at: start,
+            revoked_at: None,
+            valid_from: start,
+            valid_until: end,
+            signature_hex: "deadbeef".to_string(),
+        };
+        let purposes = [FpicPurpose::Monitoring];
+        let fpic = vc.fpic_status(start, purposes);
+        assert_eq!(fpic.validate(), Ok(()));
+        assert!(fpic.permits(start, FpicPurpose::Monitoring));
+        assert!(!fpic.is_active_grant(end));
+        assert_eq!(
+            vc.fpic_status(Timestamp::UNIX_EPOCH, purposes),
+            FpicStatus::Pending
+        );
+        assert!(matches!(
+            vc.fpic_status(end, purposes),
+            FpicStatus::Withheld { .. }
+        ));
+        vc.revoked_at = Some(start);
+        assert!(matches!(
+            vc.fpic_status(start, purposes),
+            FpicStatus::Withheld { .. }
+        ));
+
+        let flags = NeurorightsFlags::new(vec![
+            NeurorightsFlag::ForbidFearPainCoercion,
+            NeurorightsFlag::NoMentalManipulation,
+            NeurorightsFlag::NoCovertInference,
+        ]);
+        let capsule = corridor_types::NeurorightsConstraints::from(&flags);
+        assert!(capsule.mental_privacy_protection && capsule.forbid_coercive_channels);
+        assert!(!capsule.forbid_downgrade_or_rollback);
+        let round_trip = NeurorightsFlags::from(&capsule);
+        assert!(flags.iter().all(|flag| round_trip.contains(flag)));
+        let fear_pain_only = NeurorightsFlags::new(vec![NeurorightsFlag::ForbidFearPainCoercion]);
+        let capsule = corridor_types::NeurorightsConstraints::from(&fear_pain_only);
+        assert!(!capsule.forbid_coercive_channels);
+        assert!(capsule.discipline_personalized_and_noncoercive);
     }
 }
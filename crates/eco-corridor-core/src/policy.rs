@@ -0,0 +1,136 @@
+//! Overlay-defined policy gates and the predicates they require.
+
+#![forbid(unsafe_code)]
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::indigenous_corridor::{
+    ActionPurpose, CorridorActionRequest, EcoImpactMetrics, EcoMetric, NeurorightsFlag,
+    NeurorightsFlags,
+};
+
+/// Comparison of a corridor score against a constant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn holds(self, actual: f32, value: f32) -> bool {
+        match self {
+            Comparison::Lt => actual < value,
+            Comparison::Le => actual <= value,
+            Comparison::Gt => actual > value,
+            Comparison::Ge => actual >= value,
+        }
+    }
+}
+
+/// Neurorights-sensitive capability an action request may declare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestedCapability {
+    FearPainChannels,
+    MentalStateInference,
+    BeliefShaping,
+}
+
+/// Serializable predicate over a corridor and an action request, so
+/// SNC/HGO overlays can ship corridor policies as data.[file:3][file:4]
+///
+/// In JSON each node is tagged by `op`, e.g.
+/// `{"op":"metric","metric":"water_quality","cmp":"ge","value":0.8}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Predicate {
+    /// A corridor metric compared against `value`.
+    Metric {
+        metric: EcoMetric,
+        cmp: Comparison,
+        value: f32,
+    },
+    /// The corridor aggregate (under the map's aggregator) compared
+    /// against `value`.
+    Aggregate {
+        cmp: Comparison,
+        value: f32,
+    },
+    /// The corridor holds consent that is effectively granted.
+    FpicGranted,
+    /// The corridor sets `flag`.
+    HasFlag {
+        flag: NeurorightsFlag,
+    },
+    /// The request is high-impact.
+    HighImpact,
+    /// The request is for `purpose`.
+    Purpose {
+        purpose: ActionPurpose,
+    },
+    /// The request declares `capability`.
+    Requests {
+        capability: RequestedCapability,
+    },
+    All {
+        all: Vec<Predicate>,
+    },
+    Any {
+        any: Vec<Predicate>,
+    },
+    Not {
+        predicate: Box<Predicate>,
+    },
+}
+
+/// What a predicate is evaluated against.
+#[derive(Clone, Copy, Debug)]
+pub struct PredicateInput<'a> {
+    pub eco_metrics: &'a EcoImpactMetrics,
+    /// `eco_metrics` folded by the corridor map's aggregator.
+    pub aggregate: f32,
+    pub neurorights_flags: &'a NeurorightsFlags,
+    /// Whether the corridor's consent is effectively granted at the
+    /// evaluation time, as `check_preconditions` decides it.
+    pub fpic_granted: bool,
+    pub request: &'a CorridorActionRequest,
+}
+
+impl Predicate {
+    pub fn holds(&self, input: &PredicateInput<'_>) -> bool {
+        match self {
+            Predicate::Metric { metric, cmp, value } => {
+                cmp.holds(input.eco_metrics.get(*metric).value(), *value)
+            }
+            Predicate::Aggregate { cmp, value } => cmp.holds(input.aggregate, *value),
+            Predicate::FpicGranted => input.fpic_granted,
+            Predicate::HasFlag { flag } => input.neurorights_flags.contains(flag),
+            Predicate::HighImpact => input.request.high_impact,
+            Predicate::Purpose { purpose } => input.request.purpose == *purpose,
+            Predicate::Requests { capability } => match capability {
+                RequestedCapability::FearPainChannels => input.request.may_use_fear_pain_channels,
+                RequestedCapability::MentalStateInference => input.request.may_infer_mental_state,
+                RequestedCapability::BeliefShaping => input.request.may_attempt_belief_shaping,
+            },
+            Predicate::All { all } => all.iter().all(|p| p.holds(input)),
+            Predicate::Any { any } => any.iter().any(|p| p.holds(input)),
+            Predicate::Not { predicate } => !predicate.holds(input),
+        }
+    }
+}
+
+/// Overlay-defined gate: a request is denied unless `require` holds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PolicyGate {
+    /// Stable identifier cited in denials, e.g. "snc:extraction-water".
+    pub id: String,
+    pub description: Option<String>,
+    pub require: Predicate,
+}
@@ -0,0 +1,82 @@
+#![forbid(unsafe_code)]
+
+use alloc::collections::BTreeMap;
+
+use crate::indigenous_corridor::{
+    ConsentVerifier, CorridorActionRequest, CorridorId, IndigenousEcoCorridorMap,
+    PreconditionError, PreconditionReport, Timestamp,
+};
+
+/// Many corridors, keyed by CorridorId. Enforces "no corridor, no build":
+/// a request naming an unregistered corridor is denied.[file:3][file:4]
+#[derive(Clone, Debug, Default)]
+pub struct CorridorRegistry {
+    corridors: BTreeMap<CorridorId, IndigenousEcoCorridorMap>,
+}
+
+impl CorridorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `corridor`, returning any corridor it replaces.
+    pub fn register(
+        &mut self,
+        corridor: IndigenousEcoCorridorMap,
+    ) -> Option<IndigenousEcoCorridorMap> {
+        self.corridors
+            .insert(corridor.corridor_id.clone(), corridor)
+    }
+
+    pub fn remove(&mut self, id: &CorridorId) -> Option<IndigenousEcoCorridorMap> {
+        self.corridors.remove(id)
+    }
+
+    pub fn get(&self, id: &CorridorId) -> Option<&IndigenousEcoCorridorMap> {
+        self.corridors.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.corridors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.corridors.is_empty()
+    }
+
+    /// Registered corridors in id order.
+    pub fn iter(&self) -> impl Iterator<Item = &IndigenousEcoCorridorMap> {
+        self.corridors.values()
+    }
+
+    /// `check_preconditions` against the corridor the request names, or
+    /// `NotRegistered` if there is none.
+    pub fn check(
+        &self,
+        request: &CorridorActionRequest,
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> Result<(), PreconditionError> {
+        self.resolve(request)?
+            .check_preconditions(request, at, verifier)
+    }
+
+    /// `evaluate_all` against the corridor the request names, or
+    /// `NotRegistered` if there is none.
+    pub fn evaluate_all(
+        &self,
+        request: &CorridorActionRequest,
+        at: Timestamp,
+        verifier: Option<&dyn ConsentVerifier>,
+    ) -> Result<PreconditionReport, PreconditionError> {
+        Ok(self.resolve(request)?.evaluate_all(request, at, verifier))
+    }
+
+    fn resolve(
+        &self,
+        request: &CorridorActionRequest,
+    ) -> Result<&IndigenousEcoCorridorMap, PreconditionError> {
+        self.get(&request.corridor_id)
+            .ok_or_else(|| PreconditionError::NotRegistered(request.corridor_id.clone()))
+    }
+}
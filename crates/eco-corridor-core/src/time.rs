@@ -0,0 +1,8 @@
+//! Clock-free timestamps for the precondition kernel.
+//! `SystemTime` needs an OS clock, which field gateways may lack, so the
+//! kernel takes `Timestamp`s; with the `std` feature they convert to and
+//! from `SystemTime`. The type is the shared one from `corridor-types`.
+
+#![forbid(unsafe_code)]
+
+pub use corridor_types::Timestamp;
@@ -0,0 +1,189 @@
+//! `Validate` re-checks for values that arrive without passing through
+//! the constructors (serde, FFI, struct literals).[file:3][file:4]
+
+#![forbid(unsafe_code)]
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use corridor_types::validate::{check_unit_interval, into_result};
+pub use corridor_types::{Finding, Validate};
+
+use crate::indigenous_corridor::{
+    decode_hex, ConsentStatus, CorridorActionRequest, CorridorId, EcoImpactMetrics, EcoMetric,
+    EcoScalar, IndigenousEcoCorridorMap, NeurorightsFlags, VerifiableConsent,
+};
+use crate::policy::{PolicyGate, Predicate};
+use crate::registry::CorridorRegistry;
+
+impl Validate for EcoScalar {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        into_result(check_unit_interval("", self.value()).into_iter().collect())
+    }
+}
+
+impl Validate for EcoImpactMetrics {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        into_result(
+            EcoMetric::ALL
+                .into_iter()
+                .filter_map(|m| check_unit_interval(&m.to_string(), self.get(m).value()))
+                .collect(),
+        )
+    }
+}
+
+impl Validate for VerifiableConsent {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Vec::new();
+        if !self.issuer_did.starts_with("did:") {
+            findings.push(Finding::new("issuer_did", "issuer must be a DID"));
+        }
+        if CorridorId::from_str(&self.subject_corridor_id).is_err() {
+            findings.push(Finding::new(
+                "subject_corridor_id",
+                "subject is not a valid corridor id",
+            ));
+        }
+        match (&self.status, self.revoked_at) {
+            (ConsentStatus::Revoked, None) => findings.push(Finding::new(
+                "revoked_at",
+                "revoked consent must record when it was revoked",
+            )),
+            (ConsentStatus::Granted | ConsentStatus::Pending, Some(_)) => findings.push(
+                Finding::new("revoked_at", "only revoked consent may carry revoked_at"),
+            ),
+            (_, Some(revoked_at)) if revoked_at < self.issued_at => findings.push(Finding::new(
+                "revoked_at",
+                "consent cannot be revoked before it was issued",
+            )),
+            _ => {}
+        }
+        if self.valid_until <= self.valid_from {
+            findings.push(Finding::new(
+                "valid_until",
+                "validity window must end after it starts",
+            ));
+        }
+        if decode_hex(&self.signature_hex).is_none() {
+            findings.push(Finding::new("signature_hex", "signature is not valid hex"));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for NeurorightsFlags {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut seen = Vec::new();
+        let mut findings = Vec::new();
+        for flag in self.iter() {
+            if seen.contains(&flag) {
+                findings.push(Finding::new("", format!("{:?} is listed twice", flag)));
+            }
+            seen.push(flag);
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for CorridorActionRequest {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings: Vec<Finding> =
+            check_unit_interval("required_min_eco_score", self.required_min_eco_score)
+                .into_iter()
+                .collect();
+        findings.extend(self.metric_minimums.iter().filter_map(|(metric, &score)| {
+            check_unit_interval(&format!("metric_minimums.{}", metric), score)
+        }));
+        if self
+            .proposer_did
+            .as_deref()
+            .is_some_and(|d| !d.starts_with("did:"))
+        {
+            findings.push(Finding::new("proposer_did", "proposer must be a DID"));
+        }
+        if self
+            .description
+            .as_deref()
+            .is_some_and(|d| d.trim().is_empty())
+        {
+            findings.push(Finding::new("description", "description is blank"));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for Predicate {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let findings = match self {
+            Predicate::Metric { value, .. } | Predicate::Aggregate { value, .. } => {
+                check_unit_interval("value", *value).into_iter().collect()
+            }
+            Predicate::All { all: nested } | Predicate::Any { any: nested } => nested
+                .iter()
+                .enumerate()
+                .flat_map(|(i, p)| Finding::within(&i.to_string(), p.validate()))
+                .collect(),
+            Predicate::Not { predicate } => Finding::within("predicate", predicate.validate()),
+            _ => Vec::new(),
+        };
+        into_result(findings)
+    }
+}
+
+impl Validate for PolicyGate {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Vec::new();
+        if self.id.trim().is_empty() {
+            findings.push(Finding::new("id", "gate id is blank"));
+        }
+        findings.extend(Finding::within("require", self.require.validate()));
+        into_result(findings)
+    }
+}
+
+impl Validate for IndigenousEcoCorridorMap {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Finding::within("eco_metrics", self.eco_metrics.validate());
+        if let Some(consent) = &self.fpic_ids_state {
+            findings.extend(Finding::within("fpic_ids_state", consent.validate()));
+            if CorridorId::from_str(&consent.subject_corridor_id)
+                .is_ok_and(|id| id != self.corridor_id)
+            {
+                findings.push(Finding::new(
+                    "fpic_ids_state.subject_corridor_id",
+                    "consent is for a different corridor",
+                ));
+            }
+        }
+        findings.extend(Finding::within(
+            "neurorights_flags",
+            self.neurorights_flags.validate(),
+        ));
+        let mut ids = BTreeSet::new();
+        for (i, gate) in self.policy_gates.iter().enumerate() {
+            let path = format!("policy_gates.{}", i);
+            findings.extend(Finding::within(&path, gate.validate()));
+            if !ids.insert(gate.id.as_str()) {
+                findings.push(Finding::new(
+                    path,
+                    format!("gate id '{}' is used twice", gate.id),
+                ));
+            }
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for CorridorRegistry {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        into_result(
+            self.iter()
+                .flat_map(|map| Finding::within(map.corridor_id.as_str(), map.validate()))
+                .collect(),
+        )
+    }
+}
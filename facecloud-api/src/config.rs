@@ -3,12 +3,26 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize, Clone)]
 pub struct ApiConfig {
     pub bind_addr: String,
+    /// Directory of `<lang>.ftl` message catalogs layered over the
+    /// built-in English strings.
+    pub locales_dir: Option<String>,
+    /// JSON corridor map served under `/corridors`.
+    pub map_path: Option<String>,
+    /// HMAC secret for corridor capability tokens; corridor routes
+    /// refuse all requests when unset.
+    pub token_key: Option<String>,
+    /// TOML or YAML `EnvelopeConfig`; the built-in thresholds when unset.
+    pub envelope_path: Option<String>,
 }
 
 impl Default for ApiConfig {
     fn default() -> Self {
         Self {
             bind_addr: "0.0.0.0:8080".to_string(),
+            locales_dir: std::env::var("FACECLOUD_LOCALES_DIR").ok(),
+            map_path: std::env::var("FACECLOUD_MAP_PATH").ok(),
+            token_key: std::env::var("FACECLOUD_TOKEN_KEY").ok(),
+            envelope_path: std::env::var("FACECLOUD_ENVELOPE_PATH").ok(),
         }
     }
 }
@@ -3,12 +3,16 @@ mod routes;
 
 use crate::config::ApiConfig;
 use crate::routes::{app_router, AppState};
-use axum::Server;
+use facecloud_core::i18n::MessageCatalog;
 use facecloud_core::neuromorphic::envelope::EnvelopeConfig;
 use facecloud_core::safety::guard::GuardKernel;
 use facecloud_core::safety::metrics::SafetyMetrics;
+use indigenous_eco_corridor_map::{
+    CorridorStore, IndigenousEcoCorridorMap, JsonFileStore, TokenAuthority,
+};
 use std::net::SocketAddr;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, RwLock};
+use tokio::net::TcpListener;
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
@@ -18,17 +22,34 @@ async fn main() {
         .init();
 
     let cfg = ApiConfig::default();
-    let guard = GuardKernel {
-        config: EnvelopeConfig::default(),
-    };
+    let guard = GuardKernel::new(match &cfg.envelope_path {
+        Some(path) => EnvelopeConfig::from_path(path).expect("invalid envelope config"),
+        None => EnvelopeConfig::default(),
+    });
     let metrics = SafetyMetrics::new();
+    let catalog = match &cfg.locales_dir {
+        Some(dir) => MessageCatalog::load_dir(dir).expect("invalid locales directory"),
+        None => MessageCatalog::builtin(),
+    };
+    let corridors = match &cfg.map_path {
+        Some(path) => JsonFileStore::new(path).load().expect("invalid corridor map"),
+        None => IndigenousEcoCorridorMap::new(),
+    };
+    let tokens = cfg
+        .token_key
+        .as_ref()
+        .map(|key| TokenAuthority::new(key.as_bytes()).expect("invalid token key"));
 
-    let state = AppState { guard, metrics };
+    let state = AppState {
+        guard,
+        metrics,
+        catalog: Arc::new(catalog),
+        corridors: Arc::new(RwLock::new(corridors.snapshot())),
+        tokens: tokens.map(Arc::new),
+    };
 
     let app = app_router(state);
     let addr: SocketAddr = cfg.bind_addr.parse().expect("invalid bind address");
-    Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await
-        .expect("server failed");
+    let listener = TcpListener::bind(addr).await.expect("failed to bind");
+    axum::serve(listener, app).await.expect("server failed");
 }
@@ -1,28 +1,80 @@
 use axum::{
-    extract::State,
+    extract::{Path, Query, Request, State},
+    http::{
+        header::{ACCEPT_LANGUAGE, AUTHORIZATION},
+        HeaderMap, StatusCode,
+    },
+    middleware::{self, Next},
+    response::Response,
     routing::{get, post},
     Json, Router,
 };
-use std::sync::{Arc, Mutex};
-use tracing::info;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::SystemTime;
+use tracing::{info, warn};
 
+use facecloud_core::i18n::MessageCatalog;
+use facecloud_core::neuromorphic::profiles::EnvelopeProfiles;
 use facecloud_core::neuromorphic::signals::InterfaceTelemetry;
 use facecloud_core::safety::guard::{GuardKernel, GuardRecommendation};
 use facecloud_core::safety::metrics::SafetyMetrics;
+use facecloud_core::safety::whatif::{EnvelopeOverrides, EnvelopeWhatIf};
 use facecloud_dna_auth::mfa::{evaluate_mfa, MultiLayerContext};
-use facecloud_dna_auth::policy::{evaluate_policy, AccessPolicy};
+use facecloud_dna_auth::policy::AccessPolicy;
+use indigenous_eco_corridor_map::{
+    CorridorId, Hypothetical, IndigenousEcoCorridor, MapSnapshot, TokenAuthority, TokenError,
+    WhatIfReport,
+};
+
+/// Purpose a capability token must cover to read corridor data.
+pub const CORRIDOR_READ_PURPOSE: &str = "monitoring";
 
 #[derive(Clone)]
 pub struct AppState {
     pub guard: GuardKernel,
     pub metrics: Arc<Mutex<SafetyMetrics>>,
+    pub catalog: Arc<MessageCatalog>,
+    /// Published corridor map; writers build the next version and swap
+    /// in its snapshot, readers never wait on them.
+    pub corridors: Arc<RwLock<MapSnapshot>>,
+    /// `None` when no token key is configured; corridor routes then
+    /// reject every request.
+    pub tokens: Option<Arc<TokenAuthority>>,
+}
+
+impl AppState {
+    /// Negotiated response language for a request.
+    fn lang(&self, headers: &HeaderMap) -> String {
+        let accept = headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        self.catalog.negotiate(accept)
+    }
+
+    /// Current published corridor snapshot.
+    fn corridors(&self) -> MapSnapshot {
+        self.corridors.read().unwrap().clone()
+    }
 }
 
 pub fn app_router(state: AppState) -> Router {
+    let corridors = Router::new()
+        .route("/corridors/:id", get(get_corridor))
+        .route("/corridors/:id/whatif", post(corridor_what_if))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_capability,
+        ));
+
     Router::new()
+        .merge(corridors)
         .route("/health", get(health))
         .route("/evaluate/envelope", post(evaluate_envelope))
+        .route("/envelope/profiles", get(envelope_profiles))
         .route("/evaluate/mfa", post(evaluate_mfa_route))
+        .route("/whatif/envelope", post(envelope_what_if))
         .route("/metrics", get(metrics))
         .with_state(state)
 }
@@ -31,23 +83,48 @@ async fn health() -> &'static str {
     "OK"
 }
 
+#[derive(Deserialize)]
+pub struct EnvelopeQuery {
+    /// Named profile to evaluate against; the guard's own config if unset.
+    pub profile: Option<String>,
+}
+
 async fn evaluate_envelope(
     State(state): State<AppState>,
+    Query(query): Query<EnvelopeQuery>,
+    headers: HeaderMap,
     Json(telemetry): Json<InterfaceTelemetry>,
-) -> Json<GuardRecommendation> {
-    let rec = state.guard.evaluate(&telemetry);
+) -> Result<Json<GuardRecommendation>, (StatusCode, String)> {
+    let mut rec = match &query.profile {
+        Some(profile) => state
+            .guard
+            .evaluate_profile(profile, &telemetry)
+            .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?,
+        None => state.guard.evaluate(&telemetry),
+    };
     {
         let metrics = state.metrics.lock().unwrap();
         metrics.observe_status(rec.evaluation.status, rec.evaluation.composite_margin);
     }
     info!("Envelope evaluation: {:?}", rec.message);
-    Json(rec)
+    rec.localize(&state.catalog, &state.lang(&headers));
+    Ok(Json(rec))
+}
+
+async fn envelope_profiles(State(state): State<AppState>) -> Json<EnvelopeProfiles> {
+    Json(state.guard.profiles.clone())
 }
 
 async fn evaluate_mfa_route(
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(ctx): Json<MultiLayerContext>,
 ) -> Json<(facecloud_dna_auth::mfa::AuthEvaluation, AccessPolicy)> {
-    let auth_eval = evaluate_mfa(&ctx);
+    let mut auth_eval = evaluate_mfa(&ctx);
+    auth_eval.explanation = state
+        .catalog
+        .message(&state.lang(&headers), &auth_eval.code)
+        .to_string();
     let policy = AccessPolicy::default();
     Json((auth_eval, policy))
 }
@@ -56,3 +133,227 @@ async fn metrics(State(state): State<AppState>) -> String {
     let metrics = state.metrics.lock().unwrap();
     metrics.export_prometheus()
 }
+
+/// Admit a corridor request only with a bearer capability token that
+/// covers the path corridor and `CORRIDOR_READ_PURPOSE` under the
+/// corridor's current FPIC grant. Missing, malformed and expired tokens
+/// get 401; valid tokens that do not cover the request get 403.
+async fn require_capability(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    request: Request,
+    next: Next,
+) -> Result<Response, (StatusCode, String)> {
+    let Some(tokens) = &state.tokens else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "capability tokens are not configured".to_string(),
+        ));
+    };
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or((StatusCode::UNAUTHORIZED, "missing bearer token".to_string()))?;
+    let id: CorridorId = id.parse().map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let map = state.corridors();
+    let now = SystemTime::now();
+    if let Err(e) = tokens.verify(token, &map, &id, CORRIDOR_READ_PURPOSE, now) {
+        warn!("Capability token rejected for {}: {}", id, e);
+        let status = match e {
+            TokenError::Invalid | TokenError::Expired => StatusCode::UNAUTHORIZED,
+            _ => StatusCode::FORBIDDEN,
+        };
+        return Err((status, e.to_string()));
+    }
+    Ok(next.run(request).await)
+}
+
+async fn get_corridor(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<IndigenousEcoCorridor>, StatusCode> {
+    let id: CorridorId = id.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+    state
+        .corridors()
+        .get(&id).cloned().map(Json).ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Hypothetical changes to one corridor; every entry must target the
+/// corridor in the path.
+async fn corridor_what_if(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(hypotheticals): Json<Vec<Hypothetical>>,
+) -> Result<Json<WhatIfReport>, (StatusCode, String)> {
+    let id: CorridorId = id.parse().map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    if hypotheticals.iter().any(|h| *h.corridor_id() != id) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("hypotheticals must target corridor '{}'", id),
+        ));
+    }
+    state
+        .corridors()
+        .what_if(&hypotheticals)
+        .map(Json)
+        .map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e))
+}
+
+#[derive(Deserialize)]
+pub struct EnvelopeWhatIfRequest {
+    #[serde(default)]
+    pub overrides: EnvelopeOverrides,
+    pub telemetry: Vec<InterfaceTelemetry>,
+}
+
+async fn envelope_what_if(
+    State(state): State<AppState>,
+    Json(req): Json<EnvelopeWhatIfRequest>,
+) -> Result<Json<EnvelopeWhatIf>, (StatusCode, String)> {
+    state
+        .guard
+        .what_if(&req.overrides, &req.telemetry)
+        .map(Json)
+        .map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use facecloud_core::neuromorphic::envelope::EnvelopeConfig;
+    use indigenous_eco_corridor_map::{
+        CommunityId, Consent, ConsentQuorum, CorridorDescriptor, CorridorKind,
+        EnvironmentalMetrics, FpicPurpose, FpicStatus, IdsFramework, IdsScope,
+        IndigenousEcoCorridorMap, Lifecycle, MetricComponent, NeurorightsConstraints, Score,
+    };
+    use std::collections::BTreeSet;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    const CORRIDOR: &str = "eco:river:api";
+
+    /// A corridor whose FPIC grant covers `purposes`.
+    fn granted(purposes: &[FpicPurpose]) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            descriptor: CorridorDescriptor::builder()
+                .id(CORRIDOR.parse().unwrap())
+                .kind(CorridorKind::River)
+                .name("API River")
+                .build()
+                .unwrap(),
+            environmental: EnvironmentalMetrics::from_components(
+                [Score::new(0.5).unwrap(); MetricComponent::COUNT],
+            ),
+            fpic: FpicStatus::Granted {
+                at: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::sole(
+                    CommunityId("nation-x".into()),
+                    Consent {
+                        at: SystemTime::UNIX_EPOCH,
+                        reference: "receipt:1".into(),
+                        delegation: None,
+                    },
+                ),
+                terms_reference: "terms:1".into(),
+                purposes: purposes.iter().copied().collect::<BTreeSet<_>>(),
+                valid_until: None,
+                renewal_notice: None,
+                terms: None,
+            },
+            fpic_trail: Vec::new(),
+            revocations: Vec::new(),
+            ids_scope: IdsScope {
+                contains_indigenous_data: true,
+                frameworks: vec![IdsFramework::Unspecified],
+                governance_ref: None,
+                embargo: None,
+            },
+            neurorights: NeurorightsConstraints::strict_non_actuating(),
+            cultural_notes: None,
+            cultural_sites: Vec::new(),
+            lifecycle: Lifecycle::Active,
+            seasons: Vec::new(),
+            signature: None,
+        }
+    }
+
+    fn router(authority: &TokenAuthority, corridor: IndigenousEcoCorridor) -> Router {
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor).unwrap();
+        app_router(AppState {
+            guard: GuardKernel::new(EnvelopeConfig::default()),
+            metrics: SafetyMetrics::new(),
+            catalog: Arc::new(MessageCatalog::builtin()),
+            corridors: Arc::new(RwLock::new(map.snapshot())),
+            tokens: Some(Arc::new(authority.clone())),
+        })
+    }
+
+    async fn status(router: &Router, method: &str, path: &str, token: Option<&str>) -> StatusCode {
+        let mut request = Request::builder().method(method).uri(path);
+        if let Some(token) = token {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let body = if method == "POST" {
+            request = request.header("content-type", "application/json");
+            Body::from("[]")
+        } else {
+            Body::empty()
+        };
+        router
+            .clone()
+            .oneshot(request.body(body).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn corridor_routes_require_a_covering_live_token() {
+        let authority = TokenAuthority::new([7u8; 32]).unwrap();
+        let both = granted(&[FpicPurpose::Monitoring, FpicPurpose::Research]);
+        let router = router(&authority, both.clone());
+        let mint = |purpose: &str, issued: SystemTime| {
+            authority
+                .mint(
+                    &both,
+                    vec![purpose.to_string()],
+                    Duration::from_secs(60),
+                    issued,
+                )
+                .unwrap()
+        };
+        let now = SystemTime::now();
+        let valid = mint(CORRIDOR_READ_PURPOSE, now);
+        let expired = mint(CORRIDOR_READ_PURPOSE, now - Duration::from_secs(3_600));
+        let wrong_scope = mint("research", now);
+
+        let read = format!("/corridors/{}", CORRIDOR);
+        let what_if = format!("/corridors/{}/whatif", CORRIDOR);
+        for (method, path) in [("GET", &read), ("POST", &what_if)] {
+            assert_eq!(
+                status(&router, method, path, None).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                status(&router, method, path, Some(&expired)).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                status(&router, method, path, Some(&wrong_scope)).await,
+                StatusCode::FORBIDDEN
+            );
+            assert_eq!(
+                status(&router, method, path, Some(&valid)).await,
+                StatusCode::OK
+            );
+        }
+        assert_eq!(
+            status(&router, "GET", "/corridors/eco:river:other", Some(&valid)).await,
+            StatusCode::FORBIDDEN
+        );
+    }
+}
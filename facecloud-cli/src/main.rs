@@ -1,17 +1,36 @@
 use clap::{Parser, Subcommand};
+use facecloud_core::i18n::MessageCatalog;
 use facecloud_core::neuromorphic::envelope::EnvelopeConfig;
 use facecloud_core::neuromorphic::signals::{
     EmFieldIntensity, InflammationIndex, InterfaceCoherence, InterfaceTelemetry, MechDensity,
-    SpikeEnergy, ThermalLoad,
+    MicroMotion, PhDeviation, SpikeEnergy, ThermalLoad, TissueOxygenation,
 };
 use facecloud_core::safety::guard::GuardKernel;
+use facecloud_core::safety::whatif::EnvelopeOverrides;
 use facecloud_dna_auth::mfa::{evaluate_mfa, DnaFactor, KnowledgeFactor, MultiLayerContext, PossessionFactor};
+use indigenous_eco_corridor_map::{
+    CorridorId, CorridorStore, Hypothetical, JsonFileStore, TokenAuthority,
+};
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
 use uuid::Uuid;
 
 #[derive(Parser)]
 #[command(name = "facecloud-cli")]
 #[command(about = "Facecloud safety and MFA inspector.")]
 struct Cli {
+    /// Language for advisory text (e.g. `es`, `nv-US`); falls back to English.
+    #[arg(long, global = true, default_value = "en")]
+    lang: String,
+    /// Directory of `<lang>.ftl` message catalogs.
+    #[arg(long, global = true)]
+    locales_dir: Option<String>,
+    /// TOML or YAML envelope config; the built-in thresholds when omitted.
+    #[arg(long, global = true)]
+    envelope: Option<String>,
+    /// Named envelope profile (e.g. `conservative`, `strict_floor`).
+    #[arg(long, global = true, conflicts_with = "envelope")]
+    profile: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -25,6 +44,15 @@ enum Commands {
         thermal: f32,
         inflammation: f32,
         spike: f32,
+        #[arg(long)]
+        ph_deviation: Option<f32>,
+        #[arg(long)]
+        oxygenation: Option<f32>,
+        #[arg(long)]
+        micro_motion: Option<f32>,
+        /// Named signal as `name=value`; repeat for several.
+        #[arg(long = "signal", value_parser = parse_signal)]
+        signals: Vec<(String, f32)>,
     },
     Mfa {
         #[arg(long)]
@@ -34,10 +62,72 @@ enum Commands {
         #[arg(long)]
         dna_confidence: Option<f32>,
     },
+    /// Mint a capability token from a corridor's active FPIC grant.
+    /// The signing key is read from `FACECLOUD_TOKEN_KEY`.
+    Token {
+        /// JSON corridor map file.
+        #[arg(long)]
+        map: String,
+        #[arg(long)]
+        corridor: CorridorId,
+        /// Purpose the token covers; repeat for several.
+        #[arg(long = "purpose", required = true)]
+        purposes: Vec<String>,
+        #[arg(long, default_value_t = 3600)]
+        ttl_secs: u64,
+    },
+    /// Dry-run hypothetical corridor and envelope changes; nothing is written.
+    Whatif {
+        /// JSON scenario file (see `Scenario`).
+        scenario: String,
+        /// JSON corridor map file; required for corridor hypotheticals.
+        #[arg(long)]
+        map: Option<String>,
+    },
+}
+
+/// What-if scenario: corridor hypotheticals are evaluated against the
+/// map, envelope overrides against the telemetry samples.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Scenario {
+    corridors: Vec<Hypothetical>,
+    envelope_overrides: EnvelopeOverrides,
+    telemetry: Vec<InterfaceTelemetry>,
+}
+
+fn parse_signal(arg: &str) -> Result<(String, f32), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got '{}'", arg))?;
+    let value = value
+        .parse()
+        .map_err(|e| format!("invalid value for signal '{}': {}", name, e))?;
+    Ok((name.to_string(), value))
+}
+
+fn fail(msg: impl std::fmt::Display) -> ! {
+    eprintln!("{}", msg);
+    std::process::exit(2);
 }
 
 fn main() {
     let cli = Cli::parse();
+    let catalog = match &cli.locales_dir {
+        Some(dir) => MessageCatalog::load_dir(dir).unwrap_or_else(|e| {
+            eprintln!("failed to load locales from {}: {}", dir, e);
+            std::process::exit(2);
+        }),
+        None => MessageCatalog::builtin(),
+    };
+    let lang = catalog.negotiate(&cli.lang);
+    let mut kernel = GuardKernel::new(match &cli.envelope {
+        Some(path) => EnvelopeConfig::from_path(path).unwrap_or_else(|e| fail(e)),
+        None => EnvelopeConfig::default(),
+    });
+    if let Some(profile) = &cli.profile {
+        kernel.config = kernel.profiles.get(profile).unwrap_or_else(|e| fail(e)).clone();
+    }
     match cli.command {
         Commands::Envelope {
             mech_density,
@@ -46,6 +136,10 @@ fn main() {
             thermal,
             inflammation,
             spike,
+            ph_deviation,
+            oxygenation,
+            micro_motion,
+            signals,
         } => {
             let telemetry = InterfaceTelemetry {
                 mech_density: MechDensity(mech_density),
@@ -54,11 +148,13 @@ fn main() {
                 thermal_load: ThermalLoad(thermal),
                 inflammation: InflammationIndex(inflammation),
                 spike_energy: SpikeEnergy(spike),
+                ph_deviation: ph_deviation.map(PhDeviation),
+                oxygenation: oxygenation.map(TissueOxygenation),
+                micro_motion: micro_motion.map(MicroMotion),
+                signals: signals.into_iter().collect(),
             };
-            let kernel = GuardKernel {
-                config: EnvelopeConfig::default(),
-            };
-            let rec = kernel.evaluate(&telemetry);
+            let mut rec = kernel.evaluate(&telemetry);
+            rec.localize(&catalog, &lang);
             println!("{}", serde_json::to_string_pretty(&rec).unwrap());
         }
         Commands::Mfa {
@@ -77,8 +173,49 @@ fn main() {
                     confidence: c,
                 }),
             };
-            let eval = evaluate_mfa(&ctx);
+            let mut eval = evaluate_mfa(&ctx);
+            eval.explanation = catalog.message(&lang, &eval.code).to_string();
             println!("{}", serde_json::to_string_pretty(&eval).unwrap());
         }
+        Commands::Token {
+            map,
+            corridor,
+            purposes,
+            ttl_secs,
+        } => {
+            let key = std::env::var("FACECLOUD_TOKEN_KEY")
+                .unwrap_or_else(|_| fail("FACECLOUD_TOKEN_KEY is not set"));
+            let authority = TokenAuthority::new(key.into_bytes()).unwrap_or_else(|e| fail(e));
+            let map = JsonFileStore::new(&map).load().unwrap_or_else(|e| fail(e));
+            let corridor = map
+                .get(&corridor)
+                .unwrap_or_else(|| fail(format!("corridor '{}' is not registered", corridor)));
+            let token = authority
+                .mint(
+                    corridor,
+                    purposes,
+                    Duration::from_secs(ttl_secs),
+                    SystemTime::now(),
+                )
+                .unwrap_or_else(|e| fail(e));
+            println!("{}", token);
+        }
+        Commands::Whatif { scenario, map } => {
+            let text = std::fs::read_to_string(&scenario).unwrap_or_else(|e| fail(e));
+            let scenario: Scenario = serde_json::from_str(&text).unwrap_or_else(|e| fail(e));
+            let corridors = match (&map, scenario.corridors.is_empty()) {
+                (_, true) => None,
+                (None, false) => fail("--map is required for corridor hypotheticals"),
+                (Some(path), false) => {
+                    let map = JsonFileStore::new(path).load().unwrap_or_else(|e| fail(e));
+                    Some(map.what_if(&scenario.corridors).unwrap_or_else(|e| fail(e)))
+                }
+            };
+            let envelope = kernel
+                .what_if(&scenario.envelope_overrides, &scenario.telemetry)
+                .unwrap_or_else(|e| fail(e));
+            let report = serde_json::json!({ "corridors": corridors, "envelope": envelope });
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        }
     }
 }
@@ -0,0 +1,23 @@
+# Facecloud advisory messages (English, built-in).
+# Keys are stable codes; translations live in `<lang>.ftl` files using the
+# same keys. Format: one `code = text` message per line.
+
+## Envelope guard (facecloud-core)
+envelope-safe = Within safety envelope.
+envelope-safe-action = Maintain current parameters; continue monitoring.
+envelope-caution = CAUTION_SCALE_THRESHOLD_APPROACHED
+envelope-caution-action = Do not increase integration density or field intensity; prefer down-scaling or simulations only.
+envelope-hard-deny = HARD_DENY: envelope breached.
+envelope-hard-deny-action = Reduce load, density, and exposure in models; consult safety governance before any further scaling.
+
+## DNA-style MFA (facecloud-dna-auth)
+mfa-allow = All three layers satisfied (knowledge, possession, DNA-like factor).
+mfa-require-additional-factors = Knowledge and possession present; DNA-like factor insufficient or missing.
+mfa-deny = Authentication factors incomplete; access denied by policy.
+
+## Advisory risk labels (eco-corridor-core)
+blocked_fpic_withheld = Blocked: FPIC withheld.
+hold_fpic_pending = On hold: FPIC pending.
+low_risk_observational = Low risk: observational use.
+medium_risk_review = Medium risk: review required.
+high_risk_review = High risk: review required.
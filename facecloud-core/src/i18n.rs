@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Language every catalog can fall back to; built-in strings are English.
+pub const DEFAULT_LANG: &str = "en";
+
+const BUILTIN_EN: &str = include_str!("../locales/en.ftl");
+
+/// Message catalog keyed by stable codes (e.g. `envelope-caution`).
+///
+/// Sources use a Fluent-compatible subset: one `code = text` message per
+/// line, `#` comments, blank lines ignored. Lookups fall back to English
+/// and finally to the code itself, so a missing translation never hides
+/// an advisory.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl MessageCatalog {
+    /// Catalog with the built-in English messages only.
+    pub fn builtin() -> Self {
+        let mut catalog = Self {
+            messages: HashMap::new(),
+        };
+        catalog
+            .add_source(DEFAULT_LANG, BUILTIN_EN)
+            .expect("built-in English catalog must parse");
+        catalog
+    }
+
+    /// Merge messages for `lang` from Fluent-subset source text.
+    pub fn add_source(&mut self, lang: &str, source: &str) -> Result<(), String> {
+        let lang = normalize_tag(lang);
+        let entries = self.messages.entry(lang.clone()).or_default();
+        for (n, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (code, text) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}.ftl line {}: expected `code = text`", lang, n + 1))?;
+            let code = code.trim();
+            let valid = code.starts_with(|c: char| c.is_ascii_alphabetic())
+                && code
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                return Err(format!(
+                    "{}.ftl line {}: invalid code `{}`",
+                    lang,
+                    n + 1,
+                    code
+                ));
+            }
+            entries.insert(code.to_string(), text.trim().to_string());
+        }
+        Ok(())
+    }
+
+    /// Load every `<lang>.ftl` file in `dir` on top of the built-ins.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Self, String> {
+        let mut catalog = Self::builtin();
+        let entries = fs::read_dir(dir.as_ref()).map_err(|e| e.to_string())?;
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ftl") {
+                continue;
+            }
+            let Some(lang) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let source = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            catalog.add_source(lang, &source)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Languages with at least one message, sorted.
+    pub fn languages(&self) -> Vec<&str> {
+        let mut langs: Vec<&str> = self.messages.keys().map(String::as_str).collect();
+        langs.sort_unstable();
+        langs
+    }
+
+    /// Pick the best available language for an HTTP `Accept-Language`
+    /// value (or a plain tag such as `es-MX`). Exact tags win over
+    /// primary-subtag matches; unknown input yields `DEFAULT_LANG`.
+    pub fn negotiate(&self, accept_language: &str) -> String {
+        let mut ranges: Vec<(String, f32)> = accept_language
+            .split(',')
+            .filter_map(|part| {
+                let mut pieces = part.split(';');
+                let tag = normalize_tag(pieces.next()?);
+                if tag.is_empty() || tag == "*" {
+                    return None;
+                }
+                let q = pieces
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (q > 0.0).then_some((tag, q))
+            })
+            .collect();
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        for (tag, _) in &ranges {
+            if self.messages.contains_key(tag) {
+                return tag.clone();
+            }
+            let primary = tag.split('-').next().unwrap_or(tag);
+            if self.messages.contains_key(primary) {
+                return primary.to_string();
+            }
+        }
+        DEFAULT_LANG.to_string()
+    }
+
+    /// Message for `code` in `lang`, falling back to English, then the code.
+    pub fn message<'a>(&'a self, lang: &str, code: &'a str) -> &'a str {
+        let lang = normalize_tag(lang);
+        let primary = lang.split('-').next().unwrap_or(&lang);
+        [lang.as_str(), primary, DEFAULT_LANG]
+            .iter()
+            .find_map(|l| self.messages.get(*l).and_then(|m| m.get(code)))
+            .map(String::as_str)
+            .unwrap_or(code)
+    }
+}
+
+/// Process-wide built-in catalog, parsed once.
+pub fn builtin_catalog() -> &'static MessageCatalog {
+    static BUILTIN: OnceLock<MessageCatalog> = OnceLock::new();
+    BUILTIN.get_or_init(MessageCatalog::builtin)
+}
+
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiation_and_fallback() {
+        let mut catalog = MessageCatalog::builtin();
+        catalog
+            .add_source("es", "envelope-safe = Dentro del margen de seguridad.")
+            .unwrap();
+
+        assert_eq!(catalog.negotiate("fr-CA, es-MX;q=0.8, en;q=0.5"), "es");
+        assert_eq!(catalog.negotiate("de"), DEFAULT_LANG);
+        assert_eq!(
+            catalog.message("es-MX", "envelope-safe"),
+            "Dentro del margen de seguridad."
+        );
+        // Untranslated codes fall back to English, unknown codes to the code.
+        assert_eq!(
+            catalog.message("es", "envelope-hard-deny"),
+            "HARD_DENY: envelope breached."
+        );
+        assert_eq!(catalog.message("es", "no-such-code"), "no-such-code");
+    }
+}
@@ -1,2 +1,3 @@
+pub mod i18n;
 pub mod neuromorphic;
 pub mod safety;
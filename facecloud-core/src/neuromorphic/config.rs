@@ -0,0 +1,292 @@
+//! Loading `EnvelopeConfig` from TOML or YAML, so deployments can change
+//! thresholds without recompiling. Loaded configs are validated before
+//! they are returned.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::envelope::{CompositeMode, EnvelopeConfig};
+use super::signals::InterfaceTelemetry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// The format named by `path`'s extension: `.toml`, `.yaml` or `.yml`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Yaml => "YAML",
+        })
+    }
+}
+
+/// Errors raised while loading an envelope config.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    /// The file extension names neither TOML nor YAML.
+    UnknownFormat(PathBuf),
+    Parse {
+        format: ConfigFormat,
+        message: String,
+    },
+    /// The config parsed but its values are unusable; one entry per problem.
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "envelope config I/O error: {}", e),
+            ConfigError::UnknownFormat(path) => write!(
+                f,
+                "cannot tell the format of envelope config {}; use a .toml, .yaml or .yml extension",
+                path.display()
+            ),
+            ConfigError::Parse { format, message } => {
+                write!(f, "invalid {} envelope config: {}", format, message)
+            }
+            ConfigError::Invalid(problems) => {
+                write!(f, "{} problem(s) in envelope config", problems.len())?;
+                for p in problems {
+                    write!(f, "; {}", p)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl EnvelopeConfig {
+    /// Read and validate the config at `path`, in the format its extension names.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)
+            .ok_or_else(|| ConfigError::UnknownFormat(path.to_path_buf()))?;
+        Self::parse(&fs::read_to_string(path)?, format)
+    }
+
+    /// Parse and validate a config. Omitted optional sections take their
+    /// defaults, as with JSON.
+    pub fn parse(text: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let parse_error = |message: String| ConfigError::Parse { format, message };
+        let config: EnvelopeConfig = match format {
+            ConfigFormat::Toml => toml::from_str(text).map_err(|e| parse_error(e.to_string()))?,
+            // Through a JSON value so enums take the same shape as in JSON
+            // and TOML; serde_yaml alone expects `!variant` tags.
+            ConfigFormat::Yaml => serde_yaml::from_str(text)
+                .and_then(|value: serde_json::Value| {
+                    serde_json::from_value(value).map_err(serde::de::Error::custom)
+                })
+                .map_err(|e| parse_error(e.to_string()))?,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that thresholds are usable: positive finite limits, an
+    /// ordered caution band, non-negative weights, and named signals that
+    /// do not shadow typed ones.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+        let mut positive = |name: &str, value: f32| {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!(
+                    "{} must be positive and finite, got {}",
+                    name, value
+                ));
+            }
+        };
+        positive("mech_density_max", self.mech_density_max);
+        positive("interface_coherence_min", self.interface_coherence_min);
+        positive("em_field_max", self.em_field_max);
+        positive("thermal_max", self.thermal_max);
+        positive("inflammation_max", self.inflammation_max);
+        positive("spike_energy_max", self.spike_energy_max);
+        positive("ph_deviation_max", self.ph_deviation_max);
+        positive("oxygenation_min", self.oxygenation_min);
+        positive("micro_motion_max", self.micro_motion_max);
+        positive("caution_lower", self.caution_lower);
+        positive("caution_upper", self.caution_upper);
+        let rates = &self.max_rates;
+        for (name, rate) in [
+            ("max_rates.mech_density", rates.mech_density),
+            ("max_rates.interface_coherence", rates.interface_coherence),
+            ("max_rates.em_field", rates.em_field),
+            ("max_rates.thermal", rates.thermal),
+            ("max_rates.inflammation", rates.inflammation),
+            ("max_rates.spike_energy", rates.spike_energy),
+            ("max_rates.ph_deviation", rates.ph_deviation),
+            ("max_rates.oxygenation", rates.oxygenation),
+            ("max_rates.micro_motion", rates.micro_motion),
+        ] {
+            if let Some(rate) = rate {
+                positive(name, rate);
+            }
+        }
+        for (name, constraint) in &self.signals {
+            positive(&format!("signals.{}.limit", name), constraint.limit);
+            if let Some(rate) = constraint.max_rate {
+                positive(&format!("signals.{}.max_rate", name), rate);
+            }
+        }
+        if let CompositeMode::SoftMin { temperature } = self.composite_mode {
+            positive("composite_mode.soft_min.temperature", temperature);
+        }
+
+        if self.caution_lower > self.caution_upper {
+            problems.push(format!(
+                "caution_lower ({}) must not exceed caution_upper ({})",
+                self.caution_lower, self.caution_upper
+            ));
+        }
+        for name in self.signals.keys() {
+            if name.trim().is_empty() {
+                problems.push("named signals must have a non-blank name".to_string());
+            } else if InterfaceTelemetry::TYPED_SIGNALS.contains(&name.as_str()) {
+                problems.push(format!(
+                    "signals.{} shadows the typed signal of that name",
+                    name
+                ));
+            }
+        }
+        let w = &self.weights;
+        for (name, weight) in [
+            ("weights.mech_density", w.mech_density),
+            ("weights.interface_coherence", w.interface_coherence),
+            ("weights.em_field", w.em_field),
+            ("weights.thermal", w.thermal),
+            ("weights.inflammation", w.inflammation),
+            ("weights.spike_energy", w.spike_energy),
+            ("weights.ph_deviation", w.ph_deviation),
+            ("weights.oxygenation", w.oxygenation),
+            ("weights.micro_motion", w.micro_motion),
+            ("weights.rate", w.rate),
+        ]
+        .into_iter()
+        .map(|(name, weight)| (name.to_string(), weight))
+        .chain(
+            w.signals
+                .iter()
+                .map(|(name, &weight)| (format!("weights.signals.{}", name), weight)),
+        ) {
+            if !(weight.is_finite() && weight >= 0.0) {
+                problems.push(format!(
+                    "{} must be non-negative and finite, got {}",
+                    name, weight
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_toml_and_yaml_and_reports_every_problem() {
+        let toml = r#"
+            mech_density_max = 1.0
+            interface_coherence_min = 0.8
+            em_field_max = 1.0
+            thermal_max = 0.9
+            inflammation_max = 1.0
+            spike_energy_max = 1.0
+            caution_lower = 1.0
+            caution_upper = 1.2
+            composite_mode = "geometric_mean"
+
+            [max_rates]
+            thermal = 0.25
+        "#;
+        let config = EnvelopeConfig::parse(toml, ConfigFormat::Toml).unwrap();
+        assert_eq!(config.thermal_max, 0.9);
+        assert_eq!(config.max_rates.thermal, Some(0.25));
+        assert_eq!(config.composite_mode, CompositeMode::GeometricMean);
+
+        let yaml = "
+mech_density_max: 1.0
+interface_coherence_min: 0.8
+em_field_max: 1.0
+thermal_max: 1.0
+inflammation_max: -1.0
+spike_energy_max: 1.0
+caution_lower: 1.3
+caution_upper: 1.1
+composite_mode:
+  soft_min:
+    temperature: 0.05
+";
+        match EnvelopeConfig::parse(yaml, ConfigFormat::Yaml) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(
+                problems,
+                [
+                    "inflammation_max must be positive and finite, got -1",
+                    "caution_lower (1.3) must not exceed caution_upper (1.1)",
+                ]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+        let named = format!(
+            "{}\n[signals.lactate]\ndirection = \"upper\"\nlimit = 0.5\n\n\
+             [signals.thermal_load]\ndirection = \"lower\"\nlimit = 0.0\n",
+            toml
+        );
+        match EnvelopeConfig::parse(&named, ConfigFormat::Toml) {
+            Err(ConfigError::Invalid(problems)) => assert_eq!(
+                problems,
+                [
+                    "signals.thermal_load.limit must be positive and finite, got 0",
+                    "signals.thermal_load shadows the typed signal of that name",
+                ]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(
+            EnvelopeConfig::parse("thermal_max = 1.0", ConfigFormat::Toml),
+            Err(ConfigError::Parse {
+                format: ConfigFormat::Toml,
+                ..
+            })
+        ));
+        assert!(matches!(
+            EnvelopeConfig::from_path("envelope.json"),
+            Err(ConfigError::UnknownFormat(_))
+        ));
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("deploy/envelope.YML")),
+            Some(ConfigFormat::Yaml)
+        );
+    }
+}
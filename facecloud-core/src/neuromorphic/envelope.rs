@@ -1,12 +1,14 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use super::signals::{
-    EmFieldIntensity, InflammationIndex, InterfaceCoherence, InterfaceTelemetry, MechDensity,
-    Salience, SpikeEnergy, ThermalLoad,
+    InterfaceCoherence, InterfaceTelemetry, MechDensity, Salience, TissueOxygenation,
 };
 
 /// Safety margins for each constraint; 1.0 = just-safe, >1.0 = margin, <1.0 = breach.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ConstraintMargins {
     pub mech_density_margin: f32,
     pub interface_coherence_margin: f32,
@@ -14,6 +16,20 @@ pub struct ConstraintMargins {
     pub thermal_margin: f32,
     pub inflammation_margin: f32,
     pub spike_energy_margin: f32,
+    /// Margins of the optional signals; `None` when not reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ph_deviation_margin: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oxygenation_margin: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub micro_motion_margin: Option<f32>,
+    /// Margins of the reported named signals that have a constraint.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub signals: BTreeMap<String, f32>,
+    /// Tightest rate-of-change margin (limit / observed change); `None`
+    /// for single samples and when no limited signal is moving adversely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_margin: Option<f32>,
 }
 
 impl ConstraintMargins {
@@ -24,11 +40,279 @@ impl ConstraintMargins {
             .min(self.thermal_margin)
             .min(self.inflammation_margin)
             .min(self.spike_energy_margin)
+            .min(self.ph_deviation_margin.unwrap_or(f32::INFINITY))
+            .min(self.oxygenation_margin.unwrap_or(f32::INFINITY))
+            .min(self.micro_motion_margin.unwrap_or(f32::INFINITY))
+            .min(self.signals.values().copied().fold(f32::INFINITY, f32::min))
+            .min(self.rate_margin.unwrap_or(f32::INFINITY))
+    }
+
+    /// The composite under `mode`; `CompositeMode::Min` is `composite`.
+    pub fn composite_with(&self, mode: CompositeMode, weights: &ConstraintWeights) -> f32 {
+        let weighted = [
+            (self.mech_density_margin, weights.mech_density),
+            (self.interface_coherence_margin, weights.interface_coherence),
+            (self.em_field_margin, weights.em_field),
+            (self.thermal_margin, weights.thermal),
+            (self.inflammation_margin, weights.inflammation),
+            (self.spike_energy_margin, weights.spike_energy),
+        ]
+        .into_iter()
+        .chain(self.ph_deviation_margin.map(|m| (m, weights.ph_deviation)))
+        .chain(self.oxygenation_margin.map(|m| (m, weights.oxygenation)))
+        .chain(self.micro_motion_margin.map(|m| (m, weights.micro_motion)))
+        .chain(
+            self.signals
+                .iter()
+                .map(|(name, &m)| (m, weights.signals.get(name).copied().unwrap_or(1.0))),
+        )
+        .chain(self.rate_margin.map(|m| (m, weights.rate)))
+        .filter(|&(_, w)| w > 0.0);
+        let total: f32 = weighted.clone().map(|(_, w)| w).sum();
+        if total == 0.0 {
+            return self.composite();
+        }
+        match mode {
+            CompositeMode::Min => self.composite(),
+            CompositeMode::WeightedMin => {
+                weighted.map(|(m, w)| m / w).fold(f32::INFINITY, f32::min)
+            }
+            CompositeMode::SoftMin { temperature } => {
+                let t = temperature.max(f32::EPSILON);
+                // Shifted by the minimum so the exponentials cannot underflow.
+                let floor = weighted
+                    .clone()
+                    .map(|(m, _)| m)
+                    .fold(f32::INFINITY, f32::min);
+                let sum: f32 = weighted.map(|(m, w)| w * (-(m - floor) / t).exp()).sum();
+                floor - t * (sum / total).ln()
+            }
+            CompositeMode::GeometricMean => {
+                if weighted.clone().any(|(m, _)| m <= 0.0) {
+                    return 0.0;
+                }
+                (weighted.map(|(m, w)| w * m.ln()).sum::<f32>() / total).exp()
+            }
+        }
+    }
+}
+
+/// How constraint margins combine into the composite margin. Every mode
+/// but `Min` lets well-kept constraints offset a tight one, so a single
+/// breach may no longer mean HardDeny; tune weights with care.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeMode {
+    /// The tightest margin; weights are ignored.
+    #[default]
+    Min,
+    /// The tightest margin after dividing each by its weight, so heavier
+    /// constraints bind earlier.
+    WeightedMin,
+    /// Weighted log-mean-exp; approaches `Min` as `temperature` goes to 0
+    /// and the weighted mean as it grows.
+    SoftMin { temperature: f32 },
+    /// Weighted geometric mean; any zero margin makes it zero.
+    GeometricMean,
+}
+
+/// Non-negative weight per constraint for the weighted composite modes;
+/// 0 leaves a constraint out. Unset weights are 1.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct ConstraintWeights {
+    pub mech_density: f32,
+    pub interface_coherence: f32,
+    pub em_field: f32,
+    pub thermal: f32,
+    pub inflammation: f32,
+    pub spike_energy: f32,
+    pub ph_deviation: f32,
+    pub oxygenation: f32,
+    pub micro_motion: f32,
+    /// Weight of the rate-of-change margin, when there is one.
+    pub rate: f32,
+    /// Weights of named signals, by name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub signals: BTreeMap<String, f32>,
+}
+
+impl Default for ConstraintWeights {
+    fn default() -> Self {
+        Self {
+            mech_density: 1.0,
+            interface_coherence: 1.0,
+            em_field: 1.0,
+            thermal: 1.0,
+            inflammation: 1.0,
+            spike_energy: 1.0,
+            ph_deviation: 1.0,
+            oxygenation: 1.0,
+            micro_motion: 1.0,
+            rate: 1.0,
+            signals: BTreeMap::new(),
+        }
+    }
+}
+
+/// Maximum change per window for each signal, from the oldest to the
+/// newest sample of a `WindowedEvaluator`; `None` leaves a signal
+/// unconstrained. Coherence and oxygenation are limited in how fast they
+/// may fall, the others in how fast they may rise. Optional signals are
+/// only limited when both samples report them.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct RateLimits {
+    pub mech_density: Option<f32>,
+    pub interface_coherence: Option<f32>,
+    pub em_field: Option<f32>,
+    pub thermal: Option<f32>,
+    pub inflammation: Option<f32>,
+    pub spike_energy: Option<f32>,
+    pub ph_deviation: Option<f32>,
+    pub oxygenation: Option<f32>,
+    pub micro_motion: Option<f32>,
+}
+
+impl RateLimits {
+    /// Tightest margin for the change from `oldest` to `newest`.
+    pub fn margin(&self, oldest: &InterfaceTelemetry, newest: &InterfaceTelemetry) -> Option<f32> {
+        let rises = [
+            (
+                self.mech_density,
+                newest.mech_density.0 - oldest.mech_density.0,
+            ),
+            (
+                self.interface_coherence,
+                oldest.interface_coherence.0 - newest.interface_coherence.0,
+            ),
+            (self.em_field, newest.em_field.0 - oldest.em_field.0),
+            (self.thermal, newest.thermal_load.0 - oldest.thermal_load.0),
+            (
+                self.inflammation,
+                newest.inflammation.0 - oldest.inflammation.0,
+            ),
+            (
+                self.spike_energy,
+                newest.spike_energy.0 - oldest.spike_energy.0,
+            ),
+        ];
+        let optional = [
+            (
+                self.ph_deviation,
+                newest
+                    .ph_deviation
+                    .zip(oldest.ph_deviation)
+                    .map(|(n, o)| n.0 - o.0),
+            ),
+            (
+                self.oxygenation,
+                oldest
+                    .oxygenation
+                    .zip(newest.oxygenation)
+                    .map(|(o, n)| o.0 - n.0),
+            ),
+            (
+                self.micro_motion,
+                newest
+                    .micro_motion
+                    .zip(oldest.micro_motion)
+                    .map(|(n, o)| n.0 - o.0),
+            ),
+        ];
+        rises
+            .into_iter()
+            .chain(
+                optional
+                    .into_iter()
+                    .filter_map(|(limit, rise)| Some((limit, rise?))),
+            )
+            .filter_map(|(limit, rise)| Some(limit? / rise).filter(|_| rise > 0.0))
+            .reduce(f32::min)
+    }
+}
+
+/// Which side of its limit a named signal must stay on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum BoundDirection {
+    /// The value must stay at or below the limit.
+    Upper,
+    /// The value must stay at or above the limit.
+    Lower,
+}
+
+/// How a named signal's value becomes a margin; both give 1.0 at the limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MarginFunction {
+    /// `limit / value` for upper bounds, `value / limit` for lower ones,
+    /// as for the typed signals.
+    #[default]
+    Ratio,
+    /// `2 - value / limit` for upper bounds, so headroom falls off
+    /// linearly; lower bounds are the same as `Ratio`.
+    Linear,
+}
+
+/// Constraint on a named signal in `InterfaceTelemetry::signals`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SignalConstraint {
+    pub direction: BoundDirection,
+    pub limit: f32,
+    #[serde(default)]
+    pub margin: MarginFunction,
+    /// Largest adverse change across a `WindowedEvaluator` window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rate: Option<f32>,
+}
+
+impl SignalConstraint {
+    pub fn new(direction: BoundDirection, limit: f32) -> Self {
+        Self {
+            direction,
+            limit,
+            margin: MarginFunction::Ratio,
+            max_rate: None,
+        }
+    }
+
+    /// The margin of `value`; a non-positive value is 2.0 under an upper
+    /// bound and 0.0 under a lower one.
+    pub fn margin(&self, value: f32) -> f32 {
+        match (self.direction, self.margin) {
+            (BoundDirection::Upper, MarginFunction::Ratio) if value <= 0.0 => 2.0,
+            (BoundDirection::Upper, MarginFunction::Ratio) => self.limit / value,
+            (BoundDirection::Upper, MarginFunction::Linear) => (2.0 - value / self.limit).max(0.0),
+            (BoundDirection::Lower, _) => (value / self.limit).max(0.0),
+        }
+    }
+
+    /// Whether larger values are the adverse ones.
+    pub fn higher_is_worse(&self) -> bool {
+        self.direction == BoundDirection::Upper
+    }
+
+    /// Margin under `max_rate` for the change from `oldest` to `newest`.
+    pub fn rate_margin(&self, oldest: f32, newest: f32) -> Option<f32> {
+        let adverse = if self.higher_is_worse() {
+            newest - oldest
+        } else {
+            oldest - newest
+        };
+        Some(self.max_rate? / adverse).filter(|_| adverse > 0.0)
     }
 }
 
 /// High-level scalar status: replaces the “face-in-cloud” with a numeric regime.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum EnvelopeStatus {
     /// Well inside safe corridor.
     Safe,
@@ -38,7 +322,27 @@ pub enum EnvelopeStatus {
     HardDeny,
 }
 
+impl EnvelopeStatus {
+    /// Stable message code; see `crate::i18n`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            EnvelopeStatus::Safe => "envelope-safe",
+            EnvelopeStatus::Caution => "envelope-caution",
+            EnvelopeStatus::HardDeny => "envelope-hard-deny",
+        }
+    }
+
+    fn severity(self) -> u8 {
+        match self {
+            EnvelopeStatus::Safe => 0,
+            EnvelopeStatus::Caution => 1,
+            EnvelopeStatus::HardDeny => 2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EnvelopeEvaluation {
     pub margins: ConstraintMargins,
     pub composite_margin: f32,
@@ -47,6 +351,7 @@ pub struct EnvelopeEvaluation {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EnvelopeConfig {
     pub mech_density_max: f32,
     pub interface_coherence_min: f32,
@@ -54,8 +359,36 @@ pub struct EnvelopeConfig {
     pub thermal_max: f32,
     pub inflammation_max: f32,
     pub spike_energy_max: f32,
+    #[serde(default = "default_ph_deviation_max")]
+    pub ph_deviation_max: f32,
+    #[serde(default = "default_oxygenation_min")]
+    pub oxygenation_min: f32,
+    #[serde(default = "default_micro_motion_max")]
+    pub micro_motion_max: f32,
     pub caution_lower: f32,
     pub caution_upper: f32,
+    /// Enforced over windows by `WindowedEvaluator`.
+    #[serde(default)]
+    pub max_rates: RateLimits,
+    #[serde(default)]
+    pub composite_mode: CompositeMode,
+    #[serde(default)]
+    pub weights: ConstraintWeights,
+    /// Constraints on named telemetry signals, by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub signals: BTreeMap<String, SignalConstraint>,
+}
+
+fn default_ph_deviation_max() -> f32 {
+    1.0
+}
+
+fn default_oxygenation_min() -> f32 {
+    0.7
+}
+
+fn default_micro_motion_max() -> f32 {
+    1.0
 }
 
 impl Default for EnvelopeConfig {
@@ -67,8 +400,15 @@ impl Default for EnvelopeConfig {
             thermal_max: 1.0,
             inflammation_max: 1.0,
             spike_energy_max: 1.0,
+            ph_deviation_max: default_ph_deviation_max(),
+            oxygenation_min: default_oxygenation_min(),
+            micro_motion_max: default_micro_motion_max(),
             caution_lower: 1.0,
             caution_upper: 1.1,
+            max_rates: RateLimits::default(),
+            composite_mode: CompositeMode::Min,
+            weights: ConstraintWeights::default(),
+            signals: BTreeMap::new(),
         }
     }
 }
@@ -90,6 +430,14 @@ impl EnvelopeConfig {
         }
     }
 
+    fn oxygenation_margin(&self, o: TissueOxygenation) -> f32 {
+        if o.0 <= 0.0 {
+            0.0
+        } else {
+            o.0 / self.oxygenation_min
+        }
+    }
+
     fn upper_bounded_margin(&self, value: f32, max: f32) -> f32 {
         if value <= 0.0 {
             max
@@ -98,6 +446,16 @@ impl EnvelopeConfig {
         }
     }
 
+    pub fn classify(&self, composite_margin: f32) -> EnvelopeStatus {
+        if composite_margin < self.caution_lower {
+            EnvelopeStatus::HardDeny
+        } else if composite_margin < self.caution_upper {
+            EnvelopeStatus::Caution
+        } else {
+            EnvelopeStatus::Safe
+        }
+    }
+
     pub fn evaluate(&self, telemetry: &InterfaceTelemetry) -> EnvelopeEvaluation {
         let mech_density_margin = self.mech_density_margin(telemetry.mech_density);
         let interface_coherence_margin =
@@ -110,6 +468,13 @@ impl EnvelopeConfig {
             self.upper_bounded_margin(telemetry.inflammation.0, self.inflammation_max);
         let spike_energy_margin =
             self.upper_bounded_margin(telemetry.spike_energy.0, self.spike_energy_max);
+        let ph_deviation_margin = telemetry
+            .ph_deviation
+            .map(|p| self.upper_bounded_margin(p.0, self.ph_deviation_max));
+        let oxygenation_margin = telemetry.oxygenation.map(|o| self.oxygenation_margin(o));
+        let micro_motion_margin = telemetry
+            .micro_motion
+            .map(|m| self.upper_bounded_margin(m.0, self.micro_motion_max));
 
         let margins = ConstraintMargins {
             mech_density_margin,
@@ -118,16 +483,26 @@ impl EnvelopeConfig {
             thermal_margin,
             inflammation_margin,
             spike_energy_margin,
+            ph_deviation_margin,
+            oxygenation_margin,
+            micro_motion_margin,
+            signals: self
+                .signals
+                .iter()
+                .filter_map(|(name, c)| {
+                    Some((name.clone(), c.margin(*telemetry.signals.get(name)?)))
+                })
+                .collect(),
+            rate_margin: None,
         };
-        let composite_margin = margins.composite();
+        self.assess(margins)
+    }
 
-        let status = if composite_margin < self.caution_lower {
-            EnvelopeStatus::HardDeny
-        } else if composite_margin < self.caution_upper {
-            EnvelopeStatus::Caution
-        } else {
-            EnvelopeStatus::Safe
-        };
+    /// Composite, status and salience for `margins`.
+    pub fn assess(&self, margins: ConstraintMargins) -> EnvelopeEvaluation {
+        let composite_margin = margins.composite_with(self.composite_mode, &self.weights);
+
+        let status = self.classify(composite_margin);
 
         let salience = Salience((self.caution_upper - composite_margin).max(0.0));
 
@@ -139,3 +514,255 @@ impl EnvelopeConfig {
         }
     }
 }
+
+/// Damping for `EnvelopeTracker`. Worsening is always reported at once;
+/// improving needs the composite to clear the threshold by `exit_band`
+/// for `dwell_samples` consecutive samples.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Hysteresis {
+    /// Added to `caution_lower` / `caution_upper` when leaving HardDeny /
+    /// Caution, so exit thresholds sit above the enter thresholds.
+    pub exit_band: f32,
+    /// Consecutive qualifying samples before an improvement is reported;
+    /// 0 and 1 both report it on the first.
+    pub dwell_samples: u32,
+}
+
+impl Default for Hysteresis {
+    /// No damping: the tracker reports what `EnvelopeConfig::evaluate` does.
+    fn default() -> Self {
+        Self {
+            exit_band: 0.0,
+            dwell_samples: 1,
+        }
+    }
+}
+
+/// Stateful evaluator that holds a status until the hysteresis lets it
+/// improve, so a composite hovering near a threshold does not flood
+/// monitoring with Safe/Caution churn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnvelopeTracker {
+    pub config: EnvelopeConfig,
+    pub hysteresis: Hysteresis,
+    status: Option<EnvelopeStatus>,
+    improving_for: u32,
+}
+
+impl EnvelopeTracker {
+    pub fn new(config: EnvelopeConfig, hysteresis: Hysteresis) -> Self {
+        Self {
+            config,
+            hysteresis,
+            status: None,
+            improving_for: 0,
+        }
+    }
+
+    /// Last reported status; `None` before the first sample.
+    pub fn status(&self) -> Option<EnvelopeStatus> {
+        self.status
+    }
+
+    /// Evaluate `telemetry`; margins and salience are the raw ones, the
+    /// status is the damped one.
+    pub fn observe(&mut self, telemetry: &InterfaceTelemetry) -> EnvelopeEvaluation {
+        let mut eval = self.config.evaluate(telemetry);
+        let status = match self.status {
+            Some(held) if eval.status.severity() >= held.severity() => {
+                self.improving_for = 0;
+                eval.status
+            }
+            Some(held) => {
+                let cleared = self
+                    .config
+                    .classify(eval.composite_margin - self.hysteresis.exit_band);
+                if cleared.severity() < held.severity() {
+                    self.improving_for += 1;
+                } else {
+                    self.improving_for = 0;
+                }
+                if self.improving_for >= self.hysteresis.dwell_samples.max(1) {
+                    self.improving_for = 0;
+                    cleared
+                } else {
+                    held
+                }
+            }
+            None => eval.status,
+        };
+        self.status = Some(status);
+        eval.status = status;
+        eval
+    }
+
+    /// Forget the held status, e.g. after a reconfiguration.
+    pub fn reset(&mut self) {
+        self.status = None;
+        self.improving_for = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuromorphic::signals::{
+        EmFieldIntensity, InflammationIndex, MicroMotion, PhDeviation, SpikeEnergy, ThermalLoad,
+    };
+
+    /// Telemetry whose composite margin is `composite` (set by thermal load).
+    fn sample(composite: f32) -> InterfaceTelemetry {
+        InterfaceTelemetry {
+            mech_density: MechDensity(0.5),
+            interface_coherence: InterfaceCoherence(1.0),
+            em_field: EmFieldIntensity(0.5),
+            thermal_load: ThermalLoad(1.0 / composite),
+            inflammation: InflammationIndex(0.5),
+            spike_energy: SpikeEnergy(0.5),
+            ph_deviation: None,
+            oxygenation: None,
+            micro_motion: None,
+            signals: Default::default(),
+        }
+    }
+
+    #[test]
+    fn tracker_damps_improvements_but_not_breaches() {
+        let mut tracker = EnvelopeTracker::new(
+            EnvelopeConfig::default(),
+            Hysteresis {
+                exit_band: 0.05,
+                dwell_samples: 2,
+            },
+        );
+        let statuses: Vec<_> = [1.12, 1.09, 1.2, 1.11, 1.2, 1.2, 0.9, 1.2]
+            .into_iter()
+            .map(|c| tracker.observe(&sample(c)).status)
+            .collect();
+        use EnvelopeStatus::*;
+        assert_eq!(
+            statuses,
+            [Safe, Caution, Caution, Caution, Caution, Safe, HardDeny, HardDeny]
+        );
+
+        let mut undamped = EnvelopeTracker::new(EnvelopeConfig::default(), Hysteresis::default());
+        assert_eq!(undamped.observe(&sample(1.09)).status, Caution);
+        assert_eq!(undamped.observe(&sample(1.11)).status, Safe);
+    }
+
+    #[test]
+    fn composite_modes_weigh_constraints() {
+        let mut config = EnvelopeConfig::default();
+        let margins = config.evaluate(&sample(2.0)).margins;
+        assert_eq!(margins.composite(), 1.25);
+        assert_eq!(config.assess(margins.clone()).composite_margin, 1.25);
+
+        config.composite_mode = CompositeMode::WeightedMin;
+        config.weights.thermal = 2.0;
+        assert_eq!(config.assess(margins.clone()).composite_margin, 1.0);
+        assert_eq!(
+            config.assess(margins.clone()).status,
+            EnvelopeStatus::Caution
+        );
+        config.weights.interface_coherence = 0.0;
+        assert_eq!(config.assess(margins.clone()).composite_margin, 1.0);
+
+        config.composite_mode = CompositeMode::GeometricMean;
+        config.weights = ConstraintWeights::default();
+        let geometric = config.assess(margins.clone()).composite_margin;
+        assert!(geometric > 1.25 && geometric < 2.0);
+
+        config.composite_mode = CompositeMode::SoftMin { temperature: 0.01 };
+        let soft = config.assess(margins).composite_margin;
+        assert!((soft - 1.25).abs() < 0.02, "{}", soft);
+
+        let parsed: EnvelopeConfig = serde_json::from_str(
+            r#"{"mech_density_max": 1.0, "interface_coherence_min": 0.8,
+                "em_field_max": 1.0, "thermal_max": 1.0, "inflammation_max": 1.0,
+                "spike_energy_max": 1.0, "caution_lower": 1.0, "caution_upper": 1.1,
+                "composite_mode": {"soft_min": {"temperature": 0.1}},
+                "weights": {"thermal": 3.0}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.composite_mode,
+            CompositeMode::SoftMin { temperature: 0.1 }
+        );
+        assert_eq!(parsed.weights.thermal, 3.0);
+        assert_eq!(parsed.weights.em_field, 1.0);
+    }
+
+    #[test]
+    fn optional_signals_constrain_only_when_reported() {
+        let config = EnvelopeConfig::default();
+        let mut telemetry = sample(1.25);
+        let eval = config.evaluate(&telemetry);
+        assert_eq!(eval.margins.oxygenation_margin, None);
+        assert_eq!(eval.status, EnvelopeStatus::Safe);
+
+        telemetry.ph_deviation = Some(PhDeviation(0.5));
+        telemetry.micro_motion = Some(MicroMotion(0.5));
+        telemetry.oxygenation = Some(TissueOxygenation(0.63));
+        let eval = config.evaluate(&telemetry);
+        assert_eq!(eval.margins.ph_deviation_margin, Some(2.0));
+        assert_eq!(eval.margins.micro_motion_margin, Some(2.0));
+        assert_eq!(eval.composite_margin, 0.63 / 0.7);
+        assert_eq!(eval.status, EnvelopeStatus::HardDeny);
+
+        let round_trip: InterfaceTelemetry =
+            serde_json::from_value(serde_json::to_value(sample(1.25)).unwrap()).unwrap();
+        assert!(round_trip.ph_deviation.is_none());
+        let legacy: EnvelopeConfig = serde_json::from_str(
+            r#"{"mech_density_max": 1.0, "interface_coherence_min": 0.8,
+                "em_field_max": 1.0, "thermal_max": 1.0, "inflammation_max": 1.0,
+                "spike_energy_max": 1.0, "caution_lower": 1.0, "caution_upper": 1.1}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.oxygenation_min, 0.7);
+        assert_eq!(legacy.micro_motion_max, 1.0);
+    }
+
+    #[test]
+    fn named_signals_are_constrained_through_the_registry() {
+        let mut config = EnvelopeConfig::default();
+        config.signals.insert(
+            "lactate".to_string(),
+            SignalConstraint::new(BoundDirection::Upper, 0.5),
+        );
+        config.signals.insert(
+            "perfusion".to_string(),
+            SignalConstraint {
+                margin: MarginFunction::Linear,
+                ..SignalConstraint::new(BoundDirection::Lower, 0.4)
+            },
+        );
+        let mut telemetry = sample(1.25);
+        telemetry.signals.insert("glucose".to_string(), 9.0);
+        let eval = config.evaluate(&telemetry);
+        assert!(eval.margins.signals.is_empty());
+        assert_eq!(eval.status, EnvelopeStatus::Safe);
+
+        telemetry.signals.insert("perfusion".to_string(), 0.6);
+        telemetry.signals.insert("lactate".to_string(), 0.4);
+        let eval = config.evaluate(&telemetry);
+        assert_eq!(eval.margins.signals["perfusion"], 1.5);
+        assert_eq!(eval.margins.signals["lactate"], 1.25);
+        assert_eq!(eval.composite_margin, 1.25);
+
+        telemetry.signals.insert("lactate".to_string(), 0.625);
+        assert_eq!(config.evaluate(&telemetry).status, EnvelopeStatus::HardDeny);
+        config.weights.signals.insert("lactate".to_string(), 0.0);
+        config.composite_mode = CompositeMode::WeightedMin;
+        assert_eq!(config.evaluate(&telemetry).composite_margin, 1.25);
+
+        let linear = SignalConstraint {
+            margin: MarginFunction::Linear,
+            ..SignalConstraint::new(BoundDirection::Upper, 0.5)
+        };
+        assert_eq!(linear.margin(0.0), 2.0);
+        assert_eq!(linear.margin(0.75), 0.5);
+        assert_eq!(linear.margin(2.0), 0.0);
+    }
+}
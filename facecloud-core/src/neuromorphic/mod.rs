@@ -1,2 +1,5 @@
+pub mod config;
 pub mod envelope;
+pub mod profiles;
 pub mod signals;
+pub mod window;
@@ -0,0 +1,258 @@
+//! Named envelope configurations, so callers pick thresholds by name
+//! instead of sharing one global default.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::envelope::EnvelopeConfig;
+
+/// Profile names to configs. `EnvelopeProfiles::default()` holds the
+/// built-ins; `new()` is empty. Deserialized profiles must each pass
+/// `EnvelopeConfig::validate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(
+    try_from = "BTreeMap<String, EnvelopeConfig>",
+    into = "BTreeMap<String, EnvelopeConfig>"
+)]
+pub struct EnvelopeProfiles {
+    profiles: BTreeMap<String, EnvelopeConfig>,
+}
+
+impl EnvelopeProfiles {
+    pub const DEFAULT: &'static str = "default";
+    pub const CONSERVATIVE: &'static str = "conservative";
+    pub const RESEARCH: &'static str = "research";
+    pub const STRICT_FLOOR: &'static str = "strict_floor";
+
+    pub fn new() -> Self {
+        Self {
+            profiles: BTreeMap::new(),
+        }
+    }
+
+    /// The built-in profiles:
+    /// - `default`: `EnvelopeConfig::default()`.
+    /// - `conservative`: upper limits tightened by a fifth, coherence and
+    ///   oxygenation floors raised, and a caution band of 1.05–1.15. The
+    ///   floors stay at or below `1 / caution_upper` so full coherence and
+    ///   oxygenation can still be Safe.
+    /// - `research`: default limits with a caution band of 1.0–1.25, so
+    ///   approaches to a limit are flagged early for study.
+    /// - `strict_floor`: default limits with no caution band; anything
+    ///   below a 1.1 margin is HardDeny.
+    pub fn builtin() -> Self {
+        let default = EnvelopeConfig::default();
+        let conservative = EnvelopeConfig {
+            mech_density_max: default.mech_density_max * 0.8,
+            interface_coherence_min: 0.85,
+            em_field_max: default.em_field_max * 0.8,
+            thermal_max: default.thermal_max * 0.8,
+            inflammation_max: default.inflammation_max * 0.8,
+            spike_energy_max: default.spike_energy_max * 0.8,
+            ph_deviation_max: default.ph_deviation_max * 0.8,
+            oxygenation_min: 0.75,
+            micro_motion_max: default.micro_motion_max * 0.8,
+            caution_lower: 1.05,
+            caution_upper: 1.15,
+            ..default.clone()
+        };
+        let research = EnvelopeConfig {
+            caution_lower: 1.0,
+            caution_upper: 1.25,
+            ..default.clone()
+        };
+        let strict_floor = EnvelopeConfig {
+            caution_lower: 1.1,
+            caution_upper: 1.1,
+            ..default.clone()
+        };
+        let mut profiles = Self::new();
+        profiles.insert(Self::DEFAULT, default);
+        profiles.insert(Self::CONSERVATIVE, conservative);
+        profiles.insert(Self::RESEARCH, research);
+        profiles.insert(Self::STRICT_FLOOR, strict_floor);
+        profiles
+    }
+
+    pub fn get(&self, name: &str) -> Result<&EnvelopeConfig, UnknownProfile> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| UnknownProfile(name.to_string()))
+    }
+
+    /// Add or replace the profile `name`, returning the one it replaced.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        config: EnvelopeConfig,
+    ) -> Option<EnvelopeConfig> {
+        self.profiles.insert(name.into(), config)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<EnvelopeConfig> {
+        self.profiles.remove(name)
+    }
+
+    /// Profile names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+}
+
+impl TryFrom<BTreeMap<String, EnvelopeConfig>> for EnvelopeProfiles {
+    type Error = String;
+
+    fn try_from(profiles: BTreeMap<String, EnvelopeConfig>) -> Result<Self, String> {
+        for (name, config) in &profiles {
+            config
+                .validate()
+                .map_err(|e| format!("envelope profile '{}': {}", name, e))?;
+        }
+        Ok(Self { profiles })
+    }
+}
+
+impl From<EnvelopeProfiles> for BTreeMap<String, EnvelopeConfig> {
+    fn from(profiles: EnvelopeProfiles) -> Self {
+        profiles.profiles
+    }
+}
+
+impl Default for EnvelopeProfiles {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// No profile of this name is registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownProfile(pub String);
+
+impl fmt::Display for UnknownProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown envelope profile '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownProfile {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuromorphic::envelope::EnvelopeStatus;
+    use crate::neuromorphic::signals::{
+        EmFieldIntensity, InflammationIndex, InterfaceCoherence, InterfaceTelemetry, MechDensity,
+        MicroMotion, PhDeviation, SpikeEnergy, ThermalLoad, TissueOxygenation,
+    };
+    use crate::safety::guard::GuardKernel;
+
+    #[test]
+    fn kernel_evaluates_against_the_selected_profile() {
+        let profiles = EnvelopeProfiles::builtin();
+        assert_eq!(
+            profiles.names().collect::<Vec<_>>(),
+            ["conservative", "default", "research", "strict_floor"]
+        );
+        for name in profiles.names() {
+            profiles.get(name).unwrap().validate().unwrap();
+        }
+
+        // Composite margin 1.05, set by thermal load.
+        let telemetry = InterfaceTelemetry {
+            mech_density: MechDensity(0.5),
+            interface_coherence: InterfaceCoherence(1.0),
+            em_field: EmFieldIntensity(0.5),
+            thermal_load: ThermalLoad(1.0 / 1.05),
+            inflammation: InflammationIndex(0.5),
+            spike_energy: SpikeEnergy(0.5),
+            ph_deviation: None,
+            oxygenation: None,
+            micro_motion: None,
+            signals: Default::default(),
+        };
+        let kernel = GuardKernel::new(EnvelopeConfig::default());
+        let status = |profile: &str| {
+            kernel
+                .evaluate_profile(profile, &telemetry)
+                .unwrap()
+                .evaluation
+                .status
+        };
+        assert_eq!(
+            kernel.evaluate(&telemetry).evaluation.status,
+            EnvelopeStatus::Caution
+        );
+        assert_eq!(status("default"), EnvelopeStatus::Caution);
+        assert_eq!(status("research"), EnvelopeStatus::Caution);
+        assert_eq!(status("conservative"), EnvelopeStatus::HardDeny);
+        assert_eq!(status("strict_floor"), EnvelopeStatus::HardDeny);
+        assert_eq!(
+            kernel.evaluate_profile("lenient", &telemetry).unwrap_err(),
+            UnknownProfile("lenient".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialized_profiles_are_validated() {
+        let json = serde_json::to_value(EnvelopeProfiles::builtin()).unwrap();
+        let round_trip: EnvelopeProfiles = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(round_trip.len(), 4);
+
+        let mut inverted = json;
+        inverted["research"]["caution_lower"] = serde_json::json!(2.0);
+        let err = serde_json::from_value::<EnvelopeProfiles>(inverted.clone()).unwrap_err();
+        assert!(
+            err.to_string().contains("envelope profile 'research'"),
+            "{}",
+            err
+        );
+        let kernel = serde_json::json!({
+            "config": EnvelopeConfig::default(),
+            "profiles": inverted,
+        });
+        assert!(serde_json::from_value::<GuardKernel>(kernel).is_err());
+    }
+
+    #[test]
+    fn every_profile_admits_benign_telemetry() {
+        let benign = InterfaceTelemetry {
+            mech_density: MechDensity(0.3),
+            interface_coherence: InterfaceCoherence(1.0),
+            em_field: EmFieldIntensity(0.3),
+            thermal_load: ThermalLoad(0.3),
+            inflammation: InflammationIndex(0.3),
+            spike_energy: SpikeEnergy(0.3),
+            ph_deviation: Some(PhDeviation(0.3)),
+            oxygenation: Some(TissueOxygenation(1.0)),
+            micro_motion: Some(MicroMotion(0.3)),
+            signals: Default::default(),
+        };
+        let profiles = EnvelopeProfiles::builtin();
+        for name in profiles.names() {
+            let config = profiles.get(name).unwrap();
+            assert!(
+                config.interface_coherence_min <= 1.0 / config.caution_upper
+                    && config.oxygenation_min <= 1.0 / config.caution_upper,
+                "{} floors leave no Safe region",
+                name
+            );
+            assert_eq!(
+                config.evaluate(&benign).status,
+                EnvelopeStatus::Safe,
+                "{}",
+                name
+            );
+        }
+    }
+}
@@ -1,32 +1,56 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Normalized biomechanical density of non-organic material per tissue volume.
 /// Purely abstract; no device control.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MechDensity(pub f32);
 
 /// Normalized interface coherence: 1.0 = crisp boundary, 0.0 = fully blurred.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InterfaceCoherence(pub f32);
 
 /// Normalized EM field intensity at the interface.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EmFieldIntensity(pub f32);
 
 /// Normalized thermal load.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ThermalLoad(pub f32);
 
 /// Normalized systemic inflammation marker.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InflammationIndex(pub f32);
 
 /// Normalized neuromorphic spike energy proxy.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SpikeEnergy(pub f32);
 
+/// Normalized local pH deviation from the tissue baseline: 0.0 = at baseline.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PhDeviation(pub f32);
+
+/// Normalized tissue oxygenation proxy: 1.0 = fully oxygenated.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TissueOxygenation(pub f32);
+
+/// Normalized micro-motion index of the interface relative to tissue.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MicroMotion(pub f32);
+
 /// Telemetry bundle used by the envelope; abstract, deviceless.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InterfaceTelemetry {
     pub mech_density: MechDensity,
     pub interface_coherence: InterfaceCoherence,
@@ -34,8 +58,35 @@ pub struct InterfaceTelemetry {
     pub thermal_load: ThermalLoad,
     pub inflammation: InflammationIndex,
     pub spike_energy: SpikeEnergy,
+    /// The signals below are optional; unreported ones impose no constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ph_deviation: Option<PhDeviation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oxygenation: Option<TissueOxygenation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub micro_motion: Option<MicroMotion>,
+    /// Normalized signals beyond the typed ones, by name; constrained by
+    /// `EnvelopeConfig::signals`, so adding one needs no code change.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub signals: BTreeMap<String, f32>,
+}
+
+impl InterfaceTelemetry {
+    /// Names of the typed signals; named signals may not reuse them.
+    pub const TYPED_SIGNALS: [&'static str; 9] = [
+        "mech_density",
+        "interface_coherence",
+        "em_field",
+        "thermal_load",
+        "inflammation",
+        "spike_energy",
+        "ph_deviation",
+        "oxygenation",
+        "micro_motion",
+    ];
 }
 
 /// Salience index: how urgently UI/monitoring should surface a warning.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Salience(pub f32);
@@ -0,0 +1,297 @@
+//! Envelope evaluation over a sliding window of recent telemetry samples.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use super::envelope::{EnvelopeConfig, EnvelopeEvaluation};
+use super::signals::{
+    EmFieldIntensity, InflammationIndex, InterfaceCoherence, InterfaceTelemetry, MechDensity,
+    MicroMotion, PhDeviation, SpikeEnergy, ThermalLoad, TissueOxygenation,
+};
+
+/// How each signal is folded over the window. `P95` and `Max` take the
+/// adverse tail: the highest values of upper-bounded signals, the lowest
+/// interface coherence and oxygenation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WindowAggregate {
+    Mean,
+    P95,
+    Max,
+}
+
+impl WindowAggregate {
+    fn fold(self, mut values: Vec<f32>, higher_is_worse: bool) -> f32 {
+        let n = values.len();
+        if n == 0 {
+            return 0.0;
+        }
+        if self == WindowAggregate::Mean {
+            return values.iter().sum::<f32>() / n as f32;
+        }
+        values.sort_by(f32::total_cmp);
+        // Nearest-rank percentile, counted from the adverse end.
+        let rank = match self {
+            WindowAggregate::P95 => (n * 95).div_ceil(100),
+            _ => n,
+        };
+        if higher_is_worse {
+            values[rank - 1]
+        } else {
+            values[n - rank]
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WindowedEvaluation {
+    /// The latest sample on its own.
+    pub instantaneous: EnvelopeEvaluation,
+    /// The window aggregate; what monitoring should act on.
+    pub windowed: EnvelopeEvaluation,
+    /// Samples in the window, at most its capacity.
+    pub samples: usize,
+}
+
+/// Evaluates the envelope over a ring buffer of the most recent samples,
+/// since single samples of real telemetry are too noisy to act on.
+#[derive(Debug, Clone)]
+pub struct WindowedEvaluator {
+    pub config: EnvelopeConfig,
+    pub aggregate: WindowAggregate,
+    capacity: usize,
+    samples: VecDeque<InterfaceTelemetry>,
+}
+
+impl WindowedEvaluator {
+    /// A window of the last `capacity` samples (at least one).
+    pub fn new(config: EnvelopeConfig, capacity: usize, aggregate: WindowAggregate) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            config,
+            aggregate,
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Add `telemetry`, dropping the oldest sample if the window is full,
+    /// and evaluate both it and the window.
+    pub fn push(&mut self, telemetry: InterfaceTelemetry) -> WindowedEvaluation {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        let instantaneous = self.config.evaluate(&telemetry);
+        self.samples.push_back(telemetry);
+        let window = self
+            .window_telemetry()
+            .expect("window holds the new sample");
+        let mut margins = self.config.evaluate(&window).margins;
+        margins.rate_margin = self.rate_margin();
+        WindowedEvaluation {
+            instantaneous,
+            windowed: self.config.assess(margins),
+            samples: self.samples.len(),
+        }
+    }
+
+    /// Tightest margin under `config.max_rates` and the named signals'
+    /// `max_rate` across the window; `None` with fewer than two samples.
+    pub fn rate_margin(&self) -> Option<f32> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        let (oldest, newest) = (self.samples.front()?, self.samples.back()?);
+        let named = self.config.signals.iter().filter_map(|(name, c)| {
+            c.rate_margin(*oldest.signals.get(name)?, *newest.signals.get(name)?)
+        });
+        self.config
+            .max_rates
+            .margin(oldest, newest)
+            .into_iter()
+            .chain(named)
+            .reduce(f32::min)
+    }
+
+    /// The window folded into one sample; `None` while empty.
+    pub fn window_telemetry(&self) -> Option<InterfaceTelemetry> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let fold = |signal: fn(&InterfaceTelemetry) -> f32, higher_is_worse: bool| {
+            self.aggregate
+                .fold(self.samples.iter().map(signal).collect(), higher_is_worse)
+        };
+        // Optional signals fold over the samples that report them.
+        let fold_reported = |signal: fn(&InterfaceTelemetry) -> Option<f32>,
+                             higher_is_worse: bool| {
+            let values: Vec<f32> = self.samples.iter().filter_map(signal).collect();
+            (!values.is_empty()).then(|| self.aggregate.fold(values, higher_is_worse))
+        };
+        Some(InterfaceTelemetry {
+            mech_density: MechDensity(fold(|t| t.mech_density.0, true)),
+            interface_coherence: InterfaceCoherence(fold(|t| t.interface_coherence.0, false)),
+            em_field: EmFieldIntensity(fold(|t| t.em_field.0, true)),
+            thermal_load: ThermalLoad(fold(|t| t.thermal_load.0, true)),
+            inflammation: InflammationIndex(fold(|t| t.inflammation.0, true)),
+            spike_energy: SpikeEnergy(fold(|t| t.spike_energy.0, true)),
+            ph_deviation: fold_reported(|t| t.ph_deviation.map(|p| p.0), true).map(PhDeviation),
+            oxygenation: fold_reported(|t| t.oxygenation.map(|o| o.0), false)
+                .map(TissueOxygenation),
+            micro_motion: fold_reported(|t| t.micro_motion.map(|m| m.0), true).map(MicroMotion),
+            signals: self.window_signals(),
+        })
+    }
+
+    /// Named signals folded over the samples that report them, in the
+    /// adverse direction of their constraint (upward if unconstrained).
+    fn window_signals(&self) -> BTreeMap<String, f32> {
+        let mut values: BTreeMap<&str, Vec<f32>> = BTreeMap::new();
+        for sample in &self.samples {
+            for (name, &value) in &sample.signals {
+                values.entry(name).or_default().push(value);
+            }
+        }
+        values
+            .into_iter()
+            .map(|(name, values)| {
+                let higher_is_worse = self
+                    .config
+                    .signals
+                    .get(name)
+                    .is_none_or(|c| c.higher_is_worse());
+                (
+                    name.to_string(),
+                    self.aggregate.fold(values, higher_is_worse),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuromorphic::envelope::{BoundDirection, EnvelopeStatus, SignalConstraint};
+
+    fn sample(thermal: f32, coherence: f32) -> InterfaceTelemetry {
+        InterfaceTelemetry {
+            mech_density: MechDensity(0.5),
+            interface_coherence: InterfaceCoherence(coherence),
+            em_field: EmFieldIntensity(0.5),
+            thermal_load: ThermalLoad(thermal),
+            inflammation: InflammationIndex(0.5),
+            spike_energy: SpikeEnergy(0.5),
+            ph_deviation: None,
+            oxygenation: None,
+            micro_motion: None,
+            signals: Default::default(),
+        }
+    }
+
+    #[test]
+    fn window_reports_instantaneous_and_aggregate_status() {
+        let mut mean = WindowedEvaluator::new(EnvelopeConfig::default(), 3, WindowAggregate::Mean);
+        mean.push(sample(0.5, 1.0));
+        mean.push(sample(0.5, 1.0));
+        let spike = mean.push(sample(1.25, 1.0));
+        assert_eq!(spike.instantaneous.status, EnvelopeStatus::HardDeny);
+        assert_eq!(spike.windowed.status, EnvelopeStatus::Safe);
+        assert_eq!(mean.window_telemetry().unwrap().thermal_load.0, 0.75);
+
+        let after = mean.push(sample(0.5, 1.0));
+        assert_eq!(after.samples, 3);
+        assert_eq!(mean.window_telemetry().unwrap().thermal_load.0, 0.75);
+        mean.push(sample(0.5, 1.0));
+        mean.push(sample(0.5, 1.0));
+        assert_eq!(mean.window_telemetry().unwrap().thermal_load.0, 0.5);
+
+        let mut worst = WindowedEvaluator::new(EnvelopeConfig::default(), 20, WindowAggregate::P95);
+        for i in 0..20 {
+            worst.push(sample(0.5, if i == 7 { 0.4 } else { 1.0 }));
+        }
+        let window = worst.window_telemetry().unwrap();
+        assert_eq!(window.interface_coherence.0, 1.0);
+        worst.push(sample(0.5, 0.4));
+        let eval = worst.push(sample(0.5, 1.0));
+        assert_eq!(eval.instantaneous.status, EnvelopeStatus::Safe);
+        assert_eq!(eval.windowed.status, EnvelopeStatus::HardDeny);
+    }
+
+    #[test]
+    fn fast_rising_signals_breach_their_rate_limit() {
+        let mut config = EnvelopeConfig::default();
+        config.max_rates.thermal = Some(0.25);
+        let mut window = WindowedEvaluator::new(config, 3, WindowAggregate::Mean);
+        assert_eq!(
+            window.push(sample(0.25, 1.0)).windowed.margins.rate_margin,
+            None
+        );
+        let eval = window.push(sample(0.5, 1.0));
+        assert_eq!(eval.windowed.margins.rate_margin, Some(1.0));
+        assert_eq!(eval.windowed.status, EnvelopeStatus::Caution);
+        let eval = window.push(sample(0.75, 1.0));
+        assert_eq!(eval.instantaneous.status, EnvelopeStatus::Safe);
+        assert_eq!(eval.windowed.status, EnvelopeStatus::HardDeny);
+        let eval = window.push(sample(0.5, 1.0));
+        assert_eq!(eval.windowed.margins.rate_margin, None);
+
+        let mut config = EnvelopeConfig::default();
+        config.max_rates.oxygenation = Some(0.1);
+        let mut window = WindowedEvaluator::new(config, 3, WindowAggregate::Max);
+        let oxygenated = |o: Option<f32>| InterfaceTelemetry {
+            oxygenation: o.map(TissueOxygenation),
+            ..sample(0.5, 1.0)
+        };
+        window.push(oxygenated(Some(0.9)));
+        window.push(oxygenated(None));
+        assert_eq!(window.rate_margin(), None);
+        let eval = window.push(oxygenated(Some(0.8)));
+        assert_eq!(
+            window.window_telemetry().unwrap().oxygenation.unwrap().0,
+            0.8
+        );
+        assert!(eval.windowed.margins.rate_margin.unwrap() < 1.01);
+
+        let mut config = EnvelopeConfig::default();
+        config.signals.insert(
+            "perfusion".to_string(),
+            SignalConstraint {
+                max_rate: Some(0.2),
+                ..SignalConstraint::new(BoundDirection::Lower, 0.4)
+            },
+        );
+        let mut window = WindowedEvaluator::new(config, 3, WindowAggregate::Max);
+        let perfused = |p: f32| {
+            let mut t = sample(0.5, 1.0);
+            t.signals.insert("perfusion".to_string(), p);
+            t
+        };
+        window.push(perfused(1.0));
+        window.push(sample(0.5, 1.0));
+        let eval = window.push(perfused(0.5));
+        assert_eq!(window.window_telemetry().unwrap().signals["perfusion"], 0.5);
+        assert_eq!(eval.windowed.margins.signals["perfusion"], 1.25);
+        assert_eq!(eval.windowed.margins.rate_margin, Some(0.4));
+        assert_eq!(eval.windowed.status, EnvelopeStatus::HardDeny);
+    }
+}
@@ -1,48 +1,76 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::neuromorphic::envelope::{EnvelopeConfig, EnvelopeEvaluation, EnvelopeStatus};
+use crate::i18n::{builtin_catalog, MessageCatalog, DEFAULT_LANG};
+use crate::neuromorphic::envelope::{EnvelopeConfig, EnvelopeEvaluation};
+use crate::neuromorphic::profiles::{EnvelopeProfiles, UnknownProfile};
 use crate::neuromorphic::signals::InterfaceTelemetry;
 
 /// Purely analytical: no actuation, only recommendations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuardRecommendation {
     pub id: Uuid,
     pub evaluation: EnvelopeEvaluation,
+    /// Stable message code (e.g. `envelope-caution`); the action text
+    /// lives under `<code>-action`.
+    pub code: String,
     pub message: String,
     pub recommended_action: String,
 }
 
+impl GuardRecommendation {
+    /// Re-render `message` and `recommended_action` in `lang`.
+    pub fn localize(&mut self, catalog: &MessageCatalog, lang: &str) {
+        self.message = catalog.message(lang, &self.code).to_string();
+        self.recommended_action = catalog
+            .message(lang, &format!("{}-action", self.code))
+            .to_string();
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GuardKernel {
+    /// Used when no profile is selected.
     pub config: EnvelopeConfig,
+    /// Selectable by name through `evaluate_profile`; the built-ins unless
+    /// configured otherwise.
+    #[serde(default)]
+    pub profiles: EnvelopeProfiles,
 }
 
 impl GuardKernel {
+    pub fn new(config: EnvelopeConfig) -> Self {
+        Self {
+            config,
+            profiles: EnvelopeProfiles::default(),
+        }
+    }
+
     pub fn evaluate(&self, telemetry: &InterfaceTelemetry) -> GuardRecommendation {
-        let eval = self.config.evaluate(telemetry);
-        let (message, action) = match eval.status {
-            EnvelopeStatus::Safe => (
-                "Within safety envelope.".to_string(),
-                "Maintain current parameters; continue monitoring.".to_string(),
-            ),
-            EnvelopeStatus::Caution => (
-                "CAUTION_SCALE_THRESHOLD_APPROACHED".to_string(),
-                "Do not increase integration density or field intensity; prefer down-scaling or simulations only."
-                    .to_string(),
-            ),
-            EnvelopeStatus::HardDeny => (
-                "HARD_DENY: envelope breached.".to_string(),
-                "Reduce load, density, and exposure in models; consult safety governance before any further scaling."
-                    .to_string(),
-            ),
-        };
+        Self::recommend(&self.config, telemetry)
+    }
+
+    /// Evaluate against the named profile instead of `config`.
+    pub fn evaluate_profile(
+        &self,
+        profile: &str,
+        telemetry: &InterfaceTelemetry,
+    ) -> Result<GuardRecommendation, UnknownProfile> {
+        Ok(Self::recommend(self.profiles.get(profile)?, telemetry))
+    }
 
-        GuardRecommendation {
+    fn recommend(config: &EnvelopeConfig, telemetry: &InterfaceTelemetry) -> GuardRecommendation {
+        let eval = config.evaluate(telemetry);
+        let mut rec = GuardRecommendation {
             id: Uuid::new_v4(),
+            code: eval.status.code().to_string(),
             evaluation: eval,
-            message,
-            recommended_action: action,
-        }
+            message: String::new(),
+            recommended_action: String::new(),
+        };
+        rec.localize(builtin_catalog(), DEFAULT_LANG);
+        rec
     }
 }
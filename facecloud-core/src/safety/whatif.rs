@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::neuromorphic::config::ConfigError;
+use crate::neuromorphic::envelope::{EnvelopeConfig, EnvelopeStatus, SignalConstraint};
+use crate::neuromorphic::signals::InterfaceTelemetry;
+use crate::safety::guard::GuardKernel;
+
+/// Hypothetical envelope settings; unset fields keep the current value.
+/// E.g. `{"thermal_max": 0.8}` for "if thermal max is lowered to 0.8".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct EnvelopeOverrides {
+    pub mech_density_max: Option<f32>,
+    pub interface_coherence_min: Option<f32>,
+    pub em_field_max: Option<f32>,
+    pub thermal_max: Option<f32>,
+    pub inflammation_max: Option<f32>,
+    pub spike_energy_max: Option<f32>,
+    pub ph_deviation_max: Option<f32>,
+    pub oxygenation_min: Option<f32>,
+    pub micro_motion_max: Option<f32>,
+    pub caution_lower: Option<f32>,
+    pub caution_upper: Option<f32>,
+    /// Named signal constraints to add or replace.
+    pub signals: BTreeMap<String, SignalConstraint>,
+}
+
+impl EnvelopeOverrides {
+    /// `config` with the overrides applied, if the result passes
+    /// `EnvelopeConfig::validate`.
+    pub fn apply(&self, config: &EnvelopeConfig) -> Result<EnvelopeConfig, ConfigError> {
+        let mut signals = config.signals.clone();
+        signals.extend(self.signals.clone());
+        let candidate = EnvelopeConfig {
+            mech_density_max: self.mech_density_max.unwrap_or(config.mech_density_max),
+            interface_coherence_min: self
+                .interface_coherence_min
+                .unwrap_or(config.interface_coherence_min),
+            em_field_max: self.em_field_max.unwrap_or(config.em_field_max),
+            thermal_max: self.thermal_max.unwrap_or(config.thermal_max),
+            inflammation_max: self.inflammation_max.unwrap_or(config.inflammation_max),
+            spike_energy_max: self.spike_energy_max.unwrap_or(config.spike_energy_max),
+            ph_deviation_max: self.ph_deviation_max.unwrap_or(config.ph_deviation_max),
+            oxygenation_min: self.oxygenation_min.unwrap_or(config.oxygenation_min),
+            micro_motion_max: self.micro_motion_max.unwrap_or(config.micro_motion_max),
+            caution_lower: self.caution_lower.unwrap_or(config.caution_lower),
+            caution_upper: self.caution_upper.unwrap_or(config.caution_upper),
+            max_rates: config.max_rates,
+            composite_mode: config.composite_mode,
+            weights: config.weights.clone(),
+            signals,
+        };
+        candidate.validate()?;
+        Ok(candidate)
+    }
+}
+
+/// A telemetry sample whose envelope status would change.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StatusChange {
+    /// Index into the evaluated samples.
+    pub sample: usize,
+    pub before: EnvelopeStatus,
+    pub after: EnvelopeStatus,
+    pub composite_before: f32,
+    pub composite_after: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EnvelopeWhatIf {
+    pub candidate: EnvelopeConfig,
+    pub changes: Vec<StatusChange>,
+}
+
+impl GuardKernel {
+    /// Re-evaluate `samples` under `overrides` and report status changes.
+    /// The kernel's own config is left untouched; overrides that yield an
+    /// invalid config are rejected.
+    pub fn what_if(
+        &self,
+        overrides: &EnvelopeOverrides,
+        samples: &[InterfaceTelemetry],
+    ) -> Result<EnvelopeWhatIf, ConfigError> {
+        let candidate = overrides.apply(&self.config)?;
+        let changes = samples
+            .iter()
+            .enumerate()
+            .filter_map(|(sample, telemetry)| {
+                let before = self.config.evaluate(telemetry);
+                let after = candidate.evaluate(telemetry);
+                (before.status != after.status).then_some(StatusChange {
+                    sample,
+                    before: before.status,
+                    after: after.status,
+                    composite_before: before.composite_margin,
+                    composite_after: after.composite_margin,
+                })
+            })
+            .collect();
+        Ok(EnvelopeWhatIf { candidate, changes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuromorphic::signals::{
+        EmFieldIntensity, InflammationIndex, InterfaceCoherence, MechDensity, SpikeEnergy,
+        ThermalLoad,
+    };
+
+    #[test]
+    fn invalid_overrides_are_rejected() {
+        let kernel = GuardKernel::default();
+        let sample = InterfaceTelemetry {
+            mech_density: MechDensity(0.5),
+            interface_coherence: InterfaceCoherence(1.0),
+            em_field: EmFieldIntensity(0.5),
+            thermal_load: ThermalLoad(0.85),
+            inflammation: InflammationIndex(0.5),
+            spike_energy: SpikeEnergy(0.5),
+            ph_deviation: None,
+            oxygenation: None,
+            micro_motion: None,
+            signals: Default::default(),
+        };
+        let lowered = EnvelopeOverrides {
+            thermal_max: Some(0.8),
+            ..Default::default()
+        };
+        let report = kernel
+            .what_if(&lowered, std::slice::from_ref(&sample))
+            .unwrap();
+        assert_eq!(report.changes[0].after, EnvelopeStatus::HardDeny);
+
+        for invalid in [
+            EnvelopeOverrides {
+                thermal_max: Some(f32::NAN),
+                ..Default::default()
+            },
+            EnvelopeOverrides {
+                caution_lower: Some(1.2),
+                ..Default::default()
+            },
+            EnvelopeOverrides {
+                caution_upper: Some(-1.0),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                kernel.what_if(&invalid, std::slice::from_ref(&sample)),
+                Err(ConfigError::Invalid(_))
+            ));
+        }
+    }
+}
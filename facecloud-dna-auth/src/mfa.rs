@@ -35,9 +35,22 @@ pub enum AuthDecision {
     Allow,
 }
 
+impl AuthDecision {
+    /// Stable message code, matching the Facecloud message catalog.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AuthDecision::Deny => "mfa-deny",
+            AuthDecision::RequireAdditionalFactors => "mfa-require-additional-factors",
+            AuthDecision::Allow => "mfa-allow",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthEvaluation {
     pub decision: AuthDecision,
+    /// Stable message code for `explanation`.
+    pub code: String,
     pub explanation: String,
 }
 
@@ -65,6 +78,7 @@ pub fn evaluate_mfa(ctx: &MultiLayerContext) -> AuthEvaluation {
     };
 
     AuthEvaluation {
+        code: decision.code().to_string(),
         decision,
         explanation,
     }
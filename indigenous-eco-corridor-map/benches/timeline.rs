@@ -0,0 +1,70 @@
+//! Row versus columnar storage for metric timelines: scanning one
+//! component, and the memory each layout holds.
+//!
+//! Run with `cargo bench -p indigenous-eco-corridor-map`.
+
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::{Duration, SystemTime};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use indigenous_eco_corridor_map::{EnvironmentalMetrics, MetricComponent, MetricsTimeline, Score};
+
+const SIZES: [usize; 2] = [10_000, 1_000_000];
+
+fn observation(i: usize) -> (SystemTime, EnvironmentalMetrics) {
+    let at = SystemTime::UNIX_EPOCH + Duration::from_secs(i as u64 * 60);
+    let values =
+        MetricComponent::ALL.map(|c| Score::new(((i + c.index()) % 100) as f32 / 100.0).unwrap());
+    (at, EnvironmentalMetrics::from_components(values))
+}
+
+fn layouts(n: usize) -> (Vec<(SystemTime, EnvironmentalMetrics)>, MetricsTimeline) {
+    let rows: Vec<_> = (0..n).map(observation).collect();
+    let mut columns = MetricsTimeline::with_capacity(n);
+    for (at, metrics) in &rows {
+        columns.push(*at, metrics).unwrap();
+    }
+    (rows, columns)
+}
+
+fn scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("timeline_scan_water_quality");
+    for n in SIZES {
+        let (rows, columns) = layouts(n);
+        group.bench_with_input(BenchmarkId::new("rows", n), &rows, |b, rows| {
+            b.iter(|| {
+                rows.iter()
+                    .map(|(_, m)| m.component(MetricComponent::WaterQuality).get())
+                    .sum::<f32>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("columnar", n), &columns, |b, columns| {
+            b.iter(|| columns.mean(MetricComponent::WaterQuality, 0..columns.len()))
+        });
+    }
+    group.finish();
+}
+
+/// Time to build a columnar timeline. Criterion measures time only, so
+/// the footprint of each layout is printed here rather than benchmarked.
+fn build(c: &mut Criterion) {
+    let n = SIZES[SIZES.len() - 1];
+    let (rows, columns) = layouts(n);
+    let row_bytes = rows.capacity() * size_of::<(SystemTime, EnvironmentalMetrics)>();
+    let column_bytes =
+        columns.len() * (size_of::<SystemTime>() + MetricComponent::COUNT * size_of::<f32>());
+    println!(
+        "timeline memory for {} observations: rows {} KiB, columnar {} KiB ({:.1}x smaller)",
+        n,
+        row_bytes / 1024,
+        column_bytes / 1024,
+        row_bytes as f64 / column_bytes as f64
+    );
+    c.bench_function("timeline_push_columnar_10000", |b| {
+        b.iter(|| layouts(black_box(10_000)).1)
+    });
+}
+
+criterion_group!(benches, scan, build);
+criterion_main!(benches);
@@ -0,0 +1,159 @@
+//! Incrementally maintained map-level aggregates.
+//! Updated on every map mutation so dashboards read them in O(1).
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::corridor::CorridorKind;
+use crate::governance::FpicStatusKind;
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::Score;
+
+/// Running counts and score statistics over all corridors in a map.
+///
+/// Scores are tracked as a multiset keyed by their bit pattern (which
+/// orders like the value for non-negative floats), so min/max survive
+/// removals without rescanning the map.
+#[derive(Clone, Debug, Default)]
+pub struct MapAggregates {
+    corridor_count: usize,
+    fpic_counts: BTreeMap<FpicStatusKind, usize>,
+    /// Keyed by `CorridorKind::key()`.
+    kind_counts: BTreeMap<String, usize>,
+    strict_neurorights: usize,
+    score_sum: f64,
+    score_bits: BTreeMap<u32, usize>,
+}
+
+impl MapAggregates {
+    pub(crate) fn add(&mut self, corridor: &IndigenousEcoCorridor) {
+        self.corridor_count += 1;
+        *self.fpic_counts.entry(corridor.fpic.kind()).or_insert(0) += 1;
+        *self
+            .kind_counts
+            .entry(corridor.descriptor.kind.key().into_owned())
+            .or_insert(0) += 1;
+        if corridor.neurorights.is_strict() {
+            self.strict_neurorights += 1;
+        }
+        let score = score_of(corridor);
+        self.score_sum += f64::from(score);
+        *self.score_bits.entry(score.to_bits()).or_insert(0) += 1;
+    }
+
+    pub(crate) fn remove(&mut self, corridor: &IndigenousEcoCorridor) {
+        self.corridor_count -= 1;
+        decrement(&mut self.fpic_counts, corridor.fpic.kind());
+        decrement(
+            &mut self.kind_counts,
+            corridor.descriptor.kind.key().into_owned(),
+        );
+        if corridor.neurorights.is_strict() {
+            self.strict_neurorights -= 1;
+        }
+        let score = score_of(corridor);
+        self.score_sum -= f64::from(score);
+        decrement(&mut self.score_bits, score.to_bits());
+    }
+
+    /// Number of corridors in the map.
+    pub fn corridor_count(&self) -> usize {
+        self.corridor_count
+    }
+
+    /// Number of corridors whose FPIC status has the given kind.
+    pub fn fpic_count(&self, kind: FpicStatusKind) -> usize {
+        self.fpic_counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Number of corridors of the given kind.
+    pub fn kind_count(&self, kind: &CorridorKind) -> usize {
+        self.kind_counts
+            .get(kind.key().as_ref())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Number of corridors with strict neurorights constraints.
+    pub fn strict_neurorights_count(&self) -> usize {
+        self.strict_neurorights
+    }
+
+    /// Mean aggregate environmental score; `None` for an empty map.
+    pub fn mean_score(&self) -> Option<Score> {
+        if self.corridor_count == 0 {
+            return None;
+        }
+        let mean = (self.score_sum / self.corridor_count as f64) as f32;
+        // Clamp absorbs floating-point drift from repeated add/remove.
+        Some(Score::exact(mean.clamp(0.0, 1.0)))
+    }
+
+    /// Lowest aggregate environmental score; `None` for an empty map.
+    pub fn min_score(&self) -> Option<Score> {
+        self.score_bits
+            .keys()
+            .next()
+            .map(|bits| Score::exact(f32::from_bits(*bits)))
+    }
+
+    /// Highest aggregate environmental score; `None` for an empty map.
+    pub fn max_score(&self) -> Option<Score> {
+        self.score_bits
+            .keys()
+            .next_back()
+            .map(|bits| Score::exact(f32::from_bits(*bits)))
+    }
+}
+
+/// Point-in-time report of the map, built from `MapAggregates`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MapSummary {
+    pub corridor_count: usize,
+    /// Keyed by `CorridorKind::key()`.
+    pub by_kind: BTreeMap<String, usize>,
+    /// Keyed by `FpicStatusKind::key()`; every status is present.
+    pub by_fpic_status: BTreeMap<String, usize>,
+    pub mean_score: Option<Score>,
+    pub min_score: Option<Score>,
+    pub max_score: Option<Score>,
+    pub strict_neurorights: usize,
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Summary statistics for reports and dashboards, without iterating
+    /// the corridors.
+    pub fn summary(&self) -> MapSummary {
+        let agg = self.aggregates();
+        MapSummary {
+            corridor_count: agg.corridor_count(),
+            by_kind: agg.kind_counts.clone(),
+            by_fpic_status: FpicStatusKind::ALL
+                .into_iter()
+                .map(|kind| (kind.key().to_string(), agg.fpic_count(kind)))
+                .collect(),
+            mean_score: agg.mean_score(),
+            min_score: agg.min_score(),
+            max_score: agg.max_score(),
+            strict_neurorights: agg.strict_neurorights_count(),
+        }
+    }
+}
+
+fn score_of(corridor: &IndigenousEcoCorridor) -> f32 {
+    // Adding 0.0 folds -0.0 into +0.0 so bit ordering matches value ordering.
+    corridor.environmental.aggregate_score().get() + 0.0
+}
+
+fn decrement<K: Ord>(counts: &mut BTreeMap<K, usize>, key: K) {
+    if let Some(n) = counts.get_mut(&key) {
+        *n -= 1;
+        if *n == 0 {
+            counts.remove(&key);
+        }
+    }
+}
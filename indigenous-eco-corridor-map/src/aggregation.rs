@@ -0,0 +1,321 @@
+//! Strategies for folding metric components into one score.
+//! `EnvironmentalMetrics::aggregate_score` is a flat mean; overlays that
+//! weigh components differently per corridor kind (water in a desert is
+//! not water in a river) select strategies through an
+//! `AggregationPolicy`.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+
+use crate::baselines::Baseline;
+use crate::corridor::{CorridorId, CorridorKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// Folds the measured metric components into one score in [0, 1].
+/// The built-ins skip unmeasured components and score 0.0 when nothing
+/// was measured.
+pub trait AggregationStrategy {
+    fn aggregate(&self, metrics: &EnvironmentalMetrics) -> Score;
+}
+
+fn values(metrics: &EnvironmentalMetrics) -> impl Iterator<Item = f64> + Clone + '_ {
+    MetricComponent::ALL
+        .into_iter()
+        .filter_map(|c| metrics.measured(c))
+        .map(|score| f64::from(score.get()))
+}
+
+fn score(value: f64) -> Score {
+    // Each strategy stays within [0, 1] up to rounding.
+    Score::exact(value.clamp(0.0, 1.0) as f32)
+}
+
+/// Every component counts equally; what `aggregate_score` uses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FlatMean;
+
+impl AggregationStrategy for FlatMean {
+    fn aggregate(&self, metrics: &EnvironmentalMetrics) -> Score {
+        let count = values(metrics).count();
+        if count == 0 {
+            return score(0.0);
+        }
+        score(values(metrics).sum::<f64>() / count as f64)
+    }
+}
+
+/// Mean with a non-negative weight per component.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedMean {
+    weights: [f32; MetricComponent::COUNT],
+}
+
+impl WeightedMean {
+    /// Weights in `MetricComponent::ALL` order; they must be finite and
+    /// non-negative, and not all zero.
+    pub fn new(weights: [f32; MetricComponent::COUNT]) -> Result<Self, String> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("aggregation weights must be finite and non-negative".into());
+        }
+        if weights.iter().all(|w| *w == 0.0) {
+            return Err("aggregation weights must not all be zero".into());
+        }
+        Ok(Self { weights })
+    }
+
+    pub fn weight(&self, component: MetricComponent) -> f32 {
+        self.weights[component.index()]
+    }
+}
+
+impl AggregationStrategy for WeightedMean {
+    fn aggregate(&self, metrics: &EnvironmentalMetrics) -> Score {
+        let weighted = MetricComponent::ALL.into_iter().filter_map(|c| {
+            let value = metrics.measured(c)?;
+            Some((f64::from(value.get()), f64::from(self.weight(c))))
+        });
+        let total: f64 = weighted.clone().map(|(_, w)| w).sum();
+        if total == 0.0 {
+            return score(0.0);
+        }
+        score(weighted.map(|(v, w)| v * w).sum::<f64>() / total)
+    }
+}
+
+/// The worst component decides: a corridor is only as healthy as its
+/// weakest system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WeakestLink;
+
+impl AggregationStrategy for WeakestLink {
+    fn aggregate(&self, metrics: &EnvironmentalMetrics) -> Score {
+        score(values(metrics).reduce(f64::min).unwrap_or(0.0))
+    }
+}
+
+/// Geometric mean: a single collapsed component pulls the score down
+/// far more than under the flat mean, and a zero makes it zero.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GeometricMean;
+
+impl AggregationStrategy for GeometricMean {
+    fn aggregate(&self, metrics: &EnvironmentalMetrics) -> Score {
+        let count = values(metrics).count();
+        if count == 0 || values(metrics).any(|v| v == 0.0) {
+            return score(0.0);
+        }
+        let log_mean = values(metrics).map(f64::ln).sum::<f64>() / count as f64;
+        score(log_mean.exp())
+    }
+}
+
+/// Which strategy scores which corridor kind, keyed by
+/// `CorridorKind::key`; kinds without an entry use the default.
+pub struct AggregationPolicy {
+    default: Box<dyn AggregationStrategy>,
+    by_kind: BTreeMap<String, Box<dyn AggregationStrategy>>,
+}
+
+impl AggregationPolicy {
+    pub fn new(default: Box<dyn AggregationStrategy>) -> Self {
+        Self {
+            default,
+            by_kind: BTreeMap::new(),
+        }
+    }
+
+    /// Score corridors of `kind` with `strategy`, replacing any earlier
+    /// choice for that kind.
+    pub fn with_kind(
+        mut self,
+        kind: &CorridorKind,
+        strategy: Box<dyn AggregationStrategy>,
+    ) -> Self {
+        self.by_kind.insert(kind.key().into_owned(), strategy);
+        self
+    }
+
+    pub fn strategy_for(&self, kind: &CorridorKind) -> &dyn AggregationStrategy {
+        self.by_kind
+            .get(kind.key().as_ref())
+            .unwrap_or(&self.default)
+            .as_ref()
+    }
+}
+
+impl Default for AggregationPolicy {
+    /// Flat mean for every kind.
+    fn default() -> Self {
+        Self::new(Box::new(FlatMean))
+    }
+}
+
+/// What aggregation does with unmeasured components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Imputation<'a> {
+    /// Leave them out; the aggregate covers measured components only.
+    Skip,
+    /// Score them 0.0, so missing data can only lower the aggregate.
+    AssumeWorst,
+    /// Score them at the baseline mean, e.g. for the current season.
+    AssumeBaseline(&'a Baseline),
+}
+
+impl EnvironmentalMetrics {
+    /// Aggregate under `strategy`, skipping unmeasured components. If
+    /// any component is bounded, the strategy is also applied to all
+    /// lower and all upper bounds to bound the result; the built-ins
+    /// never fall when a component rises, so those bound it exactly.
+    pub fn aggregate_with(&self, strategy: &dyn AggregationStrategy) -> Score {
+        let value = strategy.aggregate(self).get();
+        if MetricComponent::ALL
+            .into_iter()
+            .filter_map(|c| self.measured(c))
+            .all(|score| score.bounds().is_none())
+        {
+            return Score::exact(value);
+        }
+        let at = |bound: fn(Score) -> f32| {
+            let mut at_bound = self.clone();
+            for c in MetricComponent::ALL {
+                if let Some(score) = self.measured(c) {
+                    at_bound.set_component(c, Score::exact(bound(score)));
+                }
+            }
+            strategy.aggregate(&at_bound).get()
+        };
+        // Widening keeps the value inside the bounds for strategies that
+        // are not monotone.
+        let (low, high) = (at(Score::low).min(value), at(Score::high).max(value));
+        Score::with_bounds(value, low, high).expect("bounds enclose the value")
+    }
+
+    /// Aggregate under `strategy` with unmeasured components handled per
+    /// `imputation`.
+    pub fn aggregate_imputed(
+        &self,
+        strategy: &dyn AggregationStrategy,
+        imputation: Imputation<'_>,
+    ) -> Score {
+        let fill = |c: MetricComponent| match imputation {
+            Imputation::Skip => None,
+            Imputation::AssumeWorst => Some(Score::exact(0.0)),
+            Imputation::AssumeBaseline(baseline) => Some(Score::exact(baseline.mean(c))),
+        };
+        let mut imputed = self.clone();
+        for c in self.unmeasured.iter().copied() {
+            if let Some(score) = fill(c) {
+                imputed.set_component(c, score);
+            }
+        }
+        imputed.aggregate_with(strategy)
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Aggregate score under the strategy `policy` picks for the
+    /// corridor's kind.
+    pub fn aggregate_score_with(&self, policy: &AggregationPolicy) -> Score {
+        self.environmental
+            .aggregate_with(policy.strategy_for(&self.descriptor.kind))
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Every corridor's aggregate score under `policy`, in id order.
+    pub fn aggregate_scores(&self, policy: &AggregationPolicy) -> Vec<(CorridorId, Score)> {
+        self.iter()
+            .map(|(id, c)| (id.clone(), c.aggregate_score_with(policy)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies_differ_by_corridor_kind() {
+        let mut values = [Score::new(0.8).unwrap(); MetricComponent::COUNT];
+        values[MetricComponent::WaterQuality.index()] = Score::new(0.2).unwrap();
+        let metrics = EnvironmentalMetrics::from_components(values);
+
+        let flat = metrics.aggregate_with(&FlatMean).get();
+        assert!((flat - metrics.aggregate_score().get()).abs() < 1e-6);
+        assert_eq!(metrics.aggregate_with(&WeakestLink).get(), 0.2);
+        let geometric = metrics.aggregate_with(&GeometricMean).get();
+        assert!(geometric < flat && geometric > 0.2);
+
+        let mut desert_weights = [1.0; MetricComponent::COUNT];
+        desert_weights[MetricComponent::WaterQuality.index()] = 0.0;
+        let desert = WeightedMean::new(desert_weights).unwrap();
+        assert!((metrics.aggregate_with(&desert).get() - 0.8).abs() < 1e-6);
+        assert!(WeightedMean::new([0.0; MetricComponent::COUNT]).is_err());
+        assert!(WeightedMean::new([-1.0; MetricComponent::COUNT]).is_err());
+
+        let policy = AggregationPolicy::default()
+            .with_kind(&CorridorKind::Desert, Box::new(desert))
+            .with_kind(&CorridorKind::River, Box::new(WeakestLink));
+        let of = |kind| policy.strategy_for(&kind).aggregate(&metrics).get();
+        assert!((of(CorridorKind::Desert) - 0.8).abs() < 1e-6);
+        assert_eq!(of(CorridorKind::River), 0.2);
+        assert!((of(CorridorKind::Forest) - flat).abs() < 1e-6);
+
+        values[MetricComponent::WaterQuality.index()] = Score::with_stderr(0.2, 0.1).unwrap();
+        let sampled = EnvironmentalMetrics::from_components(values);
+        let confident = metrics.aggregate_with(&WeakestLink);
+        let uncertain = sampled.aggregate_with(&WeakestLink);
+        assert_eq!(confident.bounds(), None);
+        assert_eq!(uncertain.get(), 0.2);
+        assert!((uncertain.low() - 0.1).abs() < 1e-6 && (uncertain.high() - 0.3).abs() < 1e-6);
+        let mean = sampled.aggregate_score();
+        assert!(mean.width() > 0.0 && mean.low() <= mean.get() && mean.get() <= mean.high());
+
+        let json = serde_json::to_string(&uncertain).unwrap();
+        assert_eq!(serde_json::from_str::<Score>(&json).unwrap(), uncertain);
+        assert_eq!(serde_json::to_string(&confident).unwrap(), "0.2");
+        assert!(serde_json::from_str::<Score>(r#"{"value":0.5,"low":0.6,"high":0.7}"#).is_err());
+    }
+
+    #[test]
+    fn unmeasured_components_are_skipped_or_imputed() {
+        let mut metrics = EnvironmentalMetrics::from_components(
+            [Score::new(0.8).unwrap(); MetricComponent::COUNT],
+        );
+        metrics.unmeasured.insert(MetricComponent::WaterQuality);
+        metrics.set_component(MetricComponent::SoilFertility, Score::new(0.5).unwrap());
+        assert_eq!(metrics.measured(MetricComponent::WaterQuality), None);
+        assert!((metrics.completeness().get() - 8.0 / 9.0).abs() < 1e-6);
+
+        let skipped = metrics.aggregate_imputed(&FlatMean, Imputation::Skip).get();
+        assert!((skipped - (0.5 + 7.0 * 0.8) / 8.0).abs() < 1e-6);
+        assert_eq!(skipped, metrics.aggregate_score().get());
+        let worst = metrics.aggregate_imputed(&FlatMean, Imputation::AssumeWorst);
+        assert!((worst.get() - (0.5 + 7.0 * 0.8) / 9.0).abs() < 1e-6);
+        let baseline = Baseline::new(
+            [0.35; MetricComponent::COUNT],
+            [0.1; MetricComponent::COUNT],
+        )
+        .unwrap();
+        let assumed = metrics.aggregate_imputed(&FlatMean, Imputation::AssumeBaseline(&baseline));
+        assert!((assumed.get() - (0.85 + 7.0 * 0.8) / 9.0).abs() < 1e-6);
+        assert_eq!(
+            metrics
+                .aggregate_imputed(&WeakestLink, Imputation::AssumeWorst)
+                .get(),
+            0.0
+        );
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["unmeasured"], serde_json::json!(["water_quality"]));
+        let parsed: EnvironmentalMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.unmeasured, metrics.unmeasured);
+
+        let mut empty = metrics.clone();
+        empty.unmeasured = MetricComponent::ALL.into_iter().collect();
+        assert_eq!(empty.aggregate_score().get(), 0.0);
+        assert_eq!(empty.completeness().get(), 0.0);
+    }
+}
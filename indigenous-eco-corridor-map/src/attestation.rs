@@ -0,0 +1,269 @@
+//! Attestations binding a neurorights capsule to the envelope document
+//! its `envelope_ref` names, so the reference can be checked rather than
+//! trusted. Fetching envelopes and checking keys are external: overlays
+//! implement `EnvelopeSource` and `RecordVerifier`.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::hashing::ContentHash;
+use crate::neurorights::NeurorightsConstraints;
+use crate::signature::{RecordSigner, RecordVerifier};
+
+/// Signed statement that a capsule was adopted against a specific
+/// envelope document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Attestation {
+    /// The `envelope_ref` the capsule carried when attested.
+    pub envelope_ref: String,
+    /// SHA-256 of the envelope document.
+    pub envelope_hash: ContentHash,
+    /// `NeurorightsConstraints::content_hash` of the attested capsule.
+    pub policy_hash: ContentHash,
+    /// DID of the attester, e.g. a council.
+    pub attester: String,
+    pub attested_at: SystemTime,
+    /// Algorithm name understood by the verifier, e.g. "ed25519".
+    pub algorithm: String,
+    /// Signature over every other field; see `payload`.
+    pub signature: Vec<u8>,
+}
+
+impl Attestation {
+    /// Bytes the attester signs: SHA-256 of the attestation with an
+    /// empty signature.
+    pub fn payload(&self) -> ContentHash {
+        let unsigned = Attestation {
+            signature: Vec::new(),
+            ..self.clone()
+        };
+        let canonical = serde_json::to_vec(&unsigned).expect("attestations always serialize");
+        ContentHash(Sha256::digest(canonical).into())
+    }
+}
+
+/// Fetches envelope documents by reference.
+pub trait EnvelopeSource {
+    /// The document `envelope_ref` names, or `None` if it does not exist.
+    fn fetch(&self, envelope_ref: &str) -> Result<Option<Vec<u8>>, String>;
+}
+
+/// Why a capsule's envelope attestation did not verify.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttestationError {
+    Unattested,
+    /// The capsule's `envelope_ref` differs from the attested one.
+    EnvelopeRefChanged {
+        attested: String,
+        current: Option<String>,
+    },
+    /// The capsule changed since it was attested.
+    PolicyChanged,
+    /// The capsule cannot be hashed, e.g. it was adopted before 1970.
+    Unhashable(String),
+    EnvelopeUnavailable(String),
+    EnvelopeMissing(String),
+    /// The envelope document no longer matches the attested hash.
+    EnvelopeChanged(String),
+    BadSignature {
+        attester: String,
+    },
+}
+
+impl fmt::Display for AttestationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttestationError::Unattested => f.write_str("neurorights capsule is not attested"),
+            AttestationError::EnvelopeRefChanged { attested, current } => write!(
+                f,
+                "envelope_ref changed from '{}' to {}",
+                attested,
+                current
+                    .as_deref()
+                    .map_or("none".to_string(), |c| format!("'{}'", c))
+            ),
+            AttestationError::PolicyChanged => {
+                f.write_str("neurorights capsule changed since it was attested")
+            }
+            AttestationError::Unhashable(e) => f.write_str(e),
+            AttestationError::EnvelopeUnavailable(e) => {
+                write!(f, "envelope source unavailable: {}", e)
+            }
+            AttestationError::EnvelopeMissing(r) => write!(f, "envelope '{}' does not exist", r),
+            AttestationError::EnvelopeChanged(r) => {
+                write!(f, "envelope '{}' changed since it was attested", r)
+            }
+            AttestationError::BadSignature { attester } => {
+                write!(f, "attestation from '{}' did not verify", attester)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AttestationError {}
+
+impl NeurorightsConstraints {
+    /// Deterministic hash of the capsule, excluding its attestation
+    /// (which is computed over this hash). Fails if the capsule does not
+    /// serialize.
+    pub fn content_hash(&self) -> Result<ContentHash, String> {
+        if self.attestation.is_some() {
+            let mut unattested = self.clone();
+            unattested.attestation = None;
+            return unattested.content_hash();
+        }
+        let canonical = serde_json::to_vec(self)
+            .map_err(|e| format!("neurorights capsule cannot be hashed: {}", e))?;
+        Ok(ContentHash(Sha256::digest(canonical).into()))
+    }
+
+    /// Attest the capsule against `envelope`, the document its
+    /// `envelope_ref` names, replacing any earlier attestation.
+    pub fn attest(
+        &mut self,
+        envelope: &[u8],
+        signer: &dyn RecordSigner,
+        at: SystemTime,
+    ) -> Result<(), String> {
+        let envelope_ref = self
+            .envelope_ref
+            .clone()
+            .ok_or("neurorights capsule has no envelope_ref to attest")?;
+        let mut attestation = Attestation {
+            envelope_ref,
+            envelope_hash: ContentHash(Sha256::digest(envelope).into()),
+            policy_hash: self.content_hash()?,
+            attester: signer.signer_id(),
+            attested_at: at,
+            algorithm: signer.algorithm(),
+            signature: Vec::new(),
+        };
+        attestation.signature = signer.sign(&attestation.payload().0);
+        self.attestation = Some(attestation);
+        Ok(())
+    }
+
+    /// Check that the attestation covers this capsule as it stands, that
+    /// the referenced envelope still hashes as attested, and that the
+    /// attester's signature verifies.
+    pub fn verify_attestation(
+        &self,
+        source: &dyn EnvelopeSource,
+        verifier: &dyn RecordVerifier,
+    ) -> Result<(), AttestationError> {
+        let attestation = self
+            .attestation
+            .as_ref()
+            .ok_or(AttestationError::Unattested)?;
+        if self.envelope_ref.as_deref() != Some(attestation.envelope_ref.as_str()) {
+            return Err(AttestationError::EnvelopeRefChanged {
+                attested: attestation.envelope_ref.clone(),
+                current: self.envelope_ref.clone(),
+            });
+        }
+        if self.content_hash().map_err(AttestationError::Unhashable)? != attestation.policy_hash {
+            return Err(AttestationError::PolicyChanged);
+        }
+        let envelope_ref = &attestation.envelope_ref;
+        let document = source
+            .fetch(envelope_ref)
+            .map_err(AttestationError::EnvelopeUnavailable)?
+            .ok_or_else(|| AttestationError::EnvelopeMissing(envelope_ref.clone()))?;
+        if ContentHash(Sha256::digest(document).into()) != attestation.envelope_hash {
+            return Err(AttestationError::EnvelopeChanged(envelope_ref.clone()));
+        }
+        let payload = attestation.payload();
+        if verifier.verify(
+            &attestation.attester,
+            &attestation.algorithm,
+            &payload.0,
+            &attestation.signature,
+        ) {
+            Ok(())
+        } else {
+            Err(AttestationError::BadSignature {
+                attester: attestation.attester.clone(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    struct Council;
+
+    impl RecordSigner for Council {
+        fn signer_id(&self) -> String {
+            "did:example:council".into()
+        }
+        fn algorithm(&self) -> String {
+            "xor".into()
+        }
+        fn sign(&self, payload: &[u8]) -> Vec<u8> {
+            payload.iter().map(|b| b ^ 0x5a).collect()
+        }
+    }
+
+    impl RecordVerifier for Council {
+        fn verify(&self, signer: &str, _: &str, payload: &[u8], signature: &[u8]) -> bool {
+            signer == self.signer_id() && self.sign(payload) == signature
+        }
+    }
+
+    struct Envelopes(BTreeMap<String, Vec<u8>>);
+
+    impl EnvelopeSource for Envelopes {
+        fn fetch(&self, envelope_ref: &str) -> Result<Option<Vec<u8>>, String> {
+            Ok(self.0.get(envelope_ref).cloned())
+        }
+    }
+
+    #[test]
+    fn attestation_pins_capsule_and_envelope() {
+        let mut capsule = NeurorightsConstraints::strict_non_actuating();
+        let at = SystemTime::UNIX_EPOCH;
+        assert!(capsule.attest(b"envelope v1", &Council, at).is_err());
+
+        capsule.envelope_ref = Some("envelope:river".into());
+        capsule.attest(b"envelope v1", &Council, at).unwrap();
+        let mut envelopes = Envelopes(BTreeMap::from([(
+            "envelope:river".to_string(),
+            b"envelope v1".to_vec(),
+        )]));
+        assert_eq!(capsule.verify_attestation(&envelopes, &Council), Ok(()));
+
+        envelopes
+            .0
+            .insert("envelope:river".into(), b"envelope v2".to_vec());
+        assert_eq!(
+            capsule.verify_attestation(&envelopes, &Council),
+            Err(AttestationError::EnvelopeChanged("envelope:river".into()))
+        );
+
+        let mut loosened = capsule.clone();
+        loosened.actuation.non_actuating_required = false;
+        assert_eq!(
+            loosened.verify_attestation(&envelopes, &Council),
+            Err(AttestationError::PolicyChanged)
+        );
+
+        let mut forged = capsule.clone();
+        forged.attestation.as_mut().unwrap().attester = "did:example:other".into();
+        envelopes
+            .0
+            .insert("envelope:river".into(), b"envelope v1".to_vec());
+        assert!(matches!(
+            forged.verify_attestation(&envelopes, &Council),
+            Err(AttestationError::BadSignature { .. })
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+//! Append-only audit of governance decisions.
+//! Every FPIC transition, revocation, and IDS scope change made through
+//! the map is recorded here, so compliance reviews can read one trail
+//! instead of reconstructing it from per-record fields and event logs.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::governance::FpicStatusKind;
+use crate::hashing::ContentHash;
+use crate::map::IndigenousEcoCorridorMap;
+use crate::revocation::RevocationScope;
+
+/// What was decided.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GovernanceDecision {
+    FpicTransition {
+        from: FpicStatusKind,
+        to: FpicStatusKind,
+    },
+    /// Consent was withdrawn; FPIC moved from `from` to `to`.
+    Revocation {
+        scope: RevocationScope,
+        from: FpicStatusKind,
+        to: FpicStatusKind,
+    },
+    IdsScopeChanged,
+}
+
+impl fmt::Display for GovernanceDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GovernanceDecision::FpicTransition { from, to } => {
+                write!(f, "FPIC {} -> {}", from, to)
+            }
+            GovernanceDecision::Revocation { from, to, .. } => {
+                write!(f, "FPIC revoked ({} -> {})", from, to)
+            }
+            GovernanceDecision::IdsScopeChanged => f.write_str("IDS scope changed"),
+        }
+    }
+}
+
+/// One recorded decision.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AuditEntry {
+    /// Id of the corridor at the time of the decision; it may since have
+    /// been renamed.
+    pub corridor_id: CorridorId,
+    pub actor: String,
+    pub at: SystemTime,
+    pub decision: GovernanceDecision,
+    /// Decision records and the data uses the decision affected.
+    pub evidence_refs: Vec<String>,
+    /// `content_hash` of the record before the decision.
+    pub prior_state_hash: ContentHash,
+}
+
+/// Decisions in the order they were made. Entries can only be appended,
+/// and survive removal of the corridor they concern.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct GovernanceAudit(Vec<AuditEntry>);
+
+impl GovernanceAudit {
+    pub(crate) fn append(&mut self, entry: AuditEntry) {
+        self.0.push(entry);
+    }
+
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Decisions about `id` in the order they were made, including those
+    /// recorded under ids that are now aliases of it.
+    pub fn corridor_audit(&self, id: &CorridorId) -> Vec<&AuditEntry> {
+        let Some(id) = self.resolve(id) else {
+            return Vec::new();
+        };
+        self.governance_audit()
+            .entries()
+            .iter()
+            .filter(|e| self.resolve(&e.corridor_id) == Some(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{
+        CommunityId, Consent, ConsentQuorum, FpicEvidence, FpicPurpose, FpicStatus, IdsFramework,
+        IdsScope,
+    };
+    use crate::map::IndigenousEcoCorridor;
+    use crate::revocation::RevocationRecord;
+    use crate::test_support;
+    use std::collections::BTreeSet;
+    use std::time::Duration;
+
+    const NATION: &str = "did:web:nation-x.example";
+
+    fn evidence(reference: &str) -> FpicEvidence {
+        FpicEvidence {
+            actor: NATION.into(),
+            at: SystemTime::UNIX_EPOCH,
+            reference: reference.into(),
+        }
+    }
+
+    #[test]
+    fn decisions_are_audited_across_renames() {
+        let id = CorridorId::new("eco:river:audit").unwrap();
+        let corridor = IndigenousEcoCorridor {
+            fpic: FpicStatus::Consulting {
+                since: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::unanimous([CommunityId(NATION.into())]).unwrap(),
+            },
+            ..test_support::corridor(id.as_str())
+        };
+        let initial_hash = corridor.content_hash().unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor).unwrap();
+
+        let grant = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId(NATION.into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        map.transition_fpic(&id, grant, evidence("minutes:1"))
+            .unwrap();
+        let granted_hash = map.get(&id).unwrap().content_hash().unwrap();
+        let scope = IdsScope {
+            contains_indigenous_data: true,
+            frameworks: vec![IdsFramework::Care],
+            governance_ref: Some("nation-x:ids-policy".into()),
+            embargo: None,
+        };
+        map.set_ids_scope(&id, scope, evidence("minutes:2"))
+            .unwrap();
+        let renamed = CorridorId::new("eco:river:audit-2").unwrap();
+        map.rename_corridor(&id, renamed.clone()).unwrap();
+        let revocation = RevocationRecord {
+            at: SystemTime::UNIX_EPOCH,
+            reason: "council withdrew consent".into(),
+            scope: RevocationScope::All,
+            affected: BTreeSet::new(),
+        };
+        map.revoke_fpic(&renamed, revocation, evidence("minutes:3"))
+            .unwrap();
+        assert!(map
+            .transition_fpic(&renamed, FpicStatus::Pending, evidence("minutes:4"))
+            .is_err());
+
+        let trail = map.corridor_audit(&id);
+        assert_eq!(trail.len(), 3);
+        assert_eq!(trail[0].prior_state_hash, initial_hash);
+        assert_eq!(
+            trail[0].decision,
+            GovernanceDecision::FpicTransition {
+                from: FpicStatusKind::Consulting,
+                to: FpicStatusKind::Granted,
+            }
+        );
+        assert_eq!(trail[1].decision, GovernanceDecision::IdsScopeChanged);
+        assert_eq!(trail[1].prior_state_hash, granted_hash);
+        assert_eq!(trail[2].corridor_id, renamed);
+        assert_eq!(trail[2].evidence_refs, ["minutes:3", "terms:1"]);
+
+        map.remove_corridor(&renamed);
+        assert_eq!(map.governance_audit().len(), 3);
+    }
+
+    #[test]
+    fn unhashable_records_refuse_decisions_and_stay_in_the_map() {
+        let id = CorridorId::new("eco:river:1969").unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(IndigenousEcoCorridor {
+            fpic: FpicStatus::Consulting {
+                since: SystemTime::UNIX_EPOCH - Duration::from_secs(1),
+                quorum: ConsentQuorum::unanimous([CommunityId(NATION.into())]).unwrap(),
+            },
+            ..test_support::corridor(id.as_str())
+        })
+        .unwrap();
+
+        let withheld = FpicStatus::Withheld {
+            at: SystemTime::UNIX_EPOCH,
+            reason: "council declined".into(),
+        };
+        assert!(map
+            .transition_fpic(&id, withheld, evidence("minutes:1"))
+            .is_err());
+        assert!(map
+            .set_ids_scope(
+                &id,
+                IdsScope {
+                    contains_indigenous_data: true,
+                    frameworks: vec![IdsFramework::Care],
+                    governance_ref: None,
+                    embargo: None,
+                },
+                evidence("minutes:2")
+            )
+            .is_err());
+        let kept = map.get(&id).expect("record stays registered");
+        assert!(matches!(kept.fpic, FpicStatus::Consulting { .. }));
+        assert!(map.governance_audit().is_empty());
+    }
+}
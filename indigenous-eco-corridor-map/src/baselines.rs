@@ -0,0 +1,201 @@
+//! Per-season metric baselines and anomaly scores.
+//! A naturally low dry-season flow is not the concern the same value
+//! would be in spring; comparing against the season's own baseline
+//! separates the two.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent};
+
+/// Deviations at or beyond this many spreads count as anomalous.
+pub const ANOMALY_THRESHOLD: f32 = 2.0;
+
+/// Spread used in place of smaller ones, so a baseline built from
+/// identical samples does not flag every rounding difference.
+const MIN_SPREAD: f32 = 0.01;
+
+/// Typical value and natural variability of each component in one
+/// season, in `MetricComponent::ALL` order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Baseline {
+    mean: [f32; MetricComponent::COUNT],
+    spread: [f32; MetricComponent::COUNT],
+}
+
+impl Baseline {
+    pub fn new(
+        mean: [f32; MetricComponent::COUNT],
+        spread: [f32; MetricComponent::COUNT],
+    ) -> Result<Self, String> {
+        if mean.iter().any(|m| !(0.0..=1.0).contains(m)) {
+            return Err("baseline means must lie within [0, 1]".into());
+        }
+        if spread.iter().any(|s| !s.is_finite() || *s < 0.0) {
+            return Err("baseline spreads must be finite and non-negative".into());
+        }
+        Ok(Self { mean, spread })
+    }
+
+    /// Mean and standard deviation of at least two samples taken in the
+    /// season.
+    pub fn from_samples(samples: &[EnvironmentalMetrics]) -> Result<Self, String> {
+        if samples.len() < 2 {
+            return Err("a baseline needs at least two samples".into());
+        }
+        let n = samples.len() as f64;
+        let values =
+            |c: MetricComponent| samples.iter().map(move |m| f64::from(m.component(c).get()));
+        let mean = MetricComponent::ALL.map(|c| values(c).sum::<f64>() / n);
+        let spread = MetricComponent::ALL.map(|c| {
+            let mean = mean[c.index()];
+            (values(c).map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt() as f32
+        });
+        Self::new(mean.map(|m| m as f32), spread)
+    }
+
+    pub fn mean(&self, component: MetricComponent) -> f32 {
+        self.mean[component.index()]
+    }
+
+    pub fn spread(&self, component: MetricComponent) -> f32 {
+        self.spread[component.index()]
+    }
+
+    /// Signed deviation of each component from the mean, in spreads.
+    pub fn compare(&self, metrics: &EnvironmentalMetrics) -> [f32; MetricComponent::COUNT] {
+        MetricComponent::ALL
+            .map(|c| (metrics.component(c).get() - self.mean(c)) / self.spread(c).max(MIN_SPREAD))
+    }
+}
+
+/// Metrics compared against one season's baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BaselineComparison {
+    pub season: String,
+    /// Signed deviations in spreads, in `MetricComponent::ALL` order.
+    pub deviations: [f32; MetricComponent::COUNT],
+}
+
+impl BaselineComparison {
+    pub fn deviation(&self, component: MetricComponent) -> f32 {
+        self.deviations[component.index()]
+    }
+
+    /// Components deviating by at least `ANOMALY_THRESHOLD` spreads, in
+    /// `MetricComponent::ALL` order.
+    pub fn anomalies(&self) -> Vec<MetricComponent> {
+        MetricComponent::ALL
+            .into_iter()
+            .filter(|c| self.deviation(*c).abs() >= ANOMALY_THRESHOLD)
+            .collect()
+    }
+}
+
+/// Baselines per corridor and season, with seasons named as in the
+/// corridor's `SeasonalWindow`s.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SeasonalBaselines {
+    baselines: BTreeMap<CorridorId, BTreeMap<String, Baseline>>,
+}
+
+impl SeasonalBaselines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the baseline for `season` on `id`, replacing any earlier one.
+    pub fn set(&mut self, id: &CorridorId, season: &str, baseline: Baseline) -> Result<(), String> {
+        if season.trim().is_empty() {
+            return Err(format!("CorridorId '{}': baseline season is empty", id));
+        }
+        self.baselines
+            .entry(id.clone())
+            .or_default()
+            .insert(season.to_string(), baseline);
+        Ok(())
+    }
+
+    pub fn baseline(&self, id: &CorridorId, season: &str) -> Option<&Baseline> {
+        self.baselines.get(id)?.get(season)
+    }
+
+    /// Seasons with a baseline for `id`, in name order.
+    pub fn seasons(&self, id: &CorridorId) -> impl Iterator<Item = &str> {
+        self.baselines
+            .get(id)
+            .into_iter()
+            .flat_map(|seasons| seasons.keys().map(String::as_str))
+    }
+
+    /// Compare `metrics` to the baseline for `season` on `id`; `None` if
+    /// there is no such baseline.
+    pub fn compare_to_baseline(
+        &self,
+        id: &CorridorId,
+        metrics: &EnvironmentalMetrics,
+        season: &str,
+    ) -> Option<BaselineComparison> {
+        Some(BaselineComparison {
+            season: season.to_string(),
+            deviations: self.baseline(id, season)?.compare(metrics),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Score;
+
+    #[test]
+    fn same_value_is_anomalous_in_one_season_only() {
+        let with_flow = |flow: f32| {
+            let mut values = [Score::new(0.7).unwrap(); MetricComponent::COUNT];
+            values[MetricComponent::WaterFlowResilience.index()] = Score::new(flow).unwrap();
+            EnvironmentalMetrics::from_components(values)
+        };
+        let id = CorridorId::new("eco:river:wash").unwrap();
+        let mut baselines = SeasonalBaselines::new();
+        let dry = [0.25, 0.3, 0.35].map(with_flow);
+        let spring = [0.75, 0.8, 0.85].map(with_flow);
+        baselines
+            .set(&id, "dry", Baseline::from_samples(&dry).unwrap())
+            .unwrap();
+        baselines
+            .set(&id, "spring", Baseline::from_samples(&spring).unwrap())
+            .unwrap();
+        assert!(baselines
+            .set(&id, " ", Baseline::from_samples(&dry).unwrap())
+            .is_err());
+        assert!(Baseline::from_samples(&dry[..1]).is_err());
+        assert_eq!(
+            baselines.seasons(&id).collect::<Vec<_>>(),
+            ["dry", "spring"]
+        );
+
+        let observed = with_flow(0.3);
+        let in_dry = baselines
+            .compare_to_baseline(&id, &observed, "dry")
+            .unwrap();
+        let in_spring = baselines
+            .compare_to_baseline(&id, &observed, "spring")
+            .unwrap();
+        assert!(in_dry.anomalies().is_empty());
+        assert_eq!(
+            in_spring.anomalies(),
+            [MetricComponent::WaterFlowResilience]
+        );
+        assert!(in_spring.deviation(MetricComponent::WaterFlowResilience) < 0.0);
+        assert_eq!(in_spring.deviation(MetricComponent::WaterQuality), 0.0);
+        assert!(baselines
+            .compare_to_baseline(&id, &observed, "monsoon")
+            .is_none());
+    }
+}
@@ -0,0 +1,215 @@
+//! All-or-nothing bulk upsert.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::corridor::CorridorId;
+use crate::governance::{FpicStatus, IdsRequirement};
+use crate::ledger::{ConsentLedger, LedgerFinding};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::MetricComponent;
+
+/// Problem with one record, identified by its position in the batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordError {
+    pub index: usize,
+    pub id: CorridorId,
+    pub message: String,
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "record {} ('{}'): {}", self.index, self.id, self.message)
+    }
+}
+
+/// Every rejected record of a batch; nothing was written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchError {
+    pub errors: Vec<RecordError>,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} record(s) rejected", self.errors.len())?;
+        for e in &self.errors {
+            write!(f, "; {}", e)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+impl IndigenousEcoCorridorMap {
+    /// Validate every record, then upsert them all; if any record is
+    /// rejected the map is left untouched and all problems are reported.
+    /// Relationship targets may be in the map already or in the batch.
+    pub fn upsert_batch(
+        &mut self,
+        corridors: Vec<IndigenousEcoCorridor>,
+    ) -> Result<(), BatchError> {
+        self.upsert_checked(corridors, None)
+    }
+
+    /// Like `upsert_batch`, but also rejects records whose FPIC references
+    /// disagree with `ledger` (see `IndigenousEcoCorridor::verify_consent`).
+    pub fn upsert_batch_verified(
+        &mut self,
+        corridors: Vec<IndigenousEcoCorridor>,
+        ledger: &dyn ConsentLedger,
+    ) -> Result<(), BatchError> {
+        self.upsert_checked(corridors, Some(ledger))
+    }
+
+    fn upsert_checked(
+        &mut self,
+        corridors: Vec<IndigenousEcoCorridor>,
+        ledger: Option<&dyn ConsentLedger>,
+    ) -> Result<(), BatchError> {
+        let mut seen = BTreeSet::new();
+        let mut seen_uuids = BTreeSet::new();
+        let mut errors = Vec::new();
+        for (index, corridor) in corridors.iter().enumerate() {
+            let mut problems = Vec::new();
+            if !seen.insert(corridor.id()) {
+                problems.push("duplicate corridor id in batch".to_string());
+            }
+            if let Some(uuid) = corridor.descriptor.uuid {
+                if !seen_uuids.insert(uuid) {
+                    problems.push("duplicate corridor uuid in batch".to_string());
+                }
+                let holder = self.get_by_uuid(&uuid).map(|c| c.id());
+                if holder.is_some_and(|id| id != corridor.id()) {
+                    problems.push(format!("uuid {} already belongs to another corridor", uuid));
+                }
+            }
+            problems.extend(record_problems(corridor));
+            if let Some(ledger) = ledger {
+                let findings = corridor.verify_consent(ledger);
+                problems.extend(findings.iter().map(LedgerFinding::to_string));
+            }
+            for r in &corridor.descriptor.relationships {
+                let in_batch = corridors.iter().any(|c| c.id() == &r.target);
+                if !in_batch && self.resolve(&r.target).is_none() {
+                    problems.push(format!(
+                        "relationship {} unknown corridor '{}'",
+                        r.kind, r.target
+                    ));
+                }
+            }
+            errors.extend(problems.into_iter().map(|message| RecordError {
+                index,
+                id: corridor.id().clone(),
+                message,
+            }));
+        }
+        if !errors.is_empty() {
+            return Err(BatchError { errors });
+        }
+        for corridor in corridors {
+            self.upsert_corridor(corridor)
+                .expect("batch uuids were checked");
+        }
+        Ok(())
+    }
+}
+
+/// Invariants that deserialized records can violate.
+fn record_problems(corridor: &IndigenousEcoCorridor) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Err(e) = corridor.descriptor.validate() {
+        problems.push(e.to_string());
+    }
+    for component in MetricComponent::ALL {
+        let value = corridor.environmental.component(component).get();
+        if !(0.0..=1.0).contains(&value) {
+            problems.push(format!("{} must be within [0, 1]", component.key()));
+        }
+    }
+    if let FpicStatus::Granted {
+        at,
+        quorum,
+        terms_reference,
+        purposes,
+        valid_until,
+        terms,
+        ..
+    } = &corridor.fpic
+    {
+        if valid_until.is_some_and(|end| end <= *at) {
+            problems.push("FPIC grant ends before it starts".to_string());
+        }
+        if !quorum.is_quorum_met() {
+            problems.push("FPIC grant's consent quorum is not met".to_string());
+        }
+        if terms_reference.trim().is_empty() {
+            problems.push("FPIC grant has no terms reference".to_string());
+        }
+        if purposes.is_empty() {
+            problems.push("FPIC grant names no purposes".to_string());
+        }
+        for (community, e) in quorum.invalid_delegations(purposes) {
+            problems.push(format!(
+                "consent of '{}' is not authorized: {}",
+                community.0, e
+            ));
+        }
+        if let Some(Err(e)) = terms.as_ref().map(|t| t.check(terms_reference, purposes)) {
+            problems.push(e.to_string());
+        }
+    }
+    let unmet = corridor.ids_scope.unmet_requirements(true);
+    if unmet.contains(&IdsRequirement::GovernanceRecord) {
+        problems.push("IDS frameworks require a governance reference".to_string());
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, ConsentQuorum, FpicPurpose};
+    use crate::test_support;
+    use std::time::SystemTime;
+
+    fn corridor(id: &str, fpic: FpicStatus) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            fpic,
+            ..test_support::corridor(id)
+        }
+    }
+
+    #[test]
+    fn batch_is_all_or_nothing() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        let empty_grant = FpicStatus::Granted {
+            at: SystemTime::now(),
+            quorum: ConsentQuorum::unanimous([CommunityId("community:b".into())]).unwrap(),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let err = map
+            .upsert_batch(vec![
+                corridor("eco:coast:a", FpicStatus::Pending),
+                corridor("eco:coast:b", empty_grant),
+                corridor("eco:coast:a", FpicStatus::Pending),
+            ])
+            .unwrap_err();
+        let indices: Vec<usize> = err.errors.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [1, 2]);
+        assert_eq!(map.iter().count(), 0);
+
+        map.upsert_batch(vec![
+            corridor("eco:coast:a", FpicStatus::Pending),
+            corridor("eco:coast:b", FpicStatus::Pending),
+        ])
+        .unwrap();
+        assert_eq!(map.aggregates().corridor_count(), 2);
+    }
+}
@@ -0,0 +1,278 @@
+//! Conversions between this crate's metrics, FPIC and neurorights models
+//! and the canonical `corridor-types` ones, so other corridor crates
+//! exchange corridors with this one through a single form.
+//!
+//! The models differ, and the mapping is:
+//!
+//! - Metrics: the canonical soil, water and microbiome scores are the
+//!   means of this crate's measured components in each group; a group
+//!   with nothing measured scores 0.0, so missing data never reads as
+//!   healthy. Converting back sets all three components of a group to
+//!   its score. The canonical biodiversity score has no counterpart and
+//!   is dropped; converting to it uses the microbiome mean, the group
+//!   this crate reports biodiversity under.
+//! - FPIC: `Pending` and `Consulting` become `Pending`; `Granted` becomes
+//!   `Granted` with the terms reference as `consent_ref`, the same
+//!   purposes and `valid_until` as `expires_at`, but without its quorum;
+//!   grants here cover the whole corridor, so there is no geographic
+//!   scope. `Suspended`, `Revoked`, `Withheld` and `Expired` become
+//!   `Withheld`. A canonical `Granted` names no communities, so it cannot
+//!   become a grant here and conversion fails; its `Withheld` is dated by
+//!   the caller.
+//! - Neurorights: mental privacy holds when no person-level inference
+//!   (individual behavior, emotional or cognitive state) is allowed;
+//!   coercive channels and voluntary discipline signals map one to one.
+//!   Canonical capsules are non-actuating by construction, so converted
+//!   constraints require non-actuation. The canonical no-downgrade flag
+//!   has no field here; capsules refuse weakening through `update_to`,
+//!   so converted capsules set it.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::governance::FpicStatus;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+use crate::neurorights::{InferenceCategory, NeurorightsConstraints};
+
+/// Inferences about an individual person; allowing any of them breaks
+/// canonical mental privacy.
+const PERSON_LEVEL: [InferenceCategory; 3] = [
+    InferenceCategory::IndividualBehavior,
+    InferenceCategory::EmotionalState,
+    InferenceCategory::CognitiveState,
+];
+
+/// Why a canonical value could not become one of this crate's.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The grant names no communities, so there is no quorum for a grant
+    /// here to rest on.
+    GrantWithoutQuorum { consent_ref: String },
+    /// A score outside [0.0, 1.0] or NaN.
+    InvalidScore(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::GrantWithoutQuorum { consent_ref } => write!(
+                f,
+                "grant '{}' names no consenting communities; record the quorum with transition_fpic",
+                consent_ref
+            ),
+            ConversionError::InvalidScore(e) => write!(f, "invalid score: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<&EnvironmentalMetrics> for corridor_types::EcoImpactMetrics {
+    fn from(metrics: &EnvironmentalMetrics) -> Self {
+        let mean = |group: &[MetricComponent]| {
+            let measured: Vec<f32> = group
+                .iter()
+                .filter_map(|&c| metrics.measured(c))
+                .map(Score::get)
+                .collect();
+            if measured.is_empty() {
+                0.0
+            } else {
+                measured.iter().sum::<f32>() / measured.len() as f32
+            }
+        };
+        let groups: Vec<f32> = MetricComponent::ALL.chunks(3).map(mean).collect();
+        corridor_types::EcoImpactMetrics::new(groups[0], groups[1], groups[2], groups[2])
+    }
+}
+
+impl TryFrom<&corridor_types::EcoImpactMetrics> for EnvironmentalMetrics {
+    type Error = ConversionError;
+
+    fn try_from(metrics: &corridor_types::EcoImpactMetrics) -> Result<Self, Self::Error> {
+        let score = |value: f32| Score::new(value).map_err(ConversionError::InvalidScore);
+        let (soil, water, microbiome) = (
+            score(metrics.soil_score)?,
+            score(metrics.water_score)?,
+            score(metrics.microbiome_score)?,
+        );
+        Ok(EnvironmentalMetrics::from_components([
+            soil, soil, soil, water, water, water, microbiome, microbiome, microbiome,
+        ]))
+    }
+}
+
+impl From<&FpicStatus> for corridor_types::FpicStatus {
+    fn from(status: &FpicStatus) -> Self {
+        match status {
+            FpicStatus::Pending | FpicStatus::Consulting { .. } => Self::Pending,
+            FpicStatus::Granted {
+                terms_reference,
+                purposes,
+                valid_until,
+                ..
+            } => Self::Granted(corridor_types::FpicGrant {
+                consent_ref: terms_reference.clone(),
+                expires_at: valid_until.map(Into::into),
+                purposes: purposes.clone(),
+                geographic_scope: None,
+            }),
+            FpicStatus::Suspended { reason, .. } => Self::Withheld {
+                reason: format!("suspended: {}", reason),
+            },
+            FpicStatus::Revoked { reason, .. } => Self::Withheld {
+                reason: format!("revoked: {}", reason),
+            },
+            FpicStatus::Withheld { reason, .. } => Self::Withheld {
+                reason: reason.clone(),
+            },
+            FpicStatus::Expired { .. } => Self::Withheld {
+                reason: "expired".into(),
+            },
+        }
+    }
+}
+
+impl FpicStatus {
+    /// The status for a canonical one, dating a withholding at
+    /// `withheld_at`; canonical grants fail, since they name no quorum.
+    pub fn from_canonical(
+        status: &corridor_types::FpicStatus,
+        withheld_at: SystemTime,
+    ) -> Result<Self, ConversionError> {
+        match status {
+            corridor_types::FpicStatus::Pending => Ok(FpicStatus::Pending),
+            corridor_types::FpicStatus::Granted(grant) => {
+                Err(ConversionError::GrantWithoutQuorum {
+                    consent_ref: grant.consent_ref.clone(),
+                })
+            }
+            corridor_types::FpicStatus::Withheld { reason } => Ok(FpicStatus::Withheld {
+                at: withheld_at,
+                reason: reason.clone(),
+            }),
+        }
+    }
+}
+
+impl From<&NeurorightsConstraints> for corridor_types::NeurorightsConstraints {
+    fn from(rights: &NeurorightsConstraints) -> Self {
+        Self {
+            mental_privacy_protection: !PERSON_LEVEL
+                .iter()
+                .any(|c| rights.inference.allowed.contains(c)),
+            forbid_coercive_channels: rights.inference.no_coercive_or_hidden_channels,
+            forbid_downgrade_or_rollback: true,
+            discipline_personalized_and_noncoercive: rights.discipline_signals.voluntary_only,
+        }
+    }
+}
+
+impl From<&corridor_types::NeurorightsConstraints> for NeurorightsConstraints {
+    fn from(rights: &corridor_types::NeurorightsConstraints) -> Self {
+        let mut neurorights = NeurorightsConstraints::strict_non_actuating();
+        if !rights.mental_privacy_protection {
+            neurorights.inference.allowed = InferenceCategory::ALL.into_iter().collect();
+            neurorights.biometrics.no_biometric_categorization = false;
+            neurorights.biometrics.no_emotion_recognition = false;
+        }
+        neurorights.inference.no_coercive_or_hidden_channels = rights.forbid_coercive_channels;
+        neurorights.discipline_signals.voluntary_only =
+            rights.discipline_personalized_and_noncoercive;
+        neurorights.discipline_signals.consent_ref_required =
+            rights.discipline_personalized_and_noncoercive;
+        neurorights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum, FpicPurpose};
+    use crate::test_support::uniform_metrics;
+    use corridor_types::Timestamp;
+    use std::collections::BTreeSet;
+    use std::time::Duration;
+
+    #[test]
+    fn models_round_trip_through_the_canonical_types() {
+        let mut metrics = uniform_metrics(0.5);
+        metrics.set_component(MetricComponent::WaterQuality, Score::new(1.0).unwrap());
+        metrics
+            .unmeasured
+            .insert(MetricComponent::WaterFlowResilience);
+        let canonical = corridor_types::EcoImpactMetrics::from(&metrics);
+        assert_eq!(canonical.water_score, 0.75);
+        assert_eq!(canonical.biodiversity_score, canonical.microbiome_score);
+        let back = EnvironmentalMetrics::try_from(&canonical).unwrap();
+        assert_eq!(
+            back.component(MetricComponent::WaterFlowResilience).get(),
+            0.75
+        );
+        let nan = corridor_types::EcoImpactMetrics {
+            soil_score: f32::NAN,
+            ..canonical
+        };
+        assert!(matches!(
+            EnvironmentalMetrics::try_from(&nan),
+            Err(ConversionError::InvalidScore(_))
+        ));
+
+        let end = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+        let granted = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: Some(end),
+            renewal_notice: None,
+            terms: None,
+        };
+        let fpic = corridor_types::FpicStatus::from(&granted);
+        assert!(fpic.permits(Timestamp::UNIX_EPOCH, FpicPurpose::Monitoring));
+        assert!(!fpic.is_active_grant(end.into()));
+        assert_eq!(
+            FpicStatus::from_canonical(&fpic, end),
+            Err(ConversionError::GrantWithoutQuorum {
+                consent_ref: "terms:1".into()
+            })
+        );
+        let revoked = FpicStatus::Revoked {
+            at: end,
+            reason: "council vote".into(),
+        };
+        let withheld = corridor_types::FpicStatus::from(&revoked);
+        assert_eq!(
+            FpicStatus::from_canonical(&withheld, end).unwrap(),
+            FpicStatus::Withheld {
+                at: end,
+                reason: "revoked: council vote".into()
+            }
+        );
+
+        let strict = NeurorightsConstraints::strict_non_actuating();
+        let capsule = corridor_types::NeurorightsConstraints::from(&strict);
+        assert_eq!(
+            capsule,
+            corridor_types::NeurorightsConstraints::strict_floor()
+        );
+        assert_eq!(NeurorightsConstraints::from(&capsule), strict);
+        let open = corridor_types::NeurorightsConstraints {
+            mental_privacy_protection: false,
+            ..capsule
+        };
+        assert!(NeurorightsConstraints::from(&open)
+            .inference
+            .allowed
+            .contains(&InferenceCategory::EmotionalState));
+    }
+}
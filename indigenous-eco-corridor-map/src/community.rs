@@ -0,0 +1,166 @@
+//! Resolution of community DIDs to community metadata.
+//! Resolution is external: overlays implement `CommunityResolver` with
+//! their DID infrastructure, so the communities named in consent can be
+//! confirmed to exist.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use crate::corridor::CorridorId;
+use crate::governance::{CommunityId, FpicStatus};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// What a resolver knows about a community.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommunityMetadata {
+    pub display_name: String,
+    /// Where governance questions go, e.g. a council contact record.
+    pub governance_contact_ref: Option<String>,
+    /// Verification methods from the DID document, e.g. "#key-1".
+    pub public_key_refs: Vec<String>,
+}
+
+/// The resolver backend could not answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolverError(pub String);
+
+impl fmt::Display for ResolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "community resolver unavailable: {}", self.0)
+    }
+}
+
+impl std::error::Error for ResolverError {}
+
+/// Resolves community DIDs.
+pub trait CommunityResolver {
+    /// Metadata for `community`, or `None` if the DID does not resolve.
+    /// Only called with ids that pass `CommunityId::did`.
+    fn resolve(&self, community: &CommunityId) -> Result<Option<CommunityMetadata>, ResolverError>;
+}
+
+/// A community named in FPIC that could not be confirmed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommunityFinding {
+    /// The id is not a well-formed DID; carries the reason.
+    NotADid(CommunityId, String),
+    Unresolved(CommunityId),
+    Unavailable(CommunityId, ResolverError),
+}
+
+impl fmt::Display for CommunityFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommunityFinding::NotADid(_, reason) => f.write_str(reason),
+            CommunityFinding::Unresolved(c) => write!(f, "community '{}' does not resolve", c.0),
+            CommunityFinding::Unavailable(c, e) => write!(f, "community '{}': {}", c.0, e),
+        }
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Check every community required by the corridor's consultation or
+    /// grant quorum against `resolver`. Empty if all resolve.
+    pub fn verify_communities(&self, resolver: &dyn CommunityResolver) -> Vec<CommunityFinding> {
+        let quorum = match &self.fpic {
+            FpicStatus::Consulting { quorum, .. } | FpicStatus::Granted { quorum, .. } => quorum,
+            _ => return Vec::new(),
+        };
+        quorum
+            .required()
+            .filter_map(|community| {
+                if let Err(reason) = community.did() {
+                    return Some(CommunityFinding::NotADid(community.clone(), reason));
+                }
+                match resolver.resolve(community) {
+                    Ok(Some(_)) => None,
+                    Ok(None) => Some(CommunityFinding::Unresolved(community.clone())),
+                    Err(e) => Some(CommunityFinding::Unavailable(community.clone(), e)),
+                }
+            })
+            .collect()
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Every unconfirmed community in the map, in corridor id order.
+    pub fn unresolved_communities(
+        &self,
+        resolver: &dyn CommunityResolver,
+    ) -> Vec<(CorridorId, CommunityFinding)> {
+        self.iter()
+            .flat_map(|(id, c)| {
+                c.verify_communities(resolver)
+                    .into_iter()
+                    .map(move |f| (id.clone(), f))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::ConsentQuorum;
+    use crate::test_support;
+    use std::time::SystemTime;
+
+    struct Directory;
+
+    impl CommunityResolver for Directory {
+        fn resolve(&self, c: &CommunityId) -> Result<Option<CommunityMetadata>, ResolverError> {
+            match c.0.as_str() {
+                "did:web:nation-x.example" => Ok(Some(CommunityMetadata {
+                    display_name: "Nation X".into(),
+                    governance_contact_ref: Some("nation-x:council".into()),
+                    public_key_refs: vec!["#key-1".into()],
+                })),
+                "did:web:offline.example" => Err(ResolverError("timeout".into())),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    #[test]
+    fn quorum_communities_must_be_resolvable_dids() {
+        assert!(CommunityId::new("did:web:nation-x.example").is_ok());
+        assert!(CommunityId::new("nation-x").is_err());
+        assert!(CommunityId::new("did:web:").is_err());
+        assert_eq!(
+            CommunityId::new("did:web:a.example#council")
+                .unwrap()
+                .did()
+                .unwrap()
+                .fragment,
+            Some("council")
+        );
+
+        let required = [
+            "did:web:nation-x.example",
+            "did:web:nation-y.example",
+            "did:web:offline.example",
+            "nation-z",
+        ]
+        .map(|c| CommunityId(c.into()));
+        let corridor = IndigenousEcoCorridor {
+            fpic: FpicStatus::Consulting {
+                since: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::unanimous(required).unwrap(),
+            },
+            ..test_support::corridor("eco:river:c")
+        };
+        let findings = corridor.verify_communities(&Directory);
+        assert_eq!(findings.len(), 3);
+        assert_eq!(
+            findings[0],
+            CommunityFinding::Unresolved(CommunityId("did:web:nation-y.example".into()))
+        );
+        assert!(matches!(findings[1], CommunityFinding::Unavailable(..)));
+        assert!(matches!(findings[2], CommunityFinding::NotADid(..)));
+
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor).unwrap();
+        assert_eq!(map.unresolved_communities(&Directory).len(), 3);
+    }
+}
@@ -3,37 +3,25 @@
 
 #![forbid(unsafe_code)]
 
+use std::borrow::Cow;
 use std::fmt;
 
-/// Opaque, non-empty corridor identifier.
-/// Examples: "territory:nation-x:river-y", "eco:desert:phoenix".
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct CorridorId(String);
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-impl CorridorId {
-    /// Create a new CorridorId, rejecting empty or whitespace-only IDs.
-    pub fn new(id: impl AsRef<str>) -> Result<Self, String> {
-        let s = id.as_ref().trim();
-        if s.is_empty() {
-            return Err("CorridorId must be non-empty".into());
-        }
-        // Additional normalization or pattern checks can be added here
-        Ok(Self(s.to_string()))
-    }
+use crate::geometry::{on_segment, ring_contains, segments_intersect};
+use crate::relationships::{Relationship, RelationshipKind};
+use crate::taxonomy::CustomKind;
+use crate::units::{ElevationRange, Hectares, Kilometers};
 
-    pub fn as_str(&self) -> &str {
-        &self.0
-    }
-}
-
-impl fmt::Display for CorridorId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
-}
+pub(crate) use corridor_types::normalize_segment;
+pub use corridor_types::{CorridorId, DidParts, DidSyntaxError};
 
 /// High-level ecological corridor type, purely descriptive.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Kinds outside the built-in set are `Custom`, drawn from a
+/// community-maintained `KindRegistry`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum CorridorKind {
     Forest,
     Wetland,
@@ -42,16 +30,629 @@ pub enum CorridorKind {
     Coast,
     Mountain,
     UrbanBuffer,
-    Custom(String),
+    Custom(CustomKind),
+}
+
+impl CorridorKind {
+    /// Stable lowercase key used in interchange formats; custom kinds
+    /// use `namespace:code`.
+    pub fn key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self {
+            CorridorKind::Forest => "forest",
+            CorridorKind::Wetland => "wetland",
+            CorridorKind::Desert => "desert",
+            CorridorKind::River => "river",
+            CorridorKind::Coast => "coast",
+            CorridorKind::Mountain => "mountain",
+            CorridorKind::UrbanBuffer => "urban_buffer",
+            CorridorKind::Custom(custom) => return Cow::Owned(custom.key()),
+        })
+    }
+
+    /// Inverse of `key()`. Unknown `namespace:code` keys become `Custom`
+    /// labelled with their code; other unknown keys become legacy custom
+    /// kinds (see `CustomKind::legacy`). Use `KindRegistry::parse` to
+    /// accept registered kinds only.
+    pub fn from_key(key: &str) -> Result<Self, String> {
+        Ok(match key.trim().to_ascii_lowercase().as_str() {
+            "forest" => CorridorKind::Forest,
+            "wetland" => CorridorKind::Wetland,
+            "desert" => CorridorKind::Desert,
+            "river" => CorridorKind::River,
+            "coast" => CorridorKind::Coast,
+            "mountain" => CorridorKind::Mountain,
+            "urban_buffer" => CorridorKind::UrbanBuffer,
+            _ => CorridorKind::Custom(match key.split_once(':') {
+                Some((namespace, code)) => CustomKind::new(namespace, code, code.trim())?,
+                None => CustomKind::legacy(key)?,
+            }),
+        })
+    }
+}
+
+/// Planar coordinate pair `[x, y]`. Use projected coordinates (e.g.
+/// metres in a local CRS) when distances matter; lon/lat degrees are
+/// only adequate for small areas.
+pub type Point = [f64; 2];
+
+/// Why corridor geometry was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A coordinate is NaN or infinite.
+    NonFinite,
+    TooFewPoints {
+        needed: usize,
+        got: usize,
+    },
+    /// A ring's last position does not repeat its first.
+    NotClosed,
+    /// A ring encloses no area (e.g. all points collinear).
+    ZeroArea,
+    /// A ring crosses or touches itself.
+    SelfIntersecting,
+    /// A polygon hole is not within its exterior ring.
+    HoleOutsideExterior,
+    /// A multi-part boundary has no parts.
+    NoParts,
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryError::NonFinite => write!(f, "coordinates must be finite"),
+            GeometryError::TooFewPoints { needed, got } => {
+                write!(f, "needs at least {} positions, got {}", needed, got)
+            }
+            GeometryError::NotClosed => write!(f, "ring must end at its first position"),
+            GeometryError::ZeroArea => write!(f, "ring encloses no area"),
+            GeometryError::SelfIntersecting => write!(f, "ring intersects itself"),
+            GeometryError::HoleOutsideExterior => {
+                write!(f, "polygon hole lies outside its exterior ring")
+            }
+            GeometryError::NoParts => write!(f, "multi-part boundary has no parts"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
+fn check_finite(points: &[Point]) -> Result<(), GeometryError> {
+    if points.iter().flatten().all(|c| c.is_finite()) {
+        Ok(())
+    } else {
+        Err(GeometryError::NonFinite)
+    }
+}
+
+/// Open line of at least two distinct positions, e.g. a river
+/// centreline or migration path.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "Vec<Point>", into = "Vec<Point>")]
+pub struct Polyline(Vec<Point>);
+
+impl Polyline {
+    pub fn new(points: Vec<Point>) -> Result<Self, GeometryError> {
+        check_finite(&points)?;
+        let distinct = points.windows(2).filter(|w| w[0] != w[1]).count() + 1;
+        if points.len() < 2 || distinct < 2 {
+            return Err(GeometryError::TooFewPoints {
+                needed: 2,
+                got: points.len().min(distinct),
+            });
+        }
+        Ok(Self(points))
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    pub fn length(&self) -> f64 {
+        self.0
+            .windows(2)
+            .map(|w| (w[1][0] - w[0][0]).hypot(w[1][1] - w[0][1]))
+            .sum()
+    }
+}
+
+impl TryFrom<Vec<Point>> for Polyline {
+    type Error = GeometryError;
+
+    fn try_from(points: Vec<Point>) -> Result<Self, GeometryError> {
+        Self::new(points)
+    }
+}
+
+impl From<Polyline> for Vec<Point> {
+    fn from(line: Polyline) -> Self {
+        line.0
+    }
+}
+
+/// Closed, simple ring: at least four positions with the first repeated
+/// last (as in GeoJSON), enclosing non-zero area, and never touching or
+/// crossing itself.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "Vec<Point>", into = "Vec<Point>")]
+pub struct LinearRing(Vec<Point>);
+
+impl LinearRing {
+    pub fn new(points: Vec<Point>) -> Result<Self, GeometryError> {
+        check_finite(&points)?;
+        if points.len() < 4 {
+            return Err(GeometryError::TooFewPoints {
+                needed: 4,
+                got: points.len(),
+            });
+        }
+        if points.first() != points.last() {
+            return Err(GeometryError::NotClosed);
+        }
+        let ring = Self(points);
+        if ring.signed_area() == 0.0 {
+            return Err(GeometryError::ZeroArea);
+        }
+        if ring.self_intersects() {
+            return Err(GeometryError::SelfIntersecting);
+        }
+        Ok(ring)
+    }
+
+    /// Positions, first repeated last.
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    /// Shoelace area; positive when wound counter-clockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.0
+            .windows(2)
+            .map(|w| w[0][0] * w[1][1] - w[1][0] * w[0][1])
+            .sum::<f64>()
+            / 2.0
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    pub fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// This ring wound counter-clockwise (`ccw`) or clockwise.
+    fn wound(mut self, ccw: bool) -> Self {
+        if self.is_ccw() != ccw {
+            self.0.reverse();
+        }
+        self
+    }
+
+    /// True if any two non-adjacent edges touch or cross.
+    fn self_intersects(&self) -> bool {
+        let edges: Vec<(Point, Point)> = self.0.windows(2).map(|w| (w[0], w[1])).collect();
+        let n = edges.len();
+        for i in 0..n {
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue; // adjacent through the closing position
+                }
+                let ((p1, p2), (q1, q2)) = (edges[i], edges[j]);
+                if segments_intersect(p1, p2, q1, q2) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+impl TryFrom<Vec<Point>> for LinearRing {
+    type Error = GeometryError;
+
+    fn try_from(points: Vec<Point>) -> Result<Self, GeometryError> {
+        Self::new(points)
+    }
+}
+
+impl From<LinearRing> for Vec<Point> {
+    fn from(ring: LinearRing) -> Self {
+        ring.0
+    }
+}
+
+/// Polygon with an exterior ring and optional holes. Winding follows
+/// RFC 7946: the exterior is stored counter-clockwise and holes
+/// clockwise, whatever order the input used.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawPolygon")]
+pub struct Polygon {
+    exterior: LinearRing,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    holes: Vec<LinearRing>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawPolygon {
+    exterior: LinearRing,
+    #[serde(default)]
+    holes: Vec<LinearRing>,
+}
+
+impl TryFrom<RawPolygon> for Polygon {
+    type Error = GeometryError;
+
+    fn try_from(raw: RawPolygon) -> Result<Self, GeometryError> {
+        Self::new(raw.exterior, raw.holes)
+    }
+}
+
+impl Polygon {
+    /// Build a polygon, rejecting holes with a vertex outside the exterior.
+    pub fn new(exterior: LinearRing, holes: Vec<LinearRing>) -> Result<Self, GeometryError> {
+        for hole in &holes {
+            let outside = hole.points().iter().any(|p| {
+                !ring_contains(exterior.points(), *p)
+                    && !exterior
+                        .points()
+                        .windows(2)
+                        .any(|w| on_segment(*p, w[0], w[1]))
+            });
+            if outside {
+                return Err(GeometryError::HoleOutsideExterior);
+            }
+        }
+        Ok(Self {
+            exterior: exterior.wound(true),
+            holes: holes.into_iter().map(|h| h.wound(false)).collect(),
+        })
+    }
+
+    pub fn exterior(&self) -> &LinearRing {
+        &self.exterior
+    }
+
+    pub fn holes(&self) -> &[LinearRing] {
+        &self.holes
+    }
+
+    /// Exterior area minus hole areas.
+    pub fn area(&self) -> f64 {
+        self.exterior.area() - self.holes.iter().map(LinearRing::area).sum::<f64>()
+    }
+
+    /// Every ring, exterior first.
+    pub fn rings(&self) -> impl Iterator<Item = &LinearRing> {
+        std::iter::once(&self.exterior).chain(&self.holes)
+    }
+}
+
+/// Spatial extent of a corridor: lines for linear features (rivers,
+/// migration routes), polygons for areas, or several of either.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Boundary {
+    Polyline(Polyline),
+    MultiPolyline(Vec<Polyline>),
+    Polygon(Polygon),
+    MultiPolygon(Vec<Polygon>),
+}
+
+impl Boundary {
+    /// Constituent polygons; empty for line boundaries.
+    pub fn polygons(&self) -> &[Polygon] {
+        match self {
+            Boundary::Polygon(p) => std::slice::from_ref(p),
+            Boundary::MultiPolygon(ps) => ps,
+            _ => &[],
+        }
+    }
+
+    /// Constituent lines; empty for areal boundaries.
+    pub fn polylines(&self) -> &[Polyline] {
+        match self {
+            Boundary::Polyline(l) => std::slice::from_ref(l),
+            Boundary::MultiPolyline(ls) => ls,
+            _ => &[],
+        }
+    }
+
+    /// Enclosed area; zero for line boundaries.
+    pub fn area(&self) -> f64 {
+        self.polygons().iter().map(Polygon::area).sum()
+    }
+
+    /// Parts are validated at construction; this rejects multi-part
+    /// boundaries with no parts.
+    pub fn validate(&self) -> Result<(), GeometryError> {
+        if self.polygons().is_empty() && self.polylines().is_empty() {
+            return Err(GeometryError::NoParts);
+        }
+        Ok(())
+    }
+}
+
+/// Surveyed physical extent of a corridor. Every field is optional, since
+/// communities survey different attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PhysicalAttributes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<Hectares>,
+    /// Along-corridor length, e.g. of a river reach or migration route.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Kilometers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elevation: Option<ElevationRange>,
+}
+
+impl PhysicalAttributes {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /// Static, descriptive properties of a corridor.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CorridorDescriptor {
     pub id: CorridorId,
+    /// Optional stable identity that survives renames and retyping of
+    /// the human-readable `id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<Uuid>,
     pub kind: CorridorKind,
     /// Human-readable name as defined by the community.
     pub name: String,
     /// Optional free-text description; no actuation.
     pub description: Option<String>,
+    /// Spatial extent, when surveyed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boundary: Option<Boundary>,
+    #[serde(default, skip_serializing_if = "PhysicalAttributes::is_empty")]
+    pub physical: PhysicalAttributes,
+    /// Outgoing relationships to other corridors. Targets are checked
+    /// against the map when records are loaded or batch-upserted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+}
+
+/// Why a `CorridorDescriptor` was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DescriptorError {
+    MissingId,
+    MissingKind,
+    /// Name is empty or only whitespace.
+    EmptyName,
+    /// A description was given but is empty or only whitespace; use
+    /// `None` for no description.
+    BlankDescription,
+    /// The nil UUID cannot serve as a stable identity.
+    NilUuid,
+    InvalidBoundary(GeometryError),
+    /// A relationship targets the corridor declaring it.
+    SelfRelationship(RelationshipKind),
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescriptorError::MissingId => write!(f, "corridor descriptor needs an id"),
+            DescriptorError::MissingKind => write!(f, "corridor descriptor needs a kind"),
+            DescriptorError::EmptyName => write!(f, "corridor name must not be empty"),
+            DescriptorError::BlankDescription => {
+                write!(f, "corridor description must not be blank when present")
+            }
+            DescriptorError::NilUuid => write!(f, "corridor uuid must not be nil"),
+            DescriptorError::InvalidBoundary(e) => write!(f, "corridor boundary: {}", e),
+            DescriptorError::SelfRelationship(kind) => {
+                write!(f, "corridor cannot be {} itself", kind)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DescriptorError {}
+
+impl CorridorDescriptor {
+    /// Start building a descriptor; `build` validates it.
+    pub fn builder() -> CorridorDescriptorBuilder {
+        CorridorDescriptorBuilder::default()
+    }
+
+    /// Check the constraints `builder` enforces, e.g. for records built
+    /// by struct literal or deserialized.
+    pub fn validate(&self) -> Result<(), DescriptorError> {
+        if self.name.trim().is_empty() {
+            return Err(DescriptorError::EmptyName);
+        }
+        if self
+            .description
+            .as_ref()
+            .is_some_and(|d| d.trim().is_empty())
+        {
+            return Err(DescriptorError::BlankDescription);
+        }
+        if self.uuid.is_some_and(|u| u.is_nil()) {
+            return Err(DescriptorError::NilUuid);
+        }
+        if let Some(boundary) = &self.boundary {
+            boundary
+                .validate()
+                .map_err(DescriptorError::InvalidBoundary)?;
+        }
+        if let Some(r) = self.relationships.iter().find(|r| r.target == self.id) {
+            return Err(DescriptorError::SelfRelationship(r.kind));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for `CorridorDescriptor`; see `CorridorDescriptor::builder`.
+#[derive(Clone, Debug, Default)]
+pub struct CorridorDescriptorBuilder {
+    id: Option<CorridorId>,
+    uuid: Option<Uuid>,
+    kind: Option<CorridorKind>,
+    name: String,
+    description: Option<String>,
+    boundary: Option<Boundary>,
+    physical: PhysicalAttributes,
+    relationships: Vec<Relationship>,
+}
+
+impl CorridorDescriptorBuilder {
+    pub fn id(mut self, id: CorridorId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn uuid(mut self, uuid: Uuid) -> Self {
+        self.uuid = Some(uuid);
+        self
+    }
+
+    pub fn kind(mut self, kind: CorridorKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    pub fn area(mut self, area: Hectares) -> Self {
+        self.physical.area = Some(area);
+        self
+    }
+
+    pub fn length(mut self, length: Kilometers) -> Self {
+        self.physical.length = Some(length);
+        self
+    }
+
+    pub fn elevation(mut self, elevation: ElevationRange) -> Self {
+        self.physical.elevation = Some(elevation);
+        self
+    }
+
+    /// Add an outgoing relationship; may be called repeatedly.
+    pub fn relationship(mut self, kind: RelationshipKind, target: CorridorId) -> Self {
+        self.relationships.push(Relationship { kind, target });
+        self
+    }
+
+    pub fn build(self) -> Result<CorridorDescriptor, DescriptorError> {
+        let descriptor = CorridorDescriptor {
+            id: self.id.ok_or(DescriptorError::MissingId)?,
+            uuid: self.uuid,
+            kind: self.kind.ok_or(DescriptorError::MissingKind)?,
+            name: self.name,
+            description: self.description,
+            boundary: self.boundary,
+            physical: self.physical,
+            relationships: self.relationships,
+        };
+        descriptor.validate()?;
+        Ok(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_rejects_incomplete_descriptors() {
+        let id = CorridorId::new("eco:coast:c").unwrap();
+        let built = CorridorDescriptor::builder()
+            .id(id.clone())
+            .kind(CorridorKind::Coast)
+            .name("Coast C")
+            .build()
+            .unwrap();
+        assert_eq!(built.id, id);
+        assert_eq!(built.description, None);
+
+        let base = CorridorDescriptor::builder()
+            .id(id)
+            .kind(CorridorKind::Coast);
+        assert_eq!(
+            base.clone().name("  ").build().unwrap_err(),
+            DescriptorError::EmptyName
+        );
+        assert_eq!(
+            base.clone().name("C").description("").build().unwrap_err(),
+            DescriptorError::BlankDescription
+        );
+        assert_eq!(
+            base.name("C").uuid(Uuid::nil()).build().unwrap_err(),
+            DescriptorError::NilUuid
+        );
+        assert_eq!(
+            CorridorDescriptor::builder().name("C").build().unwrap_err(),
+            DescriptorError::MissingId
+        );
+    }
+
+    #[test]
+    fn rings_are_checked_and_rewound() {
+        let square = |pts: &[Point]| LinearRing::new(pts.to_vec());
+        assert_eq!(
+            square(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]),
+            Err(GeometryError::NotClosed)
+        );
+        assert_eq!(
+            square(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 0.0]]),
+            Err(GeometryError::ZeroArea)
+        );
+        // Bow-tie.
+        assert_eq!(
+            square(&[[0.0, 0.0], [3.0, 2.0], [3.0, 0.0], [0.0, 1.0], [0.0, 0.0]]),
+            Err(GeometryError::SelfIntersecting)
+        );
+        assert_eq!(
+            square(&[[0.0, f64::NAN], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]),
+            Err(GeometryError::NonFinite)
+        );
+
+        let clockwise =
+            square(&[[0.0, 0.0], [0.0, 4.0], [4.0, 4.0], [4.0, 0.0], [0.0, 0.0]]).unwrap();
+        assert!(!clockwise.is_ccw());
+        let hole = square(&[[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0], [1.0, 1.0]]).unwrap();
+        let polygon = Polygon::new(clockwise.clone(), vec![hole]).unwrap();
+        assert!(polygon.exterior().is_ccw());
+        assert!(!polygon.holes()[0].is_ccw());
+        assert_eq!(polygon.area(), 15.0);
+
+        let far = square(&[[5.0, 5.0], [6.0, 5.0], [6.0, 6.0], [5.0, 5.0]]).unwrap();
+        assert_eq!(
+            Polygon::new(clockwise, vec![far]),
+            Err(GeometryError::HoleOutsideExterior)
+        );
+        assert!(Polyline::new(vec![[0.0, 0.0], [0.0, 0.0]]).is_err());
+        assert_eq!(
+            Boundary::MultiPolygon(Vec::new()).validate(),
+            Err(GeometryError::NoParts)
+        );
+
+        let unclosed: Result<Polygon, _> =
+            serde_json::from_str(r#"{"exterior": [[0, 0], [1, 0], [1, 1]]}"#);
+        assert!(unclosed.is_err());
+    }
 }
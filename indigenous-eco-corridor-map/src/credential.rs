@@ -0,0 +1,278 @@
+//! FPIC grants as W3C Verifiable Credentials (VC Data Model 2.0 shape).
+//! Signing is external: `proof` is left for VC tooling to fill and is
+//! carried through unchanged.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::corridor::CorridorId;
+use crate::governance::{ConsentQuorum, FpicPurpose, FpicStatus, FpicStatusKind};
+use crate::seasons::{civil_from_days, days_from_civil};
+use crate::terms::BoundTerms;
+
+pub const VC_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+pub const CREDENTIAL_TYPE: &str = "FpicGrantCredential";
+
+/// Why a grant could not be converted to or from a credential.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CredentialError {
+    /// Only `Granted` statuses have a credential form.
+    NotGranted(FpicStatusKind),
+    Malformed(String),
+}
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialError::NotGranted(kind) => {
+                write!(f, "FPIC is {}; only grants are credentials", kind)
+            }
+            CredentialError::Malformed(m) => write!(f, "malformed FPIC credential: {}", m),
+        }
+    }
+}
+
+impl std::error::Error for CredentialError {}
+
+/// A VC asserting an FPIC grant over one corridor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FpicCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    /// DID of the issuing authority, e.g. a council.
+    pub issuer: String,
+    /// RFC 3339 UTC timestamp of the grant.
+    pub valid_from: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+    pub credential_subject: FpicCredentialSubject,
+    /// Placeholder for a Data Integrity or JOSE proof added by VC tooling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<Value>,
+}
+
+/// What the credential asserts about the corridor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FpicCredentialSubject {
+    /// The corridor id.
+    pub id: CorridorId,
+    pub terms_reference: String,
+    pub purposes: BTreeSet<FpicPurpose>,
+    pub quorum: ConsentQuorum,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renewal_notice_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms: Option<Box<BoundTerms>>,
+}
+
+impl FpicCredential {
+    /// Credential form of `status`, which must be `Granted`, for
+    /// `corridor_id` as issued by `issuer`. The proof is left empty.
+    pub fn from_grant(
+        corridor_id: &CorridorId,
+        issuer: impl Into<String>,
+        status: &FpicStatus,
+    ) -> Result<Self, CredentialError> {
+        let FpicStatus::Granted {
+            at,
+            quorum,
+            terms_reference,
+            purposes,
+            valid_until,
+            renewal_notice,
+            terms,
+        } = status
+        else {
+            return Err(CredentialError::NotGranted(status.kind()));
+        };
+        Ok(Self {
+            context: vec![VC_CONTEXT.to_string()],
+            types: vec![
+                "VerifiableCredential".to_string(),
+                CREDENTIAL_TYPE.to_string(),
+            ],
+            issuer: issuer.into(),
+            valid_from: format_timestamp(*at),
+            valid_until: valid_until.map(format_timestamp),
+            credential_subject: FpicCredentialSubject {
+                id: corridor_id.clone(),
+                terms_reference: terms_reference.clone(),
+                purposes: purposes.clone(),
+                quorum: quorum.clone(),
+                renewal_notice_seconds: renewal_notice.map(|d| d.as_secs()),
+                terms: terms.clone(),
+            },
+            proof: None,
+        })
+    }
+
+    /// The corridor id and `Granted` status the credential asserts.
+    /// Checks the context and type, not the proof.
+    pub fn to_grant(&self) -> Result<(CorridorId, FpicStatus), CredentialError> {
+        if !self.context.iter().any(|c| c == VC_CONTEXT) {
+            return Err(CredentialError::Malformed(format!(
+                "@context must include {}",
+                VC_CONTEXT
+            )));
+        }
+        if !self.types.iter().any(|t| t == CREDENTIAL_TYPE) {
+            return Err(CredentialError::Malformed(format!(
+                "type must include {}",
+                CREDENTIAL_TYPE
+            )));
+        }
+        let subject = &self.credential_subject;
+        let status = FpicStatus::Granted {
+            at: parse_timestamp(&self.valid_from)?,
+            quorum: subject.quorum.clone(),
+            terms_reference: subject.terms_reference.clone(),
+            purposes: subject.purposes.clone(),
+            valid_until: self
+                .valid_until
+                .as_deref()
+                .map(parse_timestamp)
+                .transpose()?,
+            renewal_notice: subject.renewal_notice_seconds.map(Duration::from_secs),
+            terms: subject.terms.clone(),
+        };
+        Ok((subject.id.clone(), status))
+    }
+}
+
+/// `at` as RFC 3339 in UTC, with a fraction only when it is non-zero.
+pub(crate) fn format_timestamp(at: SystemTime) -> String {
+    let (secs, nanos) = match at.duration_since(UNIX_EPOCH) {
+        Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+        Err(e) => {
+            let d = e.duration();
+            let secs = -(d.as_secs() as i64);
+            match d.subsec_nanos() {
+                0 => (secs, 0),
+                n => (secs - 1, 1_000_000_000 - n),
+            }
+        }
+    };
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let time = secs.rem_euclid(86_400);
+    let mut text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+    if nanos != 0 {
+        text.push_str(format!(".{:09}", nanos).trim_end_matches('0'));
+    }
+    text.push('Z');
+    text
+}
+
+/// Parse an RFC 3339 UTC timestamp (`Z` offset only).
+fn parse_timestamp(text: &str) -> Result<SystemTime, CredentialError> {
+    let malformed =
+        || CredentialError::Malformed(format!("'{}' is not an RFC 3339 UTC time", text));
+    let body = text.strip_suffix('Z').ok_or_else(malformed)?;
+    let (date, time) = body.split_once('T').ok_or_else(malformed)?;
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let field = |s: &str, len: usize| {
+        (s.len() == len && s.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| s.parse::<i64>().ok())
+            .flatten()
+            .ok_or_else(malformed)
+    };
+    let date: Vec<&str> = date.split('-').collect();
+    let time: Vec<&str> = time.split(':').collect();
+    let ([y, mo, d], [h, mi, s]) = (&date[..], &time[..]) else {
+        return Err(malformed());
+    };
+    let (year, month, day) = (field(y, 4)?, field(mo, 2)?, field(d, 2)?);
+    let (hour, minute, second) = (field(h, 2)?, field(mi, 2)?, field(s, 2)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(malformed());
+    }
+    let days = days_from_civil(year, month as u8, day as u8);
+    if civil_from_days(days) != (year, month as u8, day as u8) {
+        return Err(malformed());
+    }
+    let nanos = match fraction.len() {
+        0 => 0,
+        1..=9 => field(fraction, fraction.len())? * 10_i64.pow(9 - fraction.len() as u32),
+        _ => return Err(malformed()),
+    };
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second;
+    let offset = Duration::new(secs.unsigned_abs(), 0);
+    let base = if secs >= 0 {
+        UNIX_EPOCH + offset
+    } else {
+        UNIX_EPOCH - offset
+    };
+    Ok(base + Duration::from_nanos(nanos as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent};
+
+    #[test]
+    fn grants_round_trip_through_credentials() {
+        let at = UNIX_EPOCH + Duration::new(1_709_164_800, 250_000_000);
+        let grant = FpicStatus::Granted {
+            at,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: Some(UNIX_EPOCH - Duration::from_secs(1)),
+            renewal_notice: Some(Duration::from_secs(86_400)),
+            terms: None,
+        };
+        let id = CorridorId::new("eco:river:vc").unwrap();
+        let credential = FpicCredential::from_grant(&id, "did:example:council", &grant).unwrap();
+        let json = serde_json::to_value(&credential).unwrap();
+        assert_eq!(json["validFrom"], "2024-02-29T00:00:00.25Z");
+        assert_eq!(json["validUntil"], "1969-12-31T23:59:59Z");
+        assert_eq!(json["credentialSubject"]["id"], "eco:river:vc");
+        assert!(json.get("proof").is_none());
+
+        let parsed: FpicCredential = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.to_grant().unwrap(), (id.clone(), grant));
+
+        let mut bad = credential.clone();
+        bad.valid_from = "2023-02-29T00:00:00Z".into();
+        assert!(bad.to_grant().is_err());
+        bad.valid_from = credential.valid_from.clone();
+        bad.types.pop();
+        assert!(bad.to_grant().is_err());
+        assert_eq!(
+            FpicCredential::from_grant(&id, "did:example:council", &FpicStatus::Pending),
+            Err(CredentialError::NotGranted(FpicStatusKind::Pending))
+        );
+    }
+}
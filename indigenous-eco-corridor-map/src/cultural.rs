@@ -0,0 +1,223 @@
+//! Cultural site markers with access sensitivity labels.
+//! Restricted sites never carry coordinates here; their location stays
+//! with the community, behind an external governed reference.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::Point;
+use crate::map::IndigenousEcoCorridor;
+
+/// Who may know about a site, ordered from least to most sensitive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SiteAccess {
+    Public,
+    /// Members of the governing community only.
+    CommunityOnly,
+    /// Known only to designated knowledge holders; no location stored.
+    Restricted,
+}
+
+impl SiteAccess {
+    pub fn key(self) -> &'static str {
+        match self {
+            SiteAccess::Public => "public",
+            SiteAccess::CommunityOnly => "community_only",
+            SiteAccess::Restricted => "restricted",
+        }
+    }
+}
+
+impl fmt::Display for SiteAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// A cultural site within a corridor.
+///
+/// Public and community-only sites may carry a location; restricted
+/// sites carry only `governed_ref`, a pointer into the community's own
+/// governed system (e.g. a protocol register entry). Construction and
+/// deserialization both reject restricted sites with a location.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawCulturalSite")]
+pub struct CulturalSite {
+    label: String,
+    access: SiteAccess,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    location: Option<Point>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    governed_ref: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawCulturalSite {
+    label: String,
+    access: SiteAccess,
+    #[serde(default)]
+    location: Option<Point>,
+    #[serde(default)]
+    governed_ref: Option<String>,
+}
+
+impl TryFrom<RawCulturalSite> for CulturalSite {
+    type Error = String;
+
+    fn try_from(raw: RawCulturalSite) -> Result<Self, String> {
+        let site = match (raw.access, raw.governed_ref) {
+            (SiteAccess::Restricted, _) if raw.location.is_some() => {
+                return Err(format!(
+                    "restricted cultural site '{}' must not carry a location",
+                    raw.label
+                ))
+            }
+            (SiteAccess::Restricted, Some(governed_ref)) => {
+                Self::restricted(raw.label, governed_ref)?
+            }
+            (SiteAccess::Restricted, None) => {
+                return Err(format!(
+                    "restricted cultural site '{}' needs a governed reference",
+                    raw.label
+                ))
+            }
+            (access, governed_ref) => {
+                let mut site = Self::new(raw.label, access, raw.location)?;
+                site.governed_ref = governed_ref;
+                site
+            }
+        };
+        Ok(site)
+    }
+}
+
+impl CulturalSite {
+    /// A public or community-only site. Use `restricted` for restricted
+    /// sites, which cannot have a location.
+    pub fn new(
+        label: impl Into<String>,
+        access: SiteAccess,
+        location: Option<Point>,
+    ) -> Result<Self, String> {
+        let label = label.into();
+        if access == SiteAccess::Restricted {
+            return Err(format!(
+                "restricted cultural site '{}' must be created with a governed reference",
+                label
+            ));
+        }
+        if label.trim().is_empty() {
+            return Err("cultural site label must not be empty".into());
+        }
+        if location.is_some_and(|[x, y]| !x.is_finite() || !y.is_finite()) {
+            return Err(format!(
+                "cultural site '{}' has a non-finite location",
+                label
+            ));
+        }
+        Ok(Self {
+            label,
+            access,
+            location,
+            governed_ref: None,
+        })
+    }
+
+    /// A restricted site, known here only by `governed_ref`.
+    pub fn restricted(
+        label: impl Into<String>,
+        governed_ref: impl Into<String>,
+    ) -> Result<Self, String> {
+        let label = label.into();
+        let governed_ref = governed_ref.into();
+        if label.trim().is_empty() {
+            return Err("cultural site label must not be empty".into());
+        }
+        if governed_ref.trim().is_empty() {
+            return Err(format!(
+                "restricted cultural site '{}' needs a governed reference",
+                label
+            ));
+        }
+        Ok(Self {
+            label,
+            access: SiteAccess::Restricted,
+            location: None,
+            governed_ref: Some(governed_ref),
+        })
+    }
+
+    /// Attach an external governed reference to a public or
+    /// community-only site.
+    pub fn with_governed_ref(mut self, governed_ref: impl Into<String>) -> Self {
+        self.governed_ref = Some(governed_ref.into());
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn access(&self) -> SiteAccess {
+        self.access
+    }
+
+    /// Always `None` for restricted sites.
+    pub fn location(&self) -> Option<Point> {
+        self.location
+    }
+
+    pub fn governed_ref(&self) -> Option<&str> {
+        self.governed_ref.as_deref()
+    }
+
+    /// Drop the location, e.g. while it is under embargo.
+    pub(crate) fn clear_location(&mut self) {
+        self.location = None;
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Sites whose access label is at most `clearance`, e.g.
+    /// `SiteAccess::Public` for an open dashboard.
+    pub fn sites_visible_to(&self, clearance: SiteAccess) -> impl Iterator<Item = &CulturalSite> {
+        self.cultural_sites
+            .iter()
+            .filter(move |s| s.access <= clearance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restricted_sites_never_hold_coordinates() {
+        let spring = CulturalSite::new("Spring", SiteAccess::Public, Some([1.0, 2.0])).unwrap();
+        assert_eq!(spring.location(), Some([1.0, 2.0]));
+        assert!(CulturalSite::new("Grove", SiteAccess::Restricted, None).is_err());
+        assert!(CulturalSite::new(" ", SiteAccess::Public, None).is_err());
+        assert!(CulturalSite::restricted("Grove", "  ").is_err());
+
+        let grove = CulturalSite::restricted("Grove", "nation-x:register:17").unwrap();
+        let json = serde_json::to_value(&grove).unwrap();
+        assert!(json.get("location").is_none());
+        assert_eq!(serde_json::from_value::<CulturalSite>(json).unwrap(), grove);
+
+        let leaked = serde_json::json!({
+            "label": "Grove",
+            "access": "restricted",
+            "location": [1.0, 2.0],
+            "governed_ref": "nation-x:register:17",
+        });
+        assert!(serde_json::from_value::<CulturalSite>(leaked).is_err());
+        assert!(SiteAccess::Public < SiteAccess::CommunityOnly);
+    }
+}
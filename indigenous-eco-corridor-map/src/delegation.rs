@@ -0,0 +1,183 @@
+//! Delegated consent authority.
+//! A council may let a delegate sign consent on its behalf for some
+//! purposes until a set time; consents signed that way carry the
+//! `Delegation` so grant validation can check the delegate's authority.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::governance::{CommunityId, ConsentQuorum, FpicPurpose};
+
+/// Authority to sign consent for `delegator`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Delegation {
+    pub delegator: CommunityId,
+    /// DID or steward identifier of whoever may sign.
+    pub delegate: String,
+    /// Purposes the delegate may consent to.
+    pub scope: BTreeSet<FpicPurpose>,
+    /// The delegation lapses at this instant.
+    pub expires_at: SystemTime,
+    /// Hash/URI of the council decision granting the authority.
+    pub reference: String,
+}
+
+/// Why a delegation does not cover a consent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DelegationError {
+    /// The delegation was granted by another community.
+    WrongDelegator(CommunityId),
+    /// The consent was signed at or after `expires_at`.
+    Expired,
+    /// The grant covers a purpose outside the delegation's scope.
+    OutOfScope(FpicPurpose),
+    /// No delegate or no council decision is named.
+    Incomplete,
+}
+
+impl fmt::Display for DelegationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DelegationError::WrongDelegator(c) => {
+                write!(f, "delegation was granted by '{}'", c.0)
+            }
+            DelegationError::Expired => write!(f, "delegation had expired when consent was signed"),
+            DelegationError::OutOfScope(p) => write!(f, "delegation does not cover {}", p),
+            DelegationError::Incomplete => {
+                write!(
+                    f,
+                    "delegation needs a delegate and a council decision reference"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DelegationError {}
+
+impl Delegation {
+    /// Check that the delegation lets its delegate consent for
+    /// `community` to `purposes` at `signed_at`.
+    pub fn authorizes(
+        &self,
+        community: &CommunityId,
+        purposes: &BTreeSet<FpicPurpose>,
+        signed_at: SystemTime,
+    ) -> Result<(), DelegationError> {
+        if self.delegate.trim().is_empty() || self.reference.trim().is_empty() {
+            return Err(DelegationError::Incomplete);
+        }
+        if &self.delegator != community {
+            return Err(DelegationError::WrongDelegator(self.delegator.clone()));
+        }
+        if signed_at >= self.expires_at {
+            return Err(DelegationError::Expired);
+        }
+        match purposes.difference(&self.scope).next() {
+            Some(p) => Err(DelegationError::OutOfScope(*p)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ConsentQuorum {
+    /// Delegated consents whose delegation does not cover a grant for
+    /// `purposes`, in community order. Consents signed by the community
+    /// itself are not checked.
+    pub fn invalid_delegations(
+        &self,
+        purposes: &BTreeSet<FpicPurpose>,
+    ) -> Vec<(CommunityId, DelegationError)> {
+        self.consents()
+            .filter_map(|(community, consent)| {
+                let delegation = consent.delegation.as_ref()?;
+                delegation
+                    .authorizes(community, purposes, consent.at)
+                    .err()
+                    .map(|e| (community.clone(), e))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{Consent, FpicEvidence, FpicStatus, FpicTransitionError};
+    use std::time::Duration;
+
+    #[test]
+    fn grants_accept_only_covering_delegations() {
+        let council = CommunityId("did:web:nation-x.example".into());
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let delegation = Delegation {
+            delegator: council.clone(),
+            delegate: "did:web:lands-office.nation-x.example".into(),
+            scope: BTreeSet::from([FpicPurpose::Monitoring, FpicPurpose::Research]),
+            expires_at: at + Duration::from_secs(60),
+            reference: "nation-x:resolution:7".into(),
+        };
+        let grant = |delegation: Delegation, purposes: &[FpicPurpose]| FpicStatus::Granted {
+            at,
+            quorum: ConsentQuorum::sole(
+                council.clone(),
+                Consent {
+                    at,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: Some(delegation),
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: purposes.iter().copied().collect(),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let try_grant = |status: FpicStatus| {
+            let mut fpic = FpicStatus::Consulting {
+                since: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::unanimous([council.clone()]).unwrap(),
+            };
+            let evidence = FpicEvidence {
+                actor: "did:web:lands-office.nation-x.example".into(),
+                at,
+                reference: "minutes:1".into(),
+            };
+            fpic.transition(status, evidence).map(|_| ())
+        };
+
+        assert_eq!(
+            try_grant(grant(delegation.clone(), &[FpicPurpose::Monitoring])),
+            Ok(())
+        );
+        assert_eq!(
+            try_grant(grant(delegation.clone(), &[FpicPurpose::Infrastructure])),
+            Err(FpicTransitionError::Delegation(
+                council.clone(),
+                DelegationError::OutOfScope(FpicPurpose::Infrastructure)
+            ))
+        );
+        let expired = Delegation {
+            expires_at: at,
+            ..delegation.clone()
+        };
+        assert!(try_grant(grant(expired, &[FpicPurpose::Monitoring])).is_err());
+        let foreign = Delegation {
+            delegator: CommunityId("did:web:nation-y.example".into()),
+            ..delegation
+        };
+        assert!(matches!(
+            try_grant(grant(foreign, &[FpicPurpose::Monitoring])),
+            Err(FpicTransitionError::Delegation(
+                _,
+                DelegationError::WrongDelegator(_)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+//! Changes between two metric observations.
+//! "Water quality dropped 0.15 this quarter" is computed here once, for
+//! map diffs and monitoring reports alike.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use crate::corridor::CorridorId;
+use crate::map::IndigenousEcoCorridorMap;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// Change in one component. Either side may be unmeasured, in which case
+/// there is no change to report.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComponentDelta {
+    pub component: MetricComponent,
+    pub before: Option<Score>,
+    pub after: Option<Score>,
+}
+
+impl ComponentDelta {
+    /// `after - before`, if both were measured.
+    pub fn change(&self) -> Option<f32> {
+        Some(self.after?.get() - self.before?.get())
+    }
+
+    /// Change as a percentage of `before`; `None` from a zero score.
+    pub fn percent(&self) -> Option<f32> {
+        let before = self.before?.get();
+        if before == 0.0 {
+            return None;
+        }
+        Some(self.change()? / before * 100.0)
+    }
+}
+
+impl fmt::Display for ComponentDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.change(), self.percent()) {
+            (Some(change), Some(percent)) => write!(
+                f,
+                "{} {:+.2} ({:+.1}%)",
+                self.component.key(),
+                change,
+                percent
+            ),
+            (Some(change), None) => write!(f, "{} {:+.2}", self.component.key(), change),
+            (None, _) => write!(f, "{} not comparable", self.component.key()),
+        }
+    }
+}
+
+/// Per-component changes from an earlier observation to a later one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsDelta {
+    /// In `MetricComponent::ALL` order.
+    pub components: [ComponentDelta; MetricComponent::COUNT],
+    /// Change in `aggregate_score`.
+    pub aggregate_change: f32,
+}
+
+impl MetricsDelta {
+    pub fn component(&self, component: MetricComponent) -> &ComponentDelta {
+        &self.components[component.index()]
+    }
+
+    /// Components that fell by at least `by`, steepest first.
+    pub fn declines(&self, by: f32) -> Vec<&ComponentDelta> {
+        let mut declines: Vec<_> = self
+            .components
+            .iter()
+            .filter(|d| d.change().is_some_and(|c| c <= -by))
+            .collect();
+        declines.sort_by(|a, b| {
+            let change = |d: &ComponentDelta| d.change().unwrap_or_default();
+            change(a).total_cmp(&change(b))
+        });
+        declines
+    }
+
+    /// True if no measured component changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.components
+            .iter()
+            .all(|d| d.change().is_none_or(|c| c == 0.0))
+    }
+}
+
+impl EnvironmentalMetrics {
+    /// Changes from `earlier` to these metrics.
+    pub fn delta(&self, earlier: &EnvironmentalMetrics) -> MetricsDelta {
+        MetricsDelta {
+            components: MetricComponent::ALL.map(|component| ComponentDelta {
+                component,
+                before: earlier.measured(component),
+                after: self.measured(component),
+            }),
+            aggregate_change: self.aggregate_score().get() - earlier.aggregate_score().get(),
+        }
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Metric changes since `earlier` (e.g. a `MapSnapshot` from a
+    /// `MapTimeline`) for corridors present in both whose metrics
+    /// changed, in id order.
+    pub fn metrics_deltas(
+        &self,
+        earlier: &IndigenousEcoCorridorMap,
+    ) -> Vec<(CorridorId, MetricsDelta)> {
+        self.iter()
+            .filter_map(|(id, corridor)| {
+                let before = earlier.get(id)?;
+                let delta = corridor.environmental.delta(&before.environmental);
+                (!delta.is_unchanged()).then(|| (id.clone(), delta))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deltas_report_changes_and_rates() {
+        let with_water = |quality: f32| {
+            let mut values = [Score::new(0.6).unwrap(); MetricComponent::COUNT];
+            values[MetricComponent::WaterQuality.index()] = Score::new(quality).unwrap();
+            EnvironmentalMetrics::from_components(values)
+        };
+        let earlier = with_water(0.75);
+        let mut later = with_water(0.6);
+        later.unmeasured.insert(MetricComponent::SoilFertility);
+
+        let delta = later.delta(&earlier);
+        let water = delta.component(MetricComponent::WaterQuality);
+        assert!((water.change().unwrap() + 0.15).abs() < 1e-6);
+        assert!((water.percent().unwrap() + 20.0).abs() < 1e-4);
+        assert_eq!(water.to_string(), "water_quality -0.15 (-20.0%)");
+        assert_eq!(
+            delta.component(MetricComponent::SoilFertility).change(),
+            None
+        );
+        assert_eq!(
+            delta.component(MetricComponent::SoilErosionRisk).change(),
+            Some(0.0)
+        );
+        assert_eq!(delta.declines(0.1).len(), 1);
+        assert!(delta.declines(0.2).is_empty());
+        assert!(!delta.is_unchanged());
+        assert!(earlier.delta(&earlier).is_unchanged());
+
+        let zero = with_water(0.0);
+        assert_eq!(
+            earlier
+                .delta(&zero)
+                .component(MetricComponent::WaterQuality)
+                .percent(),
+            None
+        );
+    }
+}
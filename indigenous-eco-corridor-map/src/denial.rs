@@ -0,0 +1,136 @@
+//! Human-readable explanations of neurorights denials.
+//! Each violation maps to the constraint it broke, a stable key for the
+//! policy text communities publish, and what the overlay could change, so
+//! liaisons can relay a denial without reading Rust.
+
+#![forbid(unsafe_code)]
+
+use serde::Serialize;
+
+use crate::neurorights::{DeclaredCapabilities, NeurorightsConstraints, NeurorightsViolation};
+
+/// Why an overlay was denied, in terms a community liaison can relay.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DenialExplanation {
+    /// Path of the violated field in `NeurorightsConstraints`, e.g.
+    /// "actuation.non_actuating_required".
+    pub constraint: &'static str,
+    /// Key of the policy text to cite, e.g. "neurorights.non_actuating".
+    pub policy_text_key: &'static str,
+    /// What went wrong, as `NeurorightsViolation` displays it.
+    pub summary: String,
+    /// What the overlay could change to be permitted.
+    pub remediation: String,
+}
+
+impl NeurorightsViolation {
+    pub fn explain(&self) -> DenialExplanation {
+        let (constraint, policy_text_key, remediation) = match self {
+            NeurorightsViolation::Actuating => (
+                "actuation.non_actuating_required",
+                "neurorights.non_actuating",
+                "limit the overlay to observing or advising; any action must be taken by people"
+                    .to_string(),
+            ),
+            NeurorightsViolation::HiddenChannels => (
+                "inference.no_coercive_or_hidden_channels",
+                "neurorights.no_hidden_channels",
+                "disclose every channel to the people observed and let them refuse it".to_string(),
+            ),
+            NeurorightsViolation::CoerciveFeedback(channel) => (
+                "inference.no_coercive_or_hidden_channels",
+                "neurorights.no_coercive_channels",
+                format!("remove the {} feedback channel", channel),
+            ),
+            NeurorightsViolation::InferenceNotAllowed(category) => (
+                "inference.allowed",
+                "neurorights.allowed_inferences",
+                format!(
+                    "stop inferring {}, or ask the community to allow it in the corridor's policy",
+                    category
+                ),
+            ),
+            NeurorightsViolation::InvoluntaryDisciplineSignals => (
+                "discipline_signals.voluntary_only",
+                "neurorights.voluntary_discipline",
+                "make discipline signals opt-in for everyone who receives them".to_string(),
+            ),
+            NeurorightsViolation::MissingConsentReference => (
+                "discipline_signals.consent_ref_required",
+                "neurorights.discipline_consent",
+                "obtain explicit consent for discipline signals and cite its terms reference"
+                    .to_string(),
+            ),
+            NeurorightsViolation::BiometricCategorization => (
+                "biometrics.no_biometric_categorization",
+                "neurorights.no_biometric_categorization",
+                "exclude people from footage before analysis, e.g. by discarding frames with people"
+                    .to_string(),
+            ),
+            NeurorightsViolation::EmotionRecognition => (
+                "biometrics.no_emotion_recognition",
+                "neurorights.no_emotion_recognition",
+                "remove emotion recognition from the analysis pipeline".to_string(),
+            ),
+            NeurorightsViolation::RetentionExceeded { limit, .. } => (
+                "retention.limit",
+                "neurorights.retention",
+                format!(
+                    "delete observations within {}s of collection",
+                    limit.as_secs()
+                ),
+            ),
+        };
+        DenialExplanation {
+            constraint,
+            policy_text_key,
+            summary: self.to_string(),
+            remediation,
+        }
+    }
+}
+
+impl NeurorightsConstraints {
+    /// Explanations for every violation `capabilities` commit, in
+    /// constraint order; empty if the overlay may run.
+    pub fn explain(&self, capabilities: &DeclaredCapabilities) -> Vec<DenialExplanation> {
+        self.evaluate(capabilities)
+            .iter()
+            .map(NeurorightsViolation::explain)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neurorights::{ActuationIntent, FeedbackChannel};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn denials_cite_constraint_and_remediation() {
+        let policy = NeurorightsConstraints::strict_non_actuating();
+        let overlay = DeclaredCapabilities {
+            actuation: ActuationIntent::Actuate,
+            feedback: BTreeSet::from([FeedbackChannel::DisciplineSignal]),
+            discipline_signals_voluntary: true,
+            ..DeclaredCapabilities::default()
+        };
+        let explanations = policy.explain(&overlay);
+        assert_eq!(explanations.len(), 2);
+        assert_eq!(
+            explanations[0].constraint,
+            "actuation.non_actuating_required"
+        );
+        assert_eq!(
+            explanations[1].policy_text_key,
+            "neurorights.discipline_consent"
+        );
+        assert!(explanations[1].remediation.contains("explicit consent"));
+
+        let json = serde_json::to_value(&explanations[0]).unwrap();
+        assert_eq!(json["summary"], "actuation is not allowed");
+        assert!(policy.explain(&DeclaredCapabilities::default()).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+//! Embargoes on newly surveyed corridor data.
+//! A community can hold back sensitive parts of a record until it
+//! releases them; queries then redact the embargoed fields or leave the
+//! corridor out entirely.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// Part of a record an embargo can withhold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum EmbargoCategory {
+    /// Coordinates of cultural sites, e.g. where a species was surveyed.
+    SiteLocations,
+    Boundary,
+    CulturalNotes,
+    Seasons,
+}
+
+impl EmbargoCategory {
+    pub const ALL: [EmbargoCategory; 4] = [
+        EmbargoCategory::SiteLocations,
+        EmbargoCategory::Boundary,
+        EmbargoCategory::CulturalNotes,
+        EmbargoCategory::Seasons,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            EmbargoCategory::SiteLocations => "site_locations",
+            EmbargoCategory::Boundary => "boundary",
+            EmbargoCategory::CulturalNotes => "cultural_notes",
+            EmbargoCategory::Seasons => "seasons",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.key() == key.trim())
+    }
+}
+
+impl fmt::Display for EmbargoCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Data withheld until the community releases it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Embargo {
+    /// The embargo lifts at this instant.
+    pub until: SystemTime,
+    pub categories: BTreeSet<EmbargoCategory>,
+}
+
+impl Embargo {
+    pub fn covers(&self, category: EmbargoCategory, now: SystemTime) -> bool {
+        now < self.until && self.categories.contains(&category)
+    }
+}
+
+/// What a query does with corridors under an active embargo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmbargoHandling {
+    /// Return the corridor with embargoed fields cleared.
+    Redact,
+    /// Leave the corridor out.
+    Exclude,
+}
+
+impl IndigenousEcoCorridor {
+    /// True if any part of the record is embargoed at `now`.
+    pub fn is_embargoed(&self, now: SystemTime) -> bool {
+        EmbargoCategory::ALL
+            .into_iter()
+            .any(|c| self.ids_scope.is_embargoed(c, now))
+    }
+
+    /// Copy of the record with the fields embargoed at `now` cleared.
+    /// The copy is unsigned if anything was removed.
+    pub fn redacted(&self, now: SystemTime) -> IndigenousEcoCorridor {
+        let mut record = self.clone();
+        if !self.is_embargoed(now) {
+            return record;
+        }
+        let embargoed = |c| self.ids_scope.is_embargoed(c, now);
+        if embargoed(EmbargoCategory::SiteLocations) {
+            for site in &mut record.cultural_sites {
+                site.clear_location();
+            }
+        }
+        if embargoed(EmbargoCategory::Boundary) {
+            record.descriptor.boundary = None;
+        }
+        if embargoed(EmbargoCategory::CulturalNotes) {
+            record.cultural_notes = None;
+        }
+        if embargoed(EmbargoCategory::Seasons) {
+            record.seasons.clear();
+        }
+        record.signature = None;
+        record
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Corridors as they may be shown at `now`, in id order, with
+    /// embargoed ones redacted or excluded per `handling`.
+    pub fn released(
+        &self,
+        now: SystemTime,
+        handling: EmbargoHandling,
+    ) -> Vec<IndigenousEcoCorridor> {
+        self.iter()
+            .filter_map(|(_, c)| match handling {
+                EmbargoHandling::Redact => Some(c.redacted(now)),
+                EmbargoHandling::Exclude => (!c.is_embargoed(now)).then(|| c.clone()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cultural::{CulturalSite, SiteAccess};
+    use crate::test_support;
+    use std::time::Duration;
+
+    #[test]
+    fn embargoed_fields_are_withheld_until_release() {
+        let release = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut corridor = IndigenousEcoCorridor {
+            cultural_notes: Some("salmon spawning reach".into()),
+            cultural_sites: vec![
+                CulturalSite::new("Redd", SiteAccess::Public, Some([1.0, 2.0])).unwrap(),
+            ],
+            ..test_support::corridor("eco:river:survey")
+        };
+        corridor.ids_scope.embargo = Some(Embargo {
+            until: release,
+            categories: BTreeSet::from([
+                EmbargoCategory::SiteLocations,
+                EmbargoCategory::CulturalNotes,
+            ]),
+        });
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor).unwrap();
+
+        let before = SystemTime::UNIX_EPOCH;
+        let shown = map.released(before, EmbargoHandling::Redact);
+        assert_eq!(shown[0].cultural_sites[0].location(), None);
+        assert_eq!(shown[0].cultural_sites[0].label(), "Redd");
+        assert_eq!(shown[0].cultural_notes, None);
+        assert!(map.released(before, EmbargoHandling::Exclude).is_empty());
+
+        let after = map.released(release, EmbargoHandling::Exclude);
+        assert_eq!(after[0].cultural_sites[0].location(), Some([1.0, 2.0]));
+        assert_eq!(
+            EmbargoCategory::from_key("seasons"),
+            Some(EmbargoCategory::Seasons)
+        );
+    }
+}
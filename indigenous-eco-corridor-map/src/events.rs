@@ -0,0 +1,70 @@
+//! Change notifications emitted by the corridor map on mutation.
+
+#![forbid(unsafe_code)]
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+
+use crate::corridor::CorridorId;
+use crate::governance::FpicStatus;
+use crate::lifecycle::Lifecycle;
+use crate::map::IndigenousEcoCorridor;
+use crate::metrics::EnvironmentalMetrics;
+
+/// One mutation of an `IndigenousEcoCorridorMap`.
+#[derive(Clone, Debug)]
+pub enum MapEvent {
+    /// A corridor was inserted or replaced; carries the new record.
+    CorridorUpserted(Arc<IndigenousEcoCorridor>),
+    /// FPIC status differs from the previous record (`None` for new corridors).
+    /// Statuses are boxed to keep events small.
+    FpicChanged {
+        id: CorridorId,
+        previous: Option<Box<FpicStatus>>,
+        current: Box<FpicStatus>,
+    },
+    /// Metrics are boxed for the same reason.
+    MetricsUpdated {
+        id: CorridorId,
+        metrics: Box<EnvironmentalMetrics>,
+    },
+    LifecycleChanged {
+        id: CorridorId,
+        previous: Lifecycle,
+        current: Lifecycle,
+    },
+    CorridorRemoved(CorridorId),
+    /// The record moved to a new id; `from` is now an alias of `to`.
+    CorridorRenamed {
+        from: CorridorId,
+        to: CorridorId,
+    },
+}
+
+/// Subscriber channels. Cloning yields an empty list, so snapshots and
+/// scratch copies (e.g. what-if scenarios) never notify subscribers.
+#[derive(Default)]
+pub(crate) struct Subscribers(Vec<Sender<MapEvent>>);
+
+impl Clone for Subscribers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Subscribers {
+    pub(crate) fn subscribe(&mut self) -> Receiver<MapEvent> {
+        let (tx, rx) = channel();
+        self.0.push(tx);
+        rx
+    }
+
+    /// Deliver `event` to every live subscriber, dropping closed channels.
+    pub(crate) fn emit(&mut self, event: MapEvent) {
+        self.0.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
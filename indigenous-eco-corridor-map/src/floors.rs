@@ -0,0 +1,135 @@
+//! Per-kind neurorights floors.
+//! A corridor's explicit constraints may be stricter than its floor but
+//! never weaker; the checker reports every corridor that falls below.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+
+use crate::corridor::{CorridorId, CorridorKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::neurorights::{NeurorightsConstraints, NeurorightsWeakening};
+
+/// Minimum neurorights policy for each corridor kind, plus a floor that
+/// applies to every corridor holding cultural sites.
+#[derive(Clone, Debug)]
+pub struct NeurorightsFloors {
+    default: NeurorightsConstraints,
+    by_kind: BTreeMap<String, NeurorightsConstraints>,
+    cultural_sites: NeurorightsConstraints,
+}
+
+impl NeurorightsFloors {
+    /// `default` applies to kinds without their own floor. Corridors with
+    /// cultural sites are held to `strict_non_actuating` as well.
+    pub fn new(default: NeurorightsConstraints) -> Self {
+        Self {
+            default,
+            by_kind: BTreeMap::new(),
+            cultural_sites: NeurorightsConstraints::strict_non_actuating(),
+        }
+    }
+
+    /// Use `floor` for corridors of `kind`, replacing any earlier one.
+    pub fn with_kind(mut self, kind: &CorridorKind, floor: NeurorightsConstraints) -> Self {
+        self.by_kind.insert(kind.key().into_owned(), floor);
+        self
+    }
+
+    /// Replace the floor for corridors holding cultural sites.
+    pub fn with_cultural_sites(mut self, floor: NeurorightsConstraints) -> Self {
+        self.cultural_sites = floor;
+        self
+    }
+
+    pub fn floor_for_kind(&self, kind: &CorridorKind) -> &NeurorightsConstraints {
+        self.by_kind
+            .get(kind.key().as_ref())
+            .unwrap_or(&self.default)
+    }
+
+    /// The floor for `corridor`: its kind's floor, merged strictest-wins
+    /// with the cultural-site floor if it holds any cultural sites.
+    pub fn floor_for(&self, corridor: &IndigenousEcoCorridor) -> NeurorightsConstraints {
+        let floor = self.floor_for_kind(&corridor.descriptor.kind);
+        if corridor.cultural_sites.is_empty() {
+            floor.clone()
+        } else {
+            floor.merge_strictest(&self.cultural_sites)
+        }
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Every way the corridor's constraints fall below its floor.
+    pub fn neurorights_below_floor(&self, floors: &NeurorightsFloors) -> Vec<NeurorightsWeakening> {
+        floors.floor_for(self).weakenings_to(&self.neurorights)
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Corridors whose constraints fall below their floor, in id order.
+    pub fn neurorights_below_floor(
+        &self,
+        floors: &NeurorightsFloors,
+    ) -> Vec<(CorridorId, Vec<NeurorightsWeakening>)> {
+        self.iter()
+            .map(|(id, c)| (id.clone(), c.neurorights_below_floor(floors)))
+            .filter(|(_, weakenings)| !weakenings.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cultural::{CulturalSite, SiteAccess};
+    use crate::test_support;
+
+    fn corridor(
+        id: &str,
+        kind: CorridorKind,
+        neurorights: NeurorightsConstraints,
+    ) -> IndigenousEcoCorridor {
+        let mut corridor = IndigenousEcoCorridor {
+            neurorights,
+            ..test_support::corridor(id)
+        };
+        corridor.descriptor.kind = kind;
+        corridor
+    }
+
+    #[test]
+    fn corridors_below_their_floor_are_reported() {
+        let mut river_floor = NeurorightsConstraints::unconstrained();
+        river_floor.actuation.non_actuating_required = true;
+        let floors = NeurorightsFloors::new(NeurorightsConstraints::unconstrained())
+            .with_kind(&CorridorKind::River, river_floor);
+
+        let open = NeurorightsConstraints::unconstrained();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:forest:a", CorridorKind::Forest, open.clone()))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:river:b", CorridorKind::River, open.clone()))
+            .unwrap();
+        let mut sacred = corridor("eco:forest:c", CorridorKind::Forest, open);
+        sacred
+            .cultural_sites
+            .push(CulturalSite::new("Spring", SiteAccess::Public, None).unwrap());
+        map.upsert_corridor(sacred).unwrap();
+        map.upsert_corridor(corridor(
+            "eco:river:d",
+            CorridorKind::River,
+            NeurorightsConstraints::strict_non_actuating(),
+        ))
+        .unwrap();
+
+        let below = map.neurorights_below_floor(&floors);
+        let ids: Vec<_> = below.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["eco:forest:c", "eco:river:b"]);
+        assert!(below[0]
+            .1
+            .contains(&NeurorightsWeakening::EmotionRecognitionAllowed));
+        assert_eq!(below[1].1, vec![NeurorightsWeakening::ActuationAllowed]);
+    }
+}
@@ -0,0 +1,649 @@
+//! GeoJSON interchange for corridor maps.
+//! Imported records never carry consent they did not state; exports are
+//! read-only views for dashboards.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+use crate::corridor::{
+    Boundary, CorridorDescriptor, CorridorId, CorridorKind, LinearRing, PhysicalAttributes, Point,
+    Polygon, Polyline,
+};
+use crate::governance::{CommunityId, ConsentQuorum, FpicStatus, IdsScope};
+use crate::lifecycle::Lifecycle;
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+use crate::neurorights::NeurorightsConstraints;
+use crate::relationships::{Relationship, RelationshipKind};
+use crate::taxonomy::CustomKind;
+use crate::units::{ElevationRange, Hectares, Kilometers, Meters};
+
+/// Problem with a single Feature, identified by its position in the
+/// collection and, when readable, its corridor id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureError {
+    pub index: usize,
+    pub id: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for FeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "feature {} ('{}'): {}", self.index, id, self.message),
+            None => write!(f, "feature {}: {}", self.index, self.message),
+        }
+    }
+}
+
+/// Errors raised by `IndigenousEcoCorridorMap::from_geojson`.
+#[derive(Debug)]
+pub enum GeoJsonError {
+    /// The input is not valid JSON.
+    Parse(String),
+    /// The top-level object is not a FeatureCollection.
+    NotFeatureCollection,
+    /// One or more features could not be mapped; nothing was imported.
+    Features(Vec<FeatureError>),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::Parse(e) => write!(f, "invalid GeoJSON: {}", e),
+            GeoJsonError::NotFeatureCollection => {
+                write!(f, "GeoJSON root must be a FeatureCollection")
+            }
+            GeoJsonError::Features(errors) => {
+                write!(f, "{} feature(s) rejected", errors.len())?;
+                for e in errors {
+                    write!(f, "; {}", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl IndigenousEcoCorridorMap {
+    /// Build a map from a GeoJSON FeatureCollection.
+    ///
+    /// Each Feature's `properties` must provide `id`, `kind`, `name`, and
+    /// one score per `MetricComponent` keyed by `MetricComponent::key()`;
+    /// `description` is optional, as are the physical attributes `area_ha`,
+    /// `length_km`, and `elevation_min_m`/`elevation_max_m` (as a pair),
+    /// and `relationships`, an array of `{kind, target}` objects whose
+    /// targets must be features of the same collection.
+    /// A `LineString`, `MultiLineString`,
+    /// `Polygon`, or `MultiPolygon` geometry becomes the corridor boundary
+    /// after validation (closed, simple rings; see `LinearRing`); a null
+    /// geometry leaves it unset.
+    /// Imported corridors start as `Lifecycle::Draft` with
+    /// `FpicStatus::Pending`, no IDS framework, and strict non-actuating
+    /// neurorights: GeoJSON never carries ratification or consent.
+    ///
+    /// Import is all-or-nothing; every failing feature is reported.
+    pub fn from_geojson<R: Read>(reader: R) -> Result<Self, GeoJsonError> {
+        let root: Value =
+            serde_json::from_reader(reader).map_err(|e| GeoJsonError::Parse(e.to_string()))?;
+        if root.get("type").and_then(Value::as_str) != Some("FeatureCollection") {
+            return Err(GeoJsonError::NotFeatureCollection);
+        }
+        let features = root
+            .get("features")
+            .and_then(Value::as_array)
+            .ok_or(GeoJsonError::NotFeatureCollection)?;
+
+        let mut map = IndigenousEcoCorridorMap::new();
+        let mut errors = Vec::new();
+        let mut indices = BTreeMap::new();
+        for (index, feature) in features.iter().enumerate() {
+            match corridor_from_feature(feature) {
+                Ok(corridor) => {
+                    let duplicate = if map.get(corridor.id()).is_some() {
+                        Some("duplicate corridor id")
+                    } else if corridor
+                        .descriptor
+                        .uuid
+                        .is_some_and(|u| map.get_by_uuid(&u).is_some())
+                    {
+                        Some("duplicate corridor uuid")
+                    } else {
+                        None
+                    };
+                    if let Some(message) = duplicate {
+                        errors.push(FeatureError {
+                            index,
+                            id: Some(corridor.id().to_string()),
+                            message: message.into(),
+                        });
+                    } else {
+                        indices.insert(corridor.id().clone(), index);
+                        map.upsert_corridor(corridor).expect("uuid checked above");
+                    }
+                }
+                Err((id, message)) => errors.push(FeatureError { index, id, message }),
+            }
+        }
+        // Only meaningful once every well-formed feature is in.
+        if errors.is_empty() {
+            errors.extend(
+                map.dangling_relationships()
+                    .into_iter()
+                    .map(|d| FeatureError {
+                        index: indices[&d.source],
+                        id: Some(d.source.to_string()),
+                        message: format!("relationship {} unknown corridor '{}'", d.kind, d.target),
+                    }),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(map)
+        } else {
+            Err(GeoJsonError::Features(errors))
+        }
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Export the map as a GeoJSON FeatureCollection for QGIS/Leaflet.
+    ///
+    /// Properties use the same keys `from_geojson` reads, plus
+    /// `aggregate_score`, the FPIC status (and grant details when
+    /// granted), and the neurorights flags. Output re-imports cleanly,
+    /// though consent and neurorights are reset on import by design.
+    pub fn to_geojson(&self) -> Value {
+        let features: Vec<Value> = self.iter().map(|(_, c)| feature_from_corridor(c)).collect();
+        json!({
+            "type": "FeatureCollection",
+            "features": features,
+        })
+    }
+}
+
+fn feature_from_corridor(corridor: &IndigenousEcoCorridor) -> Value {
+    let mut props = Map::new();
+    props.insert("id".into(), corridor.id().as_str().into());
+    if let Some(uuid) = corridor.descriptor.uuid {
+        props.insert("uuid".into(), uuid.to_string().into());
+    }
+    props.insert("kind".into(), corridor.descriptor.kind.key().into());
+    if let CorridorKind::Custom(custom) = &corridor.descriptor.kind {
+        props.insert("kind_label".into(), custom.label().into());
+    }
+    props.insert("name".into(), corridor.descriptor.name.clone().into());
+    props.insert(
+        "description".into(),
+        corridor.descriptor.description.clone().into(),
+    );
+    let physical = &corridor.descriptor.physical;
+    if let Some(area) = physical.area {
+        props.insert("area_ha".into(), area.get().into());
+    }
+    if let Some(length) = physical.length {
+        props.insert("length_km".into(), length.get().into());
+    }
+    if let Some(elevation) = physical.elevation {
+        props.insert("elevation_min_m".into(), elevation.min().get().into());
+        props.insert("elevation_max_m".into(), elevation.max().get().into());
+    }
+    if !corridor.descriptor.relationships.is_empty() {
+        let relationships: Vec<Value> = corridor
+            .descriptor
+            .relationships
+            .iter()
+            .map(|r| json!({ "kind": r.kind.key(), "target": r.target.as_str() }))
+            .collect();
+        props.insert("relationships".into(), relationships.into());
+    }
+    for component in MetricComponent::ALL {
+        let value = corridor.environmental.component(component).get();
+        props.insert(component.key().into(), f64::from(value).into());
+    }
+    props.insert(
+        "aggregate_score".into(),
+        f64::from(corridor.environmental.aggregate_score().get()).into(),
+    );
+
+    props.insert("lifecycle".into(), corridor.lifecycle.key().into());
+    props.insert("fpic_status".into(), corridor.fpic.kind().key().into());
+    match &corridor.fpic {
+        FpicStatus::Pending | FpicStatus::Expired { .. } => {}
+        FpicStatus::Consulting { quorum, .. } => insert_quorum(&mut props, quorum),
+        FpicStatus::Granted {
+            quorum,
+            terms_reference,
+            purposes,
+            ..
+        } => {
+            insert_quorum(&mut props, quorum);
+            props.insert(
+                "fpic_terms_reference".into(),
+                terms_reference.clone().into(),
+            );
+            let purposes: Vec<Value> = purposes.iter().map(|p| p.key().into()).collect();
+            props.insert("fpic_purposes".into(), purposes.into());
+        }
+        FpicStatus::Suspended { reason, .. } => {
+            props.insert("fpic_suspended_reason".into(), reason.clone().into());
+        }
+        FpicStatus::Revoked { reason, .. } => {
+            props.insert("fpic_revoked_reason".into(), reason.clone().into());
+        }
+        FpicStatus::Withheld { reason, .. } => {
+            props.insert("fpic_withheld_reason".into(), reason.clone().into());
+        }
+    }
+
+    let nr = &corridor.neurorights;
+    props.insert(
+        "non_actuating_required".into(),
+        nr.actuation.non_actuating_required.into(),
+    );
+    props.insert(
+        "no_coercive_or_hidden_channels".into(),
+        nr.inference.no_coercive_or_hidden_channels.into(),
+    );
+    props.insert(
+        "allowed_inferences".into(),
+        nr.inference
+            .allowed
+            .iter()
+            .map(|c| c.key())
+            .collect::<Vec<_>>()
+            .into(),
+    );
+    props.insert(
+        "discipline_signals_voluntary_only".into(),
+        nr.discipline_signals.voluntary_only.into(),
+    );
+    props.insert(
+        "consent_ref_required".into(),
+        nr.discipline_signals.consent_ref_required.into(),
+    );
+    props.insert(
+        "no_biometric_categorization".into(),
+        nr.biometrics.no_biometric_categorization.into(),
+    );
+    props.insert(
+        "no_emotion_recognition".into(),
+        nr.biometrics.no_emotion_recognition.into(),
+    );
+    props.insert(
+        "retention_limit_secs".into(),
+        nr.retention.limit.map(|l| l.as_secs()).into(),
+    );
+    props.insert("envelope_ref".into(), nr.envelope_ref.clone().into());
+
+    let geometry = match &corridor.descriptor.boundary {
+        None => Value::Null,
+        Some(Boundary::Polyline(l)) => {
+            json!({ "type": "LineString", "coordinates": l.points() })
+        }
+        Some(Boundary::MultiPolyline(ls)) => json!({
+            "type": "MultiLineString",
+            "coordinates": ls.iter().map(Polyline::points).collect::<Vec<_>>(),
+        }),
+        Some(Boundary::Polygon(p)) => {
+            json!({ "type": "Polygon", "coordinates": polygon_coordinates(p) })
+        }
+        Some(Boundary::MultiPolygon(ps)) => json!({
+            "type": "MultiPolygon",
+            "coordinates": ps.iter().map(polygon_coordinates).collect::<Vec<_>>(),
+        }),
+    };
+    json!({
+        "type": "Feature",
+        "geometry": geometry,
+        "properties": props,
+    })
+}
+
+/// `fpic_communities` are the required communities, `fpic_consented`
+/// those that consented, and `fpic_quorum` the rule.
+fn insert_quorum(props: &mut Map<String, Value>, quorum: &ConsentQuorum) {
+    let ids = |it: &mut dyn Iterator<Item = &CommunityId>| -> Value {
+        it.map(|c| Value::from(c.0.clone()))
+            .collect::<Vec<_>>()
+            .into()
+    };
+    props.insert("fpic_communities".into(), ids(&mut quorum.required()));
+    props.insert(
+        "fpic_consented".into(),
+        ids(&mut quorum.consents().map(|(c, _)| c)),
+    );
+    props.insert("fpic_quorum".into(), quorum.rule().to_string().into());
+}
+
+fn polygon_coordinates(polygon: &Polygon) -> Vec<&[Point]> {
+    polygon.rings().map(LinearRing::points).collect()
+}
+
+fn boundary_from_geometry(geometry: Option<&Value>) -> Result<Option<Boundary>, String> {
+    let geometry = match geometry {
+        None | Some(Value::Null) => return Ok(None),
+        Some(g) => g,
+    };
+    let coordinates = geometry
+        .get("coordinates")
+        .ok_or("geometry has no coordinates")?;
+    let parts = |what: &str| {
+        coordinates
+            .as_array()
+            .ok_or_else(|| format!("{} coordinates must be an array", what))
+    };
+    let boundary = match geometry.get("type").and_then(Value::as_str) {
+        Some("LineString") => Boundary::Polyline(polyline_from(coordinates)?),
+        Some("MultiLineString") => Boundary::MultiPolyline(
+            parts("MultiLineString")?
+                .iter()
+                .map(polyline_from)
+                .collect::<Result<_, _>>()?,
+        ),
+        Some("Polygon") => Boundary::Polygon(polygon_from(coordinates)?),
+        Some("MultiPolygon") => Boundary::MultiPolygon(
+            parts("MultiPolygon")?
+                .iter()
+                .map(polygon_from)
+                .collect::<Result<_, _>>()?,
+        ),
+        other => {
+            return Err(format!(
+                "unsupported geometry type {}",
+                other.unwrap_or("(missing)")
+            ))
+        }
+    };
+    boundary.validate().map_err(|e| e.to_string())?;
+    Ok(Some(boundary))
+}
+
+fn polyline_from(value: &Value) -> Result<Polyline, String> {
+    Polyline::new(positions_from(value)?).map_err(|e| format!("line: {}", e))
+}
+
+fn polygon_from(value: &Value) -> Result<Polygon, String> {
+    let mut rings = value
+        .as_array()
+        .ok_or("polygon coordinates must be an array of rings")?
+        .iter()
+        .map(|ring| LinearRing::new(positions_from(ring)?).map_err(|e| format!("ring: {}", e)));
+    let exterior = rings.next().ok_or("polygon has no exterior ring")??;
+    let holes = rings.collect::<Result<_, _>>()?;
+    Polygon::new(exterior, holes).map_err(|e| format!("polygon: {}", e))
+}
+
+fn positions_from(value: &Value) -> Result<Vec<Point>, String> {
+    value
+        .as_array()
+        .ok_or("expected an array of positions")?
+        .iter()
+        .map(|position| match position.as_array().map(Vec::as_slice) {
+            // Extra ordinates (altitude) are dropped.
+            Some([x, y, ..]) => match (x.as_f64(), y.as_f64()) {
+                (Some(x), Some(y)) => Ok([x, y]),
+                _ => Err("position ordinates must be numbers".to_string()),
+            },
+            _ => Err("position must have at least two ordinates".to_string()),
+        })
+        .collect()
+}
+
+fn corridor_from_feature(
+    feature: &Value,
+) -> Result<IndigenousEcoCorridor, (Option<String>, String)> {
+    if feature.get("type").and_then(Value::as_str) != Some("Feature") {
+        return Err((None, "not a GeoJSON Feature".into()));
+    }
+    let props = feature
+        .get("properties")
+        .and_then(Value::as_object)
+        .ok_or((None, "missing properties object".to_string()))?;
+
+    let raw_id = string_prop(props, "id").map_err(|e| (None, e))?;
+    let id = CorridorId::new(&raw_id).map_err(|e| (Some(raw_id.clone()), e))?;
+    let fail = |message: String| (Some(raw_id.clone()), message);
+
+    let uuid = match props.get("uuid") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(s)) => {
+            Some(Uuid::parse_str(s).map_err(|e| fail(format!("property 'uuid': {}", e)))?)
+        }
+        Some(_) => return Err(fail("property 'uuid' must be a string".into())),
+    };
+    let mut kind = CorridorKind::from_key(&string_prop(props, "kind").map_err(fail)?)
+        .map_err(|e| fail(format!("property 'kind': {}", e)))?;
+    if let (CorridorKind::Custom(custom), Some(label)) = (&kind, props.get("kind_label")) {
+        let label = label
+            .as_str()
+            .ok_or_else(|| fail("property 'kind_label' must be a string".into()))?;
+        kind = CorridorKind::Custom(
+            CustomKind::new(custom.namespace(), custom.code(), label).map_err(fail)?,
+        );
+    }
+    let name = string_prop(props, "name").map_err(fail)?;
+    let description = match props.get("description") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(_) => return Err(fail("property 'description' must be a string".into())),
+    };
+
+    let boundary = boundary_from_geometry(feature.get("geometry")).map_err(fail)?;
+    let physical = physical_from(props).map_err(fail)?;
+    let relationships = relationships_from(props).map_err(fail)?;
+
+    let mut values = Vec::with_capacity(MetricComponent::COUNT);
+    for component in MetricComponent::ALL {
+        let key = component.key();
+        let raw = props
+            .get(key)
+            .and_then(Value::as_f64)
+            .ok_or_else(|| fail(format!("missing numeric property '{}'", key)))?;
+        let score = Score::new(raw as f32).map_err(|e| fail(format!("'{}': {}", key, e)))?;
+        values.push(score);
+    }
+    let values: [Score; MetricComponent::COUNT] = values
+        .try_into()
+        .map_err(|_| fail("metric component count mismatch".into()))?;
+
+    let descriptor = CorridorDescriptor {
+        id,
+        uuid,
+        kind,
+        name,
+        description,
+        boundary,
+        physical,
+        relationships,
+    };
+    descriptor.validate().map_err(|e| fail(e.to_string()))?;
+
+    Ok(IndigenousEcoCorridor {
+        descriptor,
+        environmental: EnvironmentalMetrics::from_components(values),
+        fpic: FpicStatus::Pending,
+        fpic_trail: Vec::new(),
+        revocations: Vec::new(),
+        ids_scope: IdsScope {
+            contains_indigenous_data: true,
+            frameworks: Vec::new(),
+            governance_ref: None,
+            embargo: None,
+        },
+        neurorights: NeurorightsConstraints::strict_non_actuating(),
+        cultural_notes: None,
+        cultural_sites: Vec::new(),
+        lifecycle: Lifecycle::Draft,
+        seasons: Vec::new(),
+        signature: None,
+    })
+}
+
+fn physical_from(props: &Map<String, Value>) -> Result<PhysicalAttributes, String> {
+    let number = |key: &str| match props.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| format!("property '{}' must be a number", key)),
+    };
+    fn with_key(key: &'static str) -> impl Fn(String) -> String {
+        move |e| format!("property '{}': {}", key, e)
+    }
+    let elevation = match (number("elevation_min_m")?, number("elevation_max_m")?) {
+        (None, None) => None,
+        (Some(min), Some(max)) => Some(
+            ElevationRange::new(Meters::new(min)?, Meters::new(max)?)
+                .map_err(with_key("elevation_min_m"))?,
+        ),
+        _ => return Err("elevation_min_m and elevation_max_m must be given together".into()),
+    };
+    Ok(PhysicalAttributes {
+        area: number("area_ha")?
+            .map(Hectares::new)
+            .transpose()
+            .map_err(with_key("area_ha"))?,
+        length: number("length_km")?
+            .map(Kilometers::new)
+            .transpose()
+            .map_err(with_key("length_km"))?,
+        elevation,
+    })
+}
+
+fn relationships_from(props: &Map<String, Value>) -> Result<Vec<Relationship>, String> {
+    let entries = match props.get("relationships") {
+        None | Some(Value::Null) => return Ok(Vec::new()),
+        Some(v) => v
+            .as_array()
+            .ok_or("property 'relationships' must be an array")?,
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let field = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| format!("relationship needs a string '{}'", key))
+            };
+            let kind = field("kind")?;
+            Ok(Relationship {
+                kind: RelationshipKind::from_key(kind)
+                    .ok_or_else(|| format!("unknown relationship kind '{}'", kind))?,
+                target: CorridorId::new(field("target")?)?,
+            })
+        })
+        .collect()
+}
+
+fn string_prop(props: &Map<String, Value>, key: &str) -> Result<String, String> {
+    props
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| format!("missing string property '{}'", key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feature(id: &str, water_quality: f64) -> Value {
+        let mut props = Map::new();
+        props.insert("id".into(), id.into());
+        props.insert("kind".into(), "river".into());
+        props.insert("name".into(), "River Y".into());
+        for component in MetricComponent::ALL {
+            props.insert(component.key().into(), 0.8.into());
+        }
+        props.insert("water_quality".into(), water_quality.into());
+        json!({ "type": "Feature", "geometry": null, "properties": props })
+    }
+
+    #[test]
+    fn export_reimports_to_same_corridors() {
+        let mut bounded = feature("eco:river:z", 0.3);
+        bounded["geometry"] = json!({
+            "type": "Polygon",
+            "coordinates": [[[0.0, 0.0, 12.0], [4.0, 0.0], [4.0, 3.0], [0.0, 0.0]]],
+        });
+        bounded["properties"]["area_ha"] = json!(42.5);
+        bounded["properties"]["elevation_min_m"] = json!(-3.0);
+        bounded["properties"]["elevation_max_m"] = json!(12.0);
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [feature("eco:river:y", 0.6), bounded],
+        });
+        let map =
+            IndigenousEcoCorridorMap::from_geojson(collection.to_string().as_bytes()).unwrap();
+        let exported = map.to_geojson();
+        let reimported =
+            IndigenousEcoCorridorMap::from_geojson(exported.to_string().as_bytes()).unwrap();
+        assert_eq!(reimported.to_geojson(), exported);
+        assert_eq!(
+            exported["features"][0]["properties"]["fpic_status"],
+            "pending"
+        );
+        assert_eq!(
+            exported["features"][1]["geometry"]["coordinates"][0][0],
+            json!([0.0, 0.0])
+        );
+        let physical = &map
+            .get(&"eco:river:z".parse().unwrap())
+            .unwrap()
+            .descriptor
+            .physical;
+        assert_eq!(physical.area.map(Hectares::get), Some(42.5));
+        assert_eq!(physical.elevation.map(|e| e.relief().get()), Some(15.0));
+        assert!(physical.length.is_none());
+    }
+
+    #[test]
+    fn import_reports_every_bad_feature() {
+        let good = json!({
+            "type": "FeatureCollection",
+            "features": [feature("eco:river:y", 0.6)],
+        });
+        let map = IndigenousEcoCorridorMap::from_geojson(good.to_string().as_bytes()).unwrap();
+        let corridor = map.get(&CorridorId::new("eco:river:y").unwrap()).unwrap();
+        assert_eq!(corridor.descriptor.kind, CorridorKind::River);
+        assert!(!corridor.has_active_fpic(std::time::SystemTime::now()));
+
+        let bad = json!({
+            "type": "FeatureCollection",
+            "features": [feature("eco:river:y", 1.4), feature("  ", 0.5), feature("eco:river:z", 0.5)],
+        });
+        match IndigenousEcoCorridorMap::from_geojson(bad.to_string().as_bytes()) {
+            Err(GeoJsonError::Features(errors)) => {
+                let indices: Vec<usize> = errors.iter().map(|e| e.index).collect();
+                assert_eq!(indices, vec![0, 1]);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+
+        let mut linked = feature("eco:river:z", 0.5);
+        linked["properties"]["relationships"] =
+            json!([{ "kind": "tributary_of", "target": "eco:river:gone" }]);
+        let dangling = json!({
+            "type": "FeatureCollection",
+            "features": [feature("eco:river:y", 0.5), linked],
+        });
+        match IndigenousEcoCorridorMap::from_geojson(dangling.to_string().as_bytes()) {
+            Err(GeoJsonError::Features(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].index, 1);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
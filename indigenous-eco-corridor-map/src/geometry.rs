@@ -0,0 +1,390 @@
+//! Spatial conflict checks between corridor boundaries.
+//! Non-actuating; flags overlapping claims and buffer intrusions for
+//! governance review.
+//!
+//! Coordinates are treated as planar. Buffer distances are in the same
+//! units as the coordinates, so use a projected CRS for metric buffers.
+
+#![forbid(unsafe_code)]
+
+use serde::Serialize;
+
+use crate::corridor::{Boundary, CorridorId, Point, Polygon, Polyline};
+use crate::map::IndigenousEcoCorridorMap;
+
+/// Two corridors whose interiors intersect. `a < b`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Overlap {
+    pub a: CorridorId,
+    pub b: CorridorId,
+}
+
+/// Two non-overlapping corridors closer than the requested buffer
+/// distance. `separation` is the shortest distance between their
+/// boundaries (0 when they touch). `a < b`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BufferConflict {
+    pub a: CorridorId,
+    pub b: CorridorId,
+    pub separation: f64,
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Corridor pairs whose boundaries overlap. Corridors that merely
+    /// share an edge are not reported here (see `buffer_conflicts`).
+    /// Corridors without a boundary are skipped.
+    pub fn detect_overlaps(&self) -> Vec<Overlap> {
+        let mut overlaps = Vec::new();
+        self.for_each_bounded_pair(|a, ab, b, bb| {
+            if boundaries_overlap(ab, bb) {
+                overlaps.push(Overlap {
+                    a: a.clone(),
+                    b: b.clone(),
+                });
+            }
+        });
+        overlaps
+    }
+
+    /// Non-overlapping corridor pairs whose boundaries come within
+    /// `distance` of each other, i.e. where one corridor intrudes on a
+    /// buffer zone of width `distance` around the other.
+    pub fn buffer_conflicts(&self, distance: f64) -> Vec<BufferConflict> {
+        let mut conflicts = Vec::new();
+        self.for_each_bounded_pair(|a, ab, b, bb| {
+            if !expanded(bbox(ab), distance).intersects(&bbox(bb)) || boundaries_overlap(ab, bb) {
+                return;
+            }
+            let separation = boundary_distance(ab, bb);
+            if separation <= distance {
+                conflicts.push(BufferConflict {
+                    a: a.clone(),
+                    b: b.clone(),
+                    separation,
+                });
+            }
+        });
+        conflicts
+    }
+
+    fn for_each_bounded_pair(
+        &self,
+        mut f: impl FnMut(&CorridorId, &Boundary, &CorridorId, &Boundary),
+    ) {
+        let bounded: Vec<(&CorridorId, &Boundary)> = self
+            .iter()
+            .filter_map(|(id, c)| c.descriptor.boundary.as_ref().map(|b| (id, b)))
+            .collect();
+        for (i, (a, ab)) in bounded.iter().enumerate() {
+            for (b, bb) in &bounded[i + 1..] {
+                f(a, ab, b, bb);
+            }
+        }
+    }
+}
+
+/// True if the interiors of `a` and `b` intersect: areas overlap, a
+/// line runs through an area, or two lines cross. Shared edges and
+/// touching endpoints do not count.
+pub fn boundaries_overlap(a: &Boundary, b: &Boundary) -> bool {
+    if !bbox(a).intersects(&bbox(b)) {
+        return false;
+    }
+    let area_area = a
+        .polygons()
+        .iter()
+        .any(|pa| b.polygons().iter().any(|pb| polygons_overlap(pa, pb)));
+    let line_area = |lines: &[Polyline], areas: &[Polygon]| {
+        lines
+            .iter()
+            .any(|l| areas.iter().any(|p| line_enters(l, p)))
+    };
+    let line_line = a.polylines().iter().any(|la| {
+        b.polylines()
+            .iter()
+            .any(|lb| any_crossing(line_edges(la), || line_edges(lb)))
+    });
+    area_area
+        || line_area(a.polylines(), b.polygons())
+        || line_area(b.polylines(), a.polygons())
+        || line_line
+}
+
+/// Shortest distance between the outlines of `a` and `b`; 0 if they
+/// touch or cross.
+pub fn boundary_distance(a: &Boundary, b: &Boundary) -> f64 {
+    let mut best = f64::INFINITY;
+    for (p1, p2) in boundary_edges(a) {
+        for (q1, q2) in boundary_edges(b) {
+            best = best.min(segment_distance(p1, p2, q1, q2));
+        }
+    }
+    best
+}
+
+/// Interiors intersect if edges cross properly, or if a probe point of
+/// one polygon (vertex, edge midpoint, or centroid) lies strictly inside
+/// the other.
+fn polygons_overlap(a: &Polygon, b: &Polygon) -> bool {
+    any_crossing(polygon_edges(a), || polygon_edges(b))
+        || probes(a).any(|p| strictly_inside(p, b))
+        || probes(b).any(|p| strictly_inside(p, a))
+}
+
+/// A line enters an area if it crosses an edge or has a vertex or edge
+/// midpoint strictly inside.
+fn line_enters(line: &Polyline, polygon: &Polygon) -> bool {
+    any_crossing(line_edges(line), || polygon_edges(polygon))
+        || line_edges(line)
+            .flat_map(|(p, q)| [p, midpoint(p, q), q])
+            .any(|p| strictly_inside(p, polygon))
+}
+
+fn any_crossing<A, B>(a: A, b: impl Fn() -> B) -> bool
+where
+    A: Iterator<Item = (Point, Point)>,
+    B: Iterator<Item = (Point, Point)>,
+{
+    let mut a = a;
+    a.any(|(p1, p2)| b().any(|(q1, q2)| segments_cross(p1, p2, q1, q2)))
+}
+
+fn probes(polygon: &Polygon) -> impl Iterator<Item = Point> + '_ {
+    polygon_edges(polygon)
+        .flat_map(|(p, q)| [p, midpoint(p, q)])
+        .chain(centroid(polygon.exterior().points()))
+}
+
+fn strictly_inside(p: Point, polygon: &Polygon) -> bool {
+    if polygon_edges(polygon).any(|(a, b)| on_segment(p, a, b)) {
+        return false;
+    }
+    ring_contains(polygon.exterior().points(), p)
+        && !polygon.holes().iter().any(|h| ring_contains(h.points(), p))
+}
+
+/// Even-odd ray cast over a closed ring; points on the ring are
+/// classified arbitrarily, so callers test `on_segment` first.
+pub(crate) fn ring_contains(ring: &[Point], p: Point) -> bool {
+    let mut inside = false;
+    for w in ring.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        if (a[1] > p[1]) != (b[1] > p[1]) {
+            let x = a[0] + (p[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+            if p[0] < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn centroid(ring: &[Point]) -> Option<Point> {
+    let mut area = 0.0;
+    let (mut cx, mut cy) = (0.0, 0.0);
+    for w in ring.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let cross = a[0] * b[1] - b[0] * a[1];
+        area += cross;
+        cx += (a[0] + b[0]) * cross;
+        cy += (a[1] + b[1]) * cross;
+    }
+    (area != 0.0).then(|| [cx / (3.0 * area), cy / (3.0 * area)])
+}
+
+fn edges(points: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    points.windows(2).map(|w| (w[0], w[1]))
+}
+
+fn line_edges(line: &Polyline) -> impl Iterator<Item = (Point, Point)> + '_ {
+    edges(line.points())
+}
+
+fn polygon_edges(polygon: &Polygon) -> impl Iterator<Item = (Point, Point)> + '_ {
+    polygon.rings().flat_map(|ring| edges(ring.points()))
+}
+
+fn boundary_edges(boundary: &Boundary) -> impl Iterator<Item = (Point, Point)> + '_ {
+    boundary
+        .polygons()
+        .iter()
+        .flat_map(polygon_edges)
+        .chain(boundary.polylines().iter().flat_map(line_edges))
+}
+
+fn orientation(p: Point, q: Point, r: Point) -> f64 {
+    (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+}
+
+pub(crate) fn on_segment(p: Point, a: Point, b: Point) -> bool {
+    orientation(a, b, p) == 0.0
+        && p[0] >= a[0].min(b[0])
+        && p[0] <= a[0].max(b[0])
+        && p[1] >= a[1].min(b[1])
+        && p[1] <= a[1].max(b[1])
+}
+
+/// Segments cross at a single interior point of both.
+fn segments_cross(p1: Point, p2: Point, q1: Point, q2: Point) -> bool {
+    let d1 = orientation(p1, p2, q1);
+    let d2 = orientation(p1, p2, q2);
+    let d3 = orientation(q1, q2, p1);
+    let d4 = orientation(q1, q2, p2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+/// Segments share at least one point.
+pub(crate) fn segments_intersect(p1: Point, p2: Point, q1: Point, q2: Point) -> bool {
+    segments_cross(p1, p2, q1, q2)
+        || on_segment(q1, p1, p2)
+        || on_segment(q2, p1, p2)
+        || on_segment(p1, q1, q2)
+        || on_segment(p2, q1, q2)
+}
+
+fn segment_distance(p1: Point, p2: Point, q1: Point, q2: Point) -> f64 {
+    if segments_intersect(p1, p2, q1, q2) {
+        return 0.0;
+    }
+    point_segment_distance(p1, q1, q2)
+        .min(point_segment_distance(p2, q1, q2))
+        .min(point_segment_distance(q1, p1, p2))
+        .min(point_segment_distance(q2, p1, p2))
+}
+
+fn point_segment_distance(p: Point, a: Point, b: Point) -> f64 {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / len2).clamp(0.0, 1.0)
+    };
+    let (x, y) = (a[0] + t * dx, a[1] + t * dy);
+    ((p[0] - x).powi(2) + (p[1] - y).powi(2)).sqrt()
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0]
+}
+
+#[derive(Clone, Copy)]
+struct BBox {
+    min: Point,
+    max: Point,
+}
+
+impl BBox {
+    fn intersects(&self, other: &BBox) -> bool {
+        self.min[0] <= other.max[0]
+            && other.min[0] <= self.max[0]
+            && self.min[1] <= other.max[1]
+            && other.min[1] <= self.max[1]
+    }
+}
+
+fn bbox(boundary: &Boundary) -> BBox {
+    let mut bb = BBox {
+        min: [f64::INFINITY; 2],
+        max: [f64::NEG_INFINITY; 2],
+    };
+    let outlines = boundary
+        .polygons()
+        .iter()
+        .map(|p| p.exterior().points())
+        .chain(boundary.polylines().iter().map(Polyline::points));
+    for p in outlines.flatten() {
+        bb.min = [bb.min[0].min(p[0]), bb.min[1].min(p[1])];
+        bb.max = [bb.max[0].max(p[0]), bb.max[1].max(p[1])];
+    }
+    bb
+}
+
+fn expanded(bb: BBox, by: f64) -> BBox {
+    BBox {
+        min: [bb.min[0] - by, bb.min[1] - by],
+        max: [bb.max[0] + by, bb.max[1] + by],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corridor::LinearRing;
+    use crate::map::IndigenousEcoCorridor;
+    use crate::test_support;
+
+    fn square(id: &str, x: f64, y: f64, size: f64) -> IndigenousEcoCorridor {
+        let exterior = vec![
+            [x, y],
+            [x + size, y],
+            [x + size, y + size],
+            [x, y + size],
+            [x, y],
+        ];
+        let mut corridor = test_support::corridor(id);
+        corridor.descriptor.boundary = Some(Boundary::Polygon(
+            Polygon::new(LinearRing::new(exterior).unwrap(), Vec::new()).unwrap(),
+        ));
+        corridor
+    }
+
+    #[test]
+    fn overlaps_and_buffer_conflicts_are_reported_separately() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(square("eco:f:a", 0.0, 0.0, 10.0))
+            .unwrap();
+        map.upsert_corridor(square("eco:f:b", 5.0, 5.0, 10.0))
+            .unwrap(); // overlaps a
+        map.upsert_corridor(square("eco:f:c", 10.0, -10.0, 10.0))
+            .unwrap(); // touches a's corner
+        map.upsert_corridor(square("eco:f:d", 0.0, 30.0, 10.0))
+            .unwrap(); // 15 above b
+        map.upsert_corridor(square("eco:f:e", 2.0, 2.0, 2.0))
+            .unwrap(); // inside a
+
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let overlaps: Vec<(CorridorId, CorridorId)> = map
+            .detect_overlaps()
+            .into_iter()
+            .map(|o| (o.a, o.b))
+            .collect();
+        assert_eq!(
+            overlaps,
+            [
+                (id("eco:f:a"), id("eco:f:b")),
+                (id("eco:f:a"), id("eco:f:e"))
+            ]
+        );
+
+        let near: Vec<(CorridorId, CorridorId, f64)> = map
+            .buffer_conflicts(15.0)
+            .into_iter()
+            .map(|c| (c.a, c.b, c.separation))
+            .collect();
+        assert!(near.contains(&(id("eco:f:a"), id("eco:f:c"), 0.0)));
+        assert!(near.contains(&(id("eco:f:b"), id("eco:f:d"), 15.0)));
+        assert!(!near
+            .iter()
+            .any(|(a, b, _)| *a == id("eco:f:a") && *b == id("eco:f:b")));
+        assert!(map
+            .buffer_conflicts(1.0)
+            .iter()
+            .all(|c| c.separation <= 1.0));
+
+        // A river running through d overlaps it; one ending on its edge does not.
+        let line = |pts: &[Point]| Boundary::Polyline(Polyline::new(pts.to_vec()).unwrap());
+        let d = square("eco:f:d", 0.0, 30.0, 10.0)
+            .descriptor
+            .boundary
+            .unwrap();
+        assert!(boundaries_overlap(&line(&[[-5.0, 35.0], [15.0, 35.0]]), &d));
+        assert!(!boundaries_overlap(&line(&[[-5.0, 35.0], [0.0, 35.0]]), &d));
+        assert_eq!(
+            boundary_distance(&line(&[[-5.0, 35.0], [-2.0, 35.0]]), &d),
+            2.0
+        );
+    }
+}
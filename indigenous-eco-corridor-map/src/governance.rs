@@ -3,43 +3,871 @@
 
 #![forbid(unsafe_code)]
 
-use std::time::SystemTime;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::time::{Duration, SystemTime};
 
-/// Minimal community identifier for ALN / DID / IDS ledgers.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct CommunityId(pub String);
+use serde::{Deserialize, Serialize};
+
+pub use corridor_types::{CommunityId, FpicPurpose};
+
+use crate::delegation::{Delegation, DelegationError};
+use crate::embargo::{Embargo, EmbargoCategory};
+use crate::terms::{BoundTerms, TermsError};
+
+/// How many of a quorum's required communities must consent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum QuorumRule {
+    /// Every required community.
+    Unanimous,
+    /// At least `k` of the required communities (k-of-n).
+    AtLeast(usize),
+}
+
+impl fmt::Display for QuorumRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuorumRule::Unanimous => write!(f, "unanimous"),
+            QuorumRule::AtLeast(k) => write!(f, "at least {}", k),
+        }
+    }
+}
+
+/// One community's recorded consent.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Consent {
+    pub at: SystemTime,
+    /// Hash/URI of the community's decision record.
+    pub reference: String,
+    /// Authority the consent was signed under, when a delegate rather
+    /// than the community itself signed it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegation: Option<Delegation>,
+}
+
+/// Consent of several communities over a shared corridor: who must be
+/// asked, who has consented, and how many consents suffice.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawConsentQuorum")]
+pub struct ConsentQuorum {
+    required: BTreeSet<CommunityId>,
+    rule: QuorumRule,
+    consents: BTreeMap<CommunityId, Consent>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawConsentQuorum {
+    required: BTreeSet<CommunityId>,
+    rule: QuorumRule,
+    #[serde(default)]
+    consents: BTreeMap<CommunityId, Consent>,
+}
+
+impl TryFrom<RawConsentQuorum> for ConsentQuorum {
+    type Error = String;
+
+    fn try_from(raw: RawConsentQuorum) -> Result<Self, String> {
+        let mut quorum = Self::new(raw.required, raw.rule)?;
+        for (community, consent) in raw.consents {
+            quorum.record_consent(community, consent)?;
+        }
+        Ok(quorum)
+    }
+}
+
+impl ConsentQuorum {
+    /// Quorum over `required` (at least one community). `AtLeast(k)`
+    /// needs `1 <= k <= required.len()`.
+    pub fn new(
+        required: impl IntoIterator<Item = CommunityId>,
+        rule: QuorumRule,
+    ) -> Result<Self, String> {
+        let required: BTreeSet<CommunityId> = required.into_iter().collect();
+        if required.is_empty() {
+            return Err("consent quorum needs at least one community".into());
+        }
+        if let QuorumRule::AtLeast(k) = rule {
+            if k == 0 || k > required.len() {
+                return Err(format!(
+                    "quorum of {} cannot be met by {} required communities",
+                    k,
+                    required.len()
+                ));
+            }
+        }
+        Ok(Self {
+            required,
+            rule,
+            consents: BTreeMap::new(),
+        })
+    }
+
+    pub fn unanimous(required: impl IntoIterator<Item = CommunityId>) -> Result<Self, String> {
+        Self::new(required, QuorumRule::Unanimous)
+    }
+
+    /// Quorum of a single community that has already consented, the
+    /// common case for corridors governed by one community.
+    pub fn sole(community: CommunityId, consent: Consent) -> Self {
+        Self {
+            required: BTreeSet::from([community.clone()]),
+            rule: QuorumRule::Unanimous,
+            consents: BTreeMap::from([(community, consent)]),
+        }
+    }
+
+    /// Record (or replace) `community`'s consent; only required
+    /// communities can consent.
+    pub fn record_consent(
+        &mut self,
+        community: CommunityId,
+        consent: Consent,
+    ) -> Result<(), String> {
+        if !self.required.contains(&community) {
+            return Err(format!(
+                "community '{}' is not part of this consent quorum",
+                community.0
+            ));
+        }
+        self.consents.insert(community, consent);
+        Ok(())
+    }
+
+    /// Withdraw `community`'s consent, returning it if one was recorded.
+    pub fn withdraw_consent(&mut self, community: &CommunityId) -> Option<Consent> {
+        self.consents.remove(community)
+    }
+
+    pub fn is_quorum_met(&self) -> bool {
+        let needed = match self.rule {
+            QuorumRule::Unanimous => self.required.len(),
+            QuorumRule::AtLeast(k) => k,
+        };
+        self.consents.len() >= needed
+    }
+
+    pub fn rule(&self) -> QuorumRule {
+        self.rule
+    }
+
+    pub fn required(&self) -> impl Iterator<Item = &CommunityId> {
+        self.required.iter()
+    }
+
+    /// Communities that have consented, with their consent records.
+    pub fn consents(&self) -> impl Iterator<Item = (&CommunityId, &Consent)> {
+        self.consents.iter()
+    }
+
+    /// Required communities that have not (yet) consented.
+    pub fn outstanding(&self) -> impl Iterator<Item = &CommunityId> {
+        self.required
+            .iter()
+            .filter(|c| !self.consents.contains_key(*c))
+    }
+}
 
 /// FPIC status for a given corridor and use-case.
-#[derive(Clone, Debug)]
+///
+/// FPIC moves through a lifecycle (see `FpicStatusKind::can_transition_to`);
+/// `transition` is the audited way to move it, producing an
+/// `FpicTransition` record of who moved it and when.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FpicStatus {
     Pending,
+    /// Consultation with the quorum's communities is under way; consents
+    /// accumulate in `quorum` until it is met.
+    Consulting {
+        since: SystemTime,
+        quorum: ConsentQuorum,
+    },
     /// Granted under specific terms, tied to community IDs and time.
     Granted {
         at: SystemTime,
-        communities: Vec<CommunityId>,
+        /// Communities whose consent the grant rests on; a grant is only
+        /// active while its quorum is met.
+        quorum: ConsentQuorum,
         terms_reference: String, // e.g., hash/URI into an FPIC ledger
+        /// Uses the communities consented to; the grant covers nothing
+        /// else.
+        purposes: BTreeSet<FpicPurpose>,
+        /// End of the grant; `None` for grants without a term. The grant
+        /// stops being active at this instant.
+        #[serde(default)]
+        valid_until: Option<SystemTime>,
+        /// How long before `valid_until` renewal should start; see
+        /// `FpicStatus::renewal_due`.
+        #[serde(default)]
+        renewal_notice: Option<Duration>,
+        /// The terms behind `terms_reference`, when carried on the grant;
+        /// boxed to keep statuses small.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        terms: Option<Box<BoundTerms>>,
     },
-    /// Withheld or revoked, with explanation.
+    /// Granted consent paused by the communities; may be reinstated.
+    Suspended {
+        at: SystemTime,
+        reason: String,
+    },
+    /// Granted consent withdrawn by the communities.
+    Revoked {
+        at: SystemTime,
+        reason: String,
+    },
+    /// Refused before any grant, with explanation.
     Withheld {
         at: SystemTime,
         reason: String,
     },
+    /// A grant or suspension ran out; renewal starts a new consultation.
+    Expired {
+        at: SystemTime,
+    },
 }
 
 impl FpicStatus {
-    pub fn is_active_grant(&self) -> bool {
-        matches!(self, FpicStatus::Granted { .. })
+    /// True if granted with its consent quorum met and, for a grant with
+    /// a term, not yet expired at `now`.
+    pub fn is_active_grant(&self, now: SystemTime) -> bool {
+        match self {
+            FpicStatus::Granted {
+                quorum,
+                valid_until,
+                ..
+            } => quorum.is_quorum_met() && valid_until.is_none_or(|end| now < end),
+            _ => false,
+        }
+    }
+
+    /// True if an active grant is inside its renewal-notice period at
+    /// `now`, i.e. renewal should be under way.
+    pub fn renewal_due(&self, now: SystemTime) -> bool {
+        match self {
+            FpicStatus::Granted {
+                valid_until: Some(end),
+                renewal_notice: Some(notice),
+                ..
+            } => {
+                self.is_active_grant(now)
+                    && end.checked_sub(*notice).is_none_or(|start| now >= start)
+            }
+            _ => false,
+        }
+    }
+
+    /// True if this is a grant naming `purpose`. Says nothing about
+    /// whether the grant is still active; see `is_active_grant`.
+    pub fn covers(&self, purpose: FpicPurpose) -> bool {
+        match self {
+            FpicStatus::Granted { purposes, .. } => purposes.contains(&purpose),
+            _ => false,
+        }
+    }
+
+    /// Variant tag without payload, for counting and indexing.
+    pub fn kind(&self) -> FpicStatusKind {
+        match self {
+            FpicStatus::Pending => FpicStatusKind::Pending,
+            FpicStatus::Consulting { .. } => FpicStatusKind::Consulting,
+            FpicStatus::Granted { .. } => FpicStatusKind::Granted,
+            FpicStatus::Suspended { .. } => FpicStatusKind::Suspended,
+            FpicStatus::Revoked { .. } => FpicStatusKind::Revoked,
+            FpicStatus::Withheld { .. } => FpicStatusKind::Withheld,
+            FpicStatus::Expired { .. } => FpicStatusKind::Expired,
+        }
+    }
+
+    /// Move to `to` if the lifecycle allows it, returning the audit
+    /// record. On error `self` is unchanged.
+    pub fn transition(
+        &mut self,
+        to: FpicStatus,
+        evidence: FpicEvidence,
+    ) -> Result<FpicTransition, FpicTransitionError> {
+        if evidence.actor.trim().is_empty() || evidence.reference.trim().is_empty() {
+            return Err(FpicTransitionError::MissingEvidence);
+        }
+        let (from, next) = (self.kind(), to.kind());
+        if !from.can_transition_to(next) {
+            return Err(FpicTransitionError::Illegal { from, to: next });
+        }
+        if let FpicStatus::Granted {
+            quorum,
+            terms_reference,
+            purposes,
+            terms,
+            ..
+        } = &to
+        {
+            if !quorum.is_quorum_met() {
+                return Err(FpicTransitionError::QuorumNotMet);
+            }
+            if purposes.is_empty() {
+                return Err(FpicTransitionError::NoPurposes);
+            }
+            if let Some((community, e)) = quorum.invalid_delegations(purposes).into_iter().next() {
+                return Err(FpicTransitionError::Delegation(community, e));
+            }
+            if let Some(terms) = terms {
+                terms
+                    .check(terms_reference, purposes)
+                    .map_err(FpicTransitionError::Terms)?;
+            }
+        }
+        *self = to;
+        Ok(FpicTransition {
+            from,
+            to: next,
+            evidence,
+        })
+    }
+}
+
+/// Payload-free tag of an `FpicStatus` variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FpicStatusKind {
+    Pending,
+    Consulting,
+    Granted,
+    Suspended,
+    Revoked,
+    Withheld,
+    Expired,
+}
+
+impl FpicStatusKind {
+    pub const ALL: [FpicStatusKind; 7] = [
+        FpicStatusKind::Pending,
+        FpicStatusKind::Consulting,
+        FpicStatusKind::Granted,
+        FpicStatusKind::Suspended,
+        FpicStatusKind::Revoked,
+        FpicStatusKind::Withheld,
+        FpicStatusKind::Expired,
+    ];
+
+    /// Stable lowercase key used in interchange formats.
+    pub fn key(self) -> &'static str {
+        match self {
+            FpicStatusKind::Pending => "pending",
+            FpicStatusKind::Consulting => "consulting",
+            FpicStatusKind::Granted => "granted",
+            FpicStatusKind::Suspended => "suspended",
+            FpicStatusKind::Revoked => "revoked",
+            FpicStatusKind::Withheld => "withheld",
+            FpicStatusKind::Expired => "expired",
+        }
+    }
+
+    /// True if FPIC may move from `self` to `next`:
+    /// - `Pending` -> `Consulting`;
+    /// - `Consulting` -> `Granted` or `Withheld`;
+    /// - `Granted` -> `Suspended`, `Revoked`, or `Expired`;
+    /// - `Suspended` -> `Granted` (reinstated), `Revoked`, or `Expired`;
+    /// - `Revoked`, `Withheld`, or `Expired` -> `Consulting` (a fresh
+    ///   consultation).
+    ///
+    /// Consent can never be granted without a consultation first.
+    /// `Consulting` and `Granted` may also move to themselves, e.g. when a
+    /// consultation widens or a grant is renewed under new terms.
+    pub fn can_transition_to(self, next: FpicStatusKind) -> bool {
+        use FpicStatusKind::*;
+        matches!(
+            (self, next),
+            (Pending, Consulting)
+                | (Consulting, Consulting)
+                | (Consulting, Granted)
+                | (Consulting, Withheld)
+                | (Granted, Granted)
+                | (Granted, Suspended)
+                | (Granted, Revoked)
+                | (Granted, Expired)
+                | (Suspended, Granted)
+                | (Suspended, Revoked)
+                | (Suspended, Expired)
+                | (Revoked, Consulting)
+                | (Withheld, Consulting)
+                | (Expired, Consulting)
+        )
+    }
+}
+
+impl fmt::Display for FpicStatusKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Who moved FPIC, when, and on what record.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FpicEvidence {
+    /// DID or steward identifier of whoever recorded the decision.
+    pub actor: String,
+    pub at: SystemTime,
+    /// Hash/URI of the decision record, e.g. meeting minutes in an FPIC
+    /// ledger.
+    pub reference: String,
+}
+
+/// Audit record of one FPIC transition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FpicTransition {
+    pub from: FpicStatusKind,
+    pub to: FpicStatusKind,
+    pub evidence: FpicEvidence,
+}
+
+/// Why an FPIC transition was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FpicTransitionError {
+    Illegal {
+        from: FpicStatusKind,
+        to: FpicStatusKind,
+    },
+    /// Evidence names no actor or no decision record.
+    MissingEvidence,
+    /// A grant was proposed without its consent quorum.
+    QuorumNotMet,
+    /// A grant was proposed that names no purposes.
+    NoPurposes,
+    /// A consent was signed under a delegation that does not cover the
+    /// grant.
+    Delegation(CommunityId, DelegationError),
+    /// The grant's structured terms are unbound or do not permit it.
+    Terms(TermsError),
+}
+
+impl fmt::Display for FpicTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FpicTransitionError::Illegal { from, to } => {
+                write!(f, "FPIC cannot move from {} to {}", from, to)
+            }
+            FpicTransitionError::MissingEvidence => {
+                write!(f, "FPIC transition needs an actor and a decision reference")
+            }
+            FpicTransitionError::QuorumNotMet => {
+                write!(f, "FPIC cannot be granted before its consent quorum is met")
+            }
+            FpicTransitionError::NoPurposes => {
+                write!(f, "FPIC cannot be granted without naming its purposes")
+            }
+            FpicTransitionError::Delegation(community, e) => {
+                write!(f, "consent of '{}' is not authorized: {}", community.0, e)
+            }
+            FpicTransitionError::Terms(e) => write!(f, "FPIC cannot be granted: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FpicTransitionError {}
+
+/// Indigenous data sovereignty framework a corridor's data is governed
+/// under.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum IdsFramework {
+    /// First Nations principles of ownership, control, access, and
+    /// possession.
+    Ocap,
+    /// CARE principles: collective benefit, authority to control,
+    /// responsibility, ethics.
+    Care,
+    /// A community's own data protocol, published at `reference`.
+    LocalProtocol { reference: String },
+    /// Recorded as framework-governed before frameworks were named; the
+    /// governing community should replace it with the actual framework.
+    Unspecified,
+}
+
+impl IdsFramework {
+    /// Obligations data users take on under this framework.
+    pub fn requirements(&self) -> Vec<IdsRequirement> {
+        use IdsRequirement::*;
+        match self {
+            IdsFramework::Ocap => vec![
+                GovernanceRecord,
+                CommunityConsent,
+                CommunityAccess,
+                CommunityPossession,
+            ],
+            IdsFramework::Care => vec![
+                CollectiveBenefit,
+                CommunityConsent,
+                GovernanceRecord,
+                EthicalReview,
+            ],
+            IdsFramework::LocalProtocol { reference } => {
+                vec![CommunityConsent, LocalProtocol(reference.clone())]
+            }
+            IdsFramework::Unspecified => vec![CommunityConsent],
+        }
+    }
+}
+
+impl fmt::Display for IdsFramework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdsFramework::Ocap => write!(f, "OCAP"),
+            IdsFramework::Care => write!(f, "CARE"),
+            IdsFramework::LocalProtocol { reference } => {
+                write!(f, "local protocol '{}'", reference)
+            }
+            IdsFramework::Unspecified => write!(f, "unspecified framework"),
+        }
+    }
+}
+
+/// One obligation implied by an `IdsFramework`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum IdsRequirement {
+    /// Data use needs an active FPIC grant.
+    CommunityConsent,
+    /// The scope names its governance record (`governance_ref`).
+    GovernanceRecord,
+    /// Communities can obtain the data held about them.
+    CommunityAccess,
+    /// Data is held in community-controlled storage.
+    CommunityPossession,
+    /// Uses must return benefit to the community.
+    CollectiveBenefit,
+    /// Uses pass an ethics review the community recognises.
+    EthicalReview,
+    /// Uses follow the community protocol at this reference.
+    LocalProtocol(String),
+}
+
+impl IdsRequirement {
+    /// True for requirements this crate can check against a record;
+    /// the rest are left to overlays.
+    pub fn is_checkable(&self) -> bool {
+        matches!(
+            self,
+            IdsRequirement::CommunityConsent | IdsRequirement::GovernanceRecord
+        )
+    }
+}
+
+impl fmt::Display for IdsRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdsRequirement::CommunityConsent => write!(f, "community consent"),
+            IdsRequirement::GovernanceRecord => write!(f, "governance record"),
+            IdsRequirement::CommunityAccess => write!(f, "community access"),
+            IdsRequirement::CommunityPossession => write!(f, "community possession"),
+            IdsRequirement::CollectiveBenefit => write!(f, "collective benefit"),
+            IdsRequirement::EthicalReview => write!(f, "ethical review"),
+            IdsRequirement::LocalProtocol(r) => write!(f, "local protocol '{}'", r),
+        }
     }
 }
 
 /// Indigenous Data Sovereignty (IDS) scope tags for this corridor.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IdsScope {
     /// Whether Indigenous data is present at all.
     pub contains_indigenous_data: bool,
-    /// True if governed under an Indigenous data sovereignty framework
-    /// (e.g., OCAP, CARE); ledger details live in overlays.
-    pub governed_by_ids_framework: bool,
+    /// Frameworks the data is governed under; empty if none. Ledger
+    /// details live in overlays.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frameworks: Vec<IdsFramework>,
     /// Optional reference to IDS governance shard / ledger entry.
     pub governance_ref: Option<String>,
+    /// Data held back until the community releases it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embargo: Option<Embargo>,
+}
+
+impl IdsScope {
+    /// True if `category` is under an embargo that has not lifted by
+    /// `now`.
+    pub fn is_embargoed(&self, category: EmbargoCategory, now: SystemTime) -> bool {
+        self.embargo
+            .as_ref()
+            .is_some_and(|e| e.covers(category, now))
+    }
+
+    pub fn is_framework_governed(&self) -> bool {
+        !self.frameworks.is_empty()
+    }
+
+    /// Requirements of every framework, without duplicates, in the order
+    /// first named.
+    pub fn requirements(&self) -> Vec<IdsRequirement> {
+        let mut all = Vec::new();
+        for requirement in self.frameworks.iter().flat_map(IdsFramework::requirements) {
+            if !all.contains(&requirement) {
+                all.push(requirement);
+            }
+        }
+        all
+    }
+
+    /// Checkable requirements the record fails, given whether its FPIC
+    /// grant is active. See `IdsRequirement::is_checkable`.
+    pub fn unmet_requirements(&self, active_grant: bool) -> Vec<IdsRequirement> {
+        let has_record = self
+            .governance_ref
+            .as_deref()
+            .is_some_and(|r| !r.trim().is_empty());
+        self.requirements()
+            .into_iter()
+            .filter(|r| match r {
+                IdsRequirement::CommunityConsent => !active_grant,
+                IdsRequirement::GovernanceRecord => !has_record,
+                _ => false,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fpic_moves_only_along_its_lifecycle() {
+        let evidence = |reference: &str| FpicEvidence {
+            actor: "did:example:council".into(),
+            at: SystemTime::UNIX_EPOCH,
+            reference: reference.into(),
+        };
+        let grant = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "fpic:terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let mut status = FpicStatus::Pending;
+        assert_eq!(
+            status.transition(grant.clone(), evidence("minutes:1")),
+            Err(FpicTransitionError::Illegal {
+                from: FpicStatusKind::Pending,
+                to: FpicStatusKind::Granted,
+            })
+        );
+        assert_eq!(status, FpicStatus::Pending);
+
+        let consulting = FpicStatus::Consulting {
+            since: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::unanimous([CommunityId("nation-x".into())]).unwrap(),
+        };
+        status
+            .transition(consulting, evidence("minutes:1"))
+            .unwrap();
+        assert_eq!(
+            status.transition(grant.clone(), evidence(" ")),
+            Err(FpicTransitionError::MissingEvidence)
+        );
+        let record = status.transition(grant, evidence("minutes:2")).unwrap();
+        assert_eq!(
+            (record.from, record.to),
+            (FpicStatusKind::Consulting, FpicStatusKind::Granted)
+        );
+        assert!(status.is_active_grant(SystemTime::now()));
+
+        let suspended = FpicStatus::Suspended {
+            at: SystemTime::UNIX_EPOCH,
+            reason: "flooding".into(),
+        };
+        status.transition(suspended, evidence("minutes:3")).unwrap();
+        status
+            .transition(
+                FpicStatus::Expired {
+                    at: SystemTime::UNIX_EPOCH,
+                },
+                evidence("minutes:4"),
+            )
+            .unwrap();
+        assert!(!FpicStatusKind::Expired.can_transition_to(FpicStatusKind::Granted));
+    }
+
+    #[test]
+    fn grants_with_a_term_expire_on_their_own() {
+        let t = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        let grant = FpicStatus::Granted {
+            at: t(0),
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "fpic:terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: Some(t(1_000)),
+            renewal_notice: Some(Duration::from_secs(100)),
+            terms: None,
+        };
+        assert!(grant.is_active_grant(t(500)) && !grant.renewal_due(t(500)));
+        assert!(grant.is_active_grant(t(950)) && grant.renewal_due(t(950)));
+        assert!(!grant.is_active_grant(t(1_000)) && !grant.renewal_due(t(1_000)));
+    }
+
+    #[test]
+    fn shared_corridors_need_their_quorum() {
+        let community = |s: &str| CommunityId(s.into());
+        let consent = |s: &str| Consent {
+            at: SystemTime::UNIX_EPOCH,
+            reference: format!("{}:decision", s),
+            delegation: None,
+        };
+        let nations = ["nation-x", "nation-y", "nation-z"].map(community);
+        assert!(ConsentQuorum::new(nations.clone(), QuorumRule::AtLeast(4)).is_err());
+        assert!(ConsentQuorum::unanimous([]).is_err());
+
+        let mut two_of_three = ConsentQuorum::new(nations.clone(), QuorumRule::AtLeast(2)).unwrap();
+        two_of_three
+            .record_consent(community("nation-x"), consent("nation-x"))
+            .unwrap();
+        assert!(!two_of_three.is_quorum_met());
+        assert!(two_of_three
+            .record_consent(community("nation-w"), consent("nation-w"))
+            .is_err());
+        two_of_three
+            .record_consent(community("nation-z"), consent("nation-z"))
+            .unwrap();
+        assert!(two_of_three.is_quorum_met());
+        assert_eq!(
+            two_of_three.outstanding().collect::<Vec<_>>(),
+            [&community("nation-y")]
+        );
+
+        let mut unanimous = two_of_three.clone();
+        unanimous.rule = QuorumRule::Unanimous;
+        assert!(!unanimous.is_quorum_met());
+
+        let mut status = FpicStatus::Consulting {
+            since: SystemTime::UNIX_EPOCH,
+            quorum: unanimous.clone(),
+        };
+        let grant = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: unanimous,
+            terms_reference: "fpic:terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        assert!(!grant.is_active_grant(SystemTime::UNIX_EPOCH));
+        let evidence = FpicEvidence {
+            actor: "did:example:council".into(),
+            at: SystemTime::UNIX_EPOCH,
+            reference: "minutes:1".into(),
+        };
+        assert_eq!(
+            status.transition(grant, evidence),
+            Err(FpicTransitionError::QuorumNotMet)
+        );
+
+        let json = serde_json::to_string(&two_of_three).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ConsentQuorum>(&json).unwrap(),
+            two_of_three
+        );
+    }
+
+    #[test]
+    fn grants_cover_only_their_purposes() {
+        let grant = |purposes: &[FpicPurpose]| FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "fpic:terms:1".into(),
+            purposes: purposes.iter().copied().collect(),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let scoped = grant(&[FpicPurpose::Monitoring, FpicPurpose::Restoration]);
+        assert!(scoped.covers(FpicPurpose::Monitoring));
+        assert!(!scoped.covers(FpicPurpose::DataSharing));
+        assert!(!FpicStatus::Pending.covers(FpicPurpose::Monitoring));
+        assert_eq!(
+            FpicPurpose::from_key("data_sharing"),
+            Some(FpicPurpose::DataSharing)
+        );
+
+        let mut status = FpicStatus::Consulting {
+            since: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::unanimous([CommunityId("nation-x".into())]).unwrap(),
+        };
+        let evidence = FpicEvidence {
+            actor: "did:example:council".into(),
+            at: SystemTime::UNIX_EPOCH,
+            reference: "minutes:1".into(),
+        };
+        assert_eq!(
+            status.transition(grant(&[]), evidence.clone()),
+            Err(FpicTransitionError::NoPurposes)
+        );
+        status.transition(scoped, evidence).unwrap();
+    }
+
+    #[test]
+    fn ids_frameworks_imply_checkable_requirements() {
+        let mut scope = IdsScope {
+            contains_indigenous_data: true,
+            frameworks: vec![
+                IdsFramework::Ocap,
+                IdsFramework::LocalProtocol {
+                    reference: "nation-x:protocol:3".into(),
+                },
+            ],
+            governance_ref: None,
+            embargo: None,
+        };
+        let requirements = scope.requirements();
+        assert_eq!(requirements.len(), 5);
+        assert!(requirements.contains(&IdsRequirement::LocalProtocol("nation-x:protocol:3".into())));
+        assert_eq!(
+            scope.unmet_requirements(false),
+            [
+                IdsRequirement::GovernanceRecord,
+                IdsRequirement::CommunityConsent
+            ]
+        );
+        scope.governance_ref = Some("ids:ledger:1".into());
+        assert!(scope.unmet_requirements(true).is_empty());
+        assert!(!IdsRequirement::CommunityPossession.is_checkable());
+
+        let json = serde_json::to_value(&scope.frameworks).unwrap();
+        assert_eq!(json[0], "ocap");
+        assert_eq!(
+            serde_json::from_value::<Vec<IdsFramework>>(json).unwrap(),
+            scope.frameworks
+        );
+    }
 }
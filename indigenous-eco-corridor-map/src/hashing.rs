@@ -0,0 +1,190 @@
+//! Content hashes of corridor records and whole maps, so overlays can pin
+//! exactly which map state they evaluated against.
+//!
+//! Records are hashed over their JSON serialization, which is canonical
+//! here: fields serialize in declaration order and every collection in a
+//! record is ordered. Hashes therefore change when the record schema
+//! changes, not only when values do. Records that cannot be serialized,
+//! such as ones holding a `SystemTime` before 1970, cannot be hashed.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// SHA-256 digest, displayed and serialized as lowercase hex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContentHash(pub [u8; 32]);
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ContentHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        if s.len() != 64 || !s.is_ascii() {
+            return Err("content hash must be 64 hex digits".into());
+        }
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16)
+                .map_err(|_| format!("content hash '{}' is not hex", s))?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl Serialize for ContentHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ContentHash {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ContentHash".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string", "pattern": "^[0-9a-f]{64}$" })
+    }
+}
+
+// Domain-separation prefixes for Merkle nodes.
+const LEAF: u8 = 0;
+const NODE: u8 = 1;
+
+impl IndigenousEcoCorridor {
+    /// Deterministic hash of the record, excluding its `signature` (which
+    /// is computed over this hash). Fails if the record does not
+    /// serialize.
+    pub fn content_hash(&self) -> Result<ContentHash, String> {
+        if self.signature.is_some() {
+            let mut unsigned = self.clone();
+            unsigned.signature = None;
+            return unsigned.content_hash();
+        }
+        let canonical = serde_json::to_vec(self)
+            .map_err(|e| format!("CorridorId '{}' cannot be hashed: {}", self.id(), e))?;
+        Ok(ContentHash(Sha256::digest(canonical).into()))
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Merkle root over every corridor in `CorridorId` order. Each leaf
+    /// hashes the id together with the record's `content_hash`; odd nodes
+    /// are promoted unchanged. Aliases are not covered, since they do not
+    /// change any record. The empty map hashes to SHA-256 of nothing.
+    /// Fails with the first record that cannot be hashed.
+    pub fn root_hash(&self) -> Result<ContentHash, String> {
+        let mut level: Vec<[u8; 32]> = self
+            .iter()
+            .map(|(id, corridor)| {
+                let mut h = Sha256::new();
+                h.update([LEAF]);
+                h.update((id.as_str().len() as u64).to_be_bytes());
+                h.update(id.as_str());
+                h.update(corridor.content_hash()?.0);
+                Ok(h.finalize().into())
+            })
+            .collect::<Result<_, String>>()?;
+        if level.is_empty() {
+            return Ok(ContentHash(Sha256::digest([]).into()));
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => {
+                        let mut h = Sha256::new();
+                        h.update([NODE]);
+                        h.update(left);
+                        h.update(right);
+                        h.finalize().into()
+                    }
+                    [single] => *single,
+                    _ => unreachable!("chunks(2)"),
+                })
+                .collect();
+        }
+        Ok(ContentHash(level[0]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::FpicStatus;
+    use crate::test_support::{self, uniform_metrics};
+    use std::time::{Duration, SystemTime};
+
+    fn corridor(id: &str, score: f32) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            environmental: uniform_metrics(score),
+            ..test_support::corridor(id)
+        }
+    }
+
+    #[test]
+    fn root_hash_pins_map_state() {
+        let mut a = IndigenousEcoCorridorMap::new();
+        let mut b = IndigenousEcoCorridorMap::new();
+        for id in ["eco:w:1", "eco:w:2", "eco:w:3"] {
+            a.upsert_corridor(corridor(id, 0.5)).unwrap();
+        }
+        for id in ["eco:w:3", "eco:w:1", "eco:w:2"] {
+            b.upsert_corridor(corridor(id, 0.5)).unwrap();
+        }
+        let pinned = a.root_hash().unwrap();
+        assert_eq!(pinned, b.root_hash().unwrap());
+        assert_ne!(pinned, IndigenousEcoCorridorMap::new().root_hash().unwrap());
+
+        b.upsert_corridor(corridor("eco:w:2", 0.6)).unwrap();
+        assert_ne!(pinned, b.root_hash().unwrap());
+        assert_ne!(
+            corridor("eco:w:2", 0.5).content_hash(),
+            corridor("eco:w:2", 0.6).content_hash()
+        );
+
+        let text = pinned.to_string();
+        assert_eq!(text.len(), 64);
+        assert_eq!(text.parse::<ContentHash>().unwrap(), pinned);
+    }
+
+    #[test]
+    fn records_before_1970_fail_to_hash_instead_of_panicking() {
+        let mut old = corridor("eco:w:old", 0.5);
+        old.fpic = FpicStatus::Withheld {
+            at: SystemTime::UNIX_EPOCH - Duration::from_secs(1),
+            reason: "1969 council".into(),
+        };
+        let err = old.content_hash().unwrap_err();
+        assert!(err.contains("eco:w:old"), "{}", err);
+
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:w:new", 0.5)).unwrap();
+        map.upsert_corridor(old).unwrap();
+        assert!(map.root_hash().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Dated map snapshots for governance audits.
+//! Non-actuating; reconstructs what the map looked like at a past time.
+
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use crate::map::{IndigenousEcoCorridorMap, MapSnapshot};
+
+/// Append-only sequence of dated `MapSnapshot`s.
+///
+/// Snapshots share unchanged corridor records, so recording after every
+/// governance decision costs roughly the changed records plus the index.
+#[derive(Clone, Default)]
+pub struct MapTimeline {
+    times: Vec<SystemTime>,
+    snapshots: Vec<MapSnapshot>,
+}
+
+impl MapTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the state of `map` as of `at`. Times must be
+    /// non-decreasing; recording twice at the same time keeps the later
+    /// state.
+    pub fn record(&mut self, at: SystemTime, map: &IndigenousEcoCorridorMap) -> Result<(), String> {
+        match self.times.last() {
+            Some(last) if at < *last => {
+                return Err("MapTimeline snapshots must be recorded in time order".into());
+            }
+            Some(last) if at == *last => {
+                *self.snapshots.last_mut().expect("aligned with times") = map.snapshot();
+            }
+            _ => {
+                self.times.push(at);
+                self.snapshots.push(map.snapshot());
+            }
+        }
+        Ok(())
+    }
+
+    /// The map as it stood at `at`: the latest snapshot recorded at or
+    /// before `at`, or `None` if `at` predates the timeline.
+    pub fn as_of(&self, at: SystemTime) -> Option<&MapSnapshot> {
+        let index = self.times.partition_point(|t| *t <= at);
+        index.checked_sub(1).map(|i| &self.snapshots[i])
+    }
+
+    pub fn latest(&self) -> Option<(SystemTime, &MapSnapshot)> {
+        Some((*self.times.last()?, self.snapshots.last()?))
+    }
+
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    /// Recorded snapshots in time order.
+    pub fn iter(&self) -> impl Iterator<Item = (SystemTime, &MapSnapshot)> {
+        self.times.iter().copied().zip(self.snapshots.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corridor::CorridorId;
+    use crate::test_support;
+    use std::time::Duration;
+
+    #[test]
+    fn as_of_returns_state_at_decision_time() {
+        let id = CorridorId::new("eco:desert:d").unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+        let mut map = IndigenousEcoCorridorMap::new();
+        let mut timeline = MapTimeline::new();
+        timeline.record(t0, &map).unwrap();
+
+        map.upsert_corridor(test_support::corridor(id.as_str()))
+            .unwrap();
+        timeline.record(t0 + Duration::from_secs(10), &map).unwrap();
+        map.remove_corridor(&id);
+        timeline.record(t0 + Duration::from_secs(20), &map).unwrap();
+
+        assert!(timeline.as_of(t0 - Duration::from_secs(1)).is_none());
+        assert!(timeline
+            .as_of(t0 + Duration::from_secs(5))
+            .unwrap()
+            .get(&id)
+            .is_none());
+        assert!(timeline
+            .as_of(t0 + Duration::from_secs(15))
+            .unwrap()
+            .get(&id)
+            .is_some());
+        assert!(timeline
+            .as_of(t0 + Duration::from_secs(99))
+            .unwrap()
+            .get(&id)
+            .is_none());
+        assert!(timeline.record(t0, &map).is_err());
+        assert_eq!(timeline.len(), 3);
+    }
+}
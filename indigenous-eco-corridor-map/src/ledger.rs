@@ -0,0 +1,295 @@
+//! Pluggable consent ledgers.
+//! This crate stores only references into external FPIC ledgers; overlays
+//! implement `ConsentLedger` with their ALN or blockchain backends so
+//! those references can be checked rather than trusted.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::corridor::CorridorId;
+use crate::governance::{CommunityId, ConsentQuorum, FpicPurpose, FpicStatus};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// Terms of consent as recorded in the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerTerms {
+    pub recorded_at: SystemTime,
+    /// Purposes the recorded terms permit.
+    pub purposes: BTreeSet<FpicPurpose>,
+}
+
+/// A community's recorded consent decision.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerReceipt {
+    pub community: CommunityId,
+    pub recorded_at: SystemTime,
+}
+
+/// The ledger backend could not answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerError(pub String);
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "consent ledger unavailable: {}", self.0)
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+/// Read access to an external FPIC ledger.
+pub trait ConsentLedger {
+    /// Terms recorded under `terms_reference`, or `None` if the ledger
+    /// has no such entry.
+    fn resolve_terms(&self, terms_reference: &str) -> Result<Option<LedgerTerms>, LedgerError>;
+    /// True if the ledger records the terms as revoked.
+    fn is_revoked(&self, terms_reference: &str) -> Result<bool, LedgerError>;
+    /// Consent decision recorded under `reference` (a `Consent::reference`).
+    fn consent_receipt(&self, reference: &str) -> Result<Option<LedgerReceipt>, LedgerError>;
+}
+
+/// Disagreement between a record and the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerFinding {
+    UnknownTerms(String),
+    RevokedTerms(String),
+    /// The grant covers a purpose its recorded terms do not permit.
+    PurposeNotInTerms(FpicPurpose),
+    /// A recorded consent has no receipt in the ledger.
+    MissingReceipt {
+        community: CommunityId,
+        reference: String,
+    },
+    /// The receipt under a consent's reference belongs to another
+    /// community.
+    ReceiptMismatch {
+        community: CommunityId,
+        reference: String,
+    },
+    Unavailable(LedgerError),
+}
+
+impl fmt::Display for LedgerFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerFinding::UnknownTerms(r) => write!(f, "terms '{}' are not in the ledger", r),
+            LedgerFinding::RevokedTerms(r) => write!(f, "ledger records terms '{}' as revoked", r),
+            LedgerFinding::PurposeNotInTerms(p) => {
+                write!(f, "grant covers {} but its recorded terms do not", p)
+            }
+            LedgerFinding::MissingReceipt {
+                community,
+                reference,
+            } => write!(
+                f,
+                "consent of '{}' has no ledger receipt '{}'",
+                community.0, reference
+            ),
+            LedgerFinding::ReceiptMismatch {
+                community,
+                reference,
+            } => write!(
+                f,
+                "ledger receipt '{}' does not belong to '{}'",
+                reference, community.0
+            ),
+            LedgerFinding::Unavailable(e) => e.fmt(f),
+        }
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Check the record's FPIC references against `ledger`: a grant's
+    /// terms must exist, be unrevoked, and permit its purposes, and every
+    /// recorded consent must have a matching receipt. Empty if all agree.
+    pub fn verify_consent(&self, ledger: &dyn ConsentLedger) -> Vec<LedgerFinding> {
+        let mut findings = Vec::new();
+        let quorum = match &self.fpic {
+            FpicStatus::Consulting { quorum, .. } => quorum,
+            FpicStatus::Granted {
+                quorum,
+                terms_reference,
+                purposes,
+                ..
+            } => {
+                if let Err(e) = check_terms(ledger, terms_reference, purposes, &mut findings) {
+                    findings.push(LedgerFinding::Unavailable(e));
+                }
+                quorum
+            }
+            _ => return findings,
+        };
+        if let Err(e) = check_receipts(ledger, quorum, &mut findings) {
+            findings.push(LedgerFinding::Unavailable(e));
+        }
+        findings
+    }
+}
+
+fn check_terms(
+    ledger: &dyn ConsentLedger,
+    terms_reference: &str,
+    purposes: &BTreeSet<FpicPurpose>,
+    findings: &mut Vec<LedgerFinding>,
+) -> Result<(), LedgerError> {
+    let Some(terms) = ledger.resolve_terms(terms_reference)? else {
+        findings.push(LedgerFinding::UnknownTerms(terms_reference.to_string()));
+        return Ok(());
+    };
+    if ledger.is_revoked(terms_reference)? {
+        findings.push(LedgerFinding::RevokedTerms(terms_reference.to_string()));
+    }
+    findings.extend(
+        purposes
+            .difference(&terms.purposes)
+            .map(|p| LedgerFinding::PurposeNotInTerms(*p)),
+    );
+    Ok(())
+}
+
+fn check_receipts(
+    ledger: &dyn ConsentLedger,
+    quorum: &ConsentQuorum,
+    findings: &mut Vec<LedgerFinding>,
+) -> Result<(), LedgerError> {
+    for (community, consent) in quorum.consents() {
+        let (community, reference) = (community.clone(), consent.reference.clone());
+        match ledger.consent_receipt(&consent.reference)? {
+            None => findings.push(LedgerFinding::MissingReceipt {
+                community,
+                reference,
+            }),
+            Some(receipt) if receipt.community != community => {
+                findings.push(LedgerFinding::ReceiptMismatch {
+                    community,
+                    reference,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Every ledger disagreement in the map, in corridor id order.
+    pub fn unverified_consent(
+        &self,
+        ledger: &dyn ConsentLedger,
+    ) -> Vec<(CorridorId, LedgerFinding)> {
+        self.iter()
+            .flat_map(|(id, c)| {
+                c.verify_consent(ledger)
+                    .into_iter()
+                    .map(move |f| (id.clone(), f))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::Consent;
+    use crate::test_support;
+    use std::collections::BTreeMap;
+
+    /// In-memory ledger for tests.
+    #[derive(Default)]
+    struct Ledger {
+        terms: BTreeMap<String, LedgerTerms>,
+        revoked: BTreeSet<String>,
+        receipts: BTreeMap<String, LedgerReceipt>,
+        down: bool,
+    }
+
+    impl ConsentLedger for Ledger {
+        fn resolve_terms(&self, r: &str) -> Result<Option<LedgerTerms>, LedgerError> {
+            if self.down {
+                return Err(LedgerError("timeout".into()));
+            }
+            Ok(self.terms.get(r).cloned())
+        }
+        fn is_revoked(&self, r: &str) -> Result<bool, LedgerError> {
+            Ok(self.revoked.contains(r))
+        }
+        fn consent_receipt(&self, r: &str) -> Result<Option<LedgerReceipt>, LedgerError> {
+            Ok(self.receipts.get(r).cloned())
+        }
+    }
+
+    fn granted(purposes: &[FpicPurpose]) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            fpic: FpicStatus::Granted {
+                at: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::sole(
+                    CommunityId("nation-x".into()),
+                    Consent {
+                        at: SystemTime::UNIX_EPOCH,
+                        reference: "receipt:1".into(),
+                        delegation: None,
+                    },
+                ),
+                terms_reference: "terms:1".into(),
+                purposes: purposes.iter().copied().collect(),
+                valid_until: None,
+                renewal_notice: None,
+                terms: None,
+            },
+            ..test_support::corridor("eco:river:l")
+        }
+    }
+
+    #[test]
+    fn grants_are_checked_against_the_ledger() {
+        let corridor = granted(&[FpicPurpose::Monitoring, FpicPurpose::Research]);
+        let mut ledger = Ledger::default();
+        assert_eq!(
+            corridor.verify_consent(&ledger),
+            [
+                LedgerFinding::UnknownTerms("terms:1".into()),
+                LedgerFinding::MissingReceipt {
+                    community: CommunityId("nation-x".into()),
+                    reference: "receipt:1".into(),
+                },
+            ]
+        );
+
+        ledger.terms.insert(
+            "terms:1".into(),
+            LedgerTerms {
+                recorded_at: SystemTime::UNIX_EPOCH,
+                purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            },
+        );
+        ledger.receipts.insert(
+            "receipt:1".into(),
+            LedgerReceipt {
+                community: CommunityId("nation-y".into()),
+                recorded_at: SystemTime::UNIX_EPOCH,
+            },
+        );
+        ledger.revoked.insert("terms:1".into());
+        assert_eq!(corridor.verify_consent(&ledger).len(), 3);
+
+        ledger.revoked.clear();
+        ledger.receipts.get_mut("receipt:1").unwrap().community = CommunityId("nation-x".into());
+        assert!(granted(&[FpicPurpose::Monitoring])
+            .verify_consent(&ledger)
+            .is_empty());
+
+        ledger.down = true;
+        let mut map = IndigenousEcoCorridorMap::new();
+        assert!(map
+            .upsert_batch_verified(vec![corridor.clone()], &ledger)
+            .is_err());
+        map.upsert_corridor(corridor).unwrap();
+        assert!(matches!(
+            map.unverified_consent(&ledger)[..],
+            [(_, LedgerFinding::Unavailable(_))]
+        ));
+    }
+}
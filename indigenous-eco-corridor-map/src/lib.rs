@@ -17,14 +17,128 @@
 
 #![forbid(unsafe_code)]
 
+pub mod aggregates;
+pub mod aggregation;
+pub mod attestation;
+pub mod audit;
+pub mod baselines;
+pub mod batch;
+pub mod canonical;
+pub mod community;
 pub mod corridor;
+pub mod credential;
+pub mod cultural;
+pub mod delegation;
+pub mod delta;
+pub mod denial;
+pub mod embargo;
+pub mod events;
+pub mod floors;
+pub mod geojson;
+pub mod geometry;
 pub mod governance;
+pub mod hashing;
+pub mod history;
+pub mod ledger;
+pub mod lifecycle;
+pub mod measurements;
 pub mod metrics;
+pub mod metrics_csv;
+#[cfg(feature = "morpheus")]
+pub mod morpheus;
 pub mod neurorights;
 pub mod map;
+pub mod provenance;
+pub mod quality;
+pub mod receipt;
+pub mod relationships;
+pub mod revocation;
+pub mod seasons;
+pub mod sharing;
+pub mod signature;
+pub mod species;
+pub mod store;
+pub mod sync;
+pub mod taxonomy;
+pub mod terms;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod thresholds;
+pub mod territory;
+pub mod timeline;
+pub mod token;
+pub mod units;
+pub mod whatif;
 
-pub use corridor::{CorridorDescriptor, CorridorId, CorridorKind};
-pub use governance::{CommunityId, FpicStatus, IdsScope};
-pub use metrics::{EnvironmentalMetrics, MicrobiomeMetrics, Score, SoilMetrics, WaterMetrics};
-pub use neurorights::NeurorightsConstraints;
-pub use map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+pub use aggregates::{MapAggregates, MapSummary};
+pub use aggregation::{
+    AggregationPolicy, AggregationStrategy, FlatMean, GeometricMean, Imputation, WeakestLink,
+    WeightedMean,
+};
+pub use attestation::{Attestation, AttestationError, EnvelopeSource};
+pub use audit::{AuditEntry, GovernanceAudit, GovernanceDecision};
+pub use baselines::{Baseline, BaselineComparison, SeasonalBaselines, ANOMALY_THRESHOLD};
+pub use batch::{BatchError, RecordError};
+pub use canonical::ConversionError;
+pub use community::{
+    CommunityFinding, CommunityMetadata, CommunityResolver, ResolverError,
+};
+pub use corridor::{
+    Boundary, CorridorDescriptor, CorridorDescriptorBuilder, CorridorId, CorridorKind,
+    DescriptorError, DidParts, GeometryError, LinearRing, PhysicalAttributes, Point, Polygon,
+    Polyline,
+};
+pub use corridor_types::{Finding, Validate};
+pub use credential::{CredentialError, FpicCredential, FpicCredentialSubject};
+pub use cultural::{CulturalSite, SiteAccess};
+pub use delta::{ComponentDelta, MetricsDelta};
+pub use denial::DenialExplanation;
+pub use delegation::{Delegation, DelegationError};
+pub use embargo::{Embargo, EmbargoCategory, EmbargoHandling};
+pub use events::MapEvent;
+pub use floors::NeurorightsFloors;
+pub use geojson::{FeatureError, GeoJsonError};
+pub use geometry::{BufferConflict, Overlap};
+pub use governance::{
+    CommunityId, Consent, ConsentQuorum, FpicEvidence, FpicPurpose, FpicStatus, FpicStatusKind,
+    FpicTransition, FpicTransitionError, IdsFramework, IdsRequirement, IdsScope, QuorumRule,
+};
+pub use hashing::ContentHash;
+pub use ledger::{ConsentLedger, LedgerError, LedgerFinding, LedgerReceipt, LedgerTerms};
+pub use history::MapTimeline;
+pub use lifecycle::Lifecycle;
+pub use measurements::{NitrateMgL, OrganicMatterPercent, ShannonIndex, TurbidityNtu};
+pub use metrics::{
+    AirMetrics, CarbonMetrics, EnvironmentalMetrics, ExtendedComponent, MetricComponent,
+    MicrobiomeMetrics, Score, SoilMetrics, WaterMetrics,
+};
+pub use metrics_csv::{MetricsCsvError, RowError};
+pub use neurorights::{
+    ActuationConstraint, ActuationIntent, BiometricConstraint, DeclaredCapabilities,
+    DisciplineSignalConstraint, FeedbackChannel, InferenceCategory, InferenceConstraint,
+    NeurorightsConstraints, NeurorightsUpdate, NeurorightsUpdateError, NeurorightsViolation,
+    NeurorightsWeakening, RetentionConstraint,
+};
+pub use map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap, MapSnapshot};
+pub use provenance::{Provenance, Reporter};
+pub use quality::DataQuality;
+pub use receipt::{ConsentReceipt, ReceiptConsent};
+pub use relationships::{DanglingRelationship, Relationship, RelationshipKind};
+pub use revocation::{RevocationImpact, RevocationRecord, RevocationScope};
+pub use seasons::{SeasonDate, SeasonalState, SeasonalWindow};
+pub use sharing::DataSharingAgreement;
+pub use signature::{RecordSignature, RecordSigner, RecordVerifier, SignatureError};
+pub use species::{
+    Abundance, AbundanceClass, BiodiversityScores, SpeciesObservation, SpeciesObservations,
+};
+pub use store::{
+    migrate_from, CborFileStore, CorridorStore, JsonFileStore, StoreError, SCHEMA_VERSION,
+};
+pub use taxonomy::{CustomKind, KindRegistry};
+pub use terms::{BoundTerms, ConsentTerms, TermsError};
+pub use thresholds::{MetricRange, ThresholdBreach, ThresholdProfile, ThresholdProfiles};
+pub use territory::{MetricsRollup, RollupWeighting, Territory, TerritoryRollup};
+pub use timeline::{MetricsSeries, MetricsTimeline, Trend};
+pub use token::{TokenAuthority, TokenClaims, TokenError};
+pub use units::{ElevationRange, Hectares, Kilometers, Meters};
+pub use whatif::{CorridorOutcome, Hypothetical, OutcomeChange, WhatIfReport};
@@ -0,0 +1,70 @@
+//! Ratification lifecycle of corridor records.
+//! Non-actuating; records where a corridor stands in community review.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Where a corridor record stands in its governance lifecycle.
+///
+/// Allowed transitions:
+/// - `Draft` -> `Active` (ratified) or `Archived` (abandoned);
+/// - `Active` <-> `UnderReview`;
+/// - `Active` or `UnderReview` -> `Archived`;
+/// - `Archived` -> `UnderReview` (reinstatement starts with review).
+///
+/// Records serialized before lifecycles existed deserialize as `Active`,
+/// since every corridor was treated as ratified then.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Lifecycle {
+    /// Proposed, not yet ratified by the governing community.
+    Draft,
+    /// Ratified and in force.
+    #[default]
+    Active,
+    /// Ratified, but terms or extent are being reconsidered.
+    UnderReview,
+    /// Retired; kept for audit.
+    Archived,
+}
+
+impl Lifecycle {
+    /// Stable lowercase key used in interchange formats.
+    pub fn key(self) -> &'static str {
+        match self {
+            Lifecycle::Draft => "draft",
+            Lifecycle::Active => "active",
+            Lifecycle::UnderReview => "under_review",
+            Lifecycle::Archived => "archived",
+        }
+    }
+
+    /// True if moving from `self` to `next` is allowed; staying in the
+    /// same state always is.
+    pub fn can_transition_to(self, next: Lifecycle) -> bool {
+        use Lifecycle::*;
+        self == next
+            || matches!(
+                (self, next),
+                (Draft, Active)
+                    | (Draft, Archived)
+                    | (Active, UnderReview)
+                    | (Active, Archived)
+                    | (UnderReview, Active)
+                    | (UnderReview, Archived)
+                    | (Archived, UnderReview)
+            )
+    }
+}
+
+impl fmt::Display for Lifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
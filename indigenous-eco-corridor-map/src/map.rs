@@ -2,25 +2,65 @@
 
 #![forbid(unsafe_code)]
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Deref;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::SystemTime;
 
+use corridor_types::{Finding, Validate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aggregates::MapAggregates;
+use crate::audit::{AuditEntry, GovernanceAudit, GovernanceDecision};
 use crate::corridor::{CorridorDescriptor, CorridorId};
-use crate::governance::{FpicStatus, IdsScope};
+use crate::cultural::CulturalSite;
+use crate::events::{MapEvent, Subscribers};
+use crate::governance::{
+    CommunityId, FpicEvidence, FpicStatus, FpicTransition, IdsRequirement, IdsScope,
+};
+use crate::lifecycle::Lifecycle;
 use crate::metrics::EnvironmentalMetrics;
 use crate::neurorights::NeurorightsConstraints;
+use crate::revocation::RevocationRecord;
+use crate::seasons::SeasonalWindow;
+use crate::sharing::DataSharingAgreement;
+use crate::signature::RecordSignature;
 
 /// Immutable snapshot of one Indigenous eco-corridor as a living
 /// ecological corridor that is governed by biophysical limits and
 /// community sovereignty. No actuation, no behavior control.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IndigenousEcoCorridor {
     pub descriptor: CorridorDescriptor,
     pub environmental: EnvironmentalMetrics,
     pub fpic: FpicStatus,
+    /// Audit trail of `fpic` moves made through `transition_fpic`, oldest
+    /// first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fpic_trail: Vec<FpicTransition>,
+    /// Withdrawals of consent made through `revoke_fpic`, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revocations: Vec<RevocationRecord>,
     pub ids_scope: IdsScope,
     pub neurorights: NeurorightsConstraints,
     /// Optional community-provided cultural metadata.
     pub cultural_notes: Option<String>,
+    /// Access-labelled cultural sites; see `CulturalSite`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cultural_sites: Vec<CulturalSite>,
+    #[serde(default)]
+    pub lifecycle: Lifecycle,
+    /// Recurring seasonal states; see `state_at`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seasons: Vec<SeasonalWindow>,
+    /// Steward's detached signature over the rest of the record. Map
+    /// methods that change a record (`update_metrics`, `set_lifecycle`,
+    /// `rename_corridor`) drop it, since it no longer covers the content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<RecordSignature>,
 }
 
 impl IndigenousEcoCorridor {
@@ -28,14 +68,25 @@ impl IndigenousEcoCorridor {
         &self.descriptor.id
     }
 
-    /// True if FPIC is actively granted under some terms.
-    pub fn has_active_fpic(&self) -> bool {
-        self.fpic.is_active_grant()
+    /// True if FPIC is actively granted under some terms at `now`.
+    pub fn has_active_fpic(&self, now: SystemTime) -> bool {
+        self.fpic.is_active_grant(now)
+    }
+
+    /// Checkable IDS requirements of this corridor's frameworks that it
+    /// fails at `now`; see `IdsScope::unmet_requirements`.
+    pub fn unmet_ids_requirements(&self, now: SystemTime) -> Vec<IdsRequirement> {
+        self.ids_scope.unmet_requirements(self.has_active_fpic(now))
+    }
+
+    /// True if the corridor is ratified and in force.
+    pub fn is_active(&self) -> bool {
+        self.lifecycle == Lifecycle::Active
     }
 
     /// True if this corridor is strictly non-actuating for neuromorphic use.
     pub fn requires_non_actuating(&self) -> bool {
-        self.neurorights.non_actuating_required
+        self.neurorights.actuation.non_actuating_required
     }
 }
 
@@ -44,42 +95,916 @@ impl IndigenousEcoCorridor {
 /// This is a foundational knowledge object that overlays (e.g.,
 /// Tribal Survival Protocol Envelope) can depend on. It exposes
 /// only read-like operations; no commands or behavior scheduling.
-#[derive(Default)]
+///
+/// State is shared copy-on-write: cloning the map or taking a
+/// `snapshot` is O(1), and a write after a snapshot copies only the
+/// index of corridor pointers plus the record being changed.
+#[derive(Clone, Default)]
 pub struct IndigenousEcoCorridorMap {
-    corridors: BTreeMap<CorridorId, IndigenousEcoCorridor>,
+    corridors: Arc<BTreeMap<CorridorId, Arc<IndigenousEcoCorridor>>>,
+    aggregates: Arc<MapAggregates>,
+    /// Community -> granted corridors whose grant it consented to.
+    by_community: Arc<BTreeMap<CommunityId, BTreeSet<CorridorId>>>,
+    /// Former or alternate id -> id it now points at (possibly another alias).
+    aliases: Arc<BTreeMap<CorridorId, CorridorId>>,
+    by_uuid: Arc<BTreeMap<Uuid, CorridorId>>,
+    audit: Arc<GovernanceAudit>,
+    sharing: Arc<Vec<DataSharingAgreement>>,
+    subscribers: Subscribers,
 }
 
 impl IndigenousEcoCorridorMap {
     pub fn new() -> Self {
-        Self {
-            corridors: BTreeMap::new(),
-        }
+        Self::default()
     }
 
     /// Insert or replace a corridor definition.
     /// Overlays can decide whether replacement is allowed by policy.
-    pub fn upsert_corridor(&mut self, corridor: IndigenousEcoCorridor) {
-        self.corridors.insert(corridor.id().clone(), corridor);
+    /// Upserting under an id that is currently an alias reclaims that id
+    /// for the new record. Fails, changing nothing, if the record's UUID
+    /// already belongs to a corridor with another id.
+    pub fn upsert_corridor(&mut self, corridor: IndigenousEcoCorridor) -> Result<(), String> {
+        if let Some(uuid) = corridor.descriptor.uuid {
+            match self.by_uuid.get(&uuid) {
+                Some(holder) if holder != corridor.id() => {
+                    return Err(format!(
+                        "uuid {} already belongs to CorridorId '{}'",
+                        uuid, holder
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if self.aliases.contains_key(corridor.id()) {
+            Arc::make_mut(&mut self.aliases).remove(corridor.id());
+        }
+        let corridor = Arc::new(corridor);
+        let previous = self.insert_record(corridor.clone());
+
+        if self.subscribers.is_empty() {
+            return Ok(());
+        }
+        let previous_fpic = previous.map(|p| p.fpic.clone());
+        if previous_fpic.as_ref() != Some(&corridor.fpic) {
+            self.subscribers.emit(MapEvent::FpicChanged {
+                id: corridor.id().clone(),
+                previous: previous_fpic.map(Box::new),
+                current: Box::new(corridor.fpic.clone()),
+            });
+        }
+        self.subscribers.emit(MapEvent::CorridorUpserted(corridor));
+        Ok(())
+    }
+
+    /// Replace the environmental metrics of an existing corridor.
+    pub fn update_metrics(
+        &mut self,
+        id: &CorridorId,
+        metrics: EnvironmentalMetrics,
+    ) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        let corridor = Arc::make_mut(
+            Arc::make_mut(&mut self.corridors)
+                .get_mut(&id)
+                .expect("canonical id is registered"),
+        );
+        let aggregates = Arc::make_mut(&mut self.aggregates);
+        aggregates.remove(corridor);
+        corridor.environmental = metrics;
+        corridor.signature = None;
+        aggregates.add(corridor);
+        if !self.subscribers.is_empty() {
+            let metrics = corridor.environmental.clone();
+            self.subscribers.emit(MapEvent::MetricsUpdated {
+                id,
+                metrics: Box::new(metrics),
+            });
+        }
+        Ok(())
+    }
+
+    /// Move a corridor to lifecycle state `next`, rejecting transitions
+    /// that `Lifecycle::can_transition_to` disallows.
+    pub fn set_lifecycle(&mut self, id: &CorridorId, next: Lifecycle) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        let previous = self.corridors[&id].lifecycle;
+        if !previous.can_transition_to(next) {
+            return Err(format!(
+                "CorridorId '{}' cannot move from {} to {}",
+                id, previous, next
+            ));
+        }
+        if previous == next {
+            return Ok(());
+        }
+        let corridor = Arc::make_mut(
+            Arc::make_mut(&mut self.corridors)
+                .get_mut(&id)
+                .expect("canonical id is registered"),
+        );
+        corridor.lifecycle = next;
+        corridor.signature = None;
+        self.subscribers.emit(MapEvent::LifecycleChanged {
+            id,
+            previous,
+            current: next,
+        });
+        Ok(())
+    }
+
+    /// Move a corridor's FPIC to `to` through `FpicStatus::transition`,
+    /// appending the audit record to its `fpic_trail`.
+    pub fn transition_fpic(
+        &mut self,
+        id: &CorridorId,
+        to: FpicStatus,
+        evidence: FpicEvidence,
+    ) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        self.move_fpic(id, to, evidence, None)
+    }
+
+    /// Withdraw consent for a corridor as `revocation` describes, moving
+    /// FPIC like `transition_fpic` and keeping the record in
+    /// `revocations`. The revoked grant's terms reference is added to the
+    /// record's `affected`, since tokens are bound to it.
+    pub fn revoke_fpic(
+        &mut self,
+        id: &CorridorId,
+        mut revocation: RevocationRecord,
+        evidence: FpicEvidence,
+    ) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        let current = &self.corridors[&id].fpic;
+        let to = revocation
+            .outcome(current)
+            .map_err(|e| format!("CorridorId '{}': {}", id, e))?;
+        if let FpicStatus::Granted {
+            terms_reference, ..
+        } = current
+        {
+            revocation.affected.insert(terms_reference.clone());
+        }
+        self.move_fpic(id, to, evidence, Some(revocation))
+    }
+
+    fn move_fpic(
+        &mut self,
+        id: CorridorId,
+        to: FpicStatus,
+        evidence: FpicEvidence,
+        revocation: Option<RevocationRecord>,
+    ) -> Result<(), String> {
+        let previous = self.corridors[&id].fpic.clone();
+        let mut fpic = previous.clone();
+        let transition = fpic
+            .transition(to, evidence)
+            .map_err(|e| format!("CorridorId '{}': {}", id, e))?;
+        // Hashed before the record leaves the map, so a failure changes nothing.
+        let prior_state_hash = self.corridors[&id].content_hash()?;
+        let mut record = Arc::unwrap_or_clone(self.take_record(&id).expect("canonical id"));
+        let (from, to) = (previous.kind(), fpic.kind());
+        let mut evidence_refs = vec![transition.evidence.reference.clone()];
+        let decision = match &revocation {
+            Some(r) => {
+                evidence_refs.extend(r.affected.iter().cloned());
+                GovernanceDecision::Revocation {
+                    scope: r.scope.clone(),
+                    from,
+                    to,
+                }
+            }
+            None => GovernanceDecision::FpicTransition { from, to },
+        };
+        Arc::make_mut(&mut self.audit).append(AuditEntry {
+            corridor_id: id.clone(),
+            actor: transition.evidence.actor.clone(),
+            at: transition.evidence.at,
+            decision,
+            evidence_refs,
+            prior_state_hash,
+        });
+        record.fpic = fpic.clone();
+        record.fpic_trail.push(transition);
+        record.revocations.extend(revocation);
+        record.signature = None;
+        self.insert_record(Arc::new(record));
+        self.subscribers.emit(MapEvent::FpicChanged {
+            id,
+            previous: Some(Box::new(previous)),
+            current: Box::new(fpic),
+        });
+        Ok(())
+    }
+
+    /// Replace a corridor's IDS scope, recording the decision in the
+    /// governance audit. `evidence.reference` names the decision record.
+    pub fn set_ids_scope(
+        &mut self,
+        id: &CorridorId,
+        scope: IdsScope,
+        evidence: FpicEvidence,
+    ) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        if evidence.actor.trim().is_empty() || evidence.reference.trim().is_empty() {
+            return Err(format!(
+                "CorridorId '{}': IDS decisions need an actor and a reference",
+                id
+            ));
+        }
+        let prior_state_hash = self.corridors[&id].content_hash()?;
+        let corridor = Arc::make_mut(
+            Arc::make_mut(&mut self.corridors)
+                .get_mut(&id)
+                .expect("canonical id is registered"),
+        );
+        Arc::make_mut(&mut self.audit).append(AuditEntry {
+            corridor_id: id.clone(),
+            actor: evidence.actor,
+            at: evidence.at,
+            decision: GovernanceDecision::IdsScopeChanged,
+            evidence_refs: vec![evidence.reference],
+            prior_state_hash,
+        });
+        corridor.ids_scope = scope;
+        corridor.signature = None;
+        let record = Arc::clone(&self.corridors[&id]);
+        self.subscribers.emit(MapEvent::CorridorUpserted(record));
+        Ok(())
+    }
+
+    /// Remove a corridor definition, returning it if it was present.
+    /// Aliases pointing at it are kept for audit but no longer resolve.
+    pub fn remove_corridor(&mut self, id: &CorridorId) -> Option<IndigenousEcoCorridor> {
+        let id = self.resolve(id)?.clone();
+        let removed = self.take_record(&id)?;
+        self.subscribers.emit(MapEvent::CorridorRemoved(id));
+        Some(Arc::unwrap_or_clone(removed))
+    }
+
+    /// Move the record at `from` (or what it resolves to) to the new id
+    /// `to`, leaving `from` as an alias so stored references keep
+    /// resolving. An existing alias named `to` is reclaimed.
+    pub fn rename_corridor(&mut self, from: &CorridorId, to: CorridorId) -> Result<(), String> {
+        let from = self.canonical(from)?;
+        if self.corridors.contains_key(&to) {
+            return Err(format!("CorridorId '{}' is already registered", to));
+        }
+        let mut record = Arc::unwrap_or_clone(self.take_record(&from).expect("canonical id"));
+        record.descriptor.id = to.clone();
+        record.signature = None;
+        let aliases = Arc::make_mut(&mut self.aliases);
+        aliases.remove(&to);
+        aliases.insert(from.clone(), to.clone());
+        self.insert_record(Arc::new(record));
+        self.subscribers
+            .emit(MapEvent::CorridorRenamed { from, to });
+        Ok(())
+    }
+
+    /// Record `alias` as another name for `target`, e.g. a legacy
+    /// spelling. `alias` must not be a registered corridor, and
+    /// repointing an existing alias must not close a cycle.
+    pub fn add_alias(&mut self, alias: CorridorId, target: &CorridorId) -> Result<(), String> {
+        self.canonical(target)?;
+        if self.corridors.contains_key(&alias) {
+            return Err(format!("CorridorId '{}' is already registered", alias));
+        }
+        if alias == *target {
+            return Err(format!("CorridorId '{}' cannot alias itself", alias));
+        }
+        if self.alias_chain(target).contains(&alias) {
+            return Err(format!(
+                "aliasing '{}' to '{}' would form a cycle",
+                alias, target
+            ));
+        }
+        Arc::make_mut(&mut self.aliases).insert(alias, target.clone());
+        Ok(())
     }
 
-    /// Retrieve a corridor by ID, if present.
+    /// Canonical id that `id` refers to, following aliases.
+    pub fn resolve(&self, id: &CorridorId) -> Option<&CorridorId> {
+        let mut current = id;
+        // Renames point at an id free at that time and `add_alias` refuses
+        // cycles, so chains are acyclic; the bound guards against
+        // hand-edited documents.
+        for _ in 0..=self.aliases.len() {
+            if let Some((canonical, _)) = self.corridors.get_key_value(current) {
+                return Some(canonical);
+            }
+            current = self.aliases.get(current)?;
+        }
+        None
+    }
+
+    /// `id` followed by every alias hop up to (and including) the
+    /// canonical id, for audit. A single element means `id` is canonical
+    /// or unknown.
+    pub fn alias_chain(&self, id: &CorridorId) -> Vec<CorridorId> {
+        let mut chain = vec![id.clone()];
+        let mut current = id;
+        while !self.corridors.contains_key(current) && chain.len() <= self.aliases.len() {
+            match self.aliases.get(current) {
+                Some(next) => {
+                    chain.push(next.clone());
+                    current = next;
+                }
+                None => break,
+            }
+        }
+        chain
+    }
+
+    /// Restore a persisted alias without validation; documents may list
+    /// chain links in any order.
+    pub(crate) fn restore_alias(&mut self, alias: CorridorId, target: CorridorId) {
+        Arc::make_mut(&mut self.aliases).insert(alias, target);
+    }
+
+    /// Restore a persisted governance audit, replacing the current one.
+    pub(crate) fn restore_audit(&mut self, audit: GovernanceAudit) {
+        self.audit = Arc::new(audit);
+    }
+
+    /// Every governance decision made through this map, oldest first.
+    pub fn governance_audit(&self) -> &GovernanceAudit {
+        &self.audit
+    }
+
+    /// Append a validated agreement; see `add_sharing_agreement`.
+    pub(crate) fn push_sharing_agreement(&mut self, agreement: DataSharingAgreement) {
+        Arc::make_mut(&mut self.sharing).push(agreement);
+    }
+
+    /// Restore persisted agreements without validation.
+    pub(crate) fn restore_sharing_agreements(&mut self, agreements: Vec<DataSharingAgreement>) {
+        self.sharing = Arc::new(agreements);
+    }
+
+    /// Registered data sharing agreements, in registration order.
+    pub fn sharing_agreements(&self) -> &[DataSharingAgreement] {
+        &self.sharing
+    }
+
+    /// All `(alias, target)` pairs.
+    pub fn aliases(&self) -> impl Iterator<Item = (&CorridorId, &CorridorId)> {
+        self.aliases.iter()
+    }
+
+    /// Retrieve a corridor by its stable UUID, if present.
+    pub fn get_by_uuid(&self, uuid: &Uuid) -> Option<&IndigenousEcoCorridor> {
+        self.get(self.by_uuid.get(uuid)?)
+    }
+
+    /// Retrieve a corridor by either identifier: `key` is tried as a
+    /// UUID first, then as a (normalized) CorridorId or alias.
+    pub fn find(&self, key: &str) -> Option<&IndigenousEcoCorridor> {
+        if let Ok(uuid) = Uuid::parse_str(key.trim()) {
+            if let Some(corridor) = self.get_by_uuid(&uuid) {
+                return Some(corridor);
+            }
+        }
+        self.get(&CorridorId::new(key).ok()?)
+    }
+
+    /// Retrieve a corridor by ID (or alias), if present.
     pub fn get(&self, id: &CorridorId) -> Option<&IndigenousEcoCorridor> {
-        self.corridors.get(id)
+        self.corridors.get(self.resolve(id)?).map(|c| &**c)
+    }
+
+    /// Corridors whose FPIC grant `community` consented to and which is
+    /// active at `now`, in `CorridorId` order.
+    pub fn corridors_for_community(
+        &self,
+        community: &CommunityId,
+        now: SystemTime,
+    ) -> impl Iterator<Item = &IndigenousEcoCorridor> {
+        self.by_community
+            .get(community)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.get(id))
+            .filter(move |c| c.has_active_fpic(now))
+    }
+
+    /// Incrementally maintained counts and score statistics.
+    pub fn aggregates(&self) -> &MapAggregates {
+        &self.aggregates
     }
 
     /// Iterate over all corridors (e.g., for monitoring or reporting).
     pub fn iter(&self) -> impl Iterator<Item = (&CorridorId, &IndigenousEcoCorridor)> {
-        self.corridors.iter()
+        self.corridors.iter().map(|(id, c)| (id, &**c))
     }
 
     /// Ensure that a corridor ID exists; overlays can call this to
     /// enforce "no missing corridor IDs" before any higher-level
-    /// governance logic runs.
+    /// governance logic runs. Draft and archived corridors exist too;
+    /// use `ensure_active` where only ratified corridors may be used.
     pub fn ensure_exists(&self, id: &CorridorId) -> Result<(), String> {
-        if self.corridors.contains_key(id) {
+        self.canonical(id).map(|_| ())
+    }
+
+    /// Like `ensure_exists`, but also require the corridor to be
+    /// `Lifecycle::Active`.
+    pub fn ensure_active(&self, id: &CorridorId) -> Result<(), String> {
+        let id = self.canonical(id)?;
+        match self.corridors[&id].lifecycle {
+            Lifecycle::Active => Ok(()),
+            other => Err(format!("CorridorId '{}' is {}, not active", id, other)),
+        }
+    }
+
+    fn canonical(&self, id: &CorridorId) -> Result<CorridorId, String> {
+        self.resolve(id)
+            .cloned()
+            .ok_or_else(|| format!("CorridorId '{}' is not registered", id))
+    }
+
+    /// Insert with aggregate and index upkeep; no events.
+    fn insert_record(
+        &mut self,
+        corridor: Arc<IndigenousEcoCorridor>,
+    ) -> Option<Arc<IndigenousEcoCorridor>> {
+        let previous = self.take_record(corridor.id());
+        Arc::make_mut(&mut self.aggregates).add(&corridor);
+        self.index_communities(&corridor);
+        if let Some(uuid) = corridor.descriptor.uuid {
+            Arc::make_mut(&mut self.by_uuid).insert(uuid, corridor.id().clone());
+        }
+        Arc::make_mut(&mut self.corridors).insert(corridor.id().clone(), corridor);
+        previous
+    }
+
+    /// Remove with aggregate and index upkeep; no events.
+    fn take_record(&mut self, id: &CorridorId) -> Option<Arc<IndigenousEcoCorridor>> {
+        if !self.corridors.contains_key(id) {
+            return None;
+        }
+        let removed = Arc::make_mut(&mut self.corridors).remove(id)?;
+        Arc::make_mut(&mut self.aggregates).remove(&removed);
+        self.unindex_communities(&removed);
+        if let Some(uuid) = removed.descriptor.uuid {
+            if self.by_uuid.get(&uuid) == Some(id) {
+                Arc::make_mut(&mut self.by_uuid).remove(&uuid);
+            }
+        }
+        Some(removed)
+    }
+
+    fn index_communities(&mut self, corridor: &IndigenousEcoCorridor) {
+        if let FpicStatus::Granted { quorum, .. } = &corridor.fpic {
+            let index = Arc::make_mut(&mut self.by_community);
+            for (community, _) in quorum.consents() {
+                index
+                    .entry(community.clone())
+                    .or_default()
+                    .insert(corridor.id().clone());
+            }
+        }
+    }
+
+    fn unindex_communities(&mut self, corridor: &IndigenousEcoCorridor) {
+        if let FpicStatus::Granted { quorum, .. } = &corridor.fpic {
+            let index = Arc::make_mut(&mut self.by_community);
+            for (community, _) in quorum.consents() {
+                if let Some(ids) = index.get_mut(community) {
+                    ids.remove(corridor.id());
+                    if ids.is_empty() {
+                        index.remove(community);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Receive a `MapEvent` for every later mutation of this map. Clones
+    /// and snapshots of the map do not inherit subscriptions; dropping the
+    /// receiver unsubscribes.
+    pub fn subscribe(&mut self) -> Receiver<MapEvent> {
+        self.subscribers.subscribe()
+    }
+
+    /// Freeze the current state for concurrent readers. Later writes to
+    /// this map do not affect the snapshot.
+    pub fn snapshot(&self) -> MapSnapshot {
+        MapSnapshot(self.clone())
+    }
+}
+
+/// Frozen, cheaply cloneable, read-only view of a corridor map.
+///
+/// Snapshots are `Send + Sync`, so a server can hand one to every
+/// request while a writer prepares the next version.
+#[derive(Clone, Default)]
+pub struct MapSnapshot(IndigenousEcoCorridorMap);
+
+impl Deref for MapSnapshot {
+    type Target = IndigenousEcoCorridorMap;
+
+    fn deref(&self) -> &IndigenousEcoCorridorMap {
+        &self.0
+    }
+}
+
+impl Validate for IndigenousEcoCorridor {
+    /// What `CorridorDescriptor::builder` enforces, for records
+    /// deserialized or built by struct literal. Scores, ids, quorums and
+    /// custom kinds already validate on deserialization.
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        match self.descriptor.validate() {
+            Ok(()) => Ok(()),
+            Err(e) => Err(vec![Finding::new("descriptor", e.to_string())]),
+        }
+    }
+}
+
+impl Validate for IndigenousEcoCorridorMap {
+    /// Findings of every corridor, under its id.
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let findings: Vec<Finding> = self
+            .iter()
+            .flat_map(|(id, c)| Finding::within(id.as_str(), c.validate()))
+            .collect();
+        if findings.is_empty() {
             Ok(())
         } else {
-            Err(format!("CorridorId '{}' is not registered", id))
+            Err(findings)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum, FpicPurpose, FpicStatusKind};
+    use crate::metrics::{MetricComponent, Score};
+    use crate::test_support::{self, uniform_metrics};
+
+    fn corridor(id: &str, score: f32, fpic: FpicStatus) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            environmental: uniform_metrics(score),
+            fpic,
+            ..test_support::corridor(id)
         }
     }
+
+    fn granted() -> FpicStatus {
+        FpicStatus::Granted {
+            at: SystemTime::now(),
+            quorum: ConsentQuorum::sole(
+                CommunityId("did:example:nation-x".into()),
+                Consent {
+                    at: SystemTime::now(),
+                    reference: "consent:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "ledger:terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        }
+    }
+
+    fn assert_consistent(map: &IndigenousEcoCorridorMap) {
+        let agg = map.aggregates();
+        let scores: Vec<f32> = map
+            .iter()
+            .map(|(_, c)| c.environmental.aggregate_score().get())
+            .collect();
+        assert_eq!(agg.corridor_count(), scores.len());
+        for kind in FpicStatusKind::ALL {
+            let expected = map.iter().filter(|(_, c)| c.fpic.kind() == kind).count();
+            assert_eq!(agg.fpic_count(kind), expected);
+        }
+        let strict = map
+            .iter()
+            .filter(|(_, c)| c.neurorights.is_strict())
+            .count();
+        assert_eq!(agg.strict_neurorights_count(), strict);
+        let summary = map.summary();
+        assert_eq!(summary.by_kind.values().sum::<usize>(), scores.len());
+        assert_eq!(summary.by_fpic_status.values().sum::<usize>(), scores.len());
+        let min = scores.iter().copied().reduce(f32::min);
+        let max = scores.iter().copied().reduce(f32::max);
+        assert_eq!(agg.min_score().map(Score::get), min);
+        assert_eq!(agg.max_score().map(Score::get), max);
+        match agg.mean_score() {
+            None => assert!(scores.is_empty()),
+            Some(mean) => {
+                let expected = scores.iter().sum::<f32>() / scores.len() as f32;
+                assert!((mean.get() - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn aggregates_track_upserts_and_removals() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        assert_consistent(&map);
+
+        map.upsert_corridor(corridor("eco:forest:a", 0.4, FpicStatus::Pending))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:forest:b", 0.9, granted()))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:forest:c", 0.6, FpicStatus::Pending))
+            .unwrap();
+        assert_consistent(&map);
+
+        // Replacement swaps both the score and the FPIC bucket.
+        map.upsert_corridor(corridor("eco:forest:a", 0.2, granted()))
+            .unwrap();
+        assert_consistent(&map);
+
+        map.remove_corridor(&CorridorId::new("eco:forest:b").unwrap());
+        assert_consistent(&map);
+        assert!(map
+            .remove_corridor(&CorridorId::new("eco:forest:missing").unwrap())
+            .is_none());
+
+        map.remove_corridor(&CorridorId::new("eco:forest:a").unwrap());
+        map.remove_corridor(&CorridorId::new("eco:forest:c").unwrap());
+        assert_consistent(&map);
+    }
+
+    #[test]
+    fn snapshots_are_isolated_from_later_writes() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:forest:a", 0.4, FpicStatus::Pending))
+            .unwrap();
+        let snapshot = map.snapshot();
+
+        let id = CorridorId::new("eco:forest:a").unwrap();
+        let lowered = EnvironmentalMetrics::from_components(
+            [Score::new(0.1).unwrap(); MetricComponent::COUNT],
+        );
+        map.update_metrics(&id, lowered).unwrap();
+        map.upsert_corridor(corridor("eco:forest:b", 0.9, granted()))
+            .unwrap();
+
+        assert_eq!(snapshot.aggregates().corridor_count(), 1);
+        assert_eq!(
+            snapshot
+                .get(&id)
+                .unwrap()
+                .environmental
+                .component(MetricComponent::SoilFertility),
+            Score::new(0.4).unwrap()
+        );
+        assert_eq!(map.aggregates().corridor_count(), 2);
+        assert_consistent(&map);
+        assert_consistent(&snapshot);
+
+        let reader = std::thread::spawn(move || snapshot.iter().count());
+        assert_eq!(reader.join().unwrap(), 1);
+    }
+
+    #[test]
+    fn community_index_follows_grants() {
+        let community = CommunityId("did:example:nation-x".into());
+        let ids = |map: &IndigenousEcoCorridorMap| -> Vec<String> {
+            map.corridors_for_community(&community, SystemTime::now())
+                .map(|c| c.id().as_str().to_string())
+                .collect()
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:forest:b", 0.5, granted()))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:forest:a", 0.5, granted()))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:forest:c", 0.5, FpicStatus::Pending))
+            .unwrap();
+        assert_eq!(ids(&map), ["eco:forest:a", "eco:forest:b"]);
+
+        let b = CorridorId::new("eco:forest:b").unwrap();
+        let evidence = FpicEvidence {
+            actor: "did:example:council".into(),
+            at: SystemTime::now(),
+            reference: "minutes:9".into(),
+        };
+        let suspended = FpicStatus::Suspended {
+            at: SystemTime::now(),
+            reason: "flooding".into(),
+        };
+        map.transition_fpic(&b, suspended, evidence.clone())
+            .unwrap();
+        assert_eq!(ids(&map), ["eco:forest:a"]);
+        assert_eq!(map.get(&b).unwrap().fpic_trail[0].evidence, evidence);
+        assert!(map
+            .transition_fpic(&b, FpicStatus::Pending, evidence)
+            .is_err());
+
+        let withheld = FpicStatus::Withheld {
+            at: SystemTime::now(),
+            reason: "revoked".into(),
+        };
+        map.upsert_corridor(corridor("eco:forest:a", 0.5, withheld))
+            .unwrap();
+        map.remove_corridor(&CorridorId::new("eco:forest:b").unwrap());
+        assert!(ids(&map).is_empty());
+        assert!(map.by_community.is_empty());
+    }
+
+    #[test]
+    fn subscribers_receive_mutations() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        let events = map.subscribe();
+        let id = CorridorId::new("eco:forest:a").unwrap();
+
+        map.upsert_corridor(corridor("eco:forest:a", 0.5, FpicStatus::Pending))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:forest:a", 0.6, FpicStatus::Pending))
+            .unwrap();
+        let mut scratch = map.clone();
+        scratch.remove_corridor(&id);
+        map.update_metrics(&id, map.get(&id).unwrap().environmental.clone())
+            .unwrap();
+        map.remove_corridor(&id);
+
+        let kinds: Vec<&str> = events
+            .try_iter()
+            .map(|e| match e {
+                MapEvent::CorridorUpserted(_) => "upserted",
+                MapEvent::FpicChanged { previous: None, .. } => "fpic-new",
+                MapEvent::FpicChanged { .. } => "fpic-changed",
+                MapEvent::MetricsUpdated { .. } => "metrics",
+                MapEvent::LifecycleChanged { .. } => "lifecycle",
+                MapEvent::CorridorRemoved(_) => "removed",
+                MapEvent::CorridorRenamed { .. } => "renamed",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            ["fpic-new", "upserted", "upserted", "metrics", "removed"]
+        );
+
+        drop(events);
+        map.upsert_corridor(corridor("eco:forest:b", 0.5, granted()))
+            .unwrap();
+        assert!(map.subscribers.is_empty());
+    }
+
+    #[test]
+    fn renamed_ids_resolve_through_alias_chain() {
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:river:old", 0.5, granted()))
+            .unwrap();
+        map.rename_corridor(&id("eco:river:old"), id("eco:river:mid"))
+            .unwrap();
+        map.rename_corridor(&id("eco:river:mid"), id("eco:river:native"))
+            .unwrap();
+
+        let record = map.get(&id("eco:river:old")).unwrap();
+        assert_eq!(record.id(), &id("eco:river:native"));
+        assert!(map.ensure_exists(&id("eco:river:mid")).is_ok());
+        assert_eq!(
+            map.alias_chain(&id("eco:river:old")),
+            [
+                id("eco:river:old"),
+                id("eco:river:mid"),
+                id("eco:river:native")
+            ]
+        );
+        assert_eq!(map.iter().count(), 1);
+        assert_consistent(&map);
+
+        // Renaming back reclaims the alias without creating a cycle.
+        map.rename_corridor(&id("eco:river:native"), id("eco:river:old"))
+            .unwrap();
+        assert_eq!(
+            map.alias_chain(&id("eco:river:mid")),
+            [
+                id("eco:river:mid"),
+                id("eco:river:native"),
+                id("eco:river:old")
+            ]
+        );
+        assert!(map
+            .rename_corridor(&id("eco:river:gone"), id("eco:river:x"))
+            .is_err());
+    }
+
+    #[test]
+    fn aliases_cannot_close_a_cycle() {
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:river:old", 0.5, granted()))
+            .unwrap();
+        map.rename_corridor(&id("eco:river:old"), id("eco:river:native"))
+            .unwrap();
+        map.add_alias(id("eco:river:legacy"), &id("eco:river:old"))
+            .unwrap();
+
+        // old -> native is the live link; pointing old at legacy would
+        // loop old -> legacy -> old and strand both names.
+        let err = map
+            .add_alias(id("eco:river:old"), &id("eco:river:legacy"))
+            .unwrap_err();
+        assert!(err.contains("cycle"), "{}", err);
+        assert_eq!(
+            map.resolve(&id("eco:river:legacy")),
+            Some(&id("eco:river:native"))
+        );
+        assert!(map
+            .add_alias(id("eco:river:self"), &id("eco:river:self"))
+            .is_err());
+
+        // Repointing without a cycle is still allowed.
+        map.add_alias(id("eco:river:old"), &id("eco:river:native"))
+            .unwrap();
+        assert_consistent(&map);
+    }
+
+    #[test]
+    fn uuids_belong_to_one_corridor() {
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let uuid = Uuid::from_u128(0x2024);
+        let with_uuid = |s: &str, score: f32| {
+            let mut record = corridor(s, score, FpicStatus::Pending);
+            record.descriptor.uuid = Some(uuid);
+            record
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(with_uuid("eco:river:a", 0.5)).unwrap();
+        map.upsert_corridor(with_uuid("eco:river:a", 0.6)).unwrap();
+
+        let err = map
+            .upsert_corridor(with_uuid("eco:river:b", 0.7))
+            .unwrap_err();
+        assert!(err.contains("eco:river:a"), "{}", err);
+        assert!(map.get(&id("eco:river:b")).is_none());
+        assert_eq!(map.get_by_uuid(&uuid).unwrap().id(), &id("eco:river:a"));
+        assert_consistent(&map);
+
+        // Renaming moves the uuid with the record.
+        map.rename_corridor(&id("eco:river:a"), id("eco:river:c"))
+            .unwrap();
+        assert_eq!(map.get_by_uuid(&uuid).unwrap().id(), &id("eco:river:c"));
+        assert!(map.upsert_corridor(with_uuid("eco:river:a", 0.7)).is_err());
+    }
+
+    #[test]
+    fn lifecycle_transitions_are_validated() {
+        let id = CorridorId::new("eco:forest:draft").unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        let mut record = corridor("eco:forest:draft", 0.5, FpicStatus::Pending);
+        record.lifecycle = Lifecycle::Draft;
+        map.upsert_corridor(record).unwrap();
+        let events = map.subscribe();
+
+        assert!(map.ensure_exists(&id).is_ok());
+        assert!(map.ensure_active(&id).is_err());
+        assert!(map.set_lifecycle(&id, Lifecycle::UnderReview).is_err());
+        map.set_lifecycle(&id, Lifecycle::Active).unwrap();
+        assert!(map.ensure_active(&id).is_ok());
+        map.set_lifecycle(&id, Lifecycle::Archived).unwrap();
+        assert!(map.set_lifecycle(&id, Lifecycle::Active).is_err());
+        assert!(map.set_lifecycle(&id, Lifecycle::Draft).is_err());
+        map.set_lifecycle(&id, Lifecycle::UnderReview).unwrap();
+
+        let changes: Vec<Lifecycle> = events
+            .try_iter()
+            .filter_map(|e| match e {
+                MapEvent::LifecycleChanged { current, .. } => Some(current),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                Lifecycle::Active,
+                Lifecycle::Archived,
+                Lifecycle::UnderReview
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_reports_descriptor_problems_by_corridor() {
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:river:a", 0.5, FpicStatus::Pending))
+            .unwrap();
+        assert_eq!(map.validate(), Ok(()));
+
+        let mut unnamed = corridor("eco:river:b", 0.5, FpicStatus::Pending);
+        unnamed.descriptor.name = " ".into();
+        map.upsert_corridor(unnamed).unwrap();
+        let findings = map.validate().unwrap_err();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, "eco:river:b.descriptor");
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn schema_follows_serde_conversions() {
+        let schema = serde_json::to_value(schemars::schema_for!(IndigenousEcoCorridor)).unwrap();
+        let defs = &schema["$defs"];
+        assert_eq!(defs["CorridorId"]["type"], "string");
+        assert_eq!(defs["Score"]["anyOf"][0]["type"], "number");
+        assert_eq!(defs["Polygon"]["required"][0], "exterior");
+    }
 }
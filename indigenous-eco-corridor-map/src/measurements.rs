@@ -0,0 +1,191 @@
+//! Raw field measurements and their normalization into scores.
+//! Instruments report nitrate in mg/L, turbidity in NTU and so on; the
+//! mapping from those readings to 0–1 metric scores lives here rather
+//! than in a spreadsheet beside the survey.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::Score;
+
+fn non_negative(value: f64, what: &str) -> Result<f64, String> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(format!("{} must be finite and non-negative", what));
+    }
+    Ok(value)
+}
+
+fn score(value: f64) -> Score {
+    Score::exact(value.clamp(0.0, 1.0) as f32)
+}
+
+/// Nitrate as nitrogen, in mg/L.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct NitrateMgL(f64);
+
+impl NitrateMgL {
+    /// Concentration scoring zero: the 10 mg/L drinking-water limit.
+    pub const LIMIT: f64 = 10.0;
+
+    pub fn new(value: f64) -> Result<Self, String> {
+        non_negative(value, "nitrate").map(Self)
+    }
+
+    /// 1.0 at zero, falling linearly to 0.0 at `LIMIT` and beyond.
+    pub fn normalize(self) -> Score {
+        score(1.0 - self.0 / Self::LIMIT)
+    }
+}
+
+/// Turbidity in nephelometric turbidity units.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct TurbidityNtu(f64);
+
+impl TurbidityNtu {
+    /// Turbidity scoring 0.5.
+    pub const HALF_SCORE: f64 = 10.0;
+
+    pub fn new(value: f64) -> Result<Self, String> {
+        non_negative(value, "turbidity").map(Self)
+    }
+
+    /// 1.0 at zero, halving at `HALF_SCORE` and approaching 0.0 for
+    /// very turbid water; storm pulses stay distinguishable.
+    pub fn normalize(self) -> Score {
+        score(Self::HALF_SCORE / (Self::HALF_SCORE + self.0))
+    }
+}
+
+/// Soil organic matter as a percentage of dry mass.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct OrganicMatterPercent(f64);
+
+impl OrganicMatterPercent {
+    /// Organic matter at and above which fertility scores 1.0.
+    pub const SATURATION: f64 = 5.0;
+
+    pub fn new(value: f64) -> Result<Self, String> {
+        let value = non_negative(value, "organic matter")?;
+        if value > 100.0 {
+            return Err("organic matter cannot exceed 100%".into());
+        }
+        Ok(Self(value))
+    }
+
+    /// Rises linearly from 0.0 to 1.0 at `SATURATION`.
+    pub fn normalize(self) -> Score {
+        score(self.0 / Self::SATURATION)
+    }
+}
+
+/// Shannon diversity index H′ (natural log).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct ShannonIndex(f64);
+
+impl ShannonIndex {
+    /// H′ at and above which diversity scores 1.0; few field
+    /// communities exceed it.
+    pub const REFERENCE: f64 = 3.5;
+
+    pub fn new(value: f64) -> Result<Self, String> {
+        non_negative(value, "Shannon index").map(Self)
+    }
+
+    /// Rises linearly from 0.0 to 1.0 at `REFERENCE`.
+    pub fn normalize(self) -> Score {
+        score(self.0 / Self::REFERENCE)
+    }
+
+    /// Pielou evenness H′ / ln(S) for `richness` S taxa, which does not
+    /// depend on a reference value; `None` below two taxa.
+    pub fn evenness(self, richness: usize) -> Option<Score> {
+        (richness >= 2).then(|| score(self.0 / (richness as f64).ln()))
+    }
+}
+
+macro_rules! measurement_conversions {
+    ($($unit:ident => $symbol:literal),*) => {$(
+        impl $unit {
+            pub fn get(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl TryFrom<f64> for $unit {
+            type Error = String;
+
+            fn try_from(value: f64) -> Result<Self, String> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$unit> for f64 {
+            fn from(value: $unit) -> f64 {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}", self.0, $symbol)
+            }
+        }
+    )*};
+}
+
+measurement_conversions!(
+    NitrateMgL => " mg/L",
+    TurbidityNtu => " NTU",
+    OrganicMatterPercent => "%",
+    ShannonIndex => " H′"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readings_normalize_into_scores() {
+        let nitrate = |v| NitrateMgL::new(v).unwrap().normalize().get();
+        assert_eq!(nitrate(0.0), 1.0);
+        assert!((nitrate(2.5) - 0.75).abs() < 1e-6);
+        assert_eq!(nitrate(40.0), 0.0);
+
+        let turbidity = |v| TurbidityNtu::new(v).unwrap().normalize().get();
+        assert_eq!(turbidity(0.0), 1.0);
+        assert_eq!(turbidity(10.0), 0.5);
+        assert!(turbidity(300.0) > 0.0 && turbidity(300.0) < turbidity(100.0));
+
+        assert_eq!(
+            OrganicMatterPercent::new(2.5).unwrap().normalize().get(),
+            0.5
+        );
+        assert_eq!(
+            OrganicMatterPercent::new(12.0).unwrap().normalize().get(),
+            1.0
+        );
+        assert!(OrganicMatterPercent::new(101.0).is_err());
+
+        let shannon = ShannonIndex::new(4.0_f64.ln()).unwrap();
+        assert!((shannon.evenness(4).unwrap().get() - 1.0).abs() < 1e-6);
+        assert_eq!(shannon.evenness(1), None);
+        assert!(shannon.normalize().get() < 1.0);
+
+        assert!(NitrateMgL::new(-0.1).is_err());
+        assert!(TurbidityNtu::new(f64::NAN).is_err());
+        assert_eq!(TurbidityNtu::new(4.0).unwrap().to_string(), "4 NTU");
+        assert!(serde_json::from_str::<NitrateMgL>("-2").is_err());
+        assert_eq!(serde_json::from_str::<NitrateMgL>("3").unwrap().get(), 3.0);
+    }
+}
@@ -3,72 +3,553 @@
 
 #![forbid(unsafe_code)]
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::aggregation::FlatMean;
+use crate::provenance::Provenance;
+use crate::quality::DataQuality;
+
 /// Normalized scalar in [0.0, 1.0].
 /// 1.0 = best ecological integrity / least harm.
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Score(f32);
+///
+/// A score may carry bounds (e.g. value +/- standard error) saying how
+/// well it is known. Exact scores serialize as a bare number, bounded
+/// ones as `{"value", "low", "high"}`.
+///
+/// NaN is rejected, so scores are totally ordered (by value, then by
+/// bounds) and can key a `BTreeMap` or be sorted directly.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawScore", into = "RawScore")]
+pub struct Score {
+    value: f32,
+    bounds: Option<(f32, f32)>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum RawScore {
+    Exact(f32),
+    Bounded { value: f32, low: f32, high: f32 },
+}
+
+impl TryFrom<RawScore> for Score {
+    type Error = String;
+
+    fn try_from(raw: RawScore) -> Result<Self, String> {
+        match raw {
+            RawScore::Exact(value) => Score::new(value),
+            RawScore::Bounded { value, low, high } => Score::with_bounds(value, low, high),
+        }
+    }
+}
+
+impl From<Score> for RawScore {
+    fn from(score: Score) -> Self {
+        match score.bounds {
+            None => RawScore::Exact(score.value),
+            Some((low, high)) => RawScore::Bounded {
+                value: score.value,
+                low,
+                high,
+            },
+        }
+    }
+}
 
 impl Score {
     pub fn new(value: f32) -> Result<Self, String> {
+        if value.is_nan() {
+            return Err("Score must not be NaN".into());
+        }
         if !(0.0..=1.0).contains(&value) {
             return Err("Score must be within [0.0, 1.0]".into());
         }
-        Ok(Self(value))
+        Ok(Self::exact(value))
+    }
+
+    /// A score known to lie within `[low, high]`, all within [0.0, 1.0].
+    pub fn with_bounds(value: f32, low: f32, high: f32) -> Result<Self, String> {
+        Score::new(value)?;
+        if !(0.0..=1.0).contains(&low) || !(0.0..=1.0).contains(&high) {
+            return Err("Score bounds must be within [0.0, 1.0]".into());
+        }
+        if !(low <= value && value <= high) {
+            return Err("Score bounds must satisfy low <= value <= high".into());
+        }
+        Ok(Self {
+            value: value + 0.0,
+            bounds: Some((low + 0.0, high + 0.0)),
+        })
+    }
+
+    /// `value` +/- `stderr`, with the bounds clipped to [0.0, 1.0].
+    pub fn with_stderr(value: f32, stderr: f32) -> Result<Self, String> {
+        if !stderr.is_finite() || stderr < 0.0 {
+            return Err("Score standard error must be finite and non-negative".into());
+        }
+        Score::new(value)?;
+        Score::with_bounds(value, (value - stderr).max(0.0), (value + stderr).min(1.0))
+    }
+
+    /// Unchecked; callers guarantee `value` is within [0.0, 1.0].
+    pub(crate) const fn exact(value: f32) -> Self {
+        Self {
+            // Adding zero turns -0.0 into 0.0, keeping `Eq` and `Ord`
+            // consistent.
+            value: value + 0.0,
+            bounds: None,
+        }
     }
 
     pub fn get(self) -> f32 {
-        self.0
+        self.value
+    }
+
+    /// `(low, high)`, or `None` for an exact score.
+    pub fn bounds(self) -> Option<(f32, f32)> {
+        self.bounds
+    }
+
+    /// Lower bound; the value itself for an exact score.
+    pub fn low(self) -> f32 {
+        self.bounds.map_or(self.value, |(low, _)| low)
+    }
+
+    /// Upper bound; the value itself for an exact score.
+    pub fn high(self) -> f32 {
+        self.bounds.map_or(self.value, |(_, high)| high)
+    }
+
+    /// `high - low`; 0.0 for an exact score. A wide interval marks a
+    /// poorly sampled score rather than a confidently bad one.
+    pub fn width(self) -> f32 {
+        self.high() - self.low()
+    }
+
+    /// `self + other`, capped at 1.0; bounds add bound by bound.
+    pub fn saturating_add(self, other: Score) -> Score {
+        let value = (self.value + other.value).clamp(0.0, 1.0);
+        self.with_combined_bounds(
+            value,
+            self.low() + other.low(),
+            self.high() + other.high(),
+            other,
+        )
+    }
+
+    /// `self - other`, floored at 0.0; the low bound subtracts the other
+    /// score's high bound and vice versa.
+    pub fn saturating_sub(self, other: Score) -> Score {
+        let value = (self.value - other.value).clamp(0.0, 1.0);
+        self.with_combined_bounds(
+            value,
+            self.low() - other.high(),
+            self.high() - other.low(),
+            other,
+        )
+    }
+
+    /// Exact if both operands are; otherwise bounded by `low`..`high`
+    /// clamped and widened to include `value`.
+    fn with_combined_bounds(self, value: f32, low: f32, high: f32, other: Score) -> Score {
+        if self.bounds.is_none() && other.bounds.is_none() {
+            return Score::exact(value);
+        }
+        let low = low.clamp(0.0, 1.0).min(value);
+        let high = high.clamp(0.0, 1.0).max(value);
+        Score::with_bounds(value, low, high).expect("clamped bounds enclose the value")
+    }
+}
+
+impl Eq for Score {}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let bounds = |s: &Score| s.bounds.map(|(low, high)| (OrdF32(low), OrdF32(high)));
+        OrdF32(self.value)
+            .cmp(&OrdF32(other.value))
+            .then_with(|| bounds(self).cmp(&bounds(other)))
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::hash::Hash for Score {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.to_bits().hash(state);
+        self.bounds
+            .map(|(low, high)| (low.to_bits(), high.to_bits()))
+            .hash(state);
+    }
+}
+
+/// `f32` under `total_cmp`, for the non-NaN, non-negative-zero values a
+/// `Score` holds.
+#[derive(PartialEq)]
+struct OrdF32(f32);
+
+impl Eq for OrdF32 {}
+
+impl PartialOrd for OrdF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdF32 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
     }
 }
 
 /// Soil health metrics.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SoilMetrics {
     pub fertility: Score,
     pub erosion_risk: Score,
     pub contamination: Score,
+    /// Boxed to keep metrics small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Box<Provenance>>,
 }
 
 /// Water system metrics.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WaterMetrics {
     pub quality: Score,
     pub flow_resilience: Score,
     pub ecological_flow_support: Score,
+    /// Boxed to keep metrics small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Box<Provenance>>,
 }
 
 /// Microbiome / biodiversity metrics.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MicrobiomeMetrics {
     pub diversity: Score,
     pub keystone_presence: Score,
     pub disturbance_resilience: Score,
+    /// Boxed to keep metrics small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Box<Provenance>>,
+}
+
+/// Air quality metrics, for corridors monitored for smoke.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AirMetrics {
+    /// Fine particulates (PM2.5/PM10); 1.0 = clean air.
+    pub particulates: Score,
+    /// Ozone or ozone-precursor proxy; 1.0 = background levels.
+    pub ozone: Score,
+    /// Boxed to keep metrics small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Box<Provenance>>,
+}
+
+/// Carbon stock metrics, for corridors in carbon programs.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CarbonMetrics {
+    pub soil_carbon: Score,
+    /// Above-ground biomass proxy, e.g. from canopy surveys.
+    pub biomass: Score,
+    /// Boxed to keep metrics small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Box<Provenance>>,
 }
 
 /// Composite environmental metrics for a corridor.
-#[derive(Clone, Debug)]
+///
+/// Soil, water, and microbiome are always present and make up the
+/// `MetricComponent`s; air and carbon are monitored only where a program
+/// needs them and are not part of the aggregate score.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EnvironmentalMetrics {
     pub soil: SoilMetrics,
     pub water: WaterMetrics,
     pub microbiome: MicrobiomeMetrics,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub air: Option<AirMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carbon: Option<CarbonMetrics>,
+    /// Components that were not measured. Their stored scores are
+    /// placeholders: aggregation skips or imputes them (see
+    /// `Imputation`) and threshold checks ignore them.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub unmeasured: BTreeSet<MetricComponent>,
+    /// Quality flag per component; components without one are
+    /// unlabelled.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub quality: BTreeMap<MetricComponent, DataQuality>,
 }
 
 impl EnvironmentalMetrics {
-    /// Simple aggregate score; overlays may refine this.
+    /// Simple aggregate score; overlays may refine this with an
+    /// `AggregationStrategy` (see `aggregate_with`). Bounded components
+    /// give a bounded aggregate.
     pub fn aggregate_score(&self) -> Score {
-        let components = [
-            self.soil.fertility.get(),
-            self.soil.erosion_risk.get(),
-            self.soil.contamination.get(),
-            self.water.quality.get(),
-            self.water.flow_resilience.get(),
-            self.water.ecological_flow_support.get(),
-            self.microbiome.diversity.get(),
-            self.microbiome.keystone_presence.get(),
-            self.microbiome.disturbance_resilience.get(),
-        ];
-        let mean = components.iter().sum::<f32>() / components.len() as f32;
-        // Safe because each component is within [0,1].
-        Score(mean)
+        self.aggregate_with(&FlatMean)
+    }
+}
+
+/// Individual metric components, in a fixed, documented order.
+/// Used for per-component access (columnar storage, reports).
+/// Serializes as its `key()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MetricComponent {
+    SoilFertility,
+    SoilErosionRisk,
+    SoilContamination,
+    WaterQuality,
+    WaterFlowResilience,
+    WaterEcologicalFlowSupport,
+    MicrobiomeDiversity,
+    MicrobiomeKeystonePresence,
+    MicrobiomeDisturbanceResilience,
+}
+
+impl MetricComponent {
+    /// Number of components.
+    pub const COUNT: usize = 9;
+
+    /// All components in canonical order.
+    pub const ALL: [MetricComponent; MetricComponent::COUNT] = [
+        MetricComponent::SoilFertility,
+        MetricComponent::SoilErosionRisk,
+        MetricComponent::SoilContamination,
+        MetricComponent::WaterQuality,
+        MetricComponent::WaterFlowResilience,
+        MetricComponent::WaterEcologicalFlowSupport,
+        MetricComponent::MicrobiomeDiversity,
+        MetricComponent::MicrobiomeKeystonePresence,
+        MetricComponent::MicrobiomeDisturbanceResilience,
+    ];
+
+    /// Position of this component in `ALL`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Stable snake_case key used in interchange formats (GeoJSON, CSV).
+    pub fn key(self) -> &'static str {
+        match self {
+            MetricComponent::SoilFertility => "soil_fertility",
+            MetricComponent::SoilErosionRisk => "soil_erosion_risk",
+            MetricComponent::SoilContamination => "soil_contamination",
+            MetricComponent::WaterQuality => "water_quality",
+            MetricComponent::WaterFlowResilience => "water_flow_resilience",
+            MetricComponent::WaterEcologicalFlowSupport => "water_ecological_flow_support",
+            MetricComponent::MicrobiomeDiversity => "microbiome_diversity",
+            MetricComponent::MicrobiomeKeystonePresence => "microbiome_keystone_presence",
+            MetricComponent::MicrobiomeDisturbanceResilience => "microbiome_disturbance_resilience",
+        }
+    }
+
+    /// Inverse of `key()`.
+    pub fn from_key(key: &str) -> Option<Self> {
+        MetricComponent::ALL.into_iter().find(|c| c.key() == key)
+    }
+}
+
+impl EnvironmentalMetrics {
+    /// Read a single component; a placeholder if it is unmeasured.
+    pub fn component(&self, component: MetricComponent) -> Score {
+        match component {
+            MetricComponent::SoilFertility => self.soil.fertility,
+            MetricComponent::SoilErosionRisk => self.soil.erosion_risk,
+            MetricComponent::SoilContamination => self.soil.contamination,
+            MetricComponent::WaterQuality => self.water.quality,
+            MetricComponent::WaterFlowResilience => self.water.flow_resilience,
+            MetricComponent::WaterEcologicalFlowSupport => self.water.ecological_flow_support,
+            MetricComponent::MicrobiomeDiversity => self.microbiome.diversity,
+            MetricComponent::MicrobiomeKeystonePresence => self.microbiome.keystone_presence,
+            MetricComponent::MicrobiomeDisturbanceResilience => {
+                self.microbiome.disturbance_resilience
+            }
+        }
+    }
+
+    /// Build metrics from component values in `MetricComponent::ALL` order,
+    /// without provenance, air, or carbon metrics.
+    pub fn from_components(values: [Score; MetricComponent::COUNT]) -> Self {
+        Self {
+            soil: SoilMetrics {
+                fertility: values[0],
+                erosion_risk: values[1],
+                contamination: values[2],
+                provenance: None,
+            },
+            water: WaterMetrics {
+                quality: values[3],
+                flow_resilience: values[4],
+                ecological_flow_support: values[5],
+                provenance: None,
+            },
+            microbiome: MicrobiomeMetrics {
+                diversity: values[6],
+                keystone_presence: values[7],
+                disturbance_resilience: values[8],
+                provenance: None,
+            },
+            air: None,
+            carbon: None,
+            unmeasured: BTreeSet::new(),
+            quality: BTreeMap::new(),
+        }
+    }
+
+    /// A component's score, or `None` if it was not measured.
+    pub fn measured(&self, component: MetricComponent) -> Option<Score> {
+        (!self.unmeasured.contains(&component)).then(|| self.component(component))
+    }
+
+    /// Set a component and mark it measured; any quality flag for the
+    /// old value is cleared.
+    pub fn set_component(&mut self, component: MetricComponent, score: Score) {
+        let slot = match component {
+            MetricComponent::SoilFertility => &mut self.soil.fertility,
+            MetricComponent::SoilErosionRisk => &mut self.soil.erosion_risk,
+            MetricComponent::SoilContamination => &mut self.soil.contamination,
+            MetricComponent::WaterQuality => &mut self.water.quality,
+            MetricComponent::WaterFlowResilience => &mut self.water.flow_resilience,
+            MetricComponent::WaterEcologicalFlowSupport => &mut self.water.ecological_flow_support,
+            MetricComponent::MicrobiomeDiversity => &mut self.microbiome.diversity,
+            MetricComponent::MicrobiomeKeystonePresence => &mut self.microbiome.keystone_presence,
+            MetricComponent::MicrobiomeDisturbanceResilience => {
+                &mut self.microbiome.disturbance_resilience
+            }
+        };
+        *slot = score;
+        self.unmeasured.remove(&component);
+        self.quality.remove(&component);
+    }
+
+    /// Share of the nine components that were measured.
+    pub fn completeness(&self) -> Score {
+        let measured = MetricComponent::COUNT - self.unmeasured.len();
+        Score::exact(measured as f32 / MetricComponent::COUNT as f32)
+    }
+}
+
+/// Components of the optional air and carbon groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ExtendedComponent {
+    AirParticulates,
+    AirOzone,
+    CarbonSoil,
+    CarbonBiomass,
+}
+
+impl ExtendedComponent {
+    pub const ALL: [ExtendedComponent; 4] = [
+        ExtendedComponent::AirParticulates,
+        ExtendedComponent::AirOzone,
+        ExtendedComponent::CarbonSoil,
+        ExtendedComponent::CarbonBiomass,
+    ];
+
+    /// Stable snake_case key used in interchange formats.
+    pub fn key(self) -> &'static str {
+        match self {
+            ExtendedComponent::AirParticulates => "air_particulates",
+            ExtendedComponent::AirOzone => "air_ozone",
+            ExtendedComponent::CarbonSoil => "carbon_soil",
+            ExtendedComponent::CarbonBiomass => "carbon_biomass",
+        }
+    }
+
+    /// Inverse of `key()`.
+    pub fn from_key(key: &str) -> Option<Self> {
+        ExtendedComponent::ALL.into_iter().find(|c| c.key() == key)
+    }
+}
+
+impl EnvironmentalMetrics {
+    /// Read an air or carbon component; `None` if its group is not
+    /// monitored.
+    pub fn extended(&self, component: ExtendedComponent) -> Option<Score> {
+        match component {
+            ExtendedComponent::AirParticulates => self.air.as_ref().map(|a| a.particulates),
+            ExtendedComponent::AirOzone => self.air.as_ref().map(|a| a.ozone),
+            ExtendedComponent::CarbonSoil => self.carbon.as_ref().map(|c| c.soil_carbon),
+            ExtendedComponent::CarbonBiomass => self.carbon.as_ref().map(|c| c.biomass),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn air_and_carbon_are_optional_groups() {
+        let mut metrics = EnvironmentalMetrics::from_components(
+            [Score::new(0.5).unwrap(); MetricComponent::COUNT],
+        );
+        let core = serde_json::to_value(&metrics).unwrap();
+        assert!(core.get("air").is_none());
+        assert_eq!(metrics.extended(ExtendedComponent::AirOzone), None);
+
+        metrics.air = Some(AirMetrics {
+            particulates: Score::new(0.3).unwrap(),
+            ozone: Score::new(0.9).unwrap(),
+            provenance: None,
+        });
+        let before = metrics.aggregate_score();
+        let json = serde_json::to_value(&metrics).unwrap();
+        let parsed: EnvironmentalMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            parsed.extended(ExtendedComponent::AirParticulates),
+            Some(Score::new(0.3).unwrap())
+        );
+        assert_eq!(parsed.extended(ExtendedComponent::CarbonSoil), None);
+        assert_eq!(parsed.aggregate_score(), before);
+        assert_eq!(
+            ExtendedComponent::from_key("carbon_biomass"),
+            Some(ExtendedComponent::CarbonBiomass)
+        );
+    }
+
+    #[test]
+    fn scores_reject_nan_and_order_totally() {
+        assert!(Score::new(f32::NAN).is_err());
+        assert!(Score::with_bounds(0.5, f32::NAN, 0.6).is_err());
+        assert!(serde_json::from_str::<Score>("NaN").is_err());
+
+        let s = |v| Score::new(v).unwrap();
+        assert_eq!(s(-0.0), s(0.0));
+        assert_eq!(s(-0.0).cmp(&s(0.0)), std::cmp::Ordering::Equal);
+        let bounded = Score::with_bounds(0.5, 0.4, 0.6).unwrap();
+        let mut sorted = vec![s(0.9), bounded, s(0.5), s(0.1)];
+        sorted.sort();
+        assert_eq!(sorted, [s(0.1), s(0.5), bounded, s(0.9)]);
+        let keyed: std::collections::BTreeMap<Score, &str> =
+            [(s(0.5), "exact"), (bounded, "bounded")].into();
+        assert_eq!(keyed.len(), 2);
+
+        assert_eq!(s(0.7).saturating_add(s(0.6)), s(1.0));
+        assert_eq!(s(0.2).saturating_sub(s(0.5)), s(0.0));
+        let sum = bounded.saturating_add(s(0.25));
+        assert_eq!((sum.get(), sum.low(), sum.high()), (0.75, 0.65, 0.85));
+        let diff = bounded.saturating_sub(Score::with_bounds(0.1, 0.0, 0.2).unwrap());
+        assert!((diff.low() - 0.2).abs() < 1e-6 && (diff.high() - 0.6).abs() < 1e-6);
     }
 }
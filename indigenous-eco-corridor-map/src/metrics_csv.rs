@@ -0,0 +1,190 @@
+//! CSV bulk import/export of environmental metrics.
+//! Updates observational metrics only; never creates corridors or
+//! touches consent and neurorights fields.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{Read, Write};
+
+use crate::corridor::CorridorId;
+use crate::map::IndigenousEcoCorridorMap;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// Name of the key column; the remaining columns are `MetricComponent::key()`s.
+pub const CORRIDOR_ID_COLUMN: &str = "corridor_id";
+
+/// Validation failure for a single CSV data row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowError {
+    /// 1-based line number in the input, header included.
+    pub line: u64,
+    pub corridor_id: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.corridor_id {
+            Some(id) => write!(f, "line {} ('{}'): {}", self.line, id, self.message),
+            None => write!(f, "line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+/// Errors raised by metric CSV import/export.
+#[derive(Debug)]
+pub enum MetricsCsvError {
+    /// Malformed CSV or I/O failure.
+    Csv(String),
+    /// The header is missing the id column or a metric column.
+    MissingColumn(String),
+    /// One or more rows failed validation; no metrics were updated.
+    Rows(Vec<RowError>),
+}
+
+impl fmt::Display for MetricsCsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricsCsvError::Csv(e) => write!(f, "CSV error: {}", e),
+            MetricsCsvError::MissingColumn(c) => write!(f, "CSV header missing column '{}'", c),
+            MetricsCsvError::Rows(errors) => {
+                write!(f, "{} row(s) rejected", errors.len())?;
+                for e in errors {
+                    write!(f, "; {}", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for MetricsCsvError {}
+
+impl From<csv::Error> for MetricsCsvError {
+    fn from(e: csv::Error) -> Self {
+        MetricsCsvError::Csv(e.to_string())
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Refresh metrics for existing corridors from CSV.
+    ///
+    /// The header must contain `corridor_id` plus one column per
+    /// `MetricComponent::key()` (in any order; extra columns are ignored).
+    /// An empty cell marks the component unmeasured.
+    /// Every row is validated first: unknown corridors, duplicate ids,
+    /// and out-of-range scores are all reported, and nothing is applied
+    /// unless every row is valid. Returns the number of corridors updated.
+    pub fn import_metrics_csv<R: Read>(&mut self, reader: R) -> Result<usize, MetricsCsvError> {
+        let mut rows = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = rows.headers()?.clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| MetricsCsvError::MissingColumn(name.to_string()))
+        };
+        let id_column = column(CORRIDOR_ID_COLUMN)?;
+        let mut metric_columns = [0usize; MetricComponent::COUNT];
+        for component in MetricComponent::ALL {
+            metric_columns[component.index()] = column(component.key())?;
+        }
+
+        let mut updates: Vec<(CorridorId, EnvironmentalMetrics)> = Vec::new();
+        let mut seen = BTreeSet::new();
+        let mut errors = Vec::new();
+        for record in rows.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line()).unwrap_or(0);
+            let raw_id = record.get(id_column).unwrap_or_default().to_string();
+            let fail = |message: String| RowError {
+                line,
+                corridor_id: (!raw_id.is_empty()).then(|| raw_id.clone()),
+                message,
+            };
+
+            let id = match CorridorId::new(&raw_id) {
+                Ok(id) => id,
+                Err(e) => {
+                    errors.push(fail(e));
+                    continue;
+                }
+            };
+            if let Err(e) = self.ensure_exists(&id) {
+                errors.push(fail(e));
+                continue;
+            }
+            if !seen.insert(id.clone()) {
+                errors.push(fail("duplicate row for corridor".into()));
+                continue;
+            }
+
+            let mut values = [Score::exact(0.0); MetricComponent::COUNT];
+            let mut unmeasured = BTreeSet::new();
+            let mut row_ok = true;
+            for component in MetricComponent::ALL {
+                let raw = record
+                    .get(metric_columns[component.index()])
+                    .unwrap_or_default();
+                if raw.trim().is_empty() {
+                    unmeasured.insert(component);
+                    continue;
+                }
+                let parsed = raw
+                    .parse::<f32>()
+                    .map_err(|_| format!("'{}': '{}' is not a number", component.key(), raw))
+                    .and_then(|v| {
+                        Score::new(v).map_err(|e| format!("'{}': {}", component.key(), e))
+                    });
+                match parsed {
+                    Ok(score) => values[component.index()] = score,
+                    Err(e) => {
+                        errors.push(fail(e));
+                        row_ok = false;
+                    }
+                }
+            }
+            if row_ok {
+                let mut metrics = EnvironmentalMetrics::from_components(values);
+                metrics.unmeasured = unmeasured;
+                updates.push((id, metrics));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(MetricsCsvError::Rows(errors));
+        }
+        let updated = updates.len();
+        for (id, metrics) in updates {
+            self.update_metrics(&id, metrics)
+                .expect("corridor existence checked during validation");
+        }
+        Ok(updated)
+    }
+
+    /// Write every corridor's metrics as CSV, in the format
+    /// `import_metrics_csv` accepts; unmeasured components are empty.
+    pub fn export_metrics_csv<W: Write>(&self, writer: W) -> Result<(), MetricsCsvError> {
+        let mut out = csv::Writer::from_writer(writer);
+        let mut header = vec![CORRIDOR_ID_COLUMN];
+        header.extend(MetricComponent::ALL.map(MetricComponent::key));
+        out.write_record(&header)?;
+        for (id, corridor) in self.iter() {
+            let mut row = vec![id.as_str().to_string()];
+            row.extend(MetricComponent::ALL.map(|c| {
+                corridor
+                    .environmental
+                    .measured(c)
+                    .map_or(String::new(), |score| score.get().to_string())
+            }));
+            out.write_record(&row)?;
+        }
+        out.flush()
+            .map_err(|e| MetricsCsvError::Csv(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Conversions between `IndigenousEcoCorridor` and the morpheus
+//! eco-corridor-core `IndigenousEcoCorridorRecord` (feature `morpheus`),
+//! so systems using both crates share one adapter.
+//!
+//! Metrics, FPIC status and neurorights are the canonical
+//! `corridor-types` ones, converted as `crate::canonical` documents; a
+//! record's `Withheld` is dated at its last update. Beyond those:
+//!
+//! - Identity: both use the canonical `CorridorId`. A record has no kind or
+//!   name; corridors converted from records get the custom kind
+//!   `morpheus:unclassified` and their id as name.
+//! - Records carry mandatory provenance and a cultural knowledge reference
+//!   that corridors lack, so `to_record` takes the provenance and neither
+//!   reference survives conversion. IDS scope, cultural sites, seasons
+//!   and signatures have no counterpart in records.
+
+#![forbid(unsafe_code)]
+
+use eco_corridor_core as morpheus;
+
+use crate::canonical::ConversionError;
+use crate::corridor::{CorridorDescriptor, CorridorKind, PhysicalAttributes};
+use crate::governance::{FpicStatus, IdsFramework, IdsScope};
+use crate::lifecycle::Lifecycle;
+use crate::map::IndigenousEcoCorridor;
+use crate::metrics::EnvironmentalMetrics;
+use crate::neurorights::NeurorightsConstraints;
+use crate::taxonomy::CustomKind;
+
+impl TryFrom<&morpheus::IndigenousEcoCorridorRecord> for IndigenousEcoCorridor {
+    type Error = ConversionError;
+
+    fn try_from(record: &morpheus::IndigenousEcoCorridorRecord) -> Result<Self, Self::Error> {
+        let environmental = EnvironmentalMetrics::try_from(&record.eco_impact)?;
+        let fpic = FpicStatus::from_canonical(&record.fpic_status, record.provenance.updated_at)?;
+        Ok(IndigenousEcoCorridor {
+            descriptor: CorridorDescriptor {
+                id: record.corridor_id.clone(),
+                uuid: None,
+                kind: CorridorKind::Custom(
+                    CustomKind::new("morpheus", "unclassified", "Unclassified")
+                        .expect("static custom kind is valid"),
+                ),
+                name: record.corridor_id.to_string(),
+                description: None,
+                boundary: None,
+                physical: PhysicalAttributes::default(),
+                relationships: Vec::new(),
+            },
+            environmental,
+            fpic,
+            fpic_trail: Vec::new(),
+            revocations: Vec::new(),
+            ids_scope: IdsScope {
+                contains_indigenous_data: true,
+                frameworks: vec![IdsFramework::Unspecified],
+                governance_ref: None,
+                embargo: None,
+            },
+            neurorights: NeurorightsConstraints::from(&record.neurorights),
+            cultural_notes: None,
+            cultural_sites: Vec::new(),
+            lifecycle: Lifecycle::Active,
+            seasons: Vec::new(),
+            signature: None,
+        })
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// The morpheus record for this corridor, asserted under
+    /// `provenance`; see the module docs for what is kept.
+    pub fn to_record(
+        &self,
+        provenance: morpheus::Provenance,
+    ) -> morpheus::IndigenousEcoCorridorRecord {
+        morpheus::IndigenousEcoCorridorRecord::new(
+            self.descriptor.id.clone(),
+            (&self.environmental).into(),
+            (&self.fpic).into(),
+            (&self.neurorights).into(),
+            None,
+            provenance,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum, FpicPurpose};
+    use std::collections::BTreeSet;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn records_round_trip_through_corridors() {
+        let record = morpheus::IndigenousEcoCorridorRecord::new(
+            "did:example:corridor:gila-river".parse().unwrap(),
+            morpheus::EcoImpactMetrics::new(0.75, 0.5, 0.25, 0.9),
+            morpheus::FpicStatus::Withheld {
+                reason: "council vote".into(),
+            },
+            morpheus::NeurorightsConstraints::strict_floor(),
+            None,
+            morpheus::Provenance::new("did:example:steward", SystemTime::UNIX_EPOCH, None),
+        );
+        let corridor = IndigenousEcoCorridor::try_from(&record).unwrap();
+        assert_eq!(
+            corridor.neurorights,
+            NeurorightsConstraints::strict_non_actuating()
+        );
+        assert_eq!(corridor.environmental.aggregate_score().get(), 0.5);
+
+        let back = corridor.to_record(record.provenance.clone());
+        assert_eq!(back.corridor_id, record.corridor_id);
+        assert_eq!(back.eco_impact.water_score, 0.5);
+        assert_eq!(back.eco_impact.biodiversity_score, 0.25);
+        assert!(matches!(
+            back.fpic_status,
+            morpheus::FpicStatus::Withheld { ref reason } if reason == "council vote"
+        ));
+        assert!(back.neurorights.mental_privacy_protection);
+
+        let mut granted_here = corridor;
+        granted_here.fpic = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(60)),
+            renewal_notice: None,
+            terms: None,
+        };
+        let fpic = granted_here
+            .to_record(record.provenance.clone())
+            .fpic_status;
+        let start = morpheus::Timestamp::UNIX_EPOCH;
+        assert!(fpic.permits(start, FpicPurpose::Monitoring));
+        assert!(!fpic.is_active_grant(start + Duration::from_secs(60)));
+
+        let granted = morpheus::IndigenousEcoCorridorRecord {
+            fpic_status: morpheus::FpicStatus::Granted(morpheus::FpicGrant::new(
+                "aln:consent:1",
+                [FpicPurpose::Monitoring],
+            )),
+            ..record
+        };
+        assert!(matches!(
+            IndigenousEcoCorridor::try_from(&granted),
+            Err(ConversionError::GrantWithoutQuorum { .. })
+        ));
+    }
+}
@@ -1,38 +1,819 @@
-//! Neurorights constraints as non-actuating flags.
-//! Designed to be used as mandatory preconditions by overlays.
+//! Neurorights constraints as a machine-readable, non-actuating policy.
+//! Designed to be used as mandatory preconditions by overlays: an overlay
+//! declares its capabilities and the policy reports every violation.
 
 #![forbid(unsafe_code)]
 
-/// How this corridor may be used in relation to neuromorphic systems.
-#[derive(Clone, Debug)]
-pub struct NeurorightsConstraints {
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::attestation::Attestation;
+
+/// What an overlay may infer from observations in a corridor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InferenceCategory {
+    /// Condition of soil, water, air and habitat.
+    EcologicalState,
+    /// Which species are present and in what abundance.
+    SpeciesPresence,
+    /// Patterns across many people, never tied to an individual.
+    AggregateBehavior,
+    /// Behavior of an identifiable person.
+    IndividualBehavior,
+    /// Emotional or affective state of a person.
+    EmotionalState,
+    /// Cognitive or neural state of a person.
+    CognitiveState,
+}
+
+impl InferenceCategory {
+    pub const ALL: [InferenceCategory; 6] = [
+        InferenceCategory::EcologicalState,
+        InferenceCategory::SpeciesPresence,
+        InferenceCategory::AggregateBehavior,
+        InferenceCategory::IndividualBehavior,
+        InferenceCategory::EmotionalState,
+        InferenceCategory::CognitiveState,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            InferenceCategory::EcologicalState => "ecological_state",
+            InferenceCategory::SpeciesPresence => "species_presence",
+            InferenceCategory::AggregateBehavior => "aggregate_behavior",
+            InferenceCategory::IndividualBehavior => "individual_behavior",
+            InferenceCategory::EmotionalState => "emotional_state",
+            InferenceCategory::CognitiveState => "cognitive_state",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.key() == key.trim())
+    }
+}
+
+impl fmt::Display for InferenceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Whether systems touching the corridor may act on it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ActuationConstraint {
     /// True if any neuromorphic / behavioral system that touches this
     /// corridor must remain observational (non-actuating) with respect
     /// to community members and species in the corridor.
     pub non_actuating_required: bool,
+}
 
+/// What may be inferred, and through which channels.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InferenceConstraint {
+    /// Categories an overlay may infer; anything else is a violation.
+    pub allowed: BTreeSet<InferenceCategory>,
     /// True if covert behavioral inference or coercive channels are
     /// categorically disallowed for this corridor.
     pub no_coercive_or_hidden_channels: bool,
+}
+
+/// Conditions on FEAR/PAIN-like discipline signals.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DisciplineSignalConstraint {
+    /// True if such signals must be explicitly declared as voluntary
+    /// discipline channels and never used as levers for behavioral
+    /// control.
+    pub voluntary_only: bool,
+    /// True if an overlay using such signals must cite the consent that
+    /// binds them (e.g. an FPIC terms reference).
+    pub consent_ref_required: bool,
+}
+
+/// Biometric processing of corridor-linked data, e.g. camera traps and
+/// community footage that capture people.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BiometricConstraint {
+    /// True if sorting people into categories (age, ethnicity, gender,
+    /// ...) from their biometric data is disallowed.
+    pub no_biometric_categorization: bool,
+    /// True if recognizing emotions from faces, voices or bodies is
+    /// disallowed.
+    pub no_emotion_recognition: bool,
+}
 
-    /// True if any FEAR/PAIN-like signals must be explicitly declared
-    /// as voluntary, consent-bound discipline channels and never used
-    /// as levers for behavioral control.
-    pub discipline_signals_voluntary_only: bool,
+/// How long an overlay may keep what it observed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RetentionConstraint {
+    /// Longest retention allowed; `None` sets no limit.
+    pub limit: Option<Duration>,
+}
+
+/// How this corridor may be used in relation to neuromorphic systems.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NeurorightsConstraints {
+    pub actuation: ActuationConstraint,
+    pub inference: InferenceConstraint,
+    pub discipline_signals: DisciplineSignalConstraint,
+    /// Absent in records written before it existed, which set no
+    /// biometric constraints.
+    #[serde(default)]
+    pub biometrics: BiometricConstraint,
+    pub retention: RetentionConstraint,
 
     /// Optional reference (e.g., hash/URI) to a TREEEnvelope /
     /// HIT Governance Object / SNC shard describing this corridor's
     /// biophysical and neurorights envelope.
     pub envelope_ref: Option<String>,
+    /// Binds this capsule to the document `envelope_ref` names; see
+    /// `verify_attestation`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<Attestation>,
+
+    /// Revision of the policy; 0 for one never formally adopted.
+    #[serde(default)]
+    pub version: u32,
+    /// DID of whoever adopted this version, e.g. a council.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_at: Option<SystemTime>,
+}
+
+/// How far an overlay intends to go beyond observing.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ActuationIntent {
+    /// Observes only.
+    #[default]
+    Observe,
+    /// Recommends actions that people decide on.
+    Advise,
+    /// Acts on people, species or the corridor itself.
+    Actuate,
+}
+
+/// Ways an overlay feeds signals back to the people it observes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackChannel {
+    /// Information such as maps, reports and alerts.
+    Informational,
+    /// FEAR/PAIN-like discipline signals.
+    DisciplineSignal,
+    /// Persuasion or belief-shaping.
+    Persuasion,
+}
+
+impl FeedbackChannel {
+    pub const ALL: [FeedbackChannel; 3] = [
+        FeedbackChannel::Informational,
+        FeedbackChannel::DisciplineSignal,
+        FeedbackChannel::Persuasion,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            FeedbackChannel::Informational => "informational",
+            FeedbackChannel::DisciplineSignal => "discipline_signal",
+            FeedbackChannel::Persuasion => "persuasion",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.key() == key.trim())
+    }
+}
+
+impl fmt::Display for FeedbackChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// What an overlay states it will do in a corridor, checked against the
+/// corridor's `NeurorightsConstraints` before it runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DeclaredCapabilities {
+    pub actuation: ActuationIntent,
+    /// The overlay uses channels the observed cannot see or refuse.
+    pub hidden_channels: bool,
+    pub inferences: BTreeSet<InferenceCategory>,
+    pub feedback: BTreeSet<FeedbackChannel>,
+    /// Discipline signals are voluntary for everyone who receives them.
+    pub discipline_signals_voluntary: bool,
+    /// The overlay sorts people into categories from biometric data.
+    pub biometric_categorization: bool,
+    /// The overlay recognizes emotions from faces, voices or bodies.
+    pub emotion_recognition: bool,
+    /// Consent the overlay operates under, e.g. an FPIC terms reference.
+    pub consent_ref: Option<String>,
+    /// How long observations are kept; `None` means indefinitely.
+    pub retention: Option<Duration>,
+}
+
+/// One way declared capabilities break a corridor's policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NeurorightsViolation {
+    Actuating,
+    HiddenChannels,
+    /// Persuasion is a coercive channel.
+    CoerciveFeedback(FeedbackChannel),
+    InferenceNotAllowed(InferenceCategory),
+    InvoluntaryDisciplineSignals,
+    MissingConsentReference,
+    BiometricCategorization,
+    EmotionRecognition,
+    /// Retention exceeds `limit`; `declared` is `None` for indefinite.
+    RetentionExceeded {
+        declared: Option<Duration>,
+        limit: Duration,
+    },
+}
+
+impl fmt::Display for NeurorightsViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeurorightsViolation::Actuating => f.write_str("actuation is not allowed"),
+            NeurorightsViolation::HiddenChannels => {
+                f.write_str("coercive or hidden channels are not allowed")
+            }
+            NeurorightsViolation::CoerciveFeedback(channel) => {
+                write!(f, "{} feedback is coercive and not allowed", channel)
+            }
+            NeurorightsViolation::InferenceNotAllowed(category) => {
+                write!(f, "inference of {} is not allowed", category)
+            }
+            NeurorightsViolation::InvoluntaryDisciplineSignals => {
+                f.write_str("discipline signals must be voluntary")
+            }
+            NeurorightsViolation::MissingConsentReference => {
+                f.write_str("discipline signals need a consent reference")
+            }
+            NeurorightsViolation::BiometricCategorization => {
+                f.write_str("biometric categorization is not allowed")
+            }
+            NeurorightsViolation::EmotionRecognition => {
+                f.write_str("emotion recognition is not allowed")
+            }
+            NeurorightsViolation::RetentionExceeded { declared, limit } => match declared {
+                Some(declared) => write!(
+                    f,
+                    "retention of {}s exceeds the {}s limit",
+                    declared.as_secs(),
+                    limit.as_secs()
+                ),
+                None => write!(
+                    f,
+                    "indefinite retention exceeds the {}s limit",
+                    limit.as_secs()
+                ),
+            },
+        }
+    }
+}
+
+/// One way a policy is weaker than the one it would replace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NeurorightsWeakening {
+    ActuationAllowed,
+    HiddenChannelsAllowed,
+    InferenceAllowed(InferenceCategory),
+    InvoluntaryDisciplineSignalsAllowed,
+    ConsentReferenceDropped,
+    BiometricCategorizationAllowed,
+    EmotionRecognitionAllowed,
+    /// `None` is unlimited retention.
+    RetentionExtended {
+        from: Option<Duration>,
+        to: Option<Duration>,
+    },
+}
+
+impl fmt::Display for NeurorightsWeakening {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeurorightsWeakening::ActuationAllowed => f.write_str("actuation becomes allowed"),
+            NeurorightsWeakening::HiddenChannelsAllowed => {
+                f.write_str("coercive or hidden channels become allowed")
+            }
+            NeurorightsWeakening::InferenceAllowed(category) => {
+                write!(f, "inference of {} becomes allowed", category)
+            }
+            NeurorightsWeakening::InvoluntaryDisciplineSignalsAllowed => {
+                f.write_str("involuntary discipline signals become allowed")
+            }
+            NeurorightsWeakening::ConsentReferenceDropped => {
+                f.write_str("discipline signals no longer need a consent reference")
+            }
+            NeurorightsWeakening::BiometricCategorizationAllowed => {
+                f.write_str("biometric categorization becomes allowed")
+            }
+            NeurorightsWeakening::EmotionRecognitionAllowed => {
+                f.write_str("emotion recognition becomes allowed")
+            }
+            NeurorightsWeakening::RetentionExtended { from, to } => {
+                let limit = |l: &Option<Duration>| match l {
+                    Some(l) => format!("{}s", l.as_secs()),
+                    None => "unlimited".to_string(),
+                };
+                write!(
+                    f,
+                    "retention limit extended from {} to {}",
+                    limit(from),
+                    limit(to)
+                )
+            }
+        }
+    }
+}
+
+/// How an accepted policy update relates to the version it replaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeurorightsUpdate {
+    /// At least one constraint got stricter.
+    Tightened,
+    /// Constraints are as strict as before; e.g. only the envelope
+    /// reference changed.
+    Equivalent,
+}
+
+/// Why a policy cannot replace the current version as a normal update.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NeurorightsUpdateError {
+    /// Versions must strictly increase.
+    NotNewer { current: u32, proposed: u32 },
+    /// The proposal weakens the current policy. A weakening is a
+    /// governance decision of its own, never a routine revision.
+    Weakened(Vec<NeurorightsWeakening>),
+}
+
+impl fmt::Display for NeurorightsUpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeurorightsUpdateError::NotNewer { current, proposed } => write!(
+                f,
+                "neurorights policy version {} does not follow version {}",
+                proposed, current
+            ),
+            NeurorightsUpdateError::Weakened(weakenings) => {
+                f.write_str("neurorights policy update weakens the policy")?;
+                for w in weakenings {
+                    write!(f, "; {}", w)?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
+impl std::error::Error for NeurorightsUpdateError {}
+
 impl NeurorightsConstraints {
+    /// Every constraint at its strictest. Only ecological and species
+    /// inferences are allowed; retention is left unlimited.
     pub fn strict_non_actuating() -> Self {
         Self {
-            non_actuating_required: true,
-            no_coercive_or_hidden_channels: true,
-            discipline_signals_voluntary_only: true,
+            actuation: ActuationConstraint {
+                non_actuating_required: true,
+            },
+            inference: InferenceConstraint {
+                allowed: BTreeSet::from([
+                    InferenceCategory::EcologicalState,
+                    InferenceCategory::SpeciesPresence,
+                ]),
+                no_coercive_or_hidden_channels: true,
+            },
+            discipline_signals: DisciplineSignalConstraint {
+                voluntary_only: true,
+                consent_ref_required: true,
+            },
+            biometrics: BiometricConstraint {
+                no_biometric_categorization: true,
+                no_emotion_recognition: true,
+            },
+            retention: RetentionConstraint::default(),
+            envelope_ref: None,
+            attestation: None,
+            version: 0,
+            author: None,
+            adopted_at: None,
+        }
+    }
+
+    /// A policy that forbids nothing: every inference is allowed and no
+    /// requirement is set. Useful as the weakest floor.
+    pub fn unconstrained() -> Self {
+        Self {
+            actuation: ActuationConstraint {
+                non_actuating_required: false,
+            },
+            inference: InferenceConstraint {
+                allowed: InferenceCategory::ALL.into_iter().collect(),
+                no_coercive_or_hidden_channels: false,
+            },
+            discipline_signals: DisciplineSignalConstraint {
+                voluntary_only: false,
+                consent_ref_required: false,
+            },
+            biometrics: BiometricConstraint::default(),
+            retention: RetentionConstraint::default(),
             envelope_ref: None,
+            attestation: None,
+            version: 0,
+            author: None,
+            adopted_at: None,
+        }
+    }
+
+    /// True if the non-actuating, no-hidden-channel and voluntary-signal
+    /// requirements are all set, as in `strict_non_actuating()`.
+    pub fn is_strict(&self) -> bool {
+        self.actuation.non_actuating_required
+            && self.inference.no_coercive_or_hidden_channels
+            && self.discipline_signals.voluntary_only
+    }
+
+    /// Every way `capabilities` break this policy, in constraint order;
+    /// empty if the overlay may run. Advising is not actuating.
+    pub fn evaluate(&self, capabilities: &DeclaredCapabilities) -> Vec<NeurorightsViolation> {
+        let mut violations = Vec::new();
+        if self.actuation.non_actuating_required
+            && capabilities.actuation == ActuationIntent::Actuate
+        {
+            violations.push(NeurorightsViolation::Actuating);
+        }
+        if self.inference.no_coercive_or_hidden_channels {
+            if capabilities.hidden_channels {
+                violations.push(NeurorightsViolation::HiddenChannels);
+            }
+            if capabilities.feedback.contains(&FeedbackChannel::Persuasion) {
+                violations.push(NeurorightsViolation::CoerciveFeedback(
+                    FeedbackChannel::Persuasion,
+                ));
+            }
+        }
+        violations.extend(
+            capabilities
+                .inferences
+                .difference(&self.inference.allowed)
+                .map(|c| NeurorightsViolation::InferenceNotAllowed(*c)),
+        );
+        if capabilities
+            .feedback
+            .contains(&FeedbackChannel::DisciplineSignal)
+        {
+            if self.discipline_signals.voluntary_only && !capabilities.discipline_signals_voluntary
+            {
+                violations.push(NeurorightsViolation::InvoluntaryDisciplineSignals);
+            }
+            if self.discipline_signals.consent_ref_required && capabilities.consent_ref.is_none() {
+                violations.push(NeurorightsViolation::MissingConsentReference);
+            }
+        }
+        if self.biometrics.no_biometric_categorization && capabilities.biometric_categorization {
+            violations.push(NeurorightsViolation::BiometricCategorization);
+        }
+        if self.biometrics.no_emotion_recognition && capabilities.emotion_recognition {
+            violations.push(NeurorightsViolation::EmotionRecognition);
+        }
+        if let Some(limit) = self.retention.limit {
+            if capabilities
+                .retention
+                .is_none_or(|declared| declared > limit)
+            {
+                violations.push(NeurorightsViolation::RetentionExceeded {
+                    declared: capabilities.retention,
+                    limit,
+                });
+            }
+        }
+        violations
+    }
+
+    /// Combine two policies so the result is at least as strict as
+    /// either: every requirement set in one is set, only categories both
+    /// allow stay allowed, and the shorter retention limit applies. The
+    /// envelope reference is `self`'s, or `other`'s if `self` has none.
+    /// The result was never adopted as such, so it is unversioned and
+    /// unattested.
+    pub fn merge_strictest(&self, other: &NeurorightsConstraints) -> NeurorightsConstraints {
+        let retention = match (self.retention.limit, other.retention.limit) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        Self {
+            actuation: ActuationConstraint {
+                non_actuating_required: self.actuation.non_actuating_required
+                    || other.actuation.non_actuating_required,
+            },
+            inference: InferenceConstraint {
+                allowed: self
+                    .inference
+                    .allowed
+                    .intersection(&other.inference.allowed)
+                    .copied()
+                    .collect(),
+                no_coercive_or_hidden_channels: self.inference.no_coercive_or_hidden_channels
+                    || other.inference.no_coercive_or_hidden_channels,
+            },
+            discipline_signals: DisciplineSignalConstraint {
+                voluntary_only: self.discipline_signals.voluntary_only
+                    || other.discipline_signals.voluntary_only,
+                consent_ref_required: self.discipline_signals.consent_ref_required
+                    || other.discipline_signals.consent_ref_required,
+            },
+            biometrics: BiometricConstraint {
+                no_biometric_categorization: self.biometrics.no_biometric_categorization
+                    || other.biometrics.no_biometric_categorization,
+                no_emotion_recognition: self.biometrics.no_emotion_recognition
+                    || other.biometrics.no_emotion_recognition,
+            },
+            retention: RetentionConstraint { limit: retention },
+            envelope_ref: self
+                .envelope_ref
+                .clone()
+                .or_else(|| other.envelope_ref.clone()),
+            attestation: None,
+            version: 0,
+            author: None,
+            adopted_at: None,
+        }
+    }
+
+    /// Compose a corridor `floor` with overlays such as a community
+    /// policy and a project-specific one, strictest wins. Earlier layers
+    /// take precedence for the envelope reference.
+    pub fn compose<'a>(
+        floor: &NeurorightsConstraints,
+        overlays: impl IntoIterator<Item = &'a NeurorightsConstraints>,
+    ) -> NeurorightsConstraints {
+        overlays.into_iter().fold(floor.clone(), |policy, overlay| {
+            policy.merge_strictest(overlay)
+        })
+    }
+
+    /// True if this policy forbids everything `other` forbids, i.e.
+    /// replacing `other` with it is not a downgrade.
+    pub fn is_at_least_as_strict_as(&self, other: &NeurorightsConstraints) -> bool {
+        other.weakenings_to(self).is_empty()
+    }
+
+    /// Every way `next` is weaker than this policy, in constraint order.
+    pub fn weakenings_to(&self, next: &NeurorightsConstraints) -> Vec<NeurorightsWeakening> {
+        let mut weakenings = Vec::new();
+        if self.actuation.non_actuating_required && !next.actuation.non_actuating_required {
+            weakenings.push(NeurorightsWeakening::ActuationAllowed);
+        }
+        if self.inference.no_coercive_or_hidden_channels
+            && !next.inference.no_coercive_or_hidden_channels
+        {
+            weakenings.push(NeurorightsWeakening::HiddenChannelsAllowed);
+        }
+        weakenings.extend(
+            next.inference
+                .allowed
+                .difference(&self.inference.allowed)
+                .map(|c| NeurorightsWeakening::InferenceAllowed(*c)),
+        );
+        if self.discipline_signals.voluntary_only && !next.discipline_signals.voluntary_only {
+            weakenings.push(NeurorightsWeakening::InvoluntaryDisciplineSignalsAllowed);
+        }
+        if self.discipline_signals.consent_ref_required
+            && !next.discipline_signals.consent_ref_required
+        {
+            weakenings.push(NeurorightsWeakening::ConsentReferenceDropped);
+        }
+        if self.biometrics.no_biometric_categorization
+            && !next.biometrics.no_biometric_categorization
+        {
+            weakenings.push(NeurorightsWeakening::BiometricCategorizationAllowed);
+        }
+        if self.biometrics.no_emotion_recognition && !next.biometrics.no_emotion_recognition {
+            weakenings.push(NeurorightsWeakening::EmotionRecognitionAllowed);
+        }
+        let extended = match (self.retention.limit, next.retention.limit) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(current), Some(proposed)) => proposed > current,
+        };
+        if extended {
+            weakenings.push(NeurorightsWeakening::RetentionExtended {
+                from: self.retention.limit,
+                to: next.retention.limit,
+            });
+        }
+        weakenings
+    }
+
+    /// Check that `next` may replace this policy as a normal update: its
+    /// version is higher and it weakens nothing.
+    pub fn update_to(
+        &self,
+        next: &NeurorightsConstraints,
+    ) -> Result<NeurorightsUpdate, NeurorightsUpdateError> {
+        if next.version <= self.version {
+            return Err(NeurorightsUpdateError::NotNewer {
+                current: self.version,
+                proposed: next.version,
+            });
+        }
+        let weakenings = self.weakenings_to(next);
+        if !weakenings.is_empty() {
+            return Err(NeurorightsUpdateError::Weakened(weakenings));
+        }
+        if self.is_at_least_as_strict_as(next) {
+            Ok(NeurorightsUpdate::Equivalent)
+        } else {
+            Ok(NeurorightsUpdate::Tightened)
+        }
+    }
+
+    /// `Ok` if `capabilities` break none of the constraints, otherwise
+    /// every violation; see `evaluate`.
+    pub fn permits(
+        &self,
+        capabilities: &DeclaredCapabilities,
+    ) -> Result<(), Vec<NeurorightsViolation>> {
+        let violations = self.evaluate(capabilities);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluation_reports_each_violated_constraint() {
+        let mut policy = NeurorightsConstraints::strict_non_actuating();
+        let observer = DeclaredCapabilities {
+            inferences: BTreeSet::from([InferenceCategory::SpeciesPresence]),
+            ..DeclaredCapabilities::default()
+        };
+        assert_eq!(policy.permits(&observer), Ok(()));
+        let advisor = DeclaredCapabilities {
+            actuation: ActuationIntent::Advise,
+            feedback: BTreeSet::from([FeedbackChannel::Informational]),
+            ..observer.clone()
+        };
+        assert_eq!(policy.permits(&advisor), Ok(()));
+
+        let overreaching = DeclaredCapabilities {
+            actuation: ActuationIntent::Actuate,
+            inferences: BTreeSet::from([
+                InferenceCategory::EcologicalState,
+                InferenceCategory::EmotionalState,
+            ]),
+            feedback: BTreeSet::from([
+                FeedbackChannel::DisciplineSignal,
+                FeedbackChannel::Persuasion,
+            ]),
+            discipline_signals_voluntary: true,
+            ..DeclaredCapabilities::default()
+        };
+        assert_eq!(
+            policy.permits(&overreaching).unwrap_err(),
+            vec![
+                NeurorightsViolation::Actuating,
+                NeurorightsViolation::CoerciveFeedback(FeedbackChannel::Persuasion),
+                NeurorightsViolation::InferenceNotAllowed(InferenceCategory::EmotionalState),
+                NeurorightsViolation::MissingConsentReference,
+            ]
+        );
+
+        let day = Duration::from_secs(86_400);
+        policy.retention.limit = Some(day);
+        assert_eq!(
+            policy.evaluate(&observer)[0].to_string(),
+            "indefinite retention exceeds the 86400s limit"
+        );
+        let brief = DeclaredCapabilities {
+            retention: Some(day),
+            ..observer
+        };
+        assert_eq!(policy.permits(&brief), Ok(()));
+
+        let camera_trap = DeclaredCapabilities {
+            biometric_categorization: true,
+            emotion_recognition: true,
+            ..brief
+        };
+        assert_eq!(
+            policy.permits(&camera_trap).unwrap_err(),
+            vec![
+                NeurorightsViolation::BiometricCategorization,
+                NeurorightsViolation::EmotionRecognition,
+            ]
+        );
+        let mut permissive = policy.clone();
+        permissive.biometrics = BiometricConstraint::default();
+        assert_eq!(
+            policy.weakenings_to(&permissive),
+            vec![
+                NeurorightsWeakening::BiometricCategorizationAllowed,
+                NeurorightsWeakening::EmotionRecognitionAllowed,
+            ]
+        );
+        assert_eq!(
+            InferenceCategory::from_key("cognitive_state"),
+            Some(InferenceCategory::CognitiveState)
+        );
+    }
+
+    #[test]
+    fn composition_is_never_weaker_than_any_layer() {
+        let floor = NeurorightsConstraints::strict_non_actuating();
+        let mut community = NeurorightsConstraints::strict_non_actuating();
+        community.actuation.non_actuating_required = false;
+        community.inference.allowed = BTreeSet::from([InferenceCategory::SpeciesPresence]);
+        community.retention.limit = Some(Duration::from_secs(3600));
+        let mut project = NeurorightsConstraints::strict_non_actuating();
+        project.inference.allowed = InferenceCategory::ALL.into_iter().collect();
+        project.retention.limit = Some(Duration::from_secs(60));
+        project.envelope_ref = Some("envelope:project".into());
+
+        let policy = NeurorightsConstraints::compose(&floor, [&community, &project]);
+        for layer in [&floor, &community, &project] {
+            assert!(policy.is_at_least_as_strict_as(layer));
+        }
+        assert!(policy.actuation.non_actuating_required);
+        assert_eq!(
+            policy.inference.allowed,
+            BTreeSet::from([InferenceCategory::SpeciesPresence])
+        );
+        assert_eq!(policy.retention.limit, Some(Duration::from_secs(60)));
+        assert_eq!(policy.envelope_ref.as_deref(), Some("envelope:project"));
+        assert!(!community.is_at_least_as_strict_as(&floor));
+        assert!(!floor.is_at_least_as_strict_as(&project));
+    }
+
+    #[test]
+    fn updates_refuse_weakening_and_stale_versions() {
+        let mut current = NeurorightsConstraints::strict_non_actuating();
+        current.version = 3;
+        current.author = Some("did:example:council".into());
+        current.adopted_at = Some(SystemTime::UNIX_EPOCH);
+        current.retention.limit = Some(Duration::from_secs(3600));
+
+        let mut next = current.clone();
+        next.version = 4;
+        next.envelope_ref = Some("envelope:v4".into());
+        assert_eq!(current.update_to(&next), Ok(NeurorightsUpdate::Equivalent));
+        next.retention.limit = Some(Duration::from_secs(60));
+        assert_eq!(current.update_to(&next), Ok(NeurorightsUpdate::Tightened));
+
+        next.retention.limit = None;
+        next.inference
+            .allowed
+            .insert(InferenceCategory::EmotionalState);
+        let err = current.update_to(&next).unwrap_err();
+        assert_eq!(
+            err,
+            NeurorightsUpdateError::Weakened(vec![
+                NeurorightsWeakening::InferenceAllowed(InferenceCategory::EmotionalState),
+                NeurorightsWeakening::RetentionExtended {
+                    from: Some(Duration::from_secs(3600)),
+                    to: None,
+                },
+            ])
+        );
+        assert!(err.to_string().ends_with("from 3600s to unlimited"));
+        assert!(matches!(
+            current.update_to(&current),
+            Err(NeurorightsUpdateError::NotNewer {
+                current: 3,
+                proposed: 3
+            })
+        ));
+
+        let json = serde_json::to_value(&current).unwrap();
+        assert_eq!(json["author"], "did:example:council");
+        let mut unversioned = json.clone();
+        for field in ["version", "author", "adopted_at"] {
+            unversioned.as_object_mut().unwrap().remove(field);
         }
+        let parsed: NeurorightsConstraints = serde_json::from_value(unversioned).unwrap();
+        assert_eq!(parsed.version, 0);
+        assert_eq!(parsed.author, None);
     }
 }
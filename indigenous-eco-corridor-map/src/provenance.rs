@@ -0,0 +1,132 @@
+//! Measurement provenance for metric groups.
+//! Overlays weigh community-reported and agency-reported data
+//! differently; provenance says who measured a group, how, and whether
+//! the community has reviewed it.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::{EnvironmentalMetrics, MetricComponent};
+
+/// Who reported a measurement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Reporter {
+    /// Community monitors or knowledge holders.
+    Community,
+    /// A government or regulatory agency.
+    Agency,
+    Research,
+    /// An unattended sensor or remote-sensing product.
+    Sensor,
+}
+
+impl Reporter {
+    pub const ALL: [Reporter; 4] = [
+        Reporter::Community,
+        Reporter::Agency,
+        Reporter::Research,
+        Reporter::Sensor,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Reporter::Community => "community",
+            Reporter::Agency => "agency",
+            Reporter::Research => "research",
+            Reporter::Sensor => "sensor",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.key() == key.trim())
+    }
+}
+
+impl fmt::Display for Reporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Where a metric group's scores came from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Provenance {
+    /// Observer DID or sensor identifier.
+    pub observer: String,
+    pub reporter: Reporter,
+    /// Sampling or survey protocol, e.g. "EPA 1603" or a community
+    /// protocol reference.
+    pub method: String,
+    pub collected_at: SystemTime,
+    /// True once the governing community has reviewed the measurement.
+    #[serde(default)]
+    pub community_reviewed: bool,
+}
+
+impl EnvironmentalMetrics {
+    /// Provenance of the group `component` belongs to, if recorded.
+    pub fn provenance(&self, component: MetricComponent) -> Option<&Provenance> {
+        match component {
+            MetricComponent::SoilFertility
+            | MetricComponent::SoilErosionRisk
+            | MetricComponent::SoilContamination => self.soil.provenance.as_deref(),
+            MetricComponent::WaterQuality
+            | MetricComponent::WaterFlowResilience
+            | MetricComponent::WaterEcologicalFlowSupport => self.water.provenance.as_deref(),
+            MetricComponent::MicrobiomeDiversity
+            | MetricComponent::MicrobiomeKeystonePresence
+            | MetricComponent::MicrobiomeDisturbanceResilience => {
+                self.microbiome.provenance.as_deref()
+            }
+        }
+    }
+
+    /// Components whose group has no provenance or has not been
+    /// reviewed by the community, in `MetricComponent::ALL` order.
+    pub fn unreviewed_components(&self) -> Vec<MetricComponent> {
+        MetricComponent::ALL
+            .into_iter()
+            .filter(|c| !self.provenance(*c).is_some_and(|p| p.community_reviewed))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Score;
+
+    #[test]
+    fn groups_carry_optional_provenance() {
+        let mut metrics = EnvironmentalMetrics::from_components(
+            [Score::new(0.5).unwrap(); MetricComponent::COUNT],
+        );
+        metrics.water.provenance = Some(Box::new(Provenance {
+            observer: "did:web:water-keepers.nation-x.example".into(),
+            reporter: Reporter::Community,
+            method: "nation-x:protocol:water-3".into(),
+            collected_at: SystemTime::UNIX_EPOCH,
+            community_reviewed: true,
+        }));
+        assert_eq!(
+            metrics
+                .provenance(MetricComponent::WaterQuality)
+                .map(|p| p.reporter),
+            Some(Reporter::Community)
+        );
+        assert!(metrics.provenance(MetricComponent::SoilFertility).is_none());
+        assert_eq!(metrics.unreviewed_components().len(), 6);
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert!(json["soil"].get("provenance").is_none());
+        let parsed: EnvironmentalMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.water.provenance, metrics.water.provenance);
+    }
+}
@@ -0,0 +1,121 @@
+//! Data quality flags on metric values.
+//! A composite score is only as trustworthy as its weakest input, so
+//! aggregates carry the worst quality among the components behind them.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::{EnvironmentalMetrics, MetricComponent};
+
+/// How a value was obtained, ordered from best to worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DataQuality {
+    /// Checked against a reference method or by a second observer.
+    Validated,
+    /// Observed directly by community monitors, not yet validated.
+    CommunityReported,
+    /// Modelled or estimated rather than observed.
+    Estimated,
+    /// Filled in from neighbouring samples in space or time.
+    Interpolated,
+    /// Flagged as doubtful, e.g. an instrument fault.
+    Suspect,
+}
+
+impl DataQuality {
+    pub const ALL: [DataQuality; 5] = [
+        DataQuality::Validated,
+        DataQuality::CommunityReported,
+        DataQuality::Estimated,
+        DataQuality::Interpolated,
+        DataQuality::Suspect,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            DataQuality::Validated => "validated",
+            DataQuality::CommunityReported => "community_reported",
+            DataQuality::Estimated => "estimated",
+            DataQuality::Interpolated => "interpolated",
+            DataQuality::Suspect => "suspect",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|q| q.key() == key.trim())
+    }
+}
+
+impl fmt::Display for DataQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl EnvironmentalMetrics {
+    /// Quality flag of a component, if one was recorded.
+    pub fn quality(&self, component: MetricComponent) -> Option<DataQuality> {
+        self.quality.get(&component).copied()
+    }
+
+    /// Worst quality among the measured components, i.e. the label the
+    /// aggregate score carries. `None` if any measured component is
+    /// unflagged, since its quality cannot be vouched for, or if nothing
+    /// was measured.
+    pub fn composite_quality(&self) -> Option<DataQuality> {
+        let mut worst = None;
+        for c in MetricComponent::ALL {
+            if self.measured(c).is_some() {
+                worst = worst.max(Some(self.quality(c)?));
+            }
+        }
+        worst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Score;
+
+    #[test]
+    fn composite_carries_the_worst_contributing_quality() {
+        let mut metrics = EnvironmentalMetrics::from_components(
+            [Score::new(0.5).unwrap(); MetricComponent::COUNT],
+        );
+        assert_eq!(metrics.composite_quality(), None);
+
+        for c in MetricComponent::ALL {
+            metrics.quality.insert(c, DataQuality::Validated);
+        }
+        assert_eq!(metrics.composite_quality(), Some(DataQuality::Validated));
+        metrics.quality.insert(
+            MetricComponent::WaterQuality,
+            DataQuality::CommunityReported,
+        );
+        metrics
+            .quality
+            .insert(MetricComponent::SoilFertility, DataQuality::Suspect);
+        assert_eq!(metrics.composite_quality(), Some(DataQuality::Suspect));
+
+        metrics.unmeasured.insert(MetricComponent::SoilFertility);
+        assert_eq!(
+            metrics.composite_quality(),
+            Some(DataQuality::CommunityReported)
+        );
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["quality"]["water_quality"], "community_reported");
+        let parsed: EnvironmentalMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.quality, metrics.quality);
+        assert_eq!(
+            DataQuality::from_key("interpolated"),
+            Some(DataQuality::Interpolated)
+        );
+    }
+}
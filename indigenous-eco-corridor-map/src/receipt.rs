@@ -0,0 +1,151 @@
+//! Consent receipts: portable records of what communities agreed to.
+//!
+//! A receipt's canonical form is its JSON with object keys sorted and no
+//! insignificant whitespace; `hash` is SHA-256 over the canonical form of
+//! every other field, so any holder can check a receipt is unaltered.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::corridor::CorridorId;
+use crate::credential::format_timestamp;
+use crate::governance::{CommunityId, FpicPurpose, FpicStatus};
+use crate::hashing::ContentHash;
+use crate::map::IndigenousEcoCorridor;
+
+/// One community's consent as recorded on a receipt.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ReceiptConsent {
+    pub community: CommunityId,
+    /// RFC 3339 UTC time of the consent.
+    pub at: String,
+    pub reference: String,
+}
+
+/// What was consented to, by whom, for which corridor.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConsentReceipt {
+    pub corridor_id: CorridorId,
+    /// Consenting communities, in community order.
+    pub consents: Vec<ReceiptConsent>,
+    pub purposes: BTreeSet<FpicPurpose>,
+    pub terms_reference: String,
+    /// RFC 3339 UTC time of the grant.
+    pub granted_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+    pub hash: ContentHash,
+}
+
+impl ConsentReceipt {
+    /// Receipt for `status`, or `None` unless it is `Granted`.
+    pub fn from_grant(corridor_id: &CorridorId, status: &FpicStatus) -> Option<Self> {
+        let FpicStatus::Granted {
+            at,
+            quorum,
+            terms_reference,
+            purposes,
+            valid_until,
+            ..
+        } = status
+        else {
+            return None;
+        };
+        let mut receipt = Self {
+            corridor_id: corridor_id.clone(),
+            consents: quorum
+                .consents()
+                .map(|(community, consent)| ReceiptConsent {
+                    community: community.clone(),
+                    at: format_timestamp(consent.at),
+                    reference: consent.reference.clone(),
+                })
+                .collect(),
+            purposes: purposes.clone(),
+            terms_reference: terms_reference.clone(),
+            granted_at: format_timestamp(*at),
+            valid_until: valid_until.map(format_timestamp),
+            hash: ContentHash([0; 32]),
+        };
+        receipt.hash = receipt.body_hash();
+        Some(receipt)
+    }
+
+    /// Canonical serialization, including the hash.
+    pub fn to_canonical_json(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("receipts always serialize");
+        serde_json::to_vec(&value).expect("JSON values always serialize")
+    }
+
+    /// True if `hash` matches the rest of the receipt.
+    pub fn verify(&self) -> bool {
+        self.hash == self.body_hash()
+    }
+
+    fn body_hash(&self) -> ContentHash {
+        let mut value = serde_json::to_value(self).expect("receipts always serialize");
+        if let Value::Object(fields) = &mut value {
+            fields.remove("hash");
+        }
+        let canonical = serde_json::to_vec(&value).expect("JSON values always serialize");
+        ContentHash(Sha256::digest(canonical).into())
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Receipt for the corridor's current grant, if it has one.
+    pub fn consent_receipt(&self) -> Option<ConsentReceipt> {
+        ConsentReceipt::from_grant(self.id(), &self.fpic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{Consent, ConsentQuorum};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn receipts_are_canonical_and_tamper_evident() {
+        let at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let grant = FpicStatus::Granted {
+            at,
+            quorum: ConsentQuorum::sole(
+                CommunityId("did:web:nation-x.example".into()),
+                Consent {
+                    at,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:1".into(),
+            purposes: BTreeSet::from([FpicPurpose::Restoration, FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let id = CorridorId::new("eco:river:receipt").unwrap();
+        let receipt = ConsentReceipt::from_grant(&id, &grant).unwrap();
+        assert!(receipt.verify());
+        assert_eq!(receipt.granted_at, "2023-11-14T22:13:20Z");
+
+        let canonical = String::from_utf8(receipt.to_canonical_json()).unwrap();
+        assert!(canonical.starts_with(r#"{"consents":[{"at":"2023-11-14T22:13:20Z""#));
+        assert!(canonical.contains(r#""purposes":["monitoring","restoration"]"#));
+        let parsed: ConsentReceipt = serde_json::from_str(&canonical).unwrap();
+        assert_eq!(parsed.to_canonical_json(), canonical.as_bytes());
+        assert!(parsed.verify());
+
+        let mut tampered = parsed;
+        tampered.purposes.insert(FpicPurpose::DataSharing);
+        assert!(!tampered.verify());
+        assert!(ConsentReceipt::from_grant(&id, &FpicStatus::Pending).is_none());
+    }
+}
@@ -0,0 +1,182 @@
+//! Typed, directed relationships between corridors, e.g. watershed
+//! structure. Descriptive only; relationships carry no flow or control.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// How the corridor declaring a relationship relates to its target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipKind {
+    /// Water flows from this corridor into the target's reach.
+    UpstreamOf,
+    /// This corridor's waterway feeds the target's.
+    TributaryOf,
+    /// Species move between this corridor and the target.
+    MigrationLinkTo,
+    /// This corridor buffers the target from surrounding pressure.
+    BufferFor,
+}
+
+impl RelationshipKind {
+    pub const ALL: [RelationshipKind; 4] = [
+        RelationshipKind::UpstreamOf,
+        RelationshipKind::TributaryOf,
+        RelationshipKind::MigrationLinkTo,
+        RelationshipKind::BufferFor,
+    ];
+
+    /// Stable snake_case key used in interchange formats.
+    pub fn key(self) -> &'static str {
+        match self {
+            RelationshipKind::UpstreamOf => "upstream_of",
+            RelationshipKind::TributaryOf => "tributary_of",
+            RelationshipKind::MigrationLinkTo => "migration_link_to",
+            RelationshipKind::BufferFor => "buffer_for",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.key() == key.trim())
+    }
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Outgoing relationship declared on a `CorridorDescriptor`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Relationship {
+    pub kind: RelationshipKind,
+    pub target: CorridorId,
+}
+
+/// A relationship whose target is not registered in the map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DanglingRelationship {
+    pub source: CorridorId,
+    pub kind: RelationshipKind,
+    pub target: CorridorId,
+}
+
+impl fmt::Display for DanglingRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' {} unknown corridor '{}'",
+            self.source, self.kind, self.target
+        )
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Every relationship whose target neither is registered nor resolves
+    /// through an alias, in source id order.
+    pub fn dangling_relationships(&self) -> Vec<DanglingRelationship> {
+        self.iter()
+            .flat_map(|(id, c)| {
+                c.descriptor
+                    .relationships
+                    .iter()
+                    .filter(|r| self.resolve(&r.target).is_none())
+                    .map(move |r| DanglingRelationship {
+                        source: id.clone(),
+                        kind: r.kind,
+                        target: r.target.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Corridors declaring a `kind` relationship to `id` (or one of its
+    /// aliases), in id order; e.g. the tributaries of a river.
+    pub fn related_to(
+        &self,
+        id: &CorridorId,
+        kind: RelationshipKind,
+    ) -> impl Iterator<Item = &IndigenousEcoCorridor> + '_ {
+        let target = self.resolve(id).cloned();
+        self.iter().map(|(_, c)| c).filter(move |c| {
+            target.is_some()
+                && c.descriptor
+                    .relationships
+                    .iter()
+                    .any(|r| r.kind == kind && self.resolve(&r.target) == target.as_ref())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corridor::{CorridorDescriptor, CorridorKind};
+    use crate::test_support;
+
+    fn river(id: &str, relationships: &[(RelationshipKind, &str)]) -> IndigenousEcoCorridor {
+        let mut river = test_support::corridor(id);
+        for &(kind, target) in relationships {
+            let target = CorridorId::new(target).unwrap();
+            river
+                .descriptor
+                .relationships
+                .push(Relationship { kind, target });
+        }
+        river
+    }
+
+    #[test]
+    fn relationships_resolve_through_aliases_and_report_dangling_targets() {
+        use RelationshipKind::*;
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(river("eco:river:main", &[])).unwrap();
+        map.upsert_corridor(river("eco:river:creek", &[(TributaryOf, "eco:river:main")]))
+            .unwrap();
+        map.upsert_corridor(river(
+            "eco:river:spring",
+            &[
+                (UpstreamOf, "eco:river:creek"),
+                (TributaryOf, "eco:river:lost"),
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            map.dangling_relationships(),
+            [DanglingRelationship {
+                source: id("eco:river:spring"),
+                kind: TributaryOf,
+                target: id("eco:river:lost"),
+            }]
+        );
+
+        map.rename_corridor(&id("eco:river:main"), id("eco:river:mainstem"))
+            .unwrap();
+        let tributaries: Vec<_> = map
+            .related_to(&id("eco:river:mainstem"), TributaryOf)
+            .map(|c| c.id().as_str())
+            .collect();
+        assert_eq!(tributaries, ["eco:river:creek"]);
+        assert_eq!(map.dangling_relationships().len(), 1);
+
+        assert_eq!(RelationshipKind::from_key("buffer_for"), Some(BufferFor));
+        assert!(CorridorDescriptor::builder()
+            .id(id("eco:river:x"))
+            .kind(CorridorKind::River)
+            .name("X")
+            .relationship(UpstreamOf, id("eco:river:x"))
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Revocation records and the impact report overlays use to find data
+//! uses that must be re-checked once consent is withdrawn.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::governance::{FpicPurpose, FpicStatus};
+use crate::map::IndigenousEcoCorridorMap;
+use crate::relationships::RelationshipKind;
+
+/// What a revocation withdraws.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RevocationScope {
+    /// The whole grant; FPIC becomes `Revoked`.
+    All,
+    /// Only these purposes; the grant stays for the rest, and is revoked
+    /// once no purpose remains.
+    Purposes(BTreeSet<FpicPurpose>),
+}
+
+/// A withdrawal of consent, kept on the corridor so the references it
+/// affected can be traced after the grant itself is gone.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RevocationRecord {
+    pub at: SystemTime,
+    pub reason: String,
+    pub scope: RevocationScope,
+    /// Data uses built on the withdrawn consent (dataset URIs, token
+    /// grant references, ledger entries). `revoke_fpic` adds the revoked
+    /// grant's terms reference.
+    #[serde(default)]
+    pub affected: BTreeSet<String>,
+}
+
+impl RevocationRecord {
+    /// FPIC status after this revocation is applied to `current`.
+    pub(crate) fn outcome(&self, current: &FpicStatus) -> Result<FpicStatus, String> {
+        let revoked = FpicStatus::Revoked {
+            at: self.at,
+            reason: self.reason.clone(),
+        };
+        let withdrawn = match &self.scope {
+            RevocationScope::All => return Ok(revoked),
+            RevocationScope::Purposes(withdrawn) => withdrawn,
+        };
+        let mut narrowed = current.clone();
+        let FpicStatus::Granted { purposes, .. } = &mut narrowed else {
+            return Err(format!(
+                "only a grant can lose purposes; FPIC is {}",
+                current.kind()
+            ));
+        };
+        if purposes.is_disjoint(withdrawn) {
+            return Err("revocation withdraws no purpose the grant covers".into());
+        }
+        purposes.retain(|p| !withdrawn.contains(p));
+        Ok(if purposes.is_empty() {
+            revoked
+        } else {
+            narrowed
+        })
+    }
+}
+
+/// Everything overlays must re-check for a corridor after revocation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RevocationImpact {
+    pub corridor_id: CorridorId,
+    /// The corridor's revocations, oldest first.
+    pub revocations: Vec<RevocationRecord>,
+    /// Every reference named by those revocations, plus the corridor's
+    /// IDS governance reference and cultural-site governed references,
+    /// whose holders should learn of the change.
+    pub references: BTreeSet<String>,
+    /// Corridors declaring a relationship to this one, whose derived
+    /// data may draw on it.
+    pub linked_corridors: BTreeSet<CorridorId>,
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Impact report for `id` (or the corridor it resolves to); empty
+    /// but valid for a corridor never revoked.
+    pub fn revocation_impact(&self, id: &CorridorId) -> Result<RevocationImpact, String> {
+        let corridor = self
+            .get(id)
+            .ok_or_else(|| format!("CorridorId '{}' is not registered", id))?;
+        let references = corridor
+            .revocations
+            .iter()
+            .flat_map(|r| r.affected.iter().cloned())
+            .chain(corridor.ids_scope.governance_ref.clone())
+            .chain(
+                corridor
+                    .cultural_sites
+                    .iter()
+                    .filter_map(|s| s.governed_ref().map(str::to_string)),
+            )
+            .collect();
+        let linked_corridors = RelationshipKind::ALL
+            .into_iter()
+            .flat_map(|kind| self.related_to(corridor.id(), kind))
+            .map(|c| c.id().clone())
+            .collect();
+        Ok(RevocationImpact {
+            corridor_id: corridor.id().clone(),
+            revocations: corridor.revocations.clone(),
+            references,
+            linked_corridors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cultural::CulturalSite;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum, FpicEvidence};
+    use crate::map::IndigenousEcoCorridor;
+    use crate::relationships::Relationship;
+    use crate::test_support;
+
+    fn corridor(id: &str, links_to: Option<&str>, fpic: FpicStatus) -> IndigenousEcoCorridor {
+        let mut corridor = IndigenousEcoCorridor {
+            fpic,
+            cultural_sites: vec![CulturalSite::restricted("Grove", "nation-x:register:17").unwrap()],
+            ..test_support::corridor(id)
+        };
+        corridor.ids_scope.governance_ref = Some("ids:ledger:9".into());
+        if let Some(target) = links_to {
+            corridor.descriptor.relationships.push(Relationship {
+                kind: RelationshipKind::BufferFor,
+                target: CorridorId::new(target).unwrap(),
+            });
+        }
+        corridor
+    }
+
+    #[test]
+    fn revocations_narrow_then_end_grants_and_report_their_impact() {
+        let id = CorridorId::new("eco:forest:r").unwrap();
+        let grant = FpicStatus::Granted {
+            at: SystemTime::UNIX_EPOCH,
+            quorum: ConsentQuorum::sole(
+                CommunityId("nation-x".into()),
+                Consent {
+                    at: SystemTime::UNIX_EPOCH,
+                    reference: "nation-x:decision:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:r".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring, FpicPurpose::Research]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:forest:r", None, grant))
+            .unwrap();
+        map.upsert_corridor(corridor(
+            "eco:forest:edge",
+            Some("eco:forest:r"),
+            FpicStatus::Pending,
+        ))
+        .unwrap();
+
+        let evidence = FpicEvidence {
+            actor: "did:example:council".into(),
+            at: SystemTime::UNIX_EPOCH,
+            reference: "minutes:7".into(),
+        };
+        let revocation = |scope, affected: &[&str]| RevocationRecord {
+            at: SystemTime::UNIX_EPOCH,
+            reason: "council vote".into(),
+            scope,
+            affected: affected.iter().map(|a| a.to_string()).collect(),
+        };
+        let research = BTreeSet::from([FpicPurpose::Research]);
+        map.revoke_fpic(
+            &id,
+            revocation(RevocationScope::Purposes(research.clone()), &["dataset:42"]),
+            evidence.clone(),
+        )
+        .unwrap();
+        let fpic = &map.get(&id).unwrap().fpic;
+        assert!(fpic.covers(FpicPurpose::Monitoring) && !fpic.covers(FpicPurpose::Research));
+        assert!(map
+            .revoke_fpic(
+                &id,
+                revocation(RevocationScope::Purposes(research), &[]),
+                evidence.clone()
+            )
+            .is_err());
+
+        map.revoke_fpic(&id, revocation(RevocationScope::All, &[]), evidence)
+            .unwrap();
+        assert!(matches!(
+            map.get(&id).unwrap().fpic,
+            FpicStatus::Revoked { .. }
+        ));
+
+        let impact = map.revocation_impact(&id).unwrap();
+        assert_eq!(impact.revocations.len(), 2);
+        assert_eq!(
+            impact.references,
+            BTreeSet::from(
+                [
+                    "dataset:42",
+                    "ids:ledger:9",
+                    "nation-x:register:17",
+                    "terms:r"
+                ]
+                .map(String::from)
+            )
+        );
+        assert_eq!(
+            impact.linked_corridors,
+            BTreeSet::from([CorridorId::new("eco:forest:edge").unwrap()])
+        );
+        assert!(map
+            .revocation_impact(&CorridorId::new("eco:forest:none").unwrap())
+            .is_err());
+    }
+}
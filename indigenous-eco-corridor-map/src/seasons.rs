@@ -0,0 +1,236 @@
+//! Recurring seasonal states of corridors (migrations, ceremonies,
+//! closures). Descriptive only; overlays decide what a state permits.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::map::IndigenousEcoCorridor;
+
+const DAYS_IN_MONTH: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Day of the year as month and day, recurring every year. February 29
+/// is allowed so windows can name it; in common years it never occurs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawSeasonDate")]
+pub struct SeasonDate {
+    month: u8,
+    day: u8,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawSeasonDate {
+    month: u8,
+    day: u8,
+}
+
+impl TryFrom<RawSeasonDate> for SeasonDate {
+    type Error = String;
+
+    fn try_from(raw: RawSeasonDate) -> Result<Self, String> {
+        Self::new(raw.month, raw.day)
+    }
+}
+
+impl SeasonDate {
+    pub fn new(month: u8, day: u8) -> Result<Self, String> {
+        let valid =
+            (1..=12).contains(&month) && (1..=DAYS_IN_MONTH[month as usize - 1]).contains(&day);
+        if !valid {
+            return Err(format!("{:02}-{:02} is not a day of the year", month, day));
+        }
+        Ok(Self { month, day })
+    }
+
+    /// Calendar day (UTC) of `at`.
+    pub fn of(at: SystemTime) -> Self {
+        let secs = match at.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        let (_, month, day) = civil_from_days(secs.div_euclid(86_400));
+        Self { month, day }
+    }
+
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+}
+
+/// Year, month, and day (proleptic Gregorian, UTC) of the day `days`
+/// after 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    // Shifted so years start in March and the leap day falls last.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u8, day)
+}
+
+/// Inverse of `civil_from_days`.
+pub(crate) fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+impl fmt::Display for SeasonDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}-{:02}", self.month, self.day)
+    }
+}
+
+/// Seasonal state of a corridor, ordered from least to most restrictive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SeasonalState {
+    /// Species are moving through the corridor.
+    MigrationActive,
+    /// Breeding, nesting, or spawning is under way.
+    Breeding,
+    /// The corridor hosts ceremony; access follows community protocol.
+    Ceremony,
+    /// Closed to outside access.
+    Closed,
+}
+
+impl SeasonalState {
+    pub fn key(self) -> &'static str {
+        match self {
+            SeasonalState::MigrationActive => "migration_active",
+            SeasonalState::Breeding => "breeding",
+            SeasonalState::Ceremony => "ceremony",
+            SeasonalState::Closed => "closed",
+        }
+    }
+}
+
+impl fmt::Display for SeasonalState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// A named season recurring every year from `start` through `end`
+/// (inclusive). Windows with `end` before `start` wrap the new year,
+/// e.g. December 1 to February 28.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SeasonalWindow {
+    pub season: String,
+    pub start: SeasonDate,
+    pub end: SeasonDate,
+    pub state: SeasonalState,
+}
+
+impl SeasonalWindow {
+    pub fn contains(&self, date: SeasonDate) -> bool {
+        if self.start <= self.end {
+            self.start <= date && date <= self.end
+        } else {
+            date >= self.start || date <= self.end
+        }
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Seasonal state on `date`: the most restrictive state among the
+    /// windows containing it, or `None` outside every window.
+    pub fn state_at(&self, date: SeasonDate) -> Option<SeasonalState> {
+        self.seasons
+            .iter()
+            .filter(|w| w.contains(date))
+            .map(|w| w.state)
+            .max()
+    }
+
+    /// Windows containing `date`, in declaration order.
+    pub fn seasons_at(&self, date: SeasonDate) -> impl Iterator<Item = &SeasonalWindow> {
+        self.seasons.iter().filter(move |w| w.contains(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use std::time::Duration;
+
+    #[test]
+    fn windows_wrap_the_year_and_restrictive_states_win() {
+        let date = |m, d| SeasonDate::new(m, d).unwrap();
+        assert!(SeasonDate::new(2, 30).is_err());
+        assert!(SeasonDate::new(13, 1).is_err());
+        assert_eq!(
+            SeasonDate::of(UNIX_EPOCH + Duration::from_secs(1_709_164_800)),
+            date(2, 29)
+        );
+        assert_eq!(
+            SeasonDate::of(UNIX_EPOCH - Duration::from_secs(1)),
+            date(12, 31)
+        );
+
+        let window = |season: &str, start, end, state| SeasonalWindow {
+            season: season.into(),
+            start,
+            end,
+            state,
+        };
+        let mut corridor = test_support::corridor("eco:river:s");
+        corridor.seasons = vec![
+            window(
+                "salmon run",
+                date(8, 15),
+                date(10, 31),
+                SeasonalState::MigrationActive,
+            ),
+            window(
+                "winter ceremony",
+                date(12, 20),
+                date(1, 10),
+                SeasonalState::Ceremony,
+            ),
+            window(
+                "spawning closure",
+                date(10, 1),
+                date(10, 15),
+                SeasonalState::Closed,
+            ),
+        ];
+
+        assert_eq!(corridor.state_at(date(6, 1)), None);
+        assert_eq!(
+            corridor.state_at(date(9, 1)),
+            Some(SeasonalState::MigrationActive)
+        );
+        assert_eq!(corridor.state_at(date(10, 5)), Some(SeasonalState::Closed));
+        assert_eq!(corridor.seasons_at(date(10, 5)).count(), 2);
+        assert_eq!(corridor.state_at(date(1, 3)), Some(SeasonalState::Ceremony));
+        assert_eq!(corridor.state_at(date(12, 19)), None);
+    }
+}
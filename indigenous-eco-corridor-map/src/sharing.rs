@@ -0,0 +1,146 @@
+//! Data sharing agreements between communities.
+//! Corridors that cross territories are governed by several communities;
+//! an agreement records which of them may pass one another data about
+//! which corridors, for what, and until when.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::governance::{CommunityId, FpicPurpose};
+use crate::map::IndigenousEcoCorridorMap;
+
+/// An agreement letting its parties share corridor data with one another.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DataSharingAgreement {
+    /// Hash/URI of the signed agreement.
+    pub reference: String,
+    /// At least two communities; each may share with every other.
+    pub parties: BTreeSet<CommunityId>,
+    pub corridors: BTreeSet<CorridorId>,
+    /// Purposes the shared data may be used for.
+    pub scope: BTreeSet<FpicPurpose>,
+    /// The agreement lapses at this instant; `None` for no expiry.
+    #[serde(default)]
+    pub expires_at: Option<SystemTime>,
+}
+
+impl DataSharingAgreement {
+    /// True if unexpired at `now` and `from` and `to` are distinct
+    /// parties. Corridor and purpose are checked by the map.
+    fn links(&self, from: &CommunityId, to: &CommunityId, now: SystemTime) -> bool {
+        from != to
+            && self.parties.contains(from)
+            && self.parties.contains(to)
+            && self.expires_at.is_none_or(|end| now < end)
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Register `agreement`. It must name a reference, two or more
+    /// parties, a purpose, and only corridors the map knows; corridor
+    /// ids are stored as given and resolved through aliases when queried.
+    pub fn add_sharing_agreement(&mut self, agreement: DataSharingAgreement) -> Result<(), String> {
+        if agreement.reference.trim().is_empty() {
+            return Err("data sharing agreement has no reference".into());
+        }
+        if agreement.parties.len() < 2 {
+            return Err(format!(
+                "data sharing agreement '{}' needs at least two communities",
+                agreement.reference
+            ));
+        }
+        if agreement.scope.is_empty() {
+            return Err(format!(
+                "data sharing agreement '{}' names no purposes",
+                agreement.reference
+            ));
+        }
+        if agreement.corridors.is_empty() {
+            return Err(format!(
+                "data sharing agreement '{}' covers no corridors",
+                agreement.reference
+            ));
+        }
+        for id in &agreement.corridors {
+            self.ensure_exists(id)?;
+        }
+        self.push_sharing_agreement(agreement);
+        Ok(())
+    }
+
+    /// Agreements covering `corridor` (or what it resolves to).
+    pub fn agreements_for(&self, corridor: &CorridorId) -> Vec<&DataSharingAgreement> {
+        let Some(corridor) = self.resolve(corridor) else {
+            return Vec::new();
+        };
+        self.sharing_agreements()
+            .iter()
+            .filter(|a| {
+                a.corridors
+                    .iter()
+                    .any(|c| self.resolve(c) == Some(corridor))
+            })
+            .collect()
+    }
+
+    /// True if an agreement in force at `now` lets `from` share data
+    /// about `corridor` with `to` for `purpose`.
+    pub fn sharing_allowed(
+        &self,
+        from: &CommunityId,
+        to: &CommunityId,
+        corridor: &CorridorId,
+        purpose: FpicPurpose,
+        now: SystemTime,
+    ) -> bool {
+        self.agreements_for(corridor)
+            .into_iter()
+            .any(|a| a.scope.contains(&purpose) && a.links(from, to, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use std::time::Duration;
+
+    #[test]
+    fn agreements_link_parties_over_their_corridors() {
+        let id = CorridorId::new("eco:river:border").unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(test_support::corridor(id.as_str()))
+            .unwrap();
+        let [x, y, z] = ["nation-x", "nation-y", "nation-z"]
+            .map(|c| CommunityId(format!("did:web:{}.example", c)));
+        let end = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let agreement = DataSharingAgreement {
+            reference: "agreement:1".into(),
+            parties: BTreeSet::from([x.clone(), y.clone()]),
+            corridors: BTreeSet::from([id.clone()]),
+            scope: BTreeSet::from([FpicPurpose::Monitoring]),
+            expires_at: Some(end),
+        };
+        let unknown = DataSharingAgreement {
+            corridors: BTreeSet::from([CorridorId::new("eco:river:nowhere").unwrap()]),
+            ..agreement.clone()
+        };
+        assert!(map.add_sharing_agreement(unknown).is_err());
+        map.add_sharing_agreement(agreement).unwrap();
+
+        let renamed = CorridorId::new("eco:river:border-2").unwrap();
+        map.rename_corridor(&id, renamed.clone()).unwrap();
+        let before = SystemTime::UNIX_EPOCH;
+        assert!(map.sharing_allowed(&y, &x, &renamed, FpicPurpose::Monitoring, before));
+        assert!(!map.sharing_allowed(&x, &z, &renamed, FpicPurpose::Monitoring, before));
+        assert!(!map.sharing_allowed(&x, &y, &renamed, FpicPurpose::Research, before));
+        assert!(!map.sharing_allowed(&x, &y, &renamed, FpicPurpose::Monitoring, end));
+        assert_eq!(map.agreements_for(&id).len(), 1);
+    }
+}
@@ -0,0 +1,179 @@
+//! Detached signatures on corridor records.
+//! Key management is external: signers and verifiers are traits that
+//! overlays implement with their own key infrastructure.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// Signature over a record's `content_hash`, which excludes the
+/// signature itself.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RecordSignature {
+    /// DID (or key identifier) of the signing steward.
+    pub signer: String,
+    /// Algorithm name understood by the verifier, e.g. "ed25519".
+    pub algorithm: String,
+    pub signature: Vec<u8>,
+}
+
+/// Produces detached record signatures with a steward's key material.
+pub trait RecordSigner {
+    /// DID or key identifier of the signer.
+    fn signer_id(&self) -> String;
+    fn algorithm(&self) -> String;
+    fn sign(&self, payload: &[u8]) -> Vec<u8>;
+}
+
+/// Verifies record signatures against known steward keys.
+pub trait RecordVerifier {
+    fn verify(&self, signer: &str, algorithm: &str, payload: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Why a record failed signature verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    Unsigned,
+    /// The signature does not match the record's current content, or the
+    /// verifier does not accept the signer.
+    BadSignature {
+        signer: String,
+    },
+    /// The record cannot be hashed, e.g. it holds a time before 1970.
+    Unhashable(String),
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::Unsigned => write!(f, "record is not signed"),
+            SignatureError::BadSignature { signer } => {
+                write!(f, "record signature from '{}' did not verify", signer)
+            }
+            SignatureError::Unhashable(e) => f.write_str(e),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+impl IndigenousEcoCorridor {
+    /// Sign the record's current content, replacing any earlier signature.
+    /// A record that cannot be hashed is left as it was.
+    pub fn sign(&mut self, signer: &dyn RecordSigner) -> Result<(), SignatureError> {
+        let payload = self.content_hash().map_err(SignatureError::Unhashable)?;
+        self.signature = Some(RecordSignature {
+            signer: signer.signer_id(),
+            algorithm: signer.algorithm(),
+            signature: signer.sign(&payload.0),
+        });
+        Ok(())
+    }
+
+    /// Check that the record carries a signature over its current content.
+    pub fn verify_signature(&self, verifier: &dyn RecordVerifier) -> Result<(), SignatureError> {
+        let sig = self.signature.as_ref().ok_or(SignatureError::Unsigned)?;
+        let payload = self.content_hash().map_err(SignatureError::Unhashable)?;
+        if verifier.verify(&sig.signer, &sig.algorithm, &payload.0, &sig.signature) {
+            Ok(())
+        } else {
+            Err(SignatureError::BadSignature {
+                signer: sig.signer.clone(),
+            })
+        }
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Every corridor whose record fails verification, in id order.
+    pub fn unverified_records(
+        &self,
+        verifier: &dyn RecordVerifier,
+    ) -> Vec<(CorridorId, SignatureError)> {
+        self.iter()
+            .filter_map(|(id, c)| c.verify_signature(verifier).err().map(|e| (id.clone(), e)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::FpicStatus;
+    use crate::lifecycle::Lifecycle;
+    use crate::test_support::corridor;
+    use std::time::{Duration, SystemTime};
+
+    /// Toy scheme for tests only: the signature is signer id + payload.
+    struct Steward(&'static str);
+
+    impl RecordSigner for Steward {
+        fn signer_id(&self) -> String {
+            self.0.to_string()
+        }
+        fn algorithm(&self) -> String {
+            "concat".into()
+        }
+        fn sign(&self, payload: &[u8]) -> Vec<u8> {
+            [self.0.as_bytes(), payload].concat()
+        }
+    }
+
+    impl RecordVerifier for Steward {
+        fn verify(&self, signer: &str, algorithm: &str, payload: &[u8], signature: &[u8]) -> bool {
+            signer == self.0 && algorithm == "concat" && signature == self.sign(payload)
+        }
+    }
+
+    #[test]
+    fn signatures_detect_tampering_and_stale_records() {
+        let steward = Steward("did:example:steward");
+        let mut signed = corridor("eco:river:s");
+        let hash_before = signed.content_hash();
+        signed.sign(&steward).unwrap();
+        assert_eq!(signed.content_hash(), hash_before);
+        assert!(signed.verify_signature(&steward).is_ok());
+        assert!(signed
+            .verify_signature(&Steward("did:example:other"))
+            .is_err());
+
+        let mut tampered = signed.clone();
+        tampered.descriptor.name = "Renamed".into();
+        assert!(tampered.verify_signature(&steward).is_err());
+
+        let mut unhashable = corridor("eco:river:old");
+        unhashable.fpic = FpicStatus::Expired {
+            at: SystemTime::UNIX_EPOCH - Duration::from_secs(1),
+        };
+        assert!(matches!(
+            unhashable.sign(&steward),
+            Err(SignatureError::Unhashable(_))
+        ));
+        assert!(unhashable.signature.is_none());
+        unhashable.signature = signed.signature.clone();
+        assert!(matches!(
+            unhashable.verify_signature(&steward),
+            Err(SignatureError::Unhashable(_))
+        ));
+
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(signed).unwrap();
+        map.upsert_corridor(corridor("eco:river:u")).unwrap();
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        assert_eq!(
+            map.unverified_records(&steward),
+            [(id("eco:river:u"), SignatureError::Unsigned)]
+        );
+
+        // Map mutations drop signatures that no longer cover the record.
+        map.set_lifecycle(&id("eco:river:s"), Lifecycle::UnderReview)
+            .unwrap();
+        assert!(map.get(&id("eco:river:s")).unwrap().signature.is_none());
+    }
+}
@@ -0,0 +1,243 @@
+//! Species observations behind biodiversity scores.
+//! Diversity and keystone-presence scores derived here carry the taxa
+//! they were computed from, so a score can be traced back to the
+//! observations rather than read as an unexplained scalar.
+
+#![forbid(unsafe_code)]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::{MicrobiomeMetrics, Score};
+
+/// Semi-quantitative abundance, for surveys that do not count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AbundanceClass {
+    /// Looked for and not found.
+    Absent,
+    Rare,
+    Occasional,
+    Common,
+    Abundant,
+}
+
+impl AbundanceClass {
+    pub fn key(self) -> &'static str {
+        match self {
+            AbundanceClass::Absent => "absent",
+            AbundanceClass::Rare => "rare",
+            AbundanceClass::Occasional => "occasional",
+            AbundanceClass::Common => "common",
+            AbundanceClass::Abundant => "abundant",
+        }
+    }
+}
+
+impl fmt::Display for AbundanceClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// How much of a taxon was seen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Abundance {
+    Count(u32),
+    Class(AbundanceClass),
+}
+
+impl Abundance {
+    pub fn is_present(self) -> bool {
+        !matches!(
+            self,
+            Abundance::Count(0) | Abundance::Class(AbundanceClass::Absent)
+        )
+    }
+}
+
+/// One taxon seen (or looked for) on one survey.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeciesObservation {
+    /// Taxonomy reference, e.g. "gbif:2435099" or a community name
+    /// register entry.
+    pub taxon: String,
+    pub abundance: Abundance,
+    /// True if the community or survey treats the taxon as keystone.
+    #[serde(default)]
+    pub keystone: bool,
+    pub observed_at: SystemTime,
+}
+
+/// Observations for one corridor.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeciesObservations {
+    observations: Vec<SpeciesObservation>,
+}
+
+/// Scores derived from observations, with the taxa behind them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BiodiversityScores {
+    /// Present taxa over the reference richness, capped at 1.0.
+    pub diversity: Score,
+    /// Share of surveyed keystone taxa that are present; `None` if no
+    /// keystone taxon was surveyed.
+    pub keystone_presence: Option<Score>,
+    pub present_taxa: BTreeSet<String>,
+    /// Keystone taxa whose latest observation found them absent.
+    pub missing_keystones: BTreeSet<String>,
+}
+
+impl BiodiversityScores {
+    /// Write the scores into `metrics`, leaving keystone presence alone
+    /// when no keystone taxon was surveyed.
+    pub fn apply(&self, metrics: &mut MicrobiomeMetrics) {
+        metrics.diversity = self.diversity;
+        if let Some(keystone) = self.keystone_presence {
+            metrics.keystone_presence = keystone;
+        }
+    }
+}
+
+impl SpeciesObservations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, observation: SpeciesObservation) -> Result<(), String> {
+        if observation.taxon.trim().is_empty() {
+            return Err("species observation needs a taxon reference".into());
+        }
+        self.observations.push(observation);
+        Ok(())
+    }
+
+    pub fn observations(&self) -> &[SpeciesObservation] {
+        &self.observations
+    }
+
+    /// Latest observation of each taxon, by taxon.
+    fn latest(&self) -> BTreeMap<&str, &SpeciesObservation> {
+        let mut latest: BTreeMap<&str, &SpeciesObservation> = BTreeMap::new();
+        for o in &self.observations {
+            let newer = latest
+                .get(o.taxon.as_str())
+                .is_none_or(|seen| o.observed_at >= seen.observed_at);
+            if newer {
+                latest.insert(&o.taxon, o);
+            }
+        }
+        latest
+    }
+
+    /// Scores from each taxon's latest observation. `reference_richness`
+    /// is the number of taxa expected in a healthy corridor of this kind.
+    pub fn scores(&self, reference_richness: usize) -> Result<BiodiversityScores, String> {
+        if reference_richness == 0 {
+            return Err("reference richness must be at least one taxon".into());
+        }
+        let latest = self.latest();
+        let present_taxa: BTreeSet<String> = latest
+            .values()
+            .filter(|o| o.abundance.is_present())
+            .map(|o| o.taxon.clone())
+            .collect();
+        let keystones: Vec<&&SpeciesObservation> = latest.values().filter(|o| o.keystone).collect();
+        let missing_keystones: BTreeSet<String> = keystones
+            .iter()
+            .filter(|o| !o.abundance.is_present())
+            .map(|o| o.taxon.clone())
+            .collect();
+        let ratio = |part: usize, whole: usize| Score::exact((part as f32 / whole as f32).min(1.0));
+        Ok(BiodiversityScores {
+            diversity: ratio(present_taxa.len(), reference_richness),
+            keystone_presence: (!keystones.is_empty())
+                .then(|| ratio(keystones.len() - missing_keystones.len(), keystones.len())),
+            present_taxa,
+            missing_keystones,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{EnvironmentalMetrics, MetricComponent};
+    use std::time::Duration;
+
+    #[test]
+    fn scores_trace_back_to_observations() {
+        let day = |d: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(d * 86_400);
+        let seen = |taxon: &str, abundance, keystone, at| SpeciesObservation {
+            taxon: taxon.into(),
+            abundance,
+            keystone,
+            observed_at: at,
+        };
+        let mut survey = SpeciesObservations::new();
+        for o in [
+            seen("gbif:salmon", Abundance::Count(14), true, day(1)),
+            seen(
+                "gbif:beaver",
+                Abundance::Class(AbundanceClass::Rare),
+                true,
+                day(1),
+            ),
+            seen(
+                "gbif:beaver",
+                Abundance::Class(AbundanceClass::Absent),
+                true,
+                day(9),
+            ),
+            seen(
+                "gbif:alder",
+                Abundance::Class(AbundanceClass::Common),
+                false,
+                day(2),
+            ),
+        ] {
+            survey.record(o).unwrap();
+        }
+        assert!(survey
+            .record(seen(" ", Abundance::Count(1), false, day(3)))
+            .is_err());
+
+        let scores = survey.scores(4).unwrap();
+        assert_eq!(scores.diversity.get(), 0.5);
+        assert_eq!(scores.keystone_presence.map(Score::get), Some(0.5));
+        assert_eq!(
+            scores.present_taxa,
+            BTreeSet::from(["gbif:alder".to_string(), "gbif:salmon".to_string()])
+        );
+        assert_eq!(
+            scores.missing_keystones,
+            BTreeSet::from(["gbif:beaver".to_string()])
+        );
+        assert!(survey.scores(0).is_err());
+
+        let mut metrics = EnvironmentalMetrics::from_components(
+            [Score::new(0.9).unwrap(); MetricComponent::COUNT],
+        );
+        scores.apply(&mut metrics.microbiome);
+        assert_eq!(
+            metrics
+                .component(MetricComponent::MicrobiomeDiversity)
+                .get(),
+            0.5
+        );
+        assert_eq!(
+            metrics
+                .component(MetricComponent::MicrobiomeDisturbanceResilience)
+                .get(),
+            0.9
+        );
+    }
+}
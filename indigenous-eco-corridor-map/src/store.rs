@@ -0,0 +1,568 @@
+//! Pluggable persistence for corridor maps.
+//! Stores are plain encoders over files; no network or ledger access.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::audit::GovernanceAudit;
+use crate::corridor::CorridorId;
+use crate::governance::{FpicPurpose, IdsFramework};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::neurorights::InferenceCategory;
+use crate::relationships::DanglingRelationship;
+use crate::sharing::DataSharingAgreement;
+use crate::taxonomy::CustomKind;
+
+/// Errors raised while persisting or restoring a map.
+#[derive(Debug)]
+pub enum StoreError {
+    Io(io::Error),
+    Encode(String),
+    Decode(String),
+    /// Document was written by a newer release than this one.
+    UnsupportedVersion(u32),
+    /// Relationships in the document target corridors it does not hold.
+    DanglingRelationships(Vec<DanglingRelationship>),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Io(e) => write!(f, "corridor store I/O error: {}", e),
+            StoreError::Encode(e) => write!(f, "failed to encode corridor map: {}", e),
+            StoreError::Decode(e) => write!(f, "failed to decode corridor map: {}", e),
+            StoreError::UnsupportedVersion(v) => write!(
+                f,
+                "corridor map schema version {} is newer than supported version {}",
+                v, SCHEMA_VERSION
+            ),
+            StoreError::DanglingRelationships(dangling) => {
+                write!(f, "{} dangling relationship(s)", dangling.len())?;
+                for d in dangling {
+                    write!(f, "; {}", d)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+/// Persistence backend for an `IndigenousEcoCorridorMap`.
+pub trait CorridorStore {
+    /// Load the most recently saved map.
+    fn load(&self) -> Result<IndigenousEcoCorridorMap, StoreError>;
+
+    /// Persist the full map, replacing any previously saved state.
+    fn save(&self, map: &IndigenousEcoCorridorMap) -> Result<(), StoreError>;
+
+    /// Encode the map in this store's format without persisting it
+    /// (e.g., for hashing, transfer, or review).
+    fn snapshot(&self, map: &IndigenousEcoCorridorMap) -> Result<Vec<u8>, StoreError>;
+}
+
+/// Schema version written into every saved map.
+///
+/// History:
+/// 1. corridors and aliases, no version field;
+/// 2. adds `schema_version`; no other structural change (fields added
+///    since v1 all have defaults);
+/// 3. `CorridorKind::Custom` holds a structured `CustomKind` instead of
+///    free text;
+/// 4. FPIC `Consulting` and `Granted` carry a `ConsentQuorum` instead of a
+///    flat `communities` list;
+/// 5. FPIC `Granted` names the `purposes` it consents to;
+/// 6. `IdsScope` lists its `frameworks` instead of a
+///    `governed_by_ids_framework` flag;
+/// 7. `NeurorightsConstraints` groups its flags into parameterized
+///    constraints.
+pub const SCHEMA_VERSION: u32 = 7;
+
+/// Rewrites a raw document from one schema version to the next.
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+/// Upgrade steps; `MIGRATIONS[i]` rewrites a version `i + 1` document
+/// into version `i + 2`.
+const MIGRATIONS: [Migration; (SCHEMA_VERSION - 1) as usize] = [
+    |_| Ok(()),
+    structure_custom_kinds,
+    structure_fpic_quorums,
+    scope_fpic_grants,
+    name_ids_frameworks,
+    structure_neurorights,
+];
+
+/// v2 -> v3: `{"Custom": "Sacred Grove"}` becomes the legacy `CustomKind`.
+fn structure_custom_kinds(doc: &mut Value) -> Result<(), String> {
+    let corridors = doc
+        .get_mut("corridors")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+    for corridor in corridors {
+        let Some(custom) = corridor.pointer_mut("/descriptor/kind/Custom") else {
+            continue;
+        };
+        if let Value::String(text) = custom {
+            let kind = CustomKind::legacy(text)?;
+            *custom = serde_json::to_value(kind).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// v3 -> v4: `communities` becomes a unanimous quorum over them. Every
+/// community of a grant is recorded as consenting at the grant time under
+/// its terms reference; a consultation starts with no consents.
+fn structure_fpic_quorums(doc: &mut Value) -> Result<(), String> {
+    let corridors = doc
+        .get_mut("corridors")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+    for corridor in corridors {
+        let Some(fpic) = corridor.get_mut("fpic").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        let (granted, status) = match fpic.iter_mut().next() {
+            Some((tag, Value::Object(status))) if tag == "Granted" => (true, status),
+            Some((tag, Value::Object(status))) if tag == "Consulting" => (false, status),
+            _ => continue,
+        };
+        let Some(communities) = status.remove("communities") else {
+            continue;
+        };
+        let required = communities
+            .as_array()
+            .ok_or("FPIC communities must be an array")?;
+        if required.is_empty() {
+            return Err("FPIC status names no communities; it has no quorum to migrate to".into());
+        }
+        let consents: serde_json::Map<String, Value> = if granted {
+            let consent = serde_json::json!({
+                "at": status.get("at").cloned().unwrap_or(Value::Null),
+                "reference": status.get("terms_reference").cloned().unwrap_or(Value::Null),
+            });
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|c| (c.to_string(), consent.clone()))
+                .collect()
+        } else {
+            serde_json::Map::new()
+        };
+        status.insert(
+            "quorum".into(),
+            serde_json::json!({
+                "required": required,
+                "rule": "unanimous",
+                "consents": consents,
+            }),
+        );
+    }
+    Ok(())
+}
+
+/// v4 -> v5: grants written before purposes existed were blanket grants,
+/// so they keep covering every `FpicPurpose`.
+fn scope_fpic_grants(doc: &mut Value) -> Result<(), String> {
+    let corridors = doc
+        .get_mut("corridors")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+    for corridor in corridors {
+        let Some(grant) = corridor
+            .pointer_mut("/fpic/Granted")
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+        if !grant.contains_key("purposes") {
+            let all = serde_json::to_value(FpicPurpose::ALL).map_err(|e| e.to_string())?;
+            grant.insert("purposes".into(), all);
+        }
+    }
+    Ok(())
+}
+
+/// v5 -> v6: a set `governed_by_ids_framework` flag becomes the
+/// `unspecified` framework, since the flag never said which one.
+fn name_ids_frameworks(doc: &mut Value) -> Result<(), String> {
+    let corridors = doc
+        .get_mut("corridors")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+    for corridor in corridors {
+        let Some(scope) = corridor.get_mut("ids_scope").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        let governed = match scope.remove("governed_by_ids_framework") {
+            None => continue,
+            Some(flag) => flag
+                .as_bool()
+                .ok_or("governed_by_ids_framework must be a boolean")?,
+        };
+        if governed {
+            let frameworks =
+                serde_json::to_value([IdsFramework::Unspecified]).map_err(|e| e.to_string())?;
+            scope.insert("frameworks".into(), frameworks);
+        }
+    }
+    Ok(())
+}
+
+/// v6 -> v7: the flat neurorights flags move into their constraints.
+/// Old records never restricted inference categories or retention, so
+/// they allow every category, keep observations indefinitely and do not
+/// require a consent reference.
+fn structure_neurorights(doc: &mut Value) -> Result<(), String> {
+    let corridors = doc
+        .get_mut("corridors")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+    for corridor in corridors {
+        let Some(nr) = corridor
+            .get_mut("neurorights")
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+        if nr.contains_key("actuation") {
+            continue;
+        }
+        let mut flag = |name: &str| -> Result<bool, String> {
+            nr.remove(name)
+                .unwrap_or(Value::Bool(false))
+                .as_bool()
+                .ok_or_else(|| format!("{} must be a boolean", name))
+        };
+        let non_actuating = flag("non_actuating_required")?;
+        let no_hidden = flag("no_coercive_or_hidden_channels")?;
+        let voluntary = flag("discipline_signals_voluntary_only")?;
+        let allowed = serde_json::to_value(InferenceCategory::ALL).map_err(|e| e.to_string())?;
+        nr.insert(
+            "actuation".into(),
+            serde_json::json!({ "non_actuating_required": non_actuating }),
+        );
+        nr.insert(
+            "inference".into(),
+            serde_json::json!({ "allowed": allowed, "no_coercive_or_hidden_channels": no_hidden }),
+        );
+        nr.insert(
+            "discipline_signals".into(),
+            serde_json::json!({ "voluntary_only": voluntary, "consent_ref_required": false }),
+        );
+        nr.insert("retention".into(), serde_json::json!({ "limit": null }));
+    }
+    Ok(())
+}
+
+/// Decode a map document written under schema `version`, upgrading it
+/// step by step to `SCHEMA_VERSION`. `bytes` must be JSON; the stores
+/// call this after reading their own format.
+pub fn migrate_from(version: u32, bytes: &[u8]) -> Result<IndigenousEcoCorridorMap, StoreError> {
+    let doc: Value =
+        serde_json::from_slice(bytes).map_err(|e| StoreError::Decode(e.to_string()))?;
+    migrate_value(version, doc)
+}
+
+fn migrate_value(version: u32, mut doc: Value) -> Result<IndigenousEcoCorridorMap, StoreError> {
+    if version == 0 {
+        return Err(StoreError::Decode("schema version 0 does not exist".into()));
+    }
+    if version > SCHEMA_VERSION {
+        return Err(StoreError::UnsupportedVersion(version));
+    }
+    for step in &MIGRATIONS[(version - 1) as usize..] {
+        step(&mut doc).map_err(StoreError::Decode)?;
+    }
+    if let Some(fields) = doc.as_object_mut() {
+        fields.insert("schema_version".into(), SCHEMA_VERSION.into());
+    }
+    let doc: MapDocument =
+        serde_json::from_value(doc).map_err(|e| StoreError::Decode(e.to_string()))?;
+    doc.into_map()
+}
+
+/// Read the document's own version (1 when absent) and migrate it.
+fn load_document(doc: Value) -> Result<IndigenousEcoCorridorMap, StoreError> {
+    let version = match doc.get("schema_version") {
+        None => 1,
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| StoreError::Decode("schema_version must be an integer".into()))?,
+    };
+    migrate_value(version, doc)
+}
+
+/// On-disk document shape shared by all backends.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct MapDocument {
+    schema_version: u32,
+    corridors: Vec<IndigenousEcoCorridor>,
+    /// `(alias, target)` pairs; absent in documents written before aliases.
+    #[serde(default)]
+    aliases: Vec<(CorridorId, CorridorId)>,
+    /// Absent in documents written before the governance audit.
+    #[serde(default)]
+    governance_audit: GovernanceAudit,
+    /// Absent in documents written before sharing agreements.
+    #[serde(default)]
+    sharing_agreements: Vec<DataSharingAgreement>,
+}
+
+impl MapDocument {
+    fn from_map(map: &IndigenousEcoCorridorMap) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            corridors: map.iter().map(|(_, c)| c.clone()).collect(),
+            aliases: map
+                .aliases()
+                .map(|(alias, target)| (alias.clone(), target.clone()))
+                .collect(),
+            governance_audit: map.governance_audit().clone(),
+            sharing_agreements: map.sharing_agreements().to_vec(),
+        }
+    }
+
+    /// Rebuild the map, rejecting documents that give two corridors one
+    /// UUID or whose relationships point at corridors they do not contain.
+    fn into_map(self) -> Result<IndigenousEcoCorridorMap, StoreError> {
+        let mut map = IndigenousEcoCorridorMap::new();
+        for corridor in self.corridors {
+            map.upsert_corridor(corridor).map_err(StoreError::Decode)?;
+        }
+        for (alias, target) in self.aliases {
+            map.restore_alias(alias, target);
+        }
+        map.restore_audit(self.governance_audit);
+        map.restore_sharing_agreements(self.sharing_agreements);
+        let dangling = map.dangling_relationships();
+        if !dangling.is_empty() {
+            return Err(StoreError::DanglingRelationships(dangling));
+        }
+        Ok(map)
+    }
+}
+
+/// Write via a sibling temp file and rename, so a crash never leaves a
+/// half-written map behind.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), StoreError> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Pretty-printed JSON file, intended for human review and diffs.
+#[derive(Clone, Debug)]
+pub struct JsonFileStore {
+    path: PathBuf,
+}
+
+impl JsonFileStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl CorridorStore for JsonFileStore {
+    fn load(&self) -> Result<IndigenousEcoCorridorMap, StoreError> {
+        let bytes = fs::read(&self.path)?;
+        let doc: Value =
+            serde_json::from_slice(&bytes).map_err(|e| StoreError::Decode(e.to_string()))?;
+        load_document(doc)
+    }
+
+    fn save(&self, map: &IndigenousEcoCorridorMap) -> Result<(), StoreError> {
+        let bytes = self.snapshot(map)?;
+        write_atomic(&self.path, &bytes)
+    }
+
+    fn snapshot(&self, map: &IndigenousEcoCorridorMap) -> Result<Vec<u8>, StoreError> {
+        serde_json::to_vec_pretty(&MapDocument::from_map(map))
+            .map_err(|e| StoreError::Encode(e.to_string()))
+    }
+}
+
+/// Compact CBOR file, intended for large maps.
+#[derive(Clone, Debug)]
+pub struct CborFileStore {
+    path: PathBuf,
+}
+
+impl CborFileStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl CorridorStore for CborFileStore {
+    fn load(&self) -> Result<IndigenousEcoCorridorMap, StoreError> {
+        let file = fs::File::open(&self.path)?;
+        let doc: Value = ciborium::from_reader(io::BufReader::new(file))
+            .map_err(|e| StoreError::Decode(e.to_string()))?;
+        load_document(doc)
+    }
+
+    fn save(&self, map: &IndigenousEcoCorridorMap) -> Result<(), StoreError> {
+        let bytes = self.snapshot(map)?;
+        write_atomic(&self.path, &bytes)
+    }
+
+    fn snapshot(&self, map: &IndigenousEcoCorridorMap) -> Result<Vec<u8>, StoreError> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&MapDocument::from_map(map), &mut bytes)
+            .map_err(|e| StoreError::Encode(e.to_string()))?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::FpicStatus;
+    use crate::neurorights::NeurorightsConstraints;
+    use crate::test_support::{self, uniform_metrics};
+
+    fn sample_map() -> IndigenousEcoCorridorMap {
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(IndigenousEcoCorridor {
+            environmental: uniform_metrics(0.7),
+            ..test_support::corridor("territory:nation-x:river-y")
+        })
+        .unwrap();
+        map
+    }
+
+    #[test]
+    fn json_and_cbor_stores_round_trip() {
+        let map = sample_map();
+        let dir = std::env::temp_dir();
+        let stores: Vec<Box<dyn CorridorStore>> = vec![
+            Box::new(JsonFileStore::new(dir.join("iecm-store-test.json"))),
+            Box::new(CborFileStore::new(dir.join("iecm-store-test.cbor"))),
+        ];
+        for store in stores {
+            store.save(&map).unwrap();
+            let loaded = store.load().unwrap();
+            assert_eq!(
+                store.snapshot(&loaded).unwrap(),
+                store.snapshot(&map).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn unversioned_documents_migrate_and_newer_ones_are_refused() {
+        // A v1 document: no version field, no lifecycle, unnormalized id.
+        let mut legacy: Value = serde_json::from_slice(
+            &JsonFileStore::new("unused")
+                .snapshot(&sample_map())
+                .unwrap(),
+        )
+        .unwrap();
+        legacy.as_object_mut().unwrap().remove("schema_version");
+        let record = &mut legacy["corridors"][0];
+        record.as_object_mut().unwrap().remove("lifecycle");
+        record["descriptor"]["id"] = "Territory:Nation X:River_Y".into();
+        record["neurorights"] = serde_json::json!({
+            "non_actuating_required": true,
+            "no_coercive_or_hidden_channels": true,
+            "discipline_signals_voluntary_only": true,
+            "envelope_ref": null,
+        });
+        record["ids_scope"] = serde_json::json!({
+            "contains_indigenous_data": true,
+            "governed_by_ids_framework": true,
+            "governance_ref": record["ids_scope"]["governance_ref"].take(),
+        });
+
+        let mut map = migrate_from(1, legacy.to_string().as_bytes()).unwrap();
+        let id = map.iter().next().unwrap().0.clone();
+        let mut corridor = map.get(&id).unwrap().clone();
+        assert!(corridor.neurorights.is_strict());
+        assert_eq!(
+            corridor.neurorights.inference.allowed.len(),
+            InferenceCategory::ALL.len()
+        );
+        corridor.neurorights = NeurorightsConstraints::strict_non_actuating();
+        map.upsert_corridor(corridor).unwrap();
+        let store = JsonFileStore::new("unused");
+        assert_eq!(
+            store.snapshot(&map).unwrap(),
+            store.snapshot(&sample_map()).unwrap()
+        );
+
+        // A v2 document with a free-text custom kind.
+        let mut v2 = legacy.clone();
+        v2["corridors"][0]["descriptor"]["kind"] = serde_json::json!({ "Custom": "Sacred Grove" });
+        let map = migrate_from(2, v2.to_string().as_bytes()).unwrap();
+        let kind = &map.iter().next().unwrap().1.descriptor.kind;
+        assert_eq!(kind.key(), "legacy:sacred-grove");
+
+        // A v3 grant with a flat community list.
+        let mut v3 = legacy.clone();
+        v3["corridors"][0]["fpic"] = serde_json::json!({ "Granted": {
+            "at": { "secs_since_epoch": 0, "nanos_since_epoch": 0 },
+            "communities": ["nation-x", "nation-y"],
+            "terms_reference": "terms:1",
+        }});
+        let map = migrate_from(3, v3.to_string().as_bytes()).unwrap();
+        let corridor = map.iter().next().unwrap().1;
+        assert!(corridor.has_active_fpic(std::time::SystemTime::now()));
+        match &corridor.fpic {
+            FpicStatus::Granted {
+                quorum, purposes, ..
+            } => {
+                assert_eq!(quorum.consents().count(), 2);
+                assert_eq!(purposes.len(), FpicPurpose::ALL.len());
+            }
+            other => panic!("unexpected status {:?}", other),
+        }
+
+        let mut dangling = legacy.clone();
+        dangling["corridors"][0]["descriptor"]["relationships"] =
+            serde_json::json!([{ "kind": "upstream_of", "target": "eco:river:gone" }]);
+        assert!(matches!(
+            migrate_from(1, dangling.to_string().as_bytes()),
+            Err(StoreError::DanglingRelationships(d)) if d.len() == 1
+        ));
+
+        let path = std::env::temp_dir().join("iecm-store-future.json");
+        fs::write(&path, r#"{"schema_version": 99, "corridors": []}"#).unwrap();
+        assert!(matches!(
+            JsonFileStore::new(&path).load(),
+            Err(StoreError::UnsupportedVersion(99))
+        ));
+    }
+}
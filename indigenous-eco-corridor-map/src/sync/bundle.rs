@@ -0,0 +1,59 @@
+//! Signed event bundles exchanged between field devices and the map holder.
+//! Key management is external: signers are a trait, as are verifiers
+//! (see `ingest`).
+
+#![forbid(unsafe_code)]
+
+use serde::{Deserialize, Serialize};
+
+use super::event_log::{EventLog, FieldEvent};
+use super::SyncError;
+
+/// Produces detached signatures with the field team's key material.
+pub trait BundleSigner {
+    /// Identifier of the signing key or DID.
+    fn signer_id(&self) -> String;
+    fn sign(&self, payload: &[u8]) -> Vec<u8>;
+}
+
+/// Contiguous run of events from a single origin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EventBundle {
+    pub origin: String,
+    pub events: Vec<FieldEvent>,
+}
+
+impl EventBundle {
+    /// Bundle every event in `log` with `seq > after`.
+    pub fn from_log(log: &EventLog, after: u64) -> Self {
+        Self {
+            origin: log.origin().to_string(),
+            events: log.since(after).to_vec(),
+        }
+    }
+
+    /// Canonical bytes covered by the signature.
+    pub fn payload(&self) -> Result<Vec<u8>, SyncError> {
+        serde_json::to_vec(self).map_err(|e| SyncError::Encoding(e.to_string()))
+    }
+
+    /// Sign the bundle for transport.
+    pub fn seal(self, signer: &dyn BundleSigner) -> Result<SignedBundle, SyncError> {
+        let payload = self.payload()?;
+        Ok(SignedBundle {
+            signer: signer.signer_id(),
+            signature: signer.sign(&payload),
+            payload,
+        })
+    }
+}
+
+/// Bundle as transported: opaque payload plus detached signature.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SignedBundle {
+    pub signer: String,
+    pub signature: Vec<u8>,
+    pub payload: Vec<u8>,
+}
@@ -0,0 +1,102 @@
+//! Local, append-only log of field changes recorded while offline.
+
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::metrics::EnvironmentalMetrics;
+
+/// Compare-and-set edit: the value the editor saw and the value they want.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Edit<T> {
+    pub from: T,
+    pub to: T,
+}
+
+/// Change a field team may propose for an existing corridor.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FieldChange {
+    /// New metric observation. Observations never conflict: the most
+    /// recently recorded one becomes the corridor's current metrics.
+    /// Boxed to keep the other changes small.
+    MetricsObserved(Box<EnvironmentalMetrics>),
+    NameEdited(Edit<String>),
+    DescriptionEdited(Edit<Option<String>>),
+    CulturalNotesEdited(Edit<Option<String>>),
+}
+
+/// One recorded change, identified by `(origin, seq)`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FieldEvent {
+    /// Stable id of the recording device or field team.
+    pub origin: String,
+    /// Per-origin sequence number, strictly increasing from 1.
+    pub seq: u64,
+    pub recorded_at: SystemTime,
+    pub corridor_id: CorridorId,
+    pub change: FieldChange,
+}
+
+/// Append-only event log kept on a field device.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EventLog {
+    origin: String,
+    next_seq: u64,
+    events: Vec<FieldEvent>,
+}
+
+impl EventLog {
+    pub fn new(origin: impl Into<String>) -> Self {
+        Self {
+            origin: origin.into(),
+            next_seq: 1,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// Append a change; returns the recorded event.
+    pub fn record(
+        &mut self,
+        corridor_id: CorridorId,
+        change: FieldChange,
+        recorded_at: SystemTime,
+    ) -> &FieldEvent {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.events.push(FieldEvent {
+            origin: self.origin.clone(),
+            seq,
+            recorded_at,
+            corridor_id,
+            change,
+        });
+        self.events.last().expect("just pushed")
+    }
+
+    pub fn events(&self) -> &[FieldEvent] {
+        &self.events
+    }
+
+    /// Events with `seq > after`, e.g. those not yet acknowledged.
+    pub fn since(&self, after: u64) -> &[FieldEvent] {
+        let start = self.events.partition_point(|e| e.seq <= after);
+        &self.events[start..]
+    }
+
+    /// Drop events up to and including `seq` once the authoritative
+    /// side has acknowledged them.
+    pub fn acknowledge(&mut self, seq: u64) {
+        self.events.retain(|e| e.seq > seq);
+    }
+}
@@ -0,0 +1,110 @@
+//! Ingestion of transported bundles: signature, origin and sequence checks
+//! before anything reaches the ledger. `SyncLedger::merge` only accepts
+//! `VerifiedBundle`s, which only `SignedBundle::open` produces.
+
+#![forbid(unsafe_code)]
+
+use crate::map::IndigenousEcoCorridorMap;
+
+use super::bundle::{EventBundle, SignedBundle};
+use super::event_log::FieldEvent;
+use super::merge::{MergeReport, SyncLedger};
+use super::SyncError;
+
+/// Verifies detached signatures against known field-team keys.
+pub trait BundleVerifier {
+    fn verify(&self, signer_id: &str, payload: &[u8], signature: &[u8]) -> bool;
+
+    /// Whether `signer_id` may publish events for `origin`. By default a
+    /// signer speaks only for the origin of the same name; override to
+    /// map keys to field teams.
+    fn may_publish(&self, signer_id: &str, origin: &str) -> bool {
+        signer_id == origin
+    }
+}
+
+/// Bundle that passed `SignedBundle::open`; cannot be built otherwise.
+#[derive(Clone, Debug)]
+pub struct VerifiedBundle {
+    bundle: EventBundle,
+}
+
+impl VerifiedBundle {
+    pub fn origin(&self) -> &str {
+        &self.bundle.origin
+    }
+
+    pub fn events(&self) -> &[FieldEvent] {
+        &self.bundle.events
+    }
+
+    pub(crate) fn into_events(self) -> Vec<FieldEvent> {
+        self.bundle.events
+    }
+}
+
+impl SignedBundle {
+    /// Verify the signature, decode the bundle, and check that the signer
+    /// may publish for its origin and that every event belongs to that
+    /// origin with strictly increasing `seq`.
+    pub fn open(&self, verifier: &dyn BundleVerifier) -> Result<VerifiedBundle, SyncError> {
+        if !verifier.verify(&self.signer, &self.payload, &self.signature) {
+            return Err(SyncError::BadSignature {
+                signer: self.signer.clone(),
+            });
+        }
+        let bundle: EventBundle = serde_json::from_slice(&self.payload)
+            .map_err(|e| SyncError::Encoding(e.to_string()))?;
+        if !verifier.may_publish(&self.signer, &bundle.origin) {
+            return Err(SyncError::UnauthorizedOrigin {
+                signer: self.signer.clone(),
+                origin: bundle.origin,
+            });
+        }
+
+        let mut last_seq = 0;
+        for event in &bundle.events {
+            if event.origin != bundle.origin {
+                return Err(SyncError::ForeignEvent {
+                    origin: event.origin.clone(),
+                    seq: event.seq,
+                });
+            }
+            if event.seq <= last_seq {
+                return Err(SyncError::OutOfOrder { seq: event.seq });
+            }
+            last_seq = event.seq;
+        }
+        Ok(VerifiedBundle { bundle })
+    }
+}
+
+/// Outcome of `ingest`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IngestReport {
+    pub merge: MergeReport,
+    /// Index into the ingested bundles and why each was rejected; the
+    /// rest are merged.
+    pub rejected: Vec<(usize, SyncError)>,
+}
+
+/// Open every bundle with `verifier` and merge those that verify.
+pub fn ingest(
+    ledger: &mut SyncLedger,
+    map: &mut IndigenousEcoCorridorMap,
+    bundles: &[SignedBundle],
+    verifier: &dyn BundleVerifier,
+) -> IngestReport {
+    let mut rejected = Vec::new();
+    let mut opened = Vec::new();
+    for (i, bundle) in bundles.iter().enumerate() {
+        match bundle.open(verifier) {
+            Ok(verified) => opened.push(verified),
+            Err(e) => rejected.push((i, e)),
+        }
+    }
+    IngestReport {
+        merge: ledger.merge(map, opened),
+        rejected,
+    }
+}
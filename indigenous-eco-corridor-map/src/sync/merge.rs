@@ -0,0 +1,359 @@
+//! Deterministic merge of opened bundles into the authoritative map.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+use crate::corridor::CorridorId;
+use crate::map::IndigenousEcoCorridorMap;
+
+use super::event_log::{Edit, FieldChange, FieldEvent};
+use super::ingest::VerifiedBundle;
+use super::SyncError;
+
+/// Why an event was routed to human review instead of being applied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReviewReason {
+    /// The corridor is not registered; field sync never creates corridors.
+    UnknownCorridor,
+    /// The edited field no longer holds the value the editor saw.
+    EditConflict,
+}
+
+/// Event awaiting a human decision.
+#[derive(Clone, Debug)]
+pub struct ReviewItem {
+    pub event: FieldEvent,
+    pub reason: ReviewReason,
+}
+
+/// Outcome of one `SyncLedger::merge` call.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MergeReport {
+    /// `(origin, seq)` of every event applied to the map, in apply order.
+    pub applied: Vec<(String, u64)>,
+    /// Events already merged earlier (re-sent bundles).
+    pub duplicates: usize,
+    /// Observations older than the corridor's latest merged observation.
+    pub superseded: usize,
+    /// Events after a sequence gap; resend from `acknowledged(origin)`.
+    pub deferred: usize,
+    /// Events newly placed in the review queue.
+    pub queued_for_review: usize,
+}
+
+/// Sync bookkeeping kept next to the authoritative map.
+///
+/// Conflict rules, applied in `(recorded_at, origin, seq)` order so the
+/// result does not depend on bundle arrival order:
+/// - observations: newest `recorded_at` wins; older ones are superseded;
+/// - edits: compare-and-set; applied when the field still holds
+///   `from` (or already holds `to`), otherwise queued for review;
+/// - events for unknown corridors are queued for review.
+#[derive(Clone, Debug, Default)]
+pub struct SyncLedger {
+    acknowledged: BTreeMap<String, u64>,
+    latest_observation: BTreeMap<CorridorId, SystemTime>,
+    review: Vec<ReviewItem>,
+}
+
+enum Disposition {
+    Applied,
+    Superseded,
+    Review(ReviewReason),
+}
+
+impl SyncLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highest contiguous sequence merged for `origin` (0 if none);
+    /// field devices resend everything after it.
+    pub fn acknowledged(&self, origin: &str) -> u64 {
+        self.acknowledged.get(origin).copied().unwrap_or(0)
+    }
+
+    /// Merge verified bundles into `map`.
+    pub fn merge(
+        &mut self,
+        map: &mut IndigenousEcoCorridorMap,
+        bundles: Vec<VerifiedBundle>,
+    ) -> MergeReport {
+        let mut report = MergeReport::default();
+
+        // Per origin, accept only the contiguous run after the watermark.
+        let mut by_origin: BTreeMap<String, BTreeMap<u64, FieldEvent>> = BTreeMap::new();
+        for bundle in bundles {
+            for event in bundle.into_events() {
+                by_origin
+                    .entry(event.origin.clone())
+                    .or_default()
+                    .insert(event.seq, event);
+            }
+        }
+        let mut accepted = Vec::new();
+        for (origin, events) in by_origin {
+            let mut next = self.acknowledged(&origin) + 1;
+            for (seq, event) in events {
+                if seq < next {
+                    report.duplicates += 1;
+                } else if seq == next {
+                    accepted.push(event);
+                    next += 1;
+                } else {
+                    report.deferred += 1;
+                }
+            }
+            self.acknowledged.insert(origin, next - 1);
+        }
+
+        accepted.sort_by(|a, b| {
+            (a.recorded_at, &a.origin, a.seq).cmp(&(b.recorded_at, &b.origin, b.seq))
+        });
+        for event in accepted {
+            match self.apply(map, &event, false) {
+                Disposition::Applied => report.applied.push((event.origin.clone(), event.seq)),
+                Disposition::Superseded => report.superseded += 1,
+                Disposition::Review(reason) => {
+                    report.queued_for_review += 1;
+                    self.review.push(ReviewItem { event, reason });
+                }
+            }
+        }
+        report
+    }
+
+    /// Events awaiting human review, oldest first.
+    pub fn review_queue(&self) -> &[ReviewItem] {
+        &self.review
+    }
+
+    /// Apply a reviewed edit regardless of conflicts (the reviewer has
+    /// accepted its `to` value) and remove it from the queue.
+    pub fn approve(
+        &mut self,
+        index: usize,
+        map: &mut IndigenousEcoCorridorMap,
+    ) -> Result<(), SyncError> {
+        let event = self
+            .review
+            .get(index)
+            .ok_or(SyncError::NoSuchReviewItem(index))?
+            .event
+            .clone();
+        match self.apply(map, &event, true) {
+            Disposition::Review(_) => Err(SyncError::Apply(format!(
+                "CorridorId '{}' is not registered",
+                event.corridor_id
+            ))),
+            _ => {
+                self.review.remove(index);
+                Ok(())
+            }
+        }
+    }
+
+    /// Discard a queued event without applying it.
+    pub fn dismiss(&mut self, index: usize) -> Result<ReviewItem, SyncError> {
+        if index < self.review.len() {
+            Ok(self.review.remove(index))
+        } else {
+            Err(SyncError::NoSuchReviewItem(index))
+        }
+    }
+
+    fn apply(
+        &mut self,
+        map: &mut IndigenousEcoCorridorMap,
+        event: &FieldEvent,
+        force: bool,
+    ) -> Disposition {
+        let Some(current) = map.get(&event.corridor_id) else {
+            return Disposition::Review(ReviewReason::UnknownCorridor);
+        };
+
+        if let FieldChange::MetricsObserved(metrics) = &event.change {
+            let latest = self.latest_observation.get(&event.corridor_id);
+            if !force && latest.is_some_and(|t| *t > event.recorded_at) {
+                return Disposition::Superseded;
+            }
+            map.update_metrics(&event.corridor_id, (**metrics).clone())
+                .expect("corridor presence checked above");
+            let latest = self
+                .latest_observation
+                .entry(event.corridor_id.clone())
+                .or_insert(event.recorded_at);
+            *latest = (*latest).max(event.recorded_at);
+            return Disposition::Applied;
+        }
+
+        let mut updated = current.clone();
+        let applied = match &event.change {
+            FieldChange::MetricsObserved(_) => unreachable!("handled above"),
+            FieldChange::NameEdited(edit) => {
+                compare_and_set(&mut updated.descriptor.name, edit, force)
+            }
+            FieldChange::DescriptionEdited(edit) => {
+                compare_and_set(&mut updated.descriptor.description, edit, force)
+            }
+            FieldChange::CulturalNotesEdited(edit) => {
+                compare_and_set(&mut updated.cultural_notes, edit, force)
+            }
+        };
+        if applied {
+            updated.signature = None;
+            map.upsert_corridor(updated)
+                .expect("edits keep the record's uuid");
+            Disposition::Applied
+        } else {
+            Disposition::Review(ReviewReason::EditConflict)
+        }
+    }
+}
+
+fn compare_and_set<T: Clone + PartialEq>(field: &mut T, edit: &Edit<T>, force: bool) -> bool {
+    if force || *field == edit.from || *field == edit.to {
+        *field = edit.to.clone();
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{MetricComponent, Score};
+    use crate::sync::{ingest, BundleSigner, BundleVerifier, EventBundle, EventLog, SignedBundle};
+    use crate::test_support::{self, uniform_metrics};
+    use std::time::Duration;
+
+    /// Toy scheme for tests only: the signature is the signer id bytes.
+    struct NameSigner<'a>(&'a str);
+
+    impl BundleSigner for NameSigner<'_> {
+        fn signer_id(&self) -> String {
+            self.0.to_string()
+        }
+        fn sign(&self, _payload: &[u8]) -> Vec<u8> {
+            self.0.as_bytes().to_vec()
+        }
+    }
+
+    struct NameVerifier;
+
+    impl BundleVerifier for NameVerifier {
+        fn verify(&self, signer_id: &str, _payload: &[u8], signature: &[u8]) -> bool {
+            signer_id.as_bytes() == signature
+        }
+    }
+
+    /// Seal `log` with the key named after its origin.
+    fn seal(log: &EventLog) -> SignedBundle {
+        EventBundle::from_log(log, 0)
+            .seal(&NameSigner(log.origin()))
+            .unwrap()
+    }
+
+    #[test]
+    fn merge_is_order_independent_and_queues_conflicts() {
+        let id = CorridorId::new("eco:river:y").unwrap();
+        let mut base = IndigenousEcoCorridorMap::new();
+        let mut river = test_support::corridor(id.as_str());
+        river.descriptor.name = "River Y".into();
+        base.upsert_corridor(river).unwrap();
+
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let rename = |to: &str| {
+            FieldChange::NameEdited(Edit {
+                from: "River Y".to_string(),
+                to: to.to_string(),
+            })
+        };
+        let mut north = EventLog::new("team-north");
+        north.record(
+            id.clone(),
+            FieldChange::MetricsObserved(Box::new(uniform_metrics(0.7))),
+            t0,
+        );
+        north.record(
+            id.clone(),
+            rename("Upper River Y"),
+            t0 + Duration::from_secs(5),
+        );
+        let mut south = EventLog::new("team-south");
+        south.record(
+            id.clone(),
+            FieldChange::MetricsObserved(Box::new(uniform_metrics(0.6))),
+            t0 + Duration::from_secs(1),
+        );
+        south.record(
+            id.clone(),
+            rename("Lower River Y"),
+            t0 + Duration::from_secs(9),
+        );
+
+        let bundles = [seal(&north), seal(&south)];
+        let mut results = Vec::new();
+        for order in [[0, 1], [1, 0]] {
+            let mut map = base.clone();
+            let mut ledger = SyncLedger::new();
+            let opened = order
+                .iter()
+                .map(|i| bundles[*i].open(&NameVerifier).unwrap())
+                .collect();
+            let report = ledger.merge(&mut map, opened);
+            assert_eq!(report.applied.len(), 3);
+            assert_eq!(report.queued_for_review, 1);
+            assert_eq!(ledger.review_queue()[0].reason, ReviewReason::EditConflict);
+
+            // Re-sending the same bundle is idempotent.
+            let again = ledger.merge(&mut map, vec![bundles[0].open(&NameVerifier).unwrap()]);
+            assert_eq!(again.duplicates, 2);
+            assert!(again.applied.is_empty());
+
+            let corridor = map.get(&id).unwrap();
+            results.push((
+                corridor.descriptor.name.clone(),
+                corridor
+                    .environmental
+                    .component(MetricComponent::WaterQuality),
+            ));
+        }
+        assert_eq!(results[0], results[1]);
+        assert_eq!(results[0].0, "Upper River Y");
+        assert_eq!(results[0].1, Score::new(0.6).unwrap());
+
+        let mut forged = seal(&north);
+        forged.signer = "did:example:intruder".into();
+        assert!(forged.open(&NameVerifier).is_err());
+
+        // A valid key for one team cannot publish under another's origin.
+        let impersonated = EventBundle::from_log(&north, 0)
+            .seal(&NameSigner("team-south"))
+            .unwrap();
+        assert_eq!(
+            impersonated.open(&NameVerifier).unwrap_err(),
+            SyncError::UnauthorizedOrigin {
+                signer: "team-south".into(),
+                origin: "team-north".into(),
+            }
+        );
+
+        // Ingestion merges what verifies and reports the rest.
+        let mut map = base.clone();
+        let mut ledger = SyncLedger::new();
+        let report = ingest(
+            &mut ledger,
+            &mut map,
+            &[impersonated, seal(&south)],
+            &NameVerifier,
+        );
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].0, 0);
+        assert_eq!(report.merge.applied.len(), 2);
+        assert_eq!(ledger.acknowledged("team-north"), 0);
+    }
+}
@@ -0,0 +1,73 @@
+//! Offline-first sync for field-collected corridor data.
+//!
+//! Field teams record changes into a local `EventLog` while disconnected,
+//! seal them into a `SignedBundle`, and hand the bundle to whoever holds
+//! the authoritative map. There `ingest` opens it (signature, origin and
+//! sequence checks) into a `VerifiedBundle`, and a `SyncLedger` merges its
+//! events with
+//! deterministic conflict resolution. Edits that cannot be reconciled
+//! automatically go to a human-review queue instead of being guessed.
+//!
+//! Field events can only carry observational and descriptive changes;
+//! FPIC, IDS, and neurorights fields are never writable through sync.
+
+#![forbid(unsafe_code)]
+
+pub mod bundle;
+pub mod event_log;
+pub mod ingest;
+pub mod merge;
+
+use std::fmt;
+
+pub use bundle::{BundleSigner, EventBundle, SignedBundle};
+pub use event_log::{Edit, EventLog, FieldChange, FieldEvent};
+pub use ingest::{ingest, BundleVerifier, IngestReport, VerifiedBundle};
+pub use merge::{MergeReport, ReviewItem, ReviewReason, SyncLedger};
+
+/// Errors raised while sealing, opening, or resolving sync data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncError {
+    /// Bundle payload could not be encoded or decoded.
+    Encoding(String),
+    /// Signature did not verify for the claimed signer.
+    BadSignature { signer: String },
+    /// The signer may not publish events for the bundle origin.
+    UnauthorizedOrigin { signer: String, origin: String },
+    /// An event's origin differs from the bundle origin.
+    ForeignEvent { origin: String, seq: u64 },
+    /// Event sequence numbers are not strictly increasing.
+    OutOfOrder { seq: u64 },
+    /// No review item exists at the given index.
+    NoSuchReviewItem(usize),
+    /// Applying a reviewed event failed (e.g., corridor was removed).
+    Apply(String),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncError::Encoding(e) => write!(f, "bundle encoding error: {}", e),
+            SyncError::BadSignature { signer } => {
+                write!(f, "bundle signature from '{}' did not verify", signer)
+            }
+            SyncError::UnauthorizedOrigin { signer, origin } => {
+                write!(f, "'{}' may not publish events for '{}'", signer, origin)
+            }
+            SyncError::ForeignEvent { origin, seq } => {
+                write!(
+                    f,
+                    "event {} from '{}' does not belong to this bundle",
+                    seq, origin
+                )
+            }
+            SyncError::OutOfOrder { seq } => {
+                write!(f, "event sequence is not strictly increasing at {}", seq)
+            }
+            SyncError::NoSuchReviewItem(i) => write!(f, "no review item at index {}", i),
+            SyncError::Apply(e) => write!(f, "could not apply event: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SyncError {}
@@ -0,0 +1,220 @@
+//! Community-defined corridor kinds beyond the built-in `CorridorKind`s.
+//! Descriptive only; a registry keeps filtering and reporting by kind
+//! consistent across communities and tools.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::{normalize_segment, CorridorId, CorridorKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+/// Namespace given to free-text kinds from before the taxonomy existed.
+pub const LEGACY_NAMESPACE: &str = "legacy";
+
+/// A community-defined kind: `namespace` (usually the defining community
+/// or standard), `code` within it, and a display `label`.
+///
+/// Namespace and code are normalized like `CorridorId` segments, and
+/// identity is `namespace:code`: two kinds differing only in label are
+/// the same kind.
+#[derive(Clone, Debug, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawCustomKind")]
+pub struct CustomKind {
+    namespace: String,
+    code: String,
+    label: String,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawCustomKind {
+    namespace: String,
+    code: String,
+    label: String,
+}
+
+impl TryFrom<RawCustomKind> for CustomKind {
+    type Error = String;
+
+    fn try_from(raw: RawCustomKind) -> Result<Self, String> {
+        Self::new(raw.namespace, raw.code, raw.label)
+    }
+}
+
+impl CustomKind {
+    pub fn new(
+        namespace: impl AsRef<str>,
+        code: impl AsRef<str>,
+        label: impl Into<String>,
+    ) -> Result<Self, String> {
+        let namespace = normalize_segment(namespace.as_ref().trim());
+        let code = normalize_segment(code.as_ref().trim());
+        if namespace.is_empty() || code.is_empty() {
+            return Err("custom corridor kind needs a namespace and a code".into());
+        }
+        if namespace.contains(':') || code.contains(':') {
+            return Err(format!(
+                "custom corridor kind '{}:{}' must not contain ':' in its parts",
+                namespace, code
+            ));
+        }
+        Ok(Self {
+            namespace,
+            code,
+            label: label.into(),
+        })
+    }
+
+    /// Kind for a pre-taxonomy free-text value, e.g. "Sacred Grove"
+    /// becomes `legacy:sacred-grove` labelled "Sacred Grove".
+    pub fn legacy(text: &str) -> Result<Self, String> {
+        Self::new(LEGACY_NAMESPACE, text, text.trim())
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Stable `namespace:code` key.
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.namespace, self.code)
+    }
+}
+
+impl PartialEq for CustomKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace == other.namespace && self.code == other.code
+    }
+}
+
+impl Hash for CustomKind {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.namespace.hash(state);
+        self.code.hash(state);
+    }
+}
+
+/// Registered community-defined kinds, keyed by `namespace:code`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct KindRegistry {
+    kinds: BTreeMap<String, CustomKind>,
+}
+
+impl KindRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `kind`. Re-registering the same kind is a no-op unless
+    /// the label differs, which is rejected so reports stay consistent.
+    pub fn register(&mut self, kind: CustomKind) -> Result<(), String> {
+        match self.kinds.get(&kind.key()) {
+            Some(existing) if existing.label != kind.label => Err(format!(
+                "corridor kind '{}' is already registered as '{}'",
+                kind.key(),
+                existing.label
+            )),
+            Some(_) => Ok(()),
+            None => {
+                self.kinds.insert(kind.key(), kind);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&CustomKind> {
+        self.kinds.get(key)
+    }
+
+    pub fn is_registered(&self, kind: &CorridorKind) -> bool {
+        match kind {
+            CorridorKind::Custom(custom) => self.kinds.contains_key(&custom.key()),
+            _ => true,
+        }
+    }
+
+    /// Strict inverse of `CorridorKind::key()`: built-in keys and
+    /// registered `namespace:code` keys resolve (with the registered
+    /// label); anything else is an error.
+    pub fn parse(&self, key: &str) -> Result<CorridorKind, String> {
+        match CorridorKind::from_key(key)? {
+            CorridorKind::Custom(custom) => self
+                .kinds
+                .get(&custom.key())
+                .cloned()
+                .map(CorridorKind::Custom)
+                .ok_or_else(|| format!("corridor kind '{}' is not registered", key.trim())),
+            builtin => Ok(builtin),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CustomKind> {
+        self.kinds.values()
+    }
+
+    /// Corridors whose custom kind is not registered, in id order.
+    pub fn unregistered_in(&self, map: &IndigenousEcoCorridorMap) -> Vec<CorridorId> {
+        map.iter()
+            .filter(|(_, c)| !self.is_registered(&c.descriptor.kind))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Corridors of `kind`, in id order. Custom kinds match on
+    /// `namespace:code`, whatever their label.
+    pub fn corridors_of_kind<'a>(
+        &'a self,
+        kind: &'a CorridorKind,
+    ) -> impl Iterator<Item = &'a IndigenousEcoCorridor> {
+        self.iter()
+            .map(|(_, c)| c)
+            .filter(move |c| c.descriptor.kind == *kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_resolves_custom_kinds_by_namespace_and_code() {
+        let mut registry = KindRegistry::new();
+        let grove = CustomKind::new("Nation X", "Sacred_Grove", "Sacred grove").unwrap();
+        assert_eq!(grove.key(), "nation-x:sacred-grove");
+        registry.register(grove.clone()).unwrap();
+        registry.register(grove.clone()).unwrap();
+        assert!(registry
+            .register(CustomKind::new("nation-x", "sacred-grove", "Grove").unwrap())
+            .is_err());
+
+        // Keys are matched normalized and the registered label is applied.
+        match registry.parse("NATION-X: sacred grove").unwrap() {
+            CorridorKind::Custom(kind) => assert_eq!(kind.label(), "Sacred grove"),
+            other => panic!("unexpected kind {:?}", other),
+        }
+        assert_eq!(registry.parse("forest").unwrap(), CorridorKind::Forest);
+        assert!(registry.parse("nation-y:grove").is_err());
+
+        let unlabelled = CorridorKind::from_key("nation-x:sacred-grove").unwrap();
+        assert_eq!(unlabelled, CorridorKind::Custom(grove));
+        assert!(registry.is_registered(&unlabelled));
+        assert!(!registry.is_registered(&CorridorKind::from_key("Sacred Grove").unwrap()));
+        assert!(CorridorKind::from_key(" : ").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Structured consent terms.
+//! A grant's `terms_reference` points into an FPIC ledger; a grant may
+//! also carry the terms themselves, bound to that reference by hash, so
+//! their preconditions can be evaluated without a ledger lookup.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::governance::FpicPurpose;
+use crate::hashing::ContentHash;
+
+/// What the communities agreed to, in checkable form.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConsentTerms {
+    pub permitted_purposes: BTreeSet<FpicPurpose>,
+    /// Uses ruled out even within permitted purposes, e.g.
+    /// "commercial_resale".
+    #[serde(default)]
+    pub prohibited_uses: BTreeSet<String>,
+    /// How long collected data may be kept; `None` for no limit.
+    #[serde(default)]
+    pub retention_limit: Option<Duration>,
+    /// Required acknowledgements, e.g. citation of the community.
+    #[serde(default)]
+    pub attribution: Vec<String>,
+    /// Conditions under which the communities may revoke consent.
+    #[serde(default)]
+    pub revocation_conditions: Vec<String>,
+}
+
+impl ConsentTerms {
+    pub fn permits(&self, purpose: FpicPurpose) -> bool {
+        self.permitted_purposes.contains(&purpose)
+    }
+
+    pub fn prohibits(&self, data_use: &str) -> bool {
+        self.prohibited_uses.contains(data_use.trim())
+    }
+
+    /// When data collected at `collected_at` must be deleted, if the
+    /// terms limit retention.
+    pub fn retention_ends(&self, collected_at: SystemTime) -> Option<SystemTime> {
+        self.retention_limit.map(|limit| collected_at + limit)
+    }
+}
+
+/// Terms carried on a grant, with the hash binding them to the grant's
+/// `terms_reference`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BoundTerms {
+    pub terms: ConsentTerms,
+    /// SHA-256 over the reference and the terms; see `BoundTerms::bind`.
+    pub hash: ContentHash,
+}
+
+impl BoundTerms {
+    /// Bind `terms` to the ledger entry at `terms_reference`.
+    pub fn bind(terms: ConsentTerms, terms_reference: &str) -> Self {
+        let hash = binding_hash(&terms, terms_reference);
+        Self { terms, hash }
+    }
+
+    /// Check the terms are bound to `terms_reference` and permit every
+    /// one of `purposes`.
+    pub fn check(
+        &self,
+        terms_reference: &str,
+        purposes: &BTreeSet<FpicPurpose>,
+    ) -> Result<(), TermsError> {
+        if self.hash != binding_hash(&self.terms, terms_reference) {
+            return Err(TermsError::Unbound);
+        }
+        match purposes.difference(&self.terms.permitted_purposes).next() {
+            Some(p) => Err(TermsError::PurposeNotPermitted(*p)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn binding_hash(terms: &ConsentTerms, terms_reference: &str) -> ContentHash {
+    let canonical =
+        serde_json::to_vec(&(terms_reference, terms)).expect("consent terms always serialize");
+    ContentHash(Sha256::digest(canonical).into())
+}
+
+/// Why a grant's structured terms were rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TermsError {
+    /// The hash does not bind the terms to the grant's terms reference.
+    Unbound,
+    /// The grant covers a purpose the terms do not permit.
+    PurposeNotPermitted(FpicPurpose),
+}
+
+impl fmt::Display for TermsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TermsError::Unbound => write!(f, "consent terms are not bound to the terms reference"),
+            TermsError::PurposeNotPermitted(p) => {
+                write!(f, "consent terms do not permit {}", p)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TermsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terms_are_bound_to_their_reference() {
+        let terms = ConsentTerms {
+            permitted_purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            prohibited_uses: BTreeSet::from(["commercial_resale".to_string()]),
+            retention_limit: Some(Duration::from_secs(3600)),
+            attribution: vec!["cite Nation X".into()],
+            revocation_conditions: vec!["council resolution".into()],
+        };
+        assert!(terms.permits(FpicPurpose::Monitoring));
+        assert!(terms.prohibits(" commercial_resale"));
+        assert_eq!(
+            terms.retention_ends(SystemTime::UNIX_EPOCH),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(3600))
+        );
+
+        let bound = BoundTerms::bind(terms, "terms:1");
+        let monitoring = BTreeSet::from([FpicPurpose::Monitoring]);
+        assert_eq!(bound.check("terms:1", &monitoring), Ok(()));
+        assert_eq!(
+            bound.check("terms:2", &monitoring),
+            Err(TermsError::Unbound)
+        );
+        assert_eq!(
+            bound.check("terms:1", &BTreeSet::from([FpicPurpose::Research])),
+            Err(TermsError::PurposeNotPermitted(FpicPurpose::Research))
+        );
+
+        let mut edited = bound;
+        edited.terms.prohibited_uses.clear();
+        assert_eq!(
+            edited.check("terms:1", &monitoring),
+            Err(TermsError::Unbound)
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Territories: nested groupings of corridors under a community or nation,
+//! matching how governance bodies reason about land.
+
+#![forbid(unsafe_code)]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::governance::{CommunityId, FpicStatusKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// A named group of corridors, optionally containing sub-territories
+/// (e.g., nation > district > watershed).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Territory {
+    pub id: String,
+    pub name: String,
+    /// Community or nation that governs this territory.
+    pub steward: CommunityId,
+    /// Corridors placed directly in this territory.
+    pub corridors: BTreeSet<CorridorId>,
+    pub children: Vec<Territory>,
+}
+
+impl Territory {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, steward: CommunityId) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            steward,
+            corridors: BTreeSet::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Corridors in this territory and all sub-territories.
+    pub fn all_corridors(&self) -> BTreeSet<CorridorId> {
+        let mut ids = self.corridors.clone();
+        for child in &self.children {
+            ids.extend(child.all_corridors());
+        }
+        ids
+    }
+
+    /// Roll-up of this territory (including sub-territories) against
+    /// `map` as of `now`, with one nested roll-up per child.
+    pub fn rollup(&self, map: &IndigenousEcoCorridorMap, now: SystemTime) -> TerritoryRollup {
+        let mut canonical = BTreeSet::new();
+        let mut missing = Vec::new();
+        for id in self.all_corridors() {
+            match map.resolve(&id) {
+                Some(resolved) => {
+                    canonical.insert(resolved.clone());
+                }
+                None => missing.push(id),
+            }
+        }
+
+        let mut fpic_counts = BTreeMap::new();
+        let mut score_sum = 0.0f64;
+        let mut active_grants = 0;
+        for id in &canonical {
+            let corridor = map.get(id).expect("resolved above");
+            *fpic_counts.entry(corridor.fpic.kind()).or_insert(0) += 1;
+            score_sum += f64::from(corridor.environmental.aggregate_score().get());
+            if corridor.has_active_fpic(now) {
+                active_grants += 1;
+            }
+        }
+        let count = canonical.len();
+
+        TerritoryRollup {
+            territory_id: self.id.clone(),
+            corridor_count: count,
+            missing,
+            mean_score: (count > 0).then(|| Score::exact((score_sum / count as f64) as f32)),
+            fpic_counts,
+            fpic_coverage: (count > 0).then(|| active_grants as f32 / count as f32),
+            metrics: map.rollup_metrics(&canonical),
+            children: self.children.iter().map(|c| c.rollup(map, now)).collect(),
+        }
+    }
+}
+
+/// Aggregates for one territory; each corridor counts once even if it is
+/// listed in several sub-territories.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TerritoryRollup {
+    pub territory_id: String,
+    pub corridor_count: usize,
+    /// Listed corridor ids the map does not know (stale or removed).
+    pub missing: Vec<CorridorId>,
+    pub mean_score: Option<Score>,
+    pub fpic_counts: BTreeMap<FpicStatusKind, usize>,
+    /// Fraction of corridors under an active FPIC grant.
+    pub fpic_coverage: Option<f32>,
+    /// Territory-level metrics; `None` without corridors.
+    pub metrics: Option<MetricsRollup>,
+    pub children: Vec<TerritoryRollup>,
+}
+
+/// How corridors were weighted in a metrics roll-up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RollupWeighting {
+    /// By surveyed `PhysicalAttributes::area`.
+    SurveyedArea,
+    /// By polygon boundary area, in the boundaries' coordinate units.
+    BoundaryArea,
+    /// Every corridor counts once, because some corridor has no area.
+    Equal,
+}
+
+/// Metrics for a set of corridors: each component is the weighted mean
+/// over the corridors that measured it, and unmeasured where none did.
+/// A component's quality is the worst among those corridors, or
+/// unlabelled if any of them is. Score bounds are not carried over.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MetricsRollup {
+    pub metrics: EnvironmentalMetrics,
+    pub weighting: RollupWeighting,
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Roll up the metrics of `ids`, weighting by area when every
+    /// corridor has one: surveyed area first, then polygon boundary area.
+    /// Ids resolve through aliases and count once; unknown ids are
+    /// ignored. `None` if no id is known.
+    pub fn rollup_metrics<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a CorridorId>,
+    ) -> Option<MetricsRollup> {
+        let canonical: BTreeSet<&CorridorId> =
+            ids.into_iter().filter_map(|id| self.resolve(id)).collect();
+        let corridors: Vec<&IndigenousEcoCorridor> = canonical
+            .into_iter()
+            .filter_map(|id| self.get(id))
+            .collect();
+        if corridors.is_empty() {
+            return None;
+        }
+
+        let surveyed = |c: &IndigenousEcoCorridor| c.descriptor.physical.area.map(|a| a.get());
+        let boundary = |c: &IndigenousEcoCorridor| c.descriptor.boundary.as_ref().map(|b| b.area());
+        let all_positive = |area: &dyn Fn(&IndigenousEcoCorridor) -> Option<f64>| {
+            corridors
+                .iter()
+                .map(|c| area(c).filter(|a| *a > 0.0))
+                .collect::<Option<Vec<f64>>>()
+        };
+        let (weighting, weights) = match (all_positive(&surveyed), all_positive(&boundary)) {
+            (Some(weights), _) => (RollupWeighting::SurveyedArea, weights),
+            (None, Some(weights)) => (RollupWeighting::BoundaryArea, weights),
+            (None, None) => (RollupWeighting::Equal, vec![1.0; corridors.len()]),
+        };
+
+        let mut metrics =
+            EnvironmentalMetrics::from_components([Score::exact(0.0); MetricComponent::COUNT]);
+        for component in MetricComponent::ALL {
+            let (sum, total) = corridors.iter().zip(&weights).fold(
+                (0.0f64, 0.0f64),
+                |(sum, total), (c, w)| match c.environmental.measured(component) {
+                    Some(score) => (sum + f64::from(score.get()) * w, total + w),
+                    None => (sum, total),
+                },
+            );
+            if total > 0.0 {
+                let mean = (sum / total).clamp(0.0, 1.0) as f32;
+                metrics.set_component(component, Score::exact(mean));
+            } else {
+                metrics.unmeasured.insert(component);
+                continue;
+            }
+            let contributing = corridors
+                .iter()
+                .filter(|c| c.environmental.measured(component).is_some())
+                .map(|c| c.environmental.quality(component));
+            if let Some(worst) = contributing
+                .collect::<Option<Vec<_>>>()
+                .and_then(|q| q.into_iter().max())
+            {
+                metrics.quality.insert(component, worst);
+            }
+        }
+        Some(MetricsRollup { metrics, weighting })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{Consent, ConsentQuorum, FpicPurpose, FpicStatus};
+    use crate::test_support::{self, uniform_metrics};
+    use crate::units::Hectares;
+
+    fn corridor(id: &str, score: f32, fpic: FpicStatus) -> IndigenousEcoCorridor {
+        IndigenousEcoCorridor {
+            environmental: uniform_metrics(score),
+            fpic,
+            ..test_support::corridor(id)
+        }
+    }
+
+    #[test]
+    fn rollup_covers_nested_territories_once() {
+        let nation = CommunityId("did:example:nation".into());
+        let grant = FpicStatus::Granted {
+            at: SystemTime::now(),
+            quorum: ConsentQuorum::sole(
+                nation.clone(),
+                Consent {
+                    at: SystemTime::now(),
+                    reference: "consent:1".into(),
+                    delegation: None,
+                },
+            ),
+            terms_reference: "terms:n".into(),
+            purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+            valid_until: None,
+            renewal_notice: None,
+            terms: None,
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor("eco:mtn:a", 0.2, grant))
+            .unwrap();
+        map.upsert_corridor(corridor("eco:mtn:b", 0.6, FpicStatus::Pending))
+            .unwrap();
+
+        let id = |s: &str| CorridorId::new(s).unwrap();
+        let mut district = Territory::new("district", "District", nation.clone());
+        district
+            .corridors
+            .extend([id("eco:mtn:a"), id("eco:mtn:gone")]);
+        let mut root = Territory::new("nation", "Nation", nation);
+        root.corridors.extend([id("eco:mtn:a"), id("eco:mtn:b")]);
+        root.children.push(district);
+
+        let rollup = root.rollup(&map, SystemTime::now());
+        assert_eq!(rollup.corridor_count, 2);
+        assert_eq!(rollup.missing, [id("eco:mtn:gone")]);
+        assert_eq!(rollup.fpic_coverage, Some(0.5));
+        assert!((rollup.mean_score.unwrap().get() - 0.4).abs() < 1e-6);
+        assert_eq!(rollup.children[0].corridor_count, 1);
+        assert_eq!(rollup.children[0].fpic_coverage, Some(1.0));
+
+        let equal = rollup.metrics.unwrap();
+        assert_eq!(equal.weighting, RollupWeighting::Equal);
+        assert!((equal.metrics.aggregate_score().get() - 0.4).abs() < 1e-6);
+
+        let mut large = corridor("eco:mtn:c", 0.9, FpicStatus::Pending);
+        large.descriptor.physical.area = Some(Hectares::new(300.0).unwrap());
+        large
+            .environmental
+            .unmeasured
+            .insert(MetricComponent::WaterQuality);
+        let mut small = corridor("eco:mtn:d", 0.1, FpicStatus::Pending);
+        small.descriptor.physical.area = Some(Hectares::new(100.0).unwrap());
+        map.upsert_corridor(large).unwrap();
+        map.upsert_corridor(small).unwrap();
+        let weighted = map
+            .rollup_metrics(&[id("eco:mtn:c"), id("eco:mtn:d"), id("eco:mtn:gone")])
+            .unwrap();
+        assert_eq!(weighted.weighting, RollupWeighting::SurveyedArea);
+        let value = |c| weighted.metrics.measured(c).unwrap().get();
+        assert!((value(MetricComponent::SoilFertility) - 0.7).abs() < 1e-6);
+        assert!((value(MetricComponent::WaterQuality) - 0.1).abs() < 1e-6);
+        assert!(map.rollup_metrics(&[id("eco:mtn:gone")]).is_none());
+    }
+}
@@ -0,0 +1,47 @@
+//! Fixtures shared by the unit tests.
+
+use crate::corridor::{CorridorDescriptor, CorridorId, CorridorKind, PhysicalAttributes};
+use crate::governance::{FpicStatus, IdsFramework, IdsScope};
+use crate::lifecycle::Lifecycle;
+use crate::map::IndigenousEcoCorridor;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+use crate::neurorights::NeurorightsConstraints;
+
+/// A river corridor named after its id: every metric 0.5, FPIC pending,
+/// Indigenous data under an unspecified framework, strict neurorights,
+/// and nothing else set. Tests override the fields they exercise.
+pub(crate) fn corridor(id: &str) -> IndigenousEcoCorridor {
+    IndigenousEcoCorridor {
+        descriptor: CorridorDescriptor {
+            id: CorridorId::new(id).unwrap(),
+            uuid: None,
+            kind: CorridorKind::River,
+            name: id.to_string(),
+            description: None,
+            boundary: None,
+            physical: PhysicalAttributes::default(),
+            relationships: Vec::new(),
+        },
+        environmental: uniform_metrics(0.5),
+        fpic: FpicStatus::Pending,
+        fpic_trail: Vec::new(),
+        revocations: Vec::new(),
+        ids_scope: IdsScope {
+            contains_indigenous_data: true,
+            frameworks: vec![IdsFramework::Unspecified],
+            governance_ref: None,
+            embargo: None,
+        },
+        neurorights: NeurorightsConstraints::strict_non_actuating(),
+        cultural_notes: None,
+        cultural_sites: Vec::new(),
+        lifecycle: Lifecycle::Active,
+        seasons: Vec::new(),
+        signature: None,
+    }
+}
+
+/// Metrics with every component at `score`.
+pub(crate) fn uniform_metrics(score: f32) -> EnvironmentalMetrics {
+    EnvironmentalMetrics::from_components([Score::new(score).unwrap(); MetricComponent::COUNT])
+}
@@ -0,0 +1,241 @@
+//! Acceptable metric ranges per corridor kind.
+//! The same score means different things in different places: 0.6 water
+//! quality is fine for a desert wash and alarming for a salmon river.
+//! A `ThresholdProfile` says what is acceptable for one kind, and
+//! `ThresholdProfiles` picks a profile per kind.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::corridor::{CorridorId, CorridorKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// Inclusive range of acceptable values within [0, 1].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetricRange {
+    min: f32,
+    max: f32,
+}
+
+impl MetricRange {
+    pub fn new(min: f32, max: f32) -> Result<Self, String> {
+        if !(0.0..=1.0).contains(&min) || !(0.0..=1.0).contains(&max) {
+            return Err(format!(
+                "threshold range [{min}, {max}] must lie within [0, 1]"
+            ));
+        }
+        if min > max {
+            return Err(format!("threshold range [{min}, {max}] has min above max"));
+        }
+        Ok(Self { min, max })
+    }
+
+    /// At least `min`.
+    pub fn at_least(min: f32) -> Result<Self, String> {
+        Self::new(min, 1.0)
+    }
+
+    /// At most `max`.
+    pub fn at_most(max: f32) -> Result<Self, String> {
+        Self::new(0.0, max)
+    }
+
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    pub fn contains(&self, value: f32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+impl fmt::Display for MetricRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+/// A component whose score falls outside its acceptable range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdBreach {
+    pub component: MetricComponent,
+    pub value: Score,
+    pub range: MetricRange,
+}
+
+impl ThresholdBreach {
+    /// True if the value is under the range rather than over it.
+    pub fn is_below(&self) -> bool {
+        self.value.get() < self.range.min
+    }
+}
+
+impl fmt::Display for ThresholdBreach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} is outside {}",
+            self.component.key(),
+            self.value.get(),
+            self.range
+        )
+    }
+}
+
+/// Acceptable ranges for one corridor kind; components without a range
+/// are never flagged.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThresholdProfile {
+    ranges: [Option<MetricRange>; MetricComponent::COUNT],
+}
+
+impl ThresholdProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the range for `component`, replacing any earlier one.
+    pub fn with_range(mut self, component: MetricComponent, range: MetricRange) -> Self {
+        self.ranges[component.index()] = Some(range);
+        self
+    }
+
+    pub fn range(&self, component: MetricComponent) -> Option<MetricRange> {
+        self.ranges[component.index()]
+    }
+
+    /// Breaches in `MetricComponent::ALL` order; unmeasured components
+    /// are not flagged.
+    pub fn evaluate(&self, metrics: &EnvironmentalMetrics) -> Vec<ThresholdBreach> {
+        MetricComponent::ALL
+            .into_iter()
+            .filter_map(|component| {
+                let range = self.range(component)?;
+                let value = metrics.measured(component)?;
+                (!range.contains(value.get())).then_some(ThresholdBreach {
+                    component,
+                    value,
+                    range,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Which profile applies to which corridor kind, keyed by
+/// `CorridorKind::key`; kinds without an entry use the default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThresholdProfiles {
+    default: ThresholdProfile,
+    by_kind: BTreeMap<String, ThresholdProfile>,
+}
+
+impl ThresholdProfiles {
+    pub fn new(default: ThresholdProfile) -> Self {
+        Self {
+            default,
+            by_kind: BTreeMap::new(),
+        }
+    }
+
+    /// Use `profile` for corridors of `kind`, replacing any earlier one.
+    pub fn with_kind(mut self, kind: &CorridorKind, profile: ThresholdProfile) -> Self {
+        self.by_kind.insert(kind.key().into_owned(), profile);
+        self
+    }
+
+    pub fn profile_for(&self, kind: &CorridorKind) -> &ThresholdProfile {
+        self.by_kind
+            .get(kind.key().as_ref())
+            .unwrap_or(&self.default)
+    }
+}
+
+impl IndigenousEcoCorridor {
+    /// Breaches under the profile for the corridor's kind.
+    pub fn threshold_breaches(&self, profiles: &ThresholdProfiles) -> Vec<ThresholdBreach> {
+        profiles
+            .profile_for(&self.descriptor.kind)
+            .evaluate(&self.environmental)
+    }
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Corridors with at least one breach, in id order.
+    pub fn threshold_breaches(
+        &self,
+        profiles: &ThresholdProfiles,
+    ) -> Vec<(CorridorId, Vec<ThresholdBreach>)> {
+        self.iter()
+            .map(|(id, c)| (id.clone(), c.threshold_breaches(profiles)))
+            .filter(|(_, breaches)| !breaches.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_score_breaches_by_kind() {
+        let mut values = [Score::new(0.9).unwrap(); MetricComponent::COUNT];
+        values[MetricComponent::WaterQuality.index()] = Score::new(0.6).unwrap();
+        values[MetricComponent::SoilContamination.index()] = Score::new(0.4).unwrap();
+        let metrics = EnvironmentalMetrics::from_components(values);
+
+        let desert = ThresholdProfile::new().with_range(
+            MetricComponent::WaterQuality,
+            MetricRange::at_least(0.3).unwrap(),
+        );
+        let river = ThresholdProfile::new()
+            .with_range(
+                MetricComponent::WaterQuality,
+                MetricRange::at_least(0.8).unwrap(),
+            )
+            .with_range(
+                MetricComponent::SoilContamination,
+                MetricRange::at_most(0.2).unwrap(),
+            );
+        let profiles = ThresholdProfiles::default()
+            .with_kind(&CorridorKind::Desert, desert)
+            .with_kind(&CorridorKind::River, river);
+
+        assert!(profiles
+            .profile_for(&CorridorKind::Desert)
+            .evaluate(&metrics)
+            .is_empty());
+        assert!(profiles
+            .profile_for(&CorridorKind::Forest)
+            .evaluate(&metrics)
+            .is_empty());
+        let breaches = profiles
+            .profile_for(&CorridorKind::River)
+            .evaluate(&metrics);
+        let flagged: Vec<_> = breaches
+            .iter()
+            .map(|b| (b.component, b.is_below()))
+            .collect();
+        assert_eq!(
+            flagged,
+            [
+                (MetricComponent::SoilContamination, false),
+                (MetricComponent::WaterQuality, true),
+            ]
+        );
+        assert_eq!(
+            breaches[1].to_string(),
+            "water_quality 0.6 is outside [0.8, 1]"
+        );
+
+        assert!(MetricRange::new(0.7, 0.2).is_err());
+        assert!(MetricRange::new(-0.1, 0.5).is_err());
+    }
+}
@@ -0,0 +1,441 @@
+//! Columnar timelines of environmental metrics.
+//! Non-actuating; observational history only.
+
+#![forbid(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+use std::time::{Duration, SystemTime};
+
+use crate::corridor::CorridorId;
+use crate::metrics::{EnvironmentalMetrics, MetricComponent, Score};
+
+/// Change over a trend window smaller than this counts as stable.
+pub const TREND_TOLERANCE: f32 = 0.01;
+
+/// Direction a component moved over a window. Scores are oriented so
+/// that higher is healthier, so rising is always improving.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Improving,
+    Declining,
+    Stable,
+}
+
+impl Trend {
+    pub fn key(self) -> &'static str {
+        match self {
+            Trend::Improving => "improving",
+            Trend::Declining => "declining",
+            Trend::Stable => "stable",
+        }
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Append-only timeline of metric observations for one corridor.
+///
+/// Storage is columnar: one `f32` column per `MetricComponent`, all
+/// sharing a single timestamp index. Scanning one component touches
+/// only that column, and each observation costs 9 floats plus one
+/// timestamp instead of a full `EnvironmentalMetrics` value. Only
+/// values are stored; score bounds are dropped. Unmeasured components
+/// are stored as NaN and left out of means, trends and downsampling.
+#[derive(Clone, Debug, Default)]
+pub struct MetricsTimeline {
+    timestamps: Vec<SystemTime>,
+    columns: [Vec<f32>; MetricComponent::COUNT],
+}
+
+impl MetricsTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pre-allocate room for `capacity` observations in every column.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            timestamps: Vec::with_capacity(capacity),
+            columns: std::array::from_fn(|_| Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Append an observation. Timestamps must be non-decreasing so that
+    /// range queries can binary-search the shared index.
+    pub fn push(&mut self, at: SystemTime, metrics: &EnvironmentalMetrics) -> Result<(), String> {
+        if let Some(last) = self.timestamps.last() {
+            if at < *last {
+                return Err("MetricsTimeline observations must be pushed in time order".into());
+            }
+        }
+        self.timestamps.push(at);
+        for component in MetricComponent::ALL {
+            let value = metrics.measured(component).map_or(f32::NAN, Score::get);
+            self.columns[component.index()].push(value);
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// Shared timestamp index.
+    pub fn timestamps(&self) -> &[SystemTime] {
+        &self.timestamps
+    }
+
+    /// Raw values of one component, aligned with `timestamps()`; NaN
+    /// where the component was unmeasured.
+    pub fn column(&self, component: MetricComponent) -> &[f32] {
+        &self.columns[component.index()]
+    }
+
+    /// Reassemble the observation at `index` into row form.
+    pub fn get(&self, index: usize) -> Option<(SystemTime, EnvironmentalMetrics)> {
+        let at = *self.timestamps.get(index)?;
+        let values = MetricComponent::ALL.map(|c| {
+            let value = self.columns[c.index()][index];
+            (!value.is_nan()).then(|| Score::exact(value))
+        });
+        Some((at, metrics_from(values)))
+    }
+
+    /// Iterate observations in row form.
+    pub fn iter(&self) -> impl Iterator<Item = (SystemTime, EnvironmentalMetrics)> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Index range of observations with `from <= at < to`.
+    pub fn range(&self, from: SystemTime, to: SystemTime) -> Range<usize> {
+        let start = self.timestamps.partition_point(|t| *t < from);
+        let end = self.timestamps.partition_point(|t| *t < to).max(start);
+        start..end
+    }
+
+    /// Mean of one component's measured values over an index range;
+    /// `None` if the range holds none.
+    pub fn mean(&self, component: MetricComponent, range: Range<usize>) -> Option<Score> {
+        let values = self.column(component).get(range)?;
+        let (sum, count) = values
+            .iter()
+            .filter(|v| !v.is_nan())
+            .fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+        if count == 0 {
+            return None;
+        }
+        let mean = sum / count as f32;
+        // Safe because every stored value came from a valid Score.
+        Some(Score::exact(mean))
+    }
+
+    /// Latest observation, if any.
+    pub fn latest(&self) -> Option<(SystemTime, EnvironmentalMetrics)> {
+        self.len().checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Per-component trend over the `window` ending at the latest
+    /// observation, in `MetricComponent::ALL` order: the least-squares
+    /// slope over the component's measured values in the window, as a
+    /// change across the whole window, compared against
+    /// `TREND_TOLERANCE`. `None` unless the window holds observations at
+    /// two or more distinct times; a component's entry is `None` unless
+    /// it was measured at two or more distinct times in the window.
+    pub fn trend(&self, window: Duration) -> Option<[Option<Trend>; MetricComponent::COUNT]> {
+        let end = *self.timestamps.last()?;
+        let start = end.checked_sub(window).unwrap_or(SystemTime::UNIX_EPOCH);
+        let range = self.timestamps.partition_point(|t| *t < start)..self.len();
+        let xs: Vec<f64> = self.timestamps[range.clone()]
+            .iter()
+            .map(|t| seconds_since(start, *t))
+            .collect();
+        if xs.first() == xs.last() {
+            return None;
+        }
+        Some(MetricComponent::ALL.map(|c| {
+            let points: Vec<(f64, f64)> = xs
+                .iter()
+                .zip(&self.column(c)[range.clone()])
+                .filter(|(_, y)| !y.is_nan())
+                .map(|(x, y)| (*x, f64::from(*y)))
+                .collect();
+            let n = points.len() as f64;
+            let x_mean = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+            let y_mean = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+            let spread: f64 = points.iter().map(|(x, _)| (x - x_mean).powi(2)).sum();
+            if spread == 0.0 {
+                return None;
+            }
+            let covariance: f64 = points
+                .iter()
+                .map(|(x, y)| (x - x_mean) * (y - y_mean))
+                .sum();
+            let change = covariance / spread * window.as_secs_f64();
+            Some(if change > f64::from(TREND_TOLERANCE) {
+                Trend::Improving
+            } else if change < -f64::from(TREND_TOLERANCE) {
+                Trend::Declining
+            } else {
+                Trend::Stable
+            })
+        }))
+    }
+
+    /// One observation per `bucket`-long interval, starting at the first
+    /// observation: each is the mean of the interval's measured values,
+    /// stamped with the interval's start. Empty intervals are skipped; a
+    /// component with no measured value in an interval is unmeasured in
+    /// the result.
+    pub fn downsample(&self, bucket: Duration) -> Result<MetricsTimeline, String> {
+        if bucket.is_zero() {
+            return Err("MetricsTimeline downsampling needs a non-zero bucket".into());
+        }
+        let mut out = MetricsTimeline::new();
+        let Some(first) = self.timestamps.first().copied() else {
+            return Ok(out);
+        };
+        let overflow = || String::from("MetricsTimeline downsampling bucket overflows SystemTime");
+        let bucket_nanos = bucket.as_nanos();
+        let mut start = 0;
+        while start < self.len() {
+            let offset = self.timestamps[start]
+                .duration_since(first)
+                .unwrap_or_default()
+                .as_nanos();
+            // Start of the bucket holding this observation, kept in u128
+            // nanoseconds; never past `offset`.
+            let skipped = offset - offset % bucket_nanos;
+            let from = u64::try_from(skipped)
+                .ok()
+                .and_then(|nanos| first.checked_add(Duration::from_nanos(nanos)))
+                .ok_or_else(overflow)?;
+            let end = match from.checked_add(bucket) {
+                Some(to) => self.timestamps.partition_point(|t| *t < to),
+                None => self.len(),
+            };
+            if end > start {
+                let values = MetricComponent::ALL.map(|c| self.mean(c, start..end));
+                out.push(from, &metrics_from(values))?;
+            }
+            start = end.max(start + 1);
+        }
+        Ok(out)
+    }
+}
+
+/// Row-form metrics from per-component values; `None` entries are
+/// unmeasured, with a 0.0 placeholder.
+fn metrics_from(values: [Option<Score>; MetricComponent::COUNT]) -> EnvironmentalMetrics {
+    let mut metrics =
+        EnvironmentalMetrics::from_components(values.map(|v| v.unwrap_or(Score::exact(0.0))));
+    metrics.unmeasured = MetricComponent::ALL
+        .into_iter()
+        .filter(|c| values[c.index()].is_none())
+        .collect();
+    metrics
+}
+
+fn seconds_since(start: SystemTime, at: SystemTime) -> f64 {
+    at.duration_since(start).unwrap_or_default().as_secs_f64()
+}
+
+/// Metric timelines for many corridors.
+#[derive(Clone, Debug, Default)]
+pub struct MetricsSeries {
+    timelines: BTreeMap<CorridorId, MetricsTimeline>,
+}
+
+impl MetricsSeries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a sample for `id`; samples for one corridor must be
+    /// recorded in time order.
+    pub fn record(
+        &mut self,
+        id: &CorridorId,
+        at: SystemTime,
+        metrics: &EnvironmentalMetrics,
+    ) -> Result<(), String> {
+        self.timelines
+            .entry(id.clone())
+            .or_default()
+            .push(at, metrics)
+            .map_err(|e| format!("CorridorId '{}': {}", id, e))
+    }
+
+    pub fn timeline(&self, id: &CorridorId) -> Option<&MetricsTimeline> {
+        self.timelines.get(id)
+    }
+
+    /// Corridors with at least one sample, in id order.
+    pub fn corridors(&self) -> impl Iterator<Item = &CorridorId> {
+        self.timelines.keys()
+    }
+
+    /// See `MetricsTimeline::trend`.
+    pub fn trend(
+        &self,
+        id: &CorridorId,
+        window: Duration,
+    ) -> Option<[Option<Trend>; MetricComponent::COUNT]> {
+        self.timelines.get(id)?.trend(window)
+    }
+
+    /// Every timeline downsampled to `bucket`; see
+    /// `MetricsTimeline::downsample`.
+    pub fn downsample(&self, bucket: Duration) -> Result<MetricsSeries, String> {
+        let timelines = self
+            .timelines
+            .iter()
+            .map(|(id, t)| Ok((id.clone(), t.downsample(bucket)?)))
+            .collect::<Result<_, String>>()?;
+        Ok(Self { timelines })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(water: f32) -> EnvironmentalMetrics {
+        let mut values = [Score::new(0.5).unwrap(); MetricComponent::COUNT];
+        values[MetricComponent::WaterQuality.index()] = Score::new(water).unwrap();
+        values[MetricComponent::SoilFertility.index()] = Score::new(1.0 - water).unwrap();
+        EnvironmentalMetrics::from_components(values)
+    }
+
+    #[test]
+    fn timeline_round_trips_rows_and_queries_ranges() {
+        let minute = Duration::from_secs(60);
+        let at = |i: u32| SystemTime::UNIX_EPOCH + minute * i;
+        let mut timeline = MetricsTimeline::with_capacity(4);
+        assert!(timeline.is_empty() && timeline.latest().is_none());
+        for (i, water) in [0.1, 0.2, 0.3, 0.4].into_iter().enumerate() {
+            timeline.push(at(i as u32), &sample(water)).unwrap();
+        }
+        assert!(timeline.push(at(2), &sample(0.5)).is_err());
+        assert_eq!(timeline.len(), 4);
+
+        let values = |m: &EnvironmentalMetrics| MetricComponent::ALL.map(|c| m.component(c));
+        let (when, metrics) = timeline.get(2).unwrap();
+        assert_eq!(when, at(2));
+        assert_eq!(values(&metrics), values(&sample(0.3)));
+        assert!(timeline.get(4).is_none());
+        assert_eq!(values(&timeline.latest().unwrap().1), values(&sample(0.4)));
+        assert_eq!(timeline.iter().count(), 4);
+        assert_eq!(
+            timeline.column(MetricComponent::WaterQuality),
+            [0.1, 0.2, 0.3, 0.4]
+        );
+
+        assert_eq!(timeline.range(at(1), at(3)), 1..3);
+        assert_eq!(timeline.range(at(3), at(1)), 3..3);
+        let mean = timeline.mean(MetricComponent::WaterQuality, 1..3).unwrap();
+        assert!((mean.get() - 0.25).abs() < 1e-6);
+        assert_eq!(timeline.mean(MetricComponent::WaterQuality, 2..2), None);
+    }
+
+    #[test]
+    fn series_report_trends_and_downsample() {
+        let id = CorridorId::new("eco:river:series").unwrap();
+        let day = Duration::from_secs(86_400);
+        let mut series = MetricsSeries::new();
+        for (i, water) in [0.2, 0.3, 0.4, 0.5, 0.6, 0.7].into_iter().enumerate() {
+            let at = SystemTime::UNIX_EPOCH + day * i as u32;
+            series.record(&id, at, &sample(water)).unwrap();
+        }
+        assert!(series
+            .record(&id, SystemTime::UNIX_EPOCH, &sample(0.5))
+            .is_err());
+
+        let trends = series.trend(&id, day * 3).unwrap();
+        assert_eq!(
+            trends[MetricComponent::WaterQuality.index()],
+            Some(Trend::Improving)
+        );
+        assert_eq!(
+            trends[MetricComponent::SoilFertility.index()],
+            Some(Trend::Declining)
+        );
+        assert_eq!(
+            trends[MetricComponent::MicrobiomeDiversity.index()],
+            Some(Trend::Stable)
+        );
+        assert_eq!(series.trend(&id, Duration::ZERO), None);
+
+        let binned = series.downsample(day * 2).unwrap();
+        let timeline = binned.timeline(&id).unwrap();
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(timeline.timestamps()[1], SystemTime::UNIX_EPOCH + day * 2);
+        let water = timeline.column(MetricComponent::WaterQuality);
+        assert!((water[1] - 0.45).abs() < 1e-6);
+        assert!(series.downsample(Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn unmeasured_values_stay_out_of_means_trends_and_buckets() {
+        let day = Duration::from_secs(86_400);
+        let mut timeline = MetricsTimeline::new();
+        for (i, water) in [0.2, 0.9, 0.4].into_iter().enumerate() {
+            let mut metrics = sample(water);
+            metrics
+                .unmeasured
+                .insert(MetricComponent::MicrobiomeDiversity);
+            if i == 1 {
+                metrics.unmeasured.insert(MetricComponent::WaterQuality);
+            }
+            timeline
+                .push(SystemTime::UNIX_EPOCH + day * i as u32, &metrics)
+                .unwrap();
+        }
+        let (_, middle) = timeline.get(1).unwrap();
+        assert_eq!(middle.measured(MetricComponent::WaterQuality), None);
+        let water = timeline.mean(MetricComponent::WaterQuality, 0..3).unwrap();
+        assert!((water.get() - 0.3).abs() < 1e-6);
+        assert_eq!(
+            timeline.mean(MetricComponent::MicrobiomeDiversity, 0..3),
+            None
+        );
+
+        let trends = timeline.trend(day * 2).unwrap();
+        assert_eq!(
+            trends[MetricComponent::WaterQuality.index()],
+            Some(Trend::Improving)
+        );
+        assert_eq!(trends[MetricComponent::MicrobiomeDiversity.index()], None);
+
+        let binned = timeline.downsample(day * 2).unwrap();
+        let (_, first) = binned.get(0).unwrap();
+        assert!((first.component(MetricComponent::WaterQuality).get() - 0.2).abs() < 1e-6);
+        assert!(first
+            .unmeasured
+            .contains(&MetricComponent::MicrobiomeDiversity));
+    }
+
+    #[test]
+    fn downsampling_far_from_the_first_observation_does_not_truncate() {
+        let mut timeline = MetricsTimeline::new();
+        let late = SystemTime::UNIX_EPOCH + Duration::from_nanos(1 << 33);
+        timeline.push(SystemTime::UNIX_EPOCH, &sample(0.2)).unwrap();
+        timeline.push(late, &sample(0.4)).unwrap();
+
+        let binned = timeline.downsample(Duration::from_nanos(1)).unwrap();
+        assert_eq!(binned.timestamps(), [SystemTime::UNIX_EPOCH, late]);
+        let whole = timeline.downsample(Duration::MAX).unwrap();
+        assert_eq!(whole.len(), 1);
+        let water = whole.column(MetricComponent::WaterQuality);
+        assert!((water[0] - 0.3).abs() < 1e-6);
+    }
+}
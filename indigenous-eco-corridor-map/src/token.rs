@@ -0,0 +1,382 @@
+//! Capability-scoped API tokens minted from active FPIC grants.
+//!
+//! A token names one corridor, a set of purposes, and an expiry, and is
+//! bound to the exact grant that authorized it. Verification re-checks
+//! the live map, so a token dies as soon as that grant is withheld,
+//! revoked, or replaced, even before it expires.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::corridor::CorridorId;
+use crate::governance::{FpicPurpose, FpicStatus};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Minimum secret length accepted for token signing.
+pub const MIN_KEY_LEN: usize = 32;
+
+/// Errors raised while minting or verifying capability tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenError {
+    /// Signing key is shorter than `MIN_KEY_LEN`.
+    WeakKey,
+    /// The corridor has no FPIC grant active at mint or verify time,
+    /// e.g. because the grant's `valid_until` has passed.
+    NoActiveGrant(CorridorId),
+    /// At least one purpose must be requested.
+    NoPurposes,
+    /// Token is not well-formed or its signature does not verify.
+    Invalid,
+    Expired,
+    /// The corridor is no longer registered.
+    UnknownCorridor(CorridorId),
+    /// The grant the token was minted from is no longer active.
+    ConsentChanged,
+    /// Token does not cover the requested corridor.
+    WrongCorridor,
+    /// Token does not cover the requested purpose.
+    PurposeNotCovered(String),
+    /// `now + ttl` is past the latest representable time.
+    TtlOverflow,
+    /// The FPIC grant does not cover the purpose, or it is not an
+    /// `FpicPurpose` key at all.
+    PurposeNotConsented(String),
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::WeakKey => {
+                write!(f, "token key must be at least {} bytes", MIN_KEY_LEN)
+            }
+            TokenError::NoActiveGrant(id) => {
+                write!(f, "corridor '{}' has no active FPIC grant", id)
+            }
+            TokenError::NoPurposes => write!(f, "token must name at least one purpose"),
+            TokenError::Invalid => write!(f, "token is malformed or has a bad signature"),
+            TokenError::Expired => write!(f, "token has expired"),
+            TokenError::UnknownCorridor(id) => write!(f, "corridor '{}' is not registered", id),
+            TokenError::ConsentChanged => {
+                write!(f, "the FPIC grant behind this token is no longer active")
+            }
+            TokenError::WrongCorridor => write!(f, "token does not cover this corridor"),
+            TokenError::PurposeNotCovered(p) => write!(f, "token does not cover purpose '{}'", p),
+            TokenError::TtlOverflow => write!(f, "token lifetime overflows the clock"),
+            TokenError::PurposeNotConsented(p) => {
+                write!(f, "the FPIC grant does not consent to purpose '{}'", p)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+/// Signed contents of a capability token.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TokenClaims {
+    pub corridor_id: CorridorId,
+    pub purposes: Vec<String>,
+    pub issued_at: SystemTime,
+    pub expires_at: SystemTime,
+    /// Grant binding: `at` and `terms_reference` of the authorizing grant.
+    pub grant_at: SystemTime,
+    pub grant_terms_reference: String,
+}
+
+impl TokenClaims {
+    pub fn covers(&self, purpose: &str) -> bool {
+        self.purposes.iter().any(|p| p == purpose)
+    }
+}
+
+/// Mints and verifies tokens with a shared HMAC-SHA256 secret.
+#[derive(Clone)]
+pub struct TokenAuthority {
+    key: Vec<u8>,
+}
+
+impl fmt::Debug for TokenAuthority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenAuthority").finish_non_exhaustive()
+    }
+}
+
+impl TokenAuthority {
+    pub fn new(key: impl Into<Vec<u8>>) -> Result<Self, TokenError> {
+        let key = key.into();
+        if key.len() < MIN_KEY_LEN {
+            return Err(TokenError::WeakKey);
+        }
+        Ok(Self { key })
+    }
+
+    /// Mint a token for `purposes` on `corridor`, valid for `ttl` from `now`
+    /// but never past the grant's `valid_until`. Every purpose must be an
+    /// `FpicPurpose` key the grant covers.
+    pub fn mint(
+        &self,
+        corridor: &IndigenousEcoCorridor,
+        purposes: Vec<String>,
+        ttl: Duration,
+        now: SystemTime,
+    ) -> Result<String, TokenError> {
+        let FpicStatus::Granted {
+            at,
+            terms_reference,
+            valid_until,
+            ..
+        } = &corridor.fpic
+        else {
+            return Err(TokenError::NoActiveGrant(corridor.id().clone()));
+        };
+        if !corridor.has_active_fpic(now) {
+            return Err(TokenError::NoActiveGrant(corridor.id().clone()));
+        }
+        if purposes.is_empty() {
+            return Err(TokenError::NoPurposes);
+        }
+        if let Some(p) = purposes.iter().find(|p| !consented(corridor, p)) {
+            return Err(TokenError::PurposeNotConsented(p.clone()));
+        }
+        let mut expires_at = now.checked_add(ttl).ok_or(TokenError::TtlOverflow)?;
+        if let Some(valid_until) = valid_until {
+            expires_at = expires_at.min(*valid_until);
+        }
+        let claims = TokenClaims {
+            corridor_id: corridor.id().clone(),
+            purposes,
+            issued_at: now,
+            expires_at,
+            grant_at: *at,
+            grant_terms_reference: terms_reference.clone(),
+        };
+        let payload = serde_json::to_vec(&claims).map_err(|_| TokenError::Invalid)?;
+        let signature = self.mac(&payload).finalize().into_bytes();
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// Check signature and expiry, then that the token covers
+    /// `corridor_id` and `purpose` and that its grant is still active.
+    pub fn verify(
+        &self,
+        token: &str,
+        map: &IndigenousEcoCorridorMap,
+        corridor_id: &CorridorId,
+        purpose: &str,
+        now: SystemTime,
+    ) -> Result<TokenClaims, TokenError> {
+        let (payload, signature) = token.split_once('.').ok_or(TokenError::Invalid)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| TokenError::Invalid)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| TokenError::Invalid)?;
+        self.mac(&payload)
+            .verify_slice(&signature)
+            .map_err(|_| TokenError::Invalid)?;
+        let claims: TokenClaims =
+            serde_json::from_slice(&payload).map_err(|_| TokenError::Invalid)?;
+
+        if now >= claims.expires_at {
+            return Err(TokenError::Expired);
+        }
+        if claims.corridor_id != *corridor_id {
+            return Err(TokenError::WrongCorridor);
+        }
+        if !claims.covers(purpose) {
+            return Err(TokenError::PurposeNotCovered(purpose.to_string()));
+        }
+        let corridor = map
+            .get(corridor_id)
+            .ok_or_else(|| TokenError::UnknownCorridor(corridor_id.clone()))?;
+        match &corridor.fpic {
+            FpicStatus::Granted {
+                at,
+                terms_reference,
+                ..
+            } if *at == claims.grant_at && *terms_reference == claims.grant_terms_reference => {
+                if !corridor.has_active_fpic(now) {
+                    Err(TokenError::NoActiveGrant(corridor_id.clone()))
+                } else if !consented(corridor, purpose) {
+                    Err(TokenError::PurposeNotConsented(purpose.to_string()))
+                } else {
+                    Ok(claims)
+                }
+            }
+            _ => Err(TokenError::ConsentChanged),
+        }
+    }
+
+    fn mac(&self, payload: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts any key length");
+        mac.update(payload);
+        mac
+    }
+}
+
+fn consented(corridor: &IndigenousEcoCorridor, purpose: &str) -> bool {
+    FpicPurpose::from_key(purpose).is_some_and(|p| corridor.fpic.covers(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum};
+    use crate::test_support;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn token_is_scoped_and_dies_with_consent() {
+        let id = CorridorId::new("eco:forest:t").unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut corridor = IndigenousEcoCorridor {
+            fpic: FpicStatus::Granted {
+                at: t0,
+                quorum: ConsentQuorum::sole(
+                    CommunityId("community:t".into()),
+                    Consent {
+                        at: SystemTime::now(),
+                        reference: "consent:1".into(),
+                        delegation: None,
+                    },
+                ),
+                terms_reference: "terms:v1".into(),
+                purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+                valid_until: None,
+                renewal_notice: None,
+                terms: None,
+            },
+            ..test_support::corridor(id.as_str())
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor.clone()).unwrap();
+
+        assert_eq!(
+            TokenAuthority::new("short").unwrap_err(),
+            TokenError::WeakKey
+        );
+        let authority = TokenAuthority::new([7u8; MIN_KEY_LEN]).unwrap();
+        let token = authority
+            .mint(
+                &corridor,
+                vec!["monitoring".into()],
+                Duration::from_secs(60),
+                t0,
+            )
+            .unwrap();
+        let now = t0 + Duration::from_secs(30);
+
+        assert!(authority
+            .verify(&token, &map, &id, "monitoring", now)
+            .is_ok());
+        assert_eq!(
+            authority.verify(&token, &map, &id, "research", now),
+            Err(TokenError::PurposeNotCovered("research".into()))
+        );
+        assert_eq!(
+            authority.verify(
+                &token,
+                &map,
+                &id,
+                "monitoring",
+                t0 + Duration::from_secs(60)
+            ),
+            Err(TokenError::Expired)
+        );
+        assert_eq!(
+            authority.mint(
+                &corridor,
+                vec!["monitoring".into(), "infrastructure".into()],
+                Duration::from_secs(60),
+                t0
+            ),
+            Err(TokenError::PurposeNotConsented("infrastructure".into()))
+        );
+        let other = TokenAuthority::new([8u8; MIN_KEY_LEN]).unwrap();
+        assert_eq!(
+            other.verify(&token, &map, &id, "monitoring", now),
+            Err(TokenError::Invalid)
+        );
+
+        corridor.fpic = FpicStatus::Withheld {
+            at: now,
+            reason: "revoked".into(),
+        };
+        map.upsert_corridor(corridor.clone()).unwrap();
+        assert_eq!(
+            authority.verify(&token, &map, &id, "monitoring", now),
+            Err(TokenError::ConsentChanged)
+        );
+        assert_eq!(
+            authority.mint(
+                &corridor,
+                vec!["monitoring".into()],
+                Duration::from_secs(60),
+                now
+            ),
+            Err(TokenError::NoActiveGrant(id))
+        );
+    }
+
+    #[test]
+    fn expiry_is_clamped_to_the_grant_and_checked() {
+        let id = CorridorId::new("eco:forest:v").unwrap();
+        let t0 = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let valid_until = t0 + Duration::from_secs(90);
+        let corridor = IndigenousEcoCorridor {
+            fpic: FpicStatus::Granted {
+                at: t0,
+                quorum: ConsentQuorum::sole(
+                    CommunityId("community:v".into()),
+                    Consent {
+                        at: t0,
+                        reference: "consent:1".into(),
+                        delegation: None,
+                    },
+                ),
+                terms_reference: "terms:v1".into(),
+                purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+                valid_until: Some(valid_until),
+                renewal_notice: None,
+                terms: None,
+            },
+            ..test_support::corridor(id.as_str())
+        };
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor.clone()).unwrap();
+        let authority = TokenAuthority::new([7u8; MIN_KEY_LEN]).unwrap();
+        let monitoring = || vec!["monitoring".to_string()];
+
+        let token = authority
+            .mint(&corridor, monitoring(), Duration::from_secs(3_600), t0)
+            .unwrap();
+        let claims = authority
+            .verify(&token, &map, &id, "monitoring", t0)
+            .unwrap();
+        assert_eq!(claims.expires_at, valid_until);
+        assert_eq!(
+            authority.verify(&token, &map, &id, "monitoring", valid_until),
+            Err(TokenError::Expired)
+        );
+        assert_eq!(
+            authority.mint(&corridor, monitoring(), Duration::MAX, t0),
+            Err(TokenError::TtlOverflow)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Physical quantities with explicit units.
+//! Overlays exchange these instead of bare floats, so a value's unit is
+//! part of its type rather than a convention.
+
+#![forbid(unsafe_code)]
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+fn non_negative(value: f64, what: &str) -> Result<f64, String> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(format!("{} must be finite and non-negative", what));
+    }
+    Ok(value)
+}
+
+/// Area in hectares (10 000 m²); finite and non-negative.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Hectares(f64);
+
+impl Hectares {
+    pub const SQUARE_METERS: f64 = 10_000.0;
+
+    pub fn new(value: f64) -> Result<Self, String> {
+        non_negative(value, "area").map(Self)
+    }
+
+    pub fn from_square_meters(value: f64) -> Result<Self, String> {
+        Self::new(value / Self::SQUARE_METERS)
+    }
+
+    pub fn from_square_kilometers(value: f64) -> Result<Self, String> {
+        Self::new(value * 100.0)
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    pub fn to_square_meters(self) -> f64 {
+        self.0 * Self::SQUARE_METERS
+    }
+
+    pub fn to_square_kilometers(self) -> f64 {
+        self.0 / 100.0
+    }
+}
+
+/// Length in kilometers; finite and non-negative.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Kilometers(f64);
+
+impl Kilometers {
+    pub fn new(value: f64) -> Result<Self, String> {
+        non_negative(value, "length").map(Self)
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    pub fn to_meters(self) -> Meters {
+        Meters(self.0 * 1000.0)
+    }
+}
+
+/// Length or elevation in meters; finite, and may be negative for
+/// elevations below sea level.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Meters(f64);
+
+impl Meters {
+    pub fn new(value: f64) -> Result<Self, String> {
+        if !value.is_finite() {
+            return Err("meters must be finite".into());
+        }
+        Ok(Self(value))
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Fails for negative values, which are not lengths.
+    pub fn to_kilometers(self) -> Result<Kilometers, String> {
+        Kilometers::new(self.0 / 1000.0)
+    }
+}
+
+/// Lowest and highest elevation of a corridor, relative to sea level.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawElevationRange")]
+pub struct ElevationRange {
+    min: Meters,
+    max: Meters,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawElevationRange {
+    min: Meters,
+    max: Meters,
+}
+
+impl TryFrom<RawElevationRange> for ElevationRange {
+    type Error = String;
+
+    fn try_from(raw: RawElevationRange) -> Result<Self, String> {
+        Self::new(raw.min, raw.max)
+    }
+}
+
+impl ElevationRange {
+    pub fn new(min: Meters, max: Meters) -> Result<Self, String> {
+        if min > max {
+            return Err(format!(
+                "elevation range minimum {} exceeds maximum {}",
+                min, max
+            ));
+        }
+        Ok(Self { min, max })
+    }
+
+    pub fn min(self) -> Meters {
+        self.min
+    }
+
+    pub fn max(self) -> Meters {
+        self.max
+    }
+
+    /// Vertical extent, `max - min`.
+    pub fn relief(self) -> Meters {
+        Meters(self.max.0 - self.min.0)
+    }
+
+    pub fn contains(self, elevation: Meters) -> bool {
+        self.min <= elevation && elevation <= self.max
+    }
+}
+
+macro_rules! unit_conversions {
+    ($($unit:ident => $symbol:literal),*) => {$(
+        impl TryFrom<f64> for $unit {
+            type Error = String;
+
+            fn try_from(value: f64) -> Result<Self, String> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$unit> for f64 {
+            fn from(value: $unit) -> f64 {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {}", self.0, $symbol)
+            }
+        }
+    )*};
+}
+
+unit_conversions!(Hectares => "ha", Kilometers => "km", Meters => "m");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units_convert_and_reject_invalid_values() {
+        let area = Hectares::from_square_kilometers(2.5).unwrap();
+        assert_eq!(area.get(), 250.0);
+        assert_eq!(area.to_square_meters(), 2_500_000.0);
+        assert_eq!(Hectares::from_square_meters(5_000.0).unwrap().get(), 0.5);
+        assert!(Hectares::new(-1.0).is_err());
+        assert!(Kilometers::new(f64::INFINITY).is_err());
+
+        assert_eq!(Kilometers::new(1.2).unwrap().to_meters().get(), 1200.0);
+        assert_eq!(
+            Meters::new(800.0).unwrap().to_kilometers().unwrap().get(),
+            0.8
+        );
+        assert!(Meters::new(-5.0).unwrap().to_kilometers().is_err());
+        assert_eq!(Kilometers::new(3.0).unwrap().to_string(), "3 km");
+
+        let m = |v| Meters::new(v).unwrap();
+        let range = ElevationRange::new(m(-20.0), m(130.0)).unwrap();
+        assert_eq!(range.relief().get(), 150.0);
+        assert!(range.contains(m(0.0)));
+        assert!(ElevationRange::new(m(10.0), m(5.0)).is_err());
+
+        let parsed: ElevationRange = serde_json::from_str(r#"{"min": -20, "max": 130}"#).unwrap();
+        assert_eq!(parsed, range);
+        assert!(serde_json::from_str::<ElevationRange>(r#"{"min": 9, "max": 1}"#).is_err());
+        assert!(serde_json::from_str::<Hectares>("-3").is_err());
+    }
+}
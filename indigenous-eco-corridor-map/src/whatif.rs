@@ -0,0 +1,252 @@
+//! Dry-run evaluation of hypothetical changes.
+//!
+//! Hypotheticals are applied to a copy-on-write clone of the map, so the
+//! live map is never touched. The report lists every corridor whose
+//! governance outcome (FPIC classification, active-grant precondition,
+//! aggregate score) would differ.
+
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::corridor::CorridorId;
+use crate::governance::{FpicStatus, FpicStatusKind};
+use crate::map::{IndigenousEcoCorridor, IndigenousEcoCorridorMap};
+use crate::metrics::{MetricComponent, Score};
+
+/// One hypothetical change to evaluate.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Hypothetical {
+    /// "If water quality drops to 0.4".
+    SetMetric {
+        corridor_id: CorridorId,
+        component: MetricComponent,
+        value: f32,
+    },
+    SetFpic {
+        corridor_id: CorridorId,
+        status: FpicStatus,
+    },
+    /// "If FPIC is revoked".
+    RevokeFpic {
+        corridor_id: CorridorId,
+        reason: String,
+    },
+}
+
+impl Hypothetical {
+    /// Corridor the change targets.
+    pub fn corridor_id(&self) -> &CorridorId {
+        match self {
+            Hypothetical::SetMetric { corridor_id, .. }
+            | Hypothetical::SetFpic { corridor_id, .. }
+            | Hypothetical::RevokeFpic { corridor_id, .. } => corridor_id,
+        }
+    }
+}
+
+/// Governance-relevant outcome of a single corridor.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CorridorOutcome {
+    pub fpic: FpicStatusKind,
+    /// Precondition for any data use, including minting capability tokens.
+    pub active_grant: bool,
+    pub aggregate_score: Score,
+}
+
+impl CorridorOutcome {
+    fn of(corridor: &IndigenousEcoCorridor, now: SystemTime) -> Self {
+        Self {
+            fpic: corridor.fpic.kind(),
+            active_grant: corridor.has_active_fpic(now),
+            aggregate_score: corridor.environmental.aggregate_score(),
+        }
+    }
+}
+
+/// A corridor whose outcome would change.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OutcomeChange {
+    pub corridor_id: CorridorId,
+    pub before: CorridorOutcome,
+    pub after: CorridorOutcome,
+}
+
+/// Result of `IndigenousEcoCorridorMap::what_if`.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WhatIfReport {
+    pub changes: Vec<OutcomeChange>,
+    pub mean_score_before: Option<Score>,
+    pub mean_score_after: Option<Score>,
+}
+
+impl IndigenousEcoCorridorMap {
+    /// Evaluate `hypotheticals`, applied in order, without mutating the
+    /// map. Grant activity is judged at the current time.
+    pub fn what_if(&self, hypotheticals: &[Hypothetical]) -> Result<WhatIfReport, String> {
+        let now = SystemTime::now();
+        let mut scenario = self.clone();
+        for hypothetical in hypotheticals {
+            apply(&mut scenario, hypothetical)?;
+        }
+
+        let changes = self
+            .iter()
+            .filter_map(|(id, corridor)| {
+                let before = CorridorOutcome::of(corridor, now);
+                let after = CorridorOutcome::of(scenario.get(id)?, now);
+                (before != after).then(|| OutcomeChange {
+                    corridor_id: id.clone(),
+                    before,
+                    after,
+                })
+            })
+            .collect();
+        Ok(WhatIfReport {
+            changes,
+            mean_score_before: self.aggregates().mean_score(),
+            mean_score_after: scenario.aggregates().mean_score(),
+        })
+    }
+}
+
+fn apply(map: &mut IndigenousEcoCorridorMap, hypothetical: &Hypothetical) -> Result<(), String> {
+    match hypothetical {
+        Hypothetical::SetMetric {
+            corridor_id,
+            component,
+            value,
+        } => {
+            // Only the named component changes; unmeasured components,
+            // air and carbon groups, provenance and quality flags carry over.
+            let mut metrics = lookup(map, corridor_id)?.environmental.clone();
+            metrics.set_component(*component, Score::new(*value)?);
+            map.update_metrics(corridor_id, metrics)
+        }
+        Hypothetical::SetFpic {
+            corridor_id,
+            status,
+        } => set_fpic(map, corridor_id, status.clone()),
+        Hypothetical::RevokeFpic {
+            corridor_id,
+            reason,
+        } => set_fpic(
+            map,
+            corridor_id,
+            FpicStatus::Revoked {
+                at: SystemTime::now(),
+                reason: reason.clone(),
+            },
+        ),
+    }
+}
+
+fn set_fpic(
+    map: &mut IndigenousEcoCorridorMap,
+    id: &CorridorId,
+    status: FpicStatus,
+) -> Result<(), String> {
+    let mut corridor = lookup(map, id)?.clone();
+    corridor.fpic = status;
+    map.upsert_corridor(corridor)
+}
+
+fn lookup<'a>(
+    map: &'a IndigenousEcoCorridorMap,
+    id: &CorridorId,
+) -> Result<&'a IndigenousEcoCorridor, String> {
+    map.get(id)
+        .ok_or_else(|| format!("CorridorId '{}' is not registered", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CommunityId, Consent, ConsentQuorum, FpicPurpose};
+    use crate::test_support::{self, uniform_metrics};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn what_if_reports_changes_without_mutating() {
+        let id = CorridorId::new("eco:wetland:w").unwrap();
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(IndigenousEcoCorridor {
+            environmental: uniform_metrics(0.8),
+            fpic: FpicStatus::Granted {
+                at: SystemTime::UNIX_EPOCH,
+                quorum: ConsentQuorum::sole(
+                    CommunityId("community:w".into()),
+                    Consent {
+                        at: SystemTime::now(),
+                        reference: "consent:1".into(),
+                        delegation: None,
+                    },
+                ),
+                terms_reference: "terms:w".into(),
+                purposes: BTreeSet::from([FpicPurpose::Monitoring]),
+                valid_until: None,
+                renewal_notice: None,
+                terms: None,
+            },
+            ..test_support::corridor(id.as_str())
+        })
+        .unwrap();
+
+        let hypotheticals: Vec<Hypothetical> = serde_json::from_str(
+            r#"[
+                {"change": "set_metric", "corridor_id": "eco:wetland:w",
+                 "component": "water_quality", "value": 0.4},
+                {"change": "revoke_fpic", "corridor_id": "eco:wetland:w",
+                 "reason": "council vote"}
+            ]"#,
+        )
+        .unwrap();
+        let report = map.what_if(&hypotheticals).unwrap();
+
+        assert_eq!(report.changes.len(), 1);
+        let change = &report.changes[0];
+        assert!(change.before.active_grant && !change.after.active_grant);
+        assert_eq!(change.after.fpic, FpicStatusKind::Revoked);
+        assert!(report.mean_score_after.unwrap().get() < report.mean_score_before.unwrap().get());
+        assert!(map.get(&id).unwrap().has_active_fpic(SystemTime::now()));
+
+        let unknown = Hypothetical::RevokeFpic {
+            corridor_id: CorridorId::new("eco:wetland:none").unwrap(),
+            reason: String::new(),
+        };
+        assert!(map.what_if(&[unknown]).is_err());
+    }
+
+    #[test]
+    fn set_metric_keeps_unmeasured_components_excluded() {
+        let id = CorridorId::new("eco:wetland:u").unwrap();
+        let mut corridor = IndigenousEcoCorridor {
+            environmental: uniform_metrics(0.8),
+            ..test_support::corridor(id.as_str())
+        };
+        let placeholder = MetricComponent::SoilFertility;
+        corridor
+            .environmental
+            .set_component(placeholder, Score::new(0.0).unwrap());
+        corridor.environmental.unmeasured.insert(placeholder);
+        let mut map = IndigenousEcoCorridorMap::new();
+        map.upsert_corridor(corridor).unwrap();
+
+        let report = map
+            .what_if(&[Hypothetical::SetMetric {
+                corridor_id: id.clone(),
+                component: MetricComponent::WaterQuality,
+                value: 0.8,
+            }])
+            .unwrap();
+        assert!(report.changes.is_empty(), "{:?}", report.changes);
+        assert_eq!(report.mean_score_after, report.mean_score_before);
+    }
+}
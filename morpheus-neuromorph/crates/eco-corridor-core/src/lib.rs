@@ -1,110 +1,330 @@
 #![forbid(unsafe_code)]
 
+use std::fmt;
+use std::time::SystemTime;
+
 use serde::{Deserialize, Serialize};
 
 /// Logical identifier for a living Indigenous eco-corridor, not a static border.
-/// This must be non-empty and resolvable in the corridor/ALN registry (checked at runtime
-/// by higher layers; here it is structurally non-optional). [file:4][file:3]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CorridorId(pub String);
-
-impl CorridorId {
-    pub fn new(id: impl Into<String>) -> Self {
-        let s = id.into();
-        assert!(
-            !s.trim().is_empty(),
-            "CorridorId must be non-empty and resolvable (no-corridor, no-build invariant)."
-        );
-        Self(s)
+/// The canonical type from `corridor-types`, shared with the corridor map, so
+/// IDs normalize and compare the same across crates. [file:4][file:3]
+pub use corridor_types::CorridorId;
+
+/// Community identifier shared with the corridor map's governance module.
+pub use corridor_types::CommunityId;
+
+/// Stable denial codes shared with the other corridor crates.
+pub use corridor_types::DenyCode;
+
+/// Post-construction validation shared with the other corridor crates;
+/// records deserialized from JSON bypass `new` and should be re-checked.
+pub use corridor_types::{Finding, Validate};
+
+use corridor_types::validate::{check_unit_interval, into_result};
+
+/// Eco-impact scores, FPIC status and neurorights capsule: the canonical
+/// types from `corridor-types`, which the corridor map converts its own
+/// models to and from.
+pub use corridor_types::{
+    EcoImpactMetrics, FpicGrant, FpicPurpose, FpicStatus, GeographicScope,
+    NeurorightsConstraints, Timestamp,
+};
+
+/// Folds `EcoImpactMetrics` into one advisory score in [0.0, 1.0].
+/// Weighting is corridor-specific by design, so callers choose the
+/// strategy for each corridor; like `aggregate`, never for actuation. [file:4][file:1]
+pub trait EcoAggregator {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> f32;
+}
+
+/// Every score counts equally, as in `EcoImpactMetrics::aggregate`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnweightedMean;
+
+impl EcoAggregator for UnweightedMean {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> f32 {
+        metrics.aggregate()
     }
 }
 
-/// Eco-impact metrics over soil, water, microbiomes, and biodiversity.
-/// All values are normalized to 0.0–1.0 where 1.0 is least harm / best observed state,
-/// matching your SNC EcoImpact patterns. [file:4][file:3]
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct EcoImpactMetrics {
-    pub soil_score: f32,
-    pub water_score: f32,
-    pub microbiome_score: f32,
-    pub biodiversity_score: f32,
-}
-
-impl EcoImpactMetrics {
-    pub fn new(soil: f32, water: f32, micro: f32, bio: f32) -> Self {
-        fn clamp01(x: f32) -> f32 {
-            if x < 0.0 {
-                0.0
-            } else if x > 1.0 {
-                1.0
-            } else {
-                x
-            }
+/// Mean with a non-negative weight per score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedMean {
+    weights: [f32; 4],
+}
+
+impl WeightedMean {
+    /// Weights for soil, water, microbiome and biodiversity, in that order;
+    /// they must be finite and non-negative, and not all zero.
+    pub fn new(weights: [f32; 4]) -> Result<Self, String> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("aggregation weights must be finite and non-negative".into());
+        }
+        if weights.iter().all(|w| *w == 0.0) {
+            return Err("aggregation weights must not all be zero".into());
+        }
+        Ok(Self { weights })
+    }
+}
+
+impl EcoAggregator for WeightedMean {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> f32 {
+        let total: f32 = self.weights.iter().sum();
+        let weighted: f32 = metrics
+            .scores()
+            .iter()
+            .zip(self.weights)
+            .map(|(score, weight)| score * weight)
+            .sum();
+        (weighted / total).clamp(0.0, 1.0)
+    }
+}
+
+/// The worst score decides: a corridor is only as healthy as its
+/// weakest system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinimumComponent;
+
+impl EcoAggregator for MinimumComponent {
+    fn aggregate(&self, metrics: &EcoImpactMetrics) -> f32 {
+        metrics.scores().into_iter().fold(1.0, f32::min)
+    }
+}
+
+/// Advisory risk classification of a corridor record. Serializes as its
+/// stable `code`; dashboards localize via `message_key`. [file:4]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLabel {
+    BlockedFpicWithheld,
+    HoldFpicPending,
+    LowRiskObservational,
+    MediumRiskReview,
+    HighRiskReview,
+}
+
+impl RiskLabel {
+    /// Stable machine code, e.g. "medium_risk_review".
+    pub fn code(self) -> &'static str {
+        match self {
+            RiskLabel::BlockedFpicWithheld => "blocked_fpic_withheld",
+            RiskLabel::HoldFpicPending => "hold_fpic_pending",
+            RiskLabel::LowRiskObservational => "low_risk_observational",
+            RiskLabel::MediumRiskReview => "medium_risk_review",
+            RiskLabel::HighRiskReview => "high_risk_review",
         }
+    }
+
+    /// Code of the denial or hold this label stands for; `None` for the
+    /// review bands, which are advice rather than denials.
+    pub fn deny_code(self) -> Option<DenyCode> {
+        match self {
+            RiskLabel::BlockedFpicWithheld => Some(DenyCode::FpicWithheld),
+            RiskLabel::HoldFpicPending => Some(DenyCode::FpicPending),
+            RiskLabel::LowRiskObservational
+            | RiskLabel::MediumRiskReview
+            | RiskLabel::HighRiskReview => None,
+        }
+    }
 
+    /// Key of the translated message, e.g. "eco_corridor.risk.medium_risk_review".
+    pub fn message_key(self) -> String {
+        format!("eco_corridor.risk.{}", self.code())
+    }
+}
+
+impl fmt::Display for RiskLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// An aggregate eco score at or above `min_aggregate` earns `label`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskBand {
+    pub min_aggregate: f32,
+    pub label: RiskLabel,
+}
+
+/// Eco-score bands for corridors with granted FPIC, highest threshold
+/// first; scores below every band are `HighRiskReview`. [file:4]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskBandConfig {
+    bands: Vec<RiskBand>,
+}
+
+impl RiskBandConfig {
+    /// Thresholds must be within [0.0, 1.0]; bands are sorted highest first.
+    pub fn new(mut bands: Vec<RiskBand>) -> Result<Self, String> {
+        if let Some(band) = bands
+            .iter()
+            .find(|b| !(0.0..=1.0).contains(&b.min_aggregate))
+        {
+            return Err(format!(
+                "risk band {} threshold must be within [0.0, 1.0]",
+                band.label
+            ));
+        }
+        bands.sort_by(|a, b| b.min_aggregate.total_cmp(&a.min_aggregate));
+        Ok(Self { bands })
+    }
+
+    pub fn bands(&self) -> &[RiskBand] {
+        &self.bands
+    }
+
+    /// The label for a granted corridor with aggregate score `eco`.
+    pub fn classify(&self, eco: f32) -> RiskLabel {
+        self.bands
+            .iter()
+            .find(|b| eco >= b.min_aggregate)
+            .map_or(RiskLabel::HighRiskReview, |b| b.label)
+    }
+}
+
+impl Default for RiskBandConfig {
+    /// The historical cutoffs: 0.8 low risk, 0.5 medium risk.
+    fn default() -> Self {
         Self {
-            soil_score: clamp01(soil),
-            water_score: clamp01(water),
-            microbiome_score: clamp01(micro),
-            biodiversity_score: clamp01(bio),
+            bands: vec![
+                RiskBand {
+                    min_aggregate: 0.8,
+                    label: RiskLabel::LowRiskObservational,
+                },
+                RiskBand {
+                    min_aggregate: 0.5,
+                    label: RiskLabel::MediumRiskReview,
+                },
+            ],
         }
     }
+}
 
-    /// Simple aggregate, used only for advisory scoring / classification.
-    /// This must NEVER be used to drive actuators or automatic land-use changes. [file:4][file:1]
-    pub fn aggregate(&self) -> f32 {
-        (self.soil_score
-            + self.water_score
-            + self.microbiome_score
-            + self.biodiversity_score)
-            / 4.0
+/// Who asserted a corridor record, when, and on what basis. [file:4][file:3]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// DID of the author asserting the record (e.g., a community data steward).
+    pub author_did: String,
+    pub created_at: SystemTime,
+    pub updated_at: SystemTime,
+    /// Optional reference to the source the record was derived from
+    /// (survey, ledger entry, monitoring dataset). [file:4]
+    pub source_ref: Option<String>,
+}
+
+impl Provenance {
+    /// Provenance for a record first asserted at `at`.
+    pub fn new(author_did: impl Into<String>, at: SystemTime, source_ref: Option<String>) -> Self {
+        Self {
+            author_did: author_did.into(),
+            created_at: at,
+            updated_at: at,
+            source_ref,
+        }
     }
 }
 
-/// FPIC / Indigenous Data Sovereignty status, treated as a mandatory, schema-level
-/// precondition for any use of corridor-linked data. [file:4][file:3]
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub enum FpicStatus {
-    /// No FPIC decision or scope established for this corridor/context.
-    Pending,
-    /// Explicit FPIC granted, with optional scope / conditions reference.
-    Granted {
-        /// External reference (e.g., ALN shard / ledger entry) describing scope,
-        /// conditions, and revocation rules. [file:4]
-        consent_ref: String,
-    },
-    /// FPIC withheld or revoked, with human-readable reason / link.
-    Withheld {
-        reason: String,
-    },
-}
-
-/// Minimal neurorights constraint capsule for corridor-linked knowledge objects.
-/// Importantly, this is **purely declarative** metadata: it has no methods that
-/// change state or actuate anything. It only labels obligations. [file:3][file:1]
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct NeurorightsConstraints {
-    /// Mental privacy: no covert inference from corridor data into inner mental state. [file:4][file:3]
-    pub mental_privacy_protection: bool,
-    /// Explicit prohibition on coercive neuromorphic channels linked to this corridor. [file:4][file:3]
-    pub forbid_coercive_channels: bool,
-    /// Explicit prohibition on downgrades/rollbacks of rights or capabilities as a result
-    /// of any analysis of this corridor. [file:3][file:1]
-    pub forbid_downgrade_or_rollback: bool,
-    /// True iff any discipline (FEAR/PAIN) signals tied to this corridor are explicitly
-    /// voluntary, labeled, and non-propagating to non-participants. [file:4][file:3]
-    pub discipline_personalized_and_noncoercive: bool,
-}
-
-impl NeurorightsConstraints {
-    /// Helper to construct a "strict floor" neurorights capsule, suitable as a
-    /// default for Indigenous Eco-Corridor Map objects. [file:4][file:3]
-    pub fn strict_floor() -> Self {
+/// How widely a cultural knowledge reference may be shown; the same
+/// levels as cultural sites in the corridor map. [file:4]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessSensitivity {
+    Public,
+    /// Members of the steward community only.
+    CommunityOnly,
+    /// Designated knowledge holders of the steward community only.
+    Restricted,
+}
+
+/// Who is asking for a cultural knowledge reference, and under what
+/// authorization; declared by the caller and recorded, not verified here.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationContext {
+    /// Community the caller acts for.
+    pub acting_for: CommunityId,
+    /// Reference to the authorization, e.g. a knowledge-holder designation
+    /// on the community's ledger.
+    pub authorization_ref: String,
+}
+
+/// Why a cultural knowledge reference was not exposed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KnowledgeAccessError {
+    /// The reference is not public and no authorization was declared.
+    AuthorizationRequired(AccessSensitivity),
+    /// The declared authorization does not come from the steward.
+    NotSteward { steward: CommunityId },
+}
+
+impl fmt::Display for KnowledgeAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnowledgeAccessError::AuthorizationRequired(level) => write!(
+                f,
+                "{:?} cultural knowledge needs a declared authorization",
+                level
+            ),
+            KnowledgeAccessError::NotSteward { steward } => write!(
+                f,
+                "only authorizations from steward '{}' expose this reference",
+                steward.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KnowledgeAccessError {}
+
+/// Typed pointer to Facecloud / cultural knowledge, co-authored and governed
+/// by its steward community. The reference itself is only handed out
+/// through `reference`, which enforces the sensitivity level. [file:4]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CulturalKnowledgeRef {
+    reference: String,
+    pub sensitivity: AccessSensitivity,
+    pub steward: CommunityId,
+    /// Lowercase hex SHA-256 of the referenced content, so holders can
+    /// tell whether it changed without reading it.
+    pub content_hash: String,
+}
+
+impl CulturalKnowledgeRef {
+    pub fn new(
+        reference: impl Into<String>,
+        sensitivity: AccessSensitivity,
+        steward: CommunityId,
+        content_hash: impl Into<String>,
+    ) -> Self {
         Self {
-            mental_privacy_protection: true,
-            forbid_coercive_channels: true,
-            forbid_downgrade_or_rollback: true,
-            discipline_personalized_and_noncoercive: true,
+            reference: reference.into(),
+            sensitivity,
+            steward,
+            content_hash: content_hash.into(),
+        }
+    }
+
+    /// The reference, if `authorization` allows it: public references
+    /// always; others only for a declared authorization from the steward.
+    pub fn reference(
+        &self,
+        authorization: Option<&AuthorizationContext>,
+    ) -> Result<&str, KnowledgeAccessError> {
+        if self.sensitivity == AccessSensitivity::Public {
+            return Ok(&self.reference);
+        }
+        match authorization {
+            Some(auth) if auth.authorization_ref.trim().is_empty() => Err(
+                KnowledgeAccessError::AuthorizationRequired(self.sensitivity),
+            ),
+            Some(auth) if auth.acting_for != self.steward => {
+                Err(KnowledgeAccessError::NotSteward {
+                    steward: self.steward.clone(),
+                })
+            }
+            Some(_) => Ok(&self.reference),
+            None => Err(KnowledgeAccessError::AuthorizationRequired(
+                self.sensitivity,
+            )),
         }
     }
 }
@@ -112,7 +332,7 @@ impl NeurorightsConstraints {
 /// Core, non-actuating Indigenous Eco-Corridor record.
 /// This is an observational/advisory knowledge object only:
 /// - It encodes corridor identity, eco-impact metrics, FPIC/IDS status,
-///   and neurorights constraints as **mandatory** fields.
+///   neurorights constraints and provenance as **mandatory** fields.
 /// - It exposes no actuation or control methods (no device or policy control).
 /// - Higher-risk layers (e.g., Tribal Survival Protocol Envelope) must treat this
 ///   as read-only input, never as a controller. [file:4][file:3][file:1]
@@ -124,40 +344,44 @@ pub struct IndigenousEcoCorridorRecord {
     pub neurorights: NeurorightsConstraints,
     /// Optional Facecloud / cultural knowledge reference, co-authored and governed
     /// externally; presence here never authorizes actuation. [file:4]
-    pub facecloud_ref: Option<String>,
+    pub cultural_knowledge: Option<CulturalKnowledgeRef>,
+    pub provenance: Provenance,
 }
 
 impl IndigenousEcoCorridorRecord {
     /// Constructor enforces presence of all core fields at the type level.
-    /// Any missing corridor ID, EcoImpact, FPIC, or neurorights capsule is
-    /// unrepresentable as a valid record. [file:3][file:4]
+    /// Any missing corridor ID, EcoImpact, FPIC, neurorights capsule or
+    /// provenance is unrepresentable as a valid record. [file:3][file:4]
     pub fn new(
         corridor_id: CorridorId,
         eco_impact: EcoImpactMetrics,
         fpic_status: FpicStatus,
         neurorights: NeurorightsConstraints,
-        facecloud_ref: Option<String>,
+        cultural_knowledge: Option<CulturalKnowledgeRef>,
+        provenance: Provenance,
     ) -> Self {
         Self {
             corridor_id,
             eco_impact,
             fpic_status,
             neurorights,
-            facecloud_ref,
+            cultural_knowledge,
+            provenance,
         }
     }
 
     /// Purely advisory classification helper, suitable for dashboards or audits.
     /// This MUST NOT be wired to any automatic enforcement or actuation path. [file:4][file:1]
-    pub fn advisory_risk_label(&self) -> &'static str {
-        let eco = self.eco_impact.aggregate();
-
-        match (&self.fpic_status, eco) {
-            (FpicStatus::Withheld { .. }, _) => "blocked_fpic_withheld",
-            (FpicStatus::Pending, _) => "hold_fpic_pending",
-            (FpicStatus::Granted { .. }, e) if e >= 0.8 => "low_risk_observational",
-            (FpicStatus::Granted { .. }, e) if e >= 0.5 => "medium_risk_review",
-            _ => "high_risk_review",
+    /// `aggregator` folds the eco-impact scores the bands classify.
+    pub fn advisory_risk_label(
+        &self,
+        config: &RiskBandConfig,
+        aggregator: &dyn EcoAggregator,
+    ) -> RiskLabel {
+        match &self.fpic_status {
+            FpicStatus::Withheld { .. } => RiskLabel::BlockedFpicWithheld,
+            FpicStatus::Pending => RiskLabel::HoldFpicPending,
+            FpicStatus::Granted(_) => config.classify(aggregator.aggregate(&self.eco_impact)),
         }
     }
 }
@@ -170,7 +394,12 @@ pub trait EcoCorridorView {
     fn eco_impact(&self) -> &EcoImpactMetrics;
     fn fpic_status(&self) -> &FpicStatus;
     fn neurorights(&self) -> &NeurorightsConstraints;
-    fn advisory_risk_label(&self) -> &'static str;
+    fn provenance(&self) -> &Provenance;
+    fn advisory_risk_label(
+        &self,
+        config: &RiskBandConfig,
+        aggregator: &dyn EcoAggregator,
+    ) -> RiskLabel;
 }
 
 impl EcoCorridorView for IndigenousEcoCorridorRecord {
@@ -190,7 +419,440 @@ impl EcoCorridorView for IndigenousEcoCorridorRecord {
         &self.neurorights
     }
 
-    fn advisory_risk_label(&self) -> &'static str {
-        self.advisory_risk_label()
+    fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    fn advisory_risk_label(
+        &self,
+        config: &RiskBandConfig,
+        aggregator: &dyn EcoAggregator,
+    ) -> RiskLabel {
+        self.advisory_risk_label(config, aggregator)
+    }
+}
+
+/// Read-only view of how a corridor's eco-impact and FPIC states changed
+/// over time, so governance layers can evaluate policies against the state
+/// that held at decision time rather than the latest snapshot. [file:3][file:4]
+pub trait EcoCorridorTimelineView: EcoCorridorView {
+    /// Eco-impact metrics in effect at `t`, or `None` before any were recorded.
+    fn eco_impact_at(&self, t: SystemTime) -> Option<&EcoImpactMetrics>;
+    /// FPIC status in effect at `t`, or `None` before any was recorded.
+    fn fpic_status_at(&self, t: SystemTime) -> Option<&FpicStatus>;
+}
+
+/// A value and the time from which it held.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Timestamped<T> {
+    pub since: SystemTime,
+    pub value: T,
+}
+
+/// A corridor record together with the history of its eco-impact metrics
+/// and FPIC status. The record always carries the latest values. [file:4]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CorridorTimeline {
+    record: IndigenousEcoCorridorRecord,
+    eco_impact_history: Vec<Timestamped<EcoImpactMetrics>>,
+    fpic_history: Vec<Timestamped<FpicStatus>>,
+}
+
+impl CorridorTimeline {
+    /// Start a timeline whose first entries are the record's current
+    /// values, in effect from its `provenance.updated_at`.
+    pub fn new(record: IndigenousEcoCorridorRecord) -> Self {
+        let since = record.provenance.updated_at;
+        Self {
+            eco_impact_history: vec![Timestamped {
+                since,
+                value: record.eco_impact.clone(),
+            }],
+            fpic_history: vec![Timestamped {
+                since,
+                value: record.fpic_status.clone(),
+            }],
+            record,
+        }
+    }
+
+    pub fn record(&self) -> &IndigenousEcoCorridorRecord {
+        &self.record
+    }
+
+    /// Record new eco-impact metrics from `at`, which must not precede the
+    /// record's last update.
+    pub fn push_eco_impact(
+        &mut self,
+        at: SystemTime,
+        eco_impact: EcoImpactMetrics,
+    ) -> Result<(), String> {
+        self.check_order(at)?;
+        self.record.eco_impact = eco_impact.clone();
+        self.record.provenance.updated_at = at;
+        self.eco_impact_history.push(Timestamped {
+            since: at,
+            value: eco_impact,
+        });
+        Ok(())
+    }
+
+    /// Record a new FPIC status from `at`, which must not precede the
+    /// record's last update.
+    pub fn push_fpic_status(
+        &mut self,
+        at: SystemTime,
+        fpic_status: FpicStatus,
+    ) -> Result<(), String> {
+        self.check_order(at)?;
+        self.record.fpic_status = fpic_status.clone();
+        self.record.provenance.updated_at = at;
+        self.fpic_history.push(Timestamped {
+            since: at,
+            value: fpic_status,
+        });
+        Ok(())
+    }
+
+    fn check_order(&self, at: SystemTime) -> Result<(), String> {
+        if at < self.record.provenance.updated_at {
+            return Err("timeline entries must be recorded in time order".into());
+        }
+        Ok(())
+    }
+
+    fn value_at<T>(history: &[Timestamped<T>], t: SystemTime) -> Option<&T> {
+        history
+            .iter()
+            .rev()
+            .find(|e| e.since <= t)
+            .map(|e| &e.value)
+    }
+}
+
+impl EcoCorridorView for CorridorTimeline {
+    fn corridor_id(&self) -> &CorridorId {
+        self.record.corridor_id()
+    }
+
+    fn eco_impact(&self) -> &EcoImpactMetrics {
+        self.record.eco_impact()
+    }
+
+    fn fpic_status(&self) -> &FpicStatus {
+        self.record.fpic_status()
+    }
+
+    fn neurorights(&self) -> &NeurorightsConstraints {
+        self.record.neurorights()
+    }
+
+    fn provenance(&self) -> &Provenance {
+        self.record.provenance()
+    }
+
+    fn advisory_risk_label(
+        &self,
+        config: &RiskBandConfig,
+        aggregator: &dyn EcoAggregator,
+    ) -> RiskLabel {
+        self.record.advisory_risk_label(config, aggregator)
+    }
+}
+
+impl EcoCorridorTimelineView for CorridorTimeline {
+    fn eco_impact_at(&self, t: SystemTime) -> Option<&EcoImpactMetrics> {
+        Self::value_at(&self.eco_impact_history, t)
+    }
+
+    fn fpic_status_at(&self, t: SystemTime) -> Option<&FpicStatus> {
+        Self::value_at(&self.fpic_history, t)
+    }
+}
+
+impl Validate for Provenance {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Vec::new();
+        if !self.author_did.starts_with("did:") {
+            findings.push(Finding::new("author_did", "author must be a DID"));
+        }
+        if self.updated_at < self.created_at {
+            findings.push(Finding::new(
+                "updated_at",
+                "record updated before it was created",
+            ));
+        }
+        if self
+            .source_ref
+            .as_deref()
+            .is_some_and(|r| r.trim().is_empty())
+        {
+            findings.push(Finding::new("source_ref", "source reference is blank"));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for CulturalKnowledgeRef {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Vec::new();
+        if self.reference.trim().is_empty() {
+            findings.push(Finding::new("reference", "reference is blank"));
+        }
+        if let Err(e) = self.steward.did() {
+            findings.push(Finding::new("steward", e));
+        }
+        if self.content_hash.len() != 64
+            || !self
+                .content_hash
+                .bytes()
+                .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            findings.push(Finding::new(
+                "content_hash",
+                "content hash must be 64 lowercase hex digits",
+            ));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for IndigenousEcoCorridorRecord {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Finding::within("eco_impact", self.eco_impact.validate());
+        findings.extend(Finding::within("fpic_status", self.fpic_status.validate()));
+        findings.extend(Finding::within("neurorights", self.neurorights.validate()));
+        findings.extend(Finding::within("provenance", self.provenance.validate()));
+        if let Some(knowledge) = &self.cultural_knowledge {
+            findings.extend(Finding::within("cultural_knowledge", knowledge.validate()));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for RiskBandConfig {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings: Vec<Finding> = self
+            .bands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, b)| check_unit_interval(&format!("bands.{}", i), b.min_aggregate))
+            .collect();
+        if self
+            .bands
+            .windows(2)
+            .any(|w| w[0].min_aggregate < w[1].min_aggregate)
+        {
+            findings.push(Finding::new(
+                "bands",
+                "bands must be ordered highest threshold first",
+            ));
+        }
+        into_result(findings)
+    }
+}
+
+impl Validate for CorridorTimeline {
+    fn validate(&self) -> Result<(), Vec<Finding>> {
+        let mut findings = Finding::within("record", self.record.validate());
+        if self
+            .eco_impact_history
+            .windows(2)
+            .any(|w| w[1].since < w[0].since)
+        {
+            findings.push(Finding::new(
+                "eco_impact_history",
+                "entries are out of time order",
+            ));
+        }
+        if self
+            .fpic_history
+            .windows(2)
+            .any(|w| w[1].since < w[0].since)
+        {
+            findings.push(Finding::new(
+                "fpic_history",
+                "entries are out of time order",
+            ));
+        }
+        for (i, entry) in self.eco_impact_history.iter().enumerate() {
+            findings.extend(Finding::within(
+                &format!("eco_impact_history.{}", i),
+                entry.value.validate(),
+            ));
+        }
+        into_result(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn risk_bands_are_configurable_with_stable_codes() {
+        let record = IndigenousEcoCorridorRecord::new(
+            CorridorId::new("did:example:corridor:gila-river").unwrap(),
+            EcoImpactMetrics::new(0.7, 0.7, 0.7, 0.7),
+            FpicStatus::Granted(
+                FpicGrant::new("aln:consent:1", [FpicPurpose::Monitoring])
+                    .expires_at(Timestamp::UNIX_EPOCH + std::time::Duration::from_secs(3600))
+                    .geographic_scope(GeographicScope {
+                        min: [-112.5, 33.0],
+                        max: [-111.5, 33.5],
+                    }),
+            ),
+            NeurorightsConstraints::strict_floor(),
+            Some(CulturalKnowledgeRef::new(
+                "facecloud:knowledge:gila:7",
+                AccessSensitivity::Restricted,
+                CommunityId::new("did:web:gila.example").unwrap(),
+                "ab".repeat(32),
+            )),
+            Provenance::new(
+                "did:example:steward:gila",
+                SystemTime::UNIX_EPOCH,
+                Some("survey:2026-04".into()),
+            ),
+        );
+        assert_eq!(
+            record.provenance().created_at,
+            record.provenance().updated_at
+        );
+        let label = record.advisory_risk_label(&RiskBandConfig::default(), &UnweightedMean);
+        assert_eq!(label, RiskLabel::MediumRiskReview);
+        assert_eq!(label.message_key(), "eco_corridor.risk.medium_risk_review");
+        assert_eq!(label.deny_code(), None);
+
+        let strict = RiskBandConfig::new(vec![
+            RiskBand {
+                min_aggregate: 0.75,
+                label: RiskLabel::MediumRiskReview,
+            },
+            RiskBand {
+                min_aggregate: 0.9,
+                label: RiskLabel::LowRiskObservational,
+            },
+        ])
+        .unwrap();
+        assert_eq!(
+            record.advisory_risk_label(&strict, &UnweightedMean),
+            RiskLabel::HighRiskReview
+        );
+        assert_eq!(strict.bands()[0].label, RiskLabel::LowRiskObservational);
+
+        let uneven = IndigenousEcoCorridorRecord {
+            eco_impact: EcoImpactMetrics::new(0.9, 0.9, 0.9, 0.3),
+            ..record.clone()
+        };
+        let bands = RiskBandConfig::default();
+        let classify =
+            |aggregator: &dyn EcoAggregator| uneven.advisory_risk_label(&bands, aggregator);
+        assert_eq!(classify(&UnweightedMean), RiskLabel::MediumRiskReview);
+        assert_eq!(classify(&MinimumComponent), RiskLabel::HighRiskReview);
+        let without_biodiversity = WeightedMean::new([1.0, 1.0, 1.0, 0.0]).unwrap();
+        assert_eq!(
+            classify(&without_biodiversity),
+            RiskLabel::LowRiskObservational
+        );
+        assert_eq!(record.validate(), Ok(()));
+
+        let knowledge = record.cultural_knowledge.as_ref().unwrap();
+        let holder = AuthorizationContext {
+            acting_for: knowledge.steward.clone(),
+            authorization_ref: "gila:designation:3".into(),
+        };
+        assert_eq!(
+            knowledge.reference(None),
+            Err(KnowledgeAccessError::AuthorizationRequired(
+                AccessSensitivity::Restricted
+            ))
+        );
+        assert!(matches!(
+            knowledge.reference(Some(&AuthorizationContext {
+                acting_for: CommunityId::new("did:web:other.example").unwrap(),
+                ..holder.clone()
+            })),
+            Err(KnowledgeAccessError::NotSteward { .. })
+        ));
+        assert_eq!(
+            knowledge.reference(Some(&holder)),
+            Ok("facecloud:knowledge:gila:7")
+        );
+
+        let t = Timestamp::UNIX_EPOCH;
+        assert!(record.fpic_status.permits(t, FpicPurpose::Monitoring));
+        assert!(!record.fpic_status.permits(t, FpicPurpose::Infrastructure));
+        assert!(!record
+            .fpic_status
+            .is_active_grant(t + std::time::Duration::from_secs(3600)));
+        let FpicStatus::Granted(grant) = &record.fpic_status else {
+            unreachable!()
+        };
+        assert!(grant.covers_point([-112.0, 33.25]));
+        assert!(!grant.covers_point([-110.0, 33.25]));
+        let legacy: FpicStatus =
+            serde_json::from_str(r#"{"Granted": {"consent_ref": "aln:consent:0"}}"#).unwrap();
+        let paths: Vec<_> = legacy
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|f| f.path)
+            .collect();
+        assert_eq!(paths, ["purposes"]);
+
+        let unsorted: RiskBandConfig = serde_json::from_str(
+            r#"{"bands": [
+                {"min_aggregate": 0.5, "label": "medium_risk_review"},
+                {"min_aggregate": 1.5, "label": "low_risk_observational"}
+            ]}"#,
+        )
+        .unwrap();
+        let paths: Vec<_> = unsorted
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|f| f.path)
+            .collect();
+        assert_eq!(paths, ["bands.1", "bands"]);
+    }
+
+    #[test]
+    fn timeline_answers_with_the_state_at_decision_time() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + std::time::Duration::from_secs(3600);
+        let record = IndigenousEcoCorridorRecord::new(
+            CorridorId::new("did:example:corridor:gila-river").unwrap(),
+            EcoImpactMetrics::new(0.9, 0.9, 0.9, 0.9),
+            FpicStatus::Pending,
+            NeurorightsConstraints::strict_floor(),
+            None,
+            Provenance::new("did:example:steward:gila", t0, None),
+        );
+        let mut timeline = CorridorTimeline::new(record);
+        timeline
+            .push_fpic_status(
+                t1,
+                FpicStatus::Withheld {
+                    reason: "council vote".into(),
+                },
+            )
+            .unwrap();
+        assert!(matches!(
+            timeline.fpic_status_at(t0),
+            Some(FpicStatus::Pending)
+        ));
+        assert!(matches!(
+            timeline.fpic_status_at(t1),
+            Some(FpicStatus::Withheld { .. })
+        ));
+        assert!(matches!(
+            timeline.fpic_status(),
+            FpicStatus::Withheld { .. }
+        ));
+        assert_eq!(timeline.eco_impact_at(t1).unwrap().soil_score, 0.9);
+        assert!(timeline
+            .push_eco_impact(t0, EcoImpactMetrics::new(0.1, 0.1, 0.1, 0.1))
+            .is_err());
     }
 }